@@ -0,0 +1,59 @@
+//! Measures the overhead of counting events.
+//!
+//! Run with `cargo bench --bench counters`, optionally with `--features fast-hash`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use counters::filters::{All, StartsWith};
+use counters::Counters;
+
+fn keys() -> Vec<String> {
+    (0..100)
+        .map(|i| format!("module{}::event{}", i % 10, i))
+        .collect()
+}
+
+fn counters() -> Counters {
+    let counters = Counters::new();
+    for key in &keys() {
+        counters.event(key);
+    }
+
+    counters
+}
+
+fn event(c: &mut Criterion) {
+    let keys = keys();
+    let counters = Counters::new();
+    c.bench_function("event x1000", |b| {
+        b.iter(|| {
+            for i in 0..1000 {
+                counters.event(&keys[i % keys.len()]);
+            }
+        })
+    });
+}
+
+fn accumulate(c: &mut Criterion) {
+    let counters = counters();
+    c.bench_function("accumulate", |b| {
+        b.iter(|| counters.accumulate(StartsWith(black_box("module3::"))))
+    });
+}
+
+fn print(c: &mut Criterion) {
+    let counters = counters();
+    let mut output = Vec::new();
+    c.bench_function("print", |b| {
+        b.iter(|| {
+            output.clear();
+            counters.print(All, &mut output).unwrap();
+            black_box(&output);
+        })
+    });
+}
+
+criterion_group!(benches, event, accumulate, print);
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+//! Procedural macros for the `counters` crate, re-exported by it with the
+//! `macros` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{parse_macro_input, Expr, ImplItem, ItemImpl, Type};
+
+/// Counts the calls to each method of an `impl` block.
+///
+/// The argument is an expression evaluating to the `Counters` in which the
+/// events are recorded, at the start of each method. Without an argument the
+/// events are recorded in `counters::global()`. The event key of a method is
+/// `"Type::method"`. If the expression refers to `self`, associated functions
+/// without a `self` parameter are not counted.
+///
+/// ```ignore
+/// #[counted(self.counters)]
+/// impl Renderer {
+///     fn draw(&self) { /* records "Renderer::draw" */ }
+/// }
+///
+/// #[counted]
+/// impl Loader {
+///     fn load(path: &str) { /* records "Loader::load" globally */ }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn counted(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let counters = if attr.is_empty() {
+        syn::parse_quote!(::counters::global())
+    } else {
+        parse_macro_input!(attr as Expr)
+    };
+    let mut item = parse_macro_input!(item as ItemImpl);
+
+    let type_name = match type_name(&item.self_ty) {
+        Some(name) => name,
+        None => {
+            return syn::Error::new_spanned(&item.self_ty, "unsupported type for #[counted]")
+                .to_compile_error()
+                .into();
+        }
+    };
+    let uses_self = mentions_self(quote!(#counters));
+
+    for impl_item in &mut item.items {
+        let method = match impl_item {
+            ImplItem::Fn(method) => method,
+            _ => continue,
+        };
+        if uses_self && method.sig.receiver().is_none() {
+            continue;
+        }
+
+        let key = format!("{}::{}", type_name, method.sig.ident);
+        let stmts = &method.block.stmts;
+        method.block = syn::parse_quote!({
+            {
+                let counters = &#counters;
+                counters.event(#key);
+            }
+            #(#stmts)*
+        });
+    }
+
+    quote!(#item).into()
+}
+
+/// The name of the last segment of a type path, without generic parameters.
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        Type::Reference(reference) => type_name(&reference.elem),
+        _ => None,
+    }
+}
+
+fn mentions_self(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "self",
+        TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
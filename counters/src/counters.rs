@@ -1,8 +1,21 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::io;
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
 use crate::filters::Filter;
+use crate::SampleStats;
+
+/// The map used for all keyed state, with a faster but not DoS-resistant
+/// hasher if the `fast-hash` feature is enabled.
+#[cfg(feature = "fast-hash")]
+type Map<K, V> = HashMap<K, V, fxhash::FxBuildHasher>;
+#[cfg(not(feature = "fast-hash"))]
+type Map<K, V> = HashMap<K, V>;
+
+#[cfg(feature = "backtrace")]
+use std::hash::{Hash, Hasher};
 
 /// Helper to count events for debugging purposes.
 ///
@@ -11,13 +24,112 @@ use crate::filters::Filter;
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
 pub struct Counters {
-    events: RefCell<HashMap<String, u64>>,
+    /// Sorted by key so that the counters of a namespace are next to each other.
+    pub(crate) events: RefCell<BTreeMap<String, u64>>,
+    /// For each key, the counts of `event_with_backtrace` per call stack hash.
+    #[cfg(feature = "backtrace")]
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    stacks: RefCell<Map<String, Map<u64, Stack>>>,
+    /// The recent values of the keys for which the history is enabled.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub(crate) histories: RefCell<Map<String, History>>,
+    /// The distribution of the values passed to `record`.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    histograms: RefCell<Map<String, Histogram>>,
+    /// The statistics of the values passed to `sample`.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    samples: RefCell<Map<String, SampleStats>>,
+    /// The time accumulated by `record_duration`, kept apart from the events
+    /// so that it isn't added to their counts.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub(crate) durations: RefCell<Map<String, Duration>>,
+    /// The timers started with `start` and not stopped yet.
+    #[cfg_attr(feature = "serialization", serde(skip))]
+    started: RefCell<Map<String, Instant>>,
+}
+
+/// A ring buffer of the last values of a counter.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub(crate) struct History {
+    pub(crate) samples: VecDeque<u64>,
+    capacity: usize,
+}
+
+/// The order of the counters in `Counters::print_sorted`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    /// Alphabetical order of the keys.
+    Key,
+    /// Highest values first.
+    Value,
+}
+
+/// The number of values recorded in each bucket of a histogram.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+struct Histogram {
+    /// The exclusive upper bounds of the buckets except the last one, or
+    /// `None` for power of two buckets.
+    bounds: Option<Vec<u64>>,
+    counts: Vec<u64>,
+}
+
+impl Histogram {
+    fn bucket(&self, value: u64) -> usize {
+        match &self.bounds {
+            Some(bounds) => bounds.partition_point(|bound| *bound <= value),
+            None => (64 - value.leading_zeros()) as usize,
+        }
+    }
+
+    fn range(&self, bucket: usize) -> Range<u64> {
+        match &self.bounds {
+            Some(bounds) => {
+                let start = if bucket == 0 { 0 } else { bounds[bucket - 1] };
+                start..bounds.get(bucket).cloned().unwrap_or(u64::MAX)
+            }
+            None if bucket == 0 => 0..1,
+            None => (1 << (bucket - 1))..1u64.checked_shl(bucket as u32).unwrap_or(u64::MAX),
+        }
+    }
+
+    fn add(&mut self, value: u64) {
+        let bucket = self.bucket(value);
+        if self.counts.len() <= bucket {
+            self.counts.resize(bucket + 1, 0);
+        }
+        self.counts[bucket] += 1;
+    }
+
+    fn buckets(&self) -> Vec<(Range<u64>, u64)> {
+        self.counts
+            .iter()
+            .enumerate()
+            .map(|(bucket, count)| (self.range(bucket), *count))
+            .collect()
+    }
+}
+
+/// The number of events recorded with a given call stack.
+#[cfg(feature = "backtrace")]
+#[derive(Clone, Debug)]
+struct Stack {
+    count: u64,
+    backtrace: backtrace::Backtrace,
 }
 
 impl Counters {
     pub fn new() -> Self {
         Counters {
-            events: RefCell::new(HashMap::default()),
+            events: RefCell::new(BTreeMap::new()),
+            #[cfg(feature = "backtrace")]
+            stacks: RefCell::new(Map::default()),
+            histories: RefCell::new(Map::default()),
+            histograms: RefCell::new(Map::default()),
+            samples: RefCell::new(Map::default()),
+            durations: RefCell::new(Map::default()),
+            started: RefCell::new(Map::default()),
         }
     }
 
@@ -26,6 +138,21 @@ impl Counters {
         *self.events.borrow_mut().entry(key.into()).or_insert(0) += 1
     }
 
+    /// Add `n` to the counter for the provided key, for example a number of
+    /// bytes. The counter saturates at `u64::MAX`.
+    pub fn add(&self, key: &str, n: u64) {
+        let mut events = self.events.borrow_mut();
+        let value = events.entry(key.into()).or_insert(0);
+        *value = value.saturating_add(n);
+    }
+
+    /// Subtract `n` from the counter for the provided key, saturating at zero.
+    pub fn sub(&self, key: &str, n: u64) {
+        let mut events = self.events.borrow_mut();
+        let value = events.entry(key.into()).or_insert(0);
+        *value = value.saturating_sub(n);
+    }
+
     /// Set the value of the counter for a given event key.
     pub fn set(&self, key: &str, value: u64) {
         self.events.borrow_mut().insert(key.into(), value);
@@ -34,6 +161,13 @@ impl Counters {
     /// Reset the counter for the provided event key to zero.
     pub fn reset_event(&self, key: &str) {
         self.events.borrow_mut().insert(key.into(), 0);
+        #[cfg(feature = "backtrace")]
+        self.stacks.borrow_mut().remove(key);
+        if let Some(histogram) = self.histograms.borrow_mut().get_mut(key) {
+            histogram.counts.clear();
+        }
+        self.samples.borrow_mut().remove(key);
+        self.durations.borrow_mut().remove(key);
     }
 
     /// Reset some of the counters to zero.
@@ -41,11 +175,21 @@ impl Counters {
         self.events
             .borrow_mut()
             .retain(|key, val| !filter.apply(key, *val));
+        self.retain_stacks();
+        self.clear_histograms();
     }
 
     /// Reset all counters to zero.
     pub fn reset_all(&self) {
         self.events.borrow_mut().clear();
+        self.started.borrow_mut().clear();
+        for histogram in self.histograms.borrow_mut().values_mut() {
+            histogram.counts.clear();
+        }
+        self.samples.borrow_mut().clear();
+        self.durations.borrow_mut().clear();
+        #[cfg(feature = "backtrace")]
+        self.stacks.borrow_mut().clear();
     }
 
     /// Keep some of the counters and throw away the rest.
@@ -53,6 +197,296 @@ impl Counters {
         self.events
             .borrow_mut()
             .retain(|key, val| filter.apply(key, *val));
+        self.retain_stacks();
+        self.clear_histograms();
+    }
+
+    /// Drops the call stacks of the events that were removed.
+    fn retain_stacks(&self) {
+        #[cfg(feature = "backtrace")]
+        {
+            let events = self.events.borrow();
+            self.stacks
+                .borrow_mut()
+                .retain(|key, _| events.contains_key(key));
+        }
+    }
+
+    /// Clears the histograms of the events that were removed, keeping their
+    /// buckets, and drops their sample statistics and durations.
+    fn clear_histograms(&self) {
+        let events = self.events.borrow();
+        for (key, histogram) in self.histograms.borrow_mut().iter_mut() {
+            if !events.contains_key(key) {
+                histogram.counts.clear();
+            }
+        }
+        self.samples
+            .borrow_mut()
+            .retain(|key, _| events.contains_key(key));
+        self.durations
+            .borrow_mut()
+            .retain(|key, _| events.contains_key(key));
+    }
+
+    /// Increment the counter for the provided key and update the count, sum,
+    /// minimum and maximum of the values sampled for it.
+    ///
+    /// `print` shows the statistics after the number of samples.
+    ///
+    /// ```
+    /// use counters::Counters;
+    ///
+    /// let counters = Counters::new();
+    /// for tiles in [4, 10, 1] {
+    ///     counters.sample("tiles per draw", tiles);
+    /// }
+    ///
+    /// let stats = counters.stats("tiles per draw").unwrap();
+    /// assert_eq!((stats.count, stats.sum, stats.min, stats.max), (3, 15, 1, 10));
+    /// assert_eq!(stats.mean(), 5.0);
+    /// ```
+    pub fn sample(&self, key: &str, value: u64) {
+        self.event(key);
+        let mut samples = self.samples.borrow_mut();
+        match samples.get_mut(key) {
+            Some(stats) => stats.add(&SampleStats::new(value)),
+            None => {
+                samples.insert(key.into(), SampleStats::new(value));
+            }
+        }
+    }
+
+    /// Get the statistics of the values passed to `sample` for the provided
+    /// key, if any.
+    pub fn stats(&self, key: &str) -> Option<SampleStats> {
+        self.samples.borrow().get(key).cloned()
+    }
+
+    /// Increment the counter for the provided key and add `value` to its
+    /// histogram.
+    ///
+    /// Values are grouped in power of two buckets unless other buckets are
+    /// set with `set_histogram_buckets`. `print` shows the distribution of
+    /// the values after the number of calls.
+    ///
+    /// ```
+    /// use counters::Counters;
+    ///
+    /// let counters = Counters::new();
+    /// counters.set_histogram_buckets("batch_size", &[10, 100]);
+    /// for size in [3, 5, 40, 200] {
+    ///     counters.record("batch_size", size);
+    /// }
+    ///
+    /// assert_eq!(counters.get("batch_size"), 4);
+    /// assert_eq!(
+    ///     counters.histogram("batch_size"),
+    ///     vec![(0..10, 2), (10..100, 1), (100..u64::MAX, 1)],
+    /// );
+    /// ```
+    pub fn record(&self, key: &str, value: u64) {
+        self.event(key);
+        self.histograms
+            .borrow_mut()
+            .entry(key.into())
+            .or_insert_with(|| Histogram {
+                bounds: None,
+                counts: Vec::new(),
+            })
+            .add(value);
+    }
+
+    /// Set the buckets of the histogram for the provided key, from their
+    /// upper bounds. The last bucket contains the values greater or equal to
+    /// the last bound.
+    ///
+    /// Values already recorded for this key are discarded.
+    pub fn set_histogram_buckets(&self, key: &str, bounds: &[u64]) {
+        let mut bounds = bounds.to_vec();
+        bounds.sort_unstable();
+        bounds.dedup();
+        self.histograms.borrow_mut().insert(
+            key.into(),
+            Histogram {
+                bounds: Some(bounds),
+                counts: Vec::new(),
+            },
+        );
+    }
+
+    /// Get the range and number of values of each bucket of the histogram for
+    /// the provided key, up to the last non-empty bucket.
+    pub fn histogram(&self, key: &str) -> Vec<(Range<u64>, u64)> {
+        self.histograms
+            .borrow()
+            .get(key)
+            .map(Histogram::buckets)
+            .unwrap_or_default()
+    }
+
+    /// Increment the counter for the provided event key, and the count of the
+    /// current call stack for this key.
+    ///
+    /// This is much slower than `event`, since the call stack is walked each
+    /// time. Use `print_stacks` to find out where the events come from.
+    #[cfg(feature = "backtrace")]
+    pub fn event_with_backtrace(&self, key: &str) {
+        self.event(key);
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        backtrace::trace(|frame| {
+            frame.ip().hash(&mut hasher);
+            true
+        });
+
+        let mut stacks = self.stacks.borrow_mut();
+        let stack = stacks
+            .entry(key.into())
+            .or_default()
+            .entry(hasher.finish())
+            .or_insert_with(|| Stack {
+                count: 0,
+                backtrace: backtrace::Backtrace::new_unresolved(),
+            });
+        stack.count += 1;
+    }
+
+    /// Print the `max` call stacks recorded with `event_with_backtrace` the
+    /// most often for the provided event key, starting with the most frequent.
+    #[cfg(feature = "backtrace")]
+    pub fn print_stacks(&self, key: &str, max: usize, to: &mut dyn io::Write) -> io::Result<()> {
+        let stacks = self.stacks.borrow();
+        let mut stacks: Vec<&Stack> = match stacks.get(key) {
+            Some(stacks) => stacks.values().collect(),
+            None => return Ok(()),
+        };
+        stacks.sort_by_key(|stack| std::cmp::Reverse(stack.count));
+
+        for stack in stacks.iter().take(max) {
+            writeln!(to, "{}: {} times", key, stack.count)?;
+            let mut backtrace = stack.backtrace.clone();
+            backtrace.resolve();
+            let symbols: Vec<&backtrace::BacktraceSymbol> = backtrace
+                .frames()
+                .iter()
+                .flat_map(|frame| frame.symbols())
+                .collect();
+            // Skip the frames of the capture itself, and stop at the entry
+            // point like the standard library's short backtraces.
+            let names: Vec<String> = symbols
+                .iter()
+                .map(|symbol| match symbol.name() {
+                    Some(name) => format!("{:#}", name),
+                    None => String::new(),
+                })
+                .collect();
+            let start = names
+                .iter()
+                .rposition(|name| name.ends_with("event_with_backtrace"))
+                .map_or(0, |idx| idx + 1);
+            for (symbol, name) in symbols.iter().zip(&names).skip(start) {
+                if name.contains("__rust_begin_short_backtrace") {
+                    break;
+                }
+                write!(to, "    {}", name)?;
+                if let (Some(file), Some(line)) = (symbol.filename(), symbol.lineno()) {
+                    write!(to, " at {}:{}", file.display(), line)?;
+                }
+                writeln!(to)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Print the most frequent call stacks of an event key to stdout.
+    #[cfg(feature = "backtrace")]
+    pub fn print_stacks_to_stdout(&self, key: &str, max: usize) {
+        let stdout = io::stdout();
+        let mut to = stdout.lock();
+        self.print_stacks(key, max, &mut to).unwrap();
+    }
+
+    /// Start measuring the time until `stop` is called with the same key.
+    ///
+    /// Starting a timer that is already running restarts it.
+    pub fn start(&self, key: &str) {
+        self.started.borrow_mut().insert(key.into(), Instant::now());
+    }
+
+    /// Stop the timer started with `start` and record the elapsed time, see
+    /// `record_duration`.
+    ///
+    /// Returns the elapsed time, or zero if the timer was not started.
+    pub fn stop(&self, key: &str) -> Duration {
+        let start = match self.started.borrow_mut().remove(key) {
+            Some(start) => start,
+            None => return Duration::ZERO,
+        };
+        let elapsed = start.elapsed();
+        self.record_duration(key, elapsed);
+
+        elapsed
+    }
+
+    /// Measure the time until the returned guard is dropped.
+    ///
+    /// Unlike `start` and `stop`, scopes with the same key can be nested,
+    /// for example in recursive functions.
+    ///
+    /// ```
+    /// use counters::Counters;
+    ///
+    /// let counters = Counters::new();
+    /// for _ in 0..3 {
+    ///     let _scope = counters.scope("parse");
+    ///     // ...
+    /// }
+    ///
+    /// assert_eq!(counters.get("parse"), 3);
+    /// println!("{:?} per call", counters.duration("parse") / 3);
+    /// ```
+    pub fn scope<'a>(&'a self, key: &'a str) -> Scope<'a> {
+        Scope {
+            counters: self,
+            key,
+            start: Instant::now(),
+        }
+    }
+
+    /// Increment the counter for the provided key and add `duration` to the
+    /// time accumulated for it.
+    ///
+    /// The time is not a counter: it doesn't show up in `accumulate` or
+    /// `for_each`, see `duration` and `for_each_duration`.
+    pub fn record_duration(&self, key: &str, duration: Duration) {
+        self.event(key);
+        let mut durations = self.durations.borrow_mut();
+        match durations.get_mut(key) {
+            Some(total) => *total = total.saturating_add(duration),
+            None => {
+                durations.insert(key.into(), duration);
+            }
+        }
+    }
+
+    /// Get the time accumulated for the provided key by `stop`, scopes and
+    /// `record_duration`.
+    pub fn duration(&self, key: &str) -> Duration {
+        self.durations
+            .borrow()
+            .get(key)
+            .cloned()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Calls `f` with the key and accumulated time of each measured key, in no
+    /// particular order.
+    pub fn for_each_duration(&self, mut f: impl FnMut(&str, Duration)) {
+        for (key, duration) in self.durations.borrow().iter() {
+            f(key, *duration);
+        }
     }
 
     /// Get the value of the counter or zero if it does not exist.
@@ -73,17 +507,176 @@ impl Counters {
         n
     }
 
+    /// Return the sum of the counter of a namespace and of all counters nested
+    /// in it, see `filters::Subtree`.
+    ///
+    /// Unlike `accumulate`, this only visits the keys of the namespace.
+    pub fn accumulate_subtree(&self, namespace: &str) -> u64 {
+        let mut n = 0;
+        self.for_each_in_subtree(namespace, |_, value| n += value);
+
+        n
+    }
+
+    /// Calls `f` with the key and value of the counter of a namespace and of
+    /// the counters nested in it, in key order.
+    pub(crate) fn for_each_in_subtree(&self, namespace: &str, mut f: impl FnMut(&str, u64)) {
+        let events = self.events.borrow();
+        if let Some(value) = events.get(namespace) {
+            f(namespace, *value);
+        }
+        // Keys nested in the namespace sort right after its `::` prefix.
+        let prefix = format!("{}::", namespace);
+        for (key, value) in events.range(prefix.clone()..) {
+            if !key.starts_with(&prefix) {
+                break;
+            }
+            f(key, *value);
+        }
+    }
+
+    /// Print the counters as a tree of namespaces separated by `::`, sorted
+    /// by name.
+    ///
+    /// Each namespace shows the sum of the counters nested in it, followed by
+    /// its own value if it also is a counter.
+    ///
+    /// ```
+    /// use counters::Counters;
+    /// use counters::filters::All;
+    ///
+    /// let counters = Counters::new();
+    /// counters.set("tiling::alloc::fast_path", 7);
+    /// counters.set("tiling::alloc::slow_path", 3);
+    /// counters.set("tiling::free", 2);
+    ///
+    /// let mut output = Vec::new();
+    /// counters.print_tree(All, &mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "tiling: 12\n    alloc: 10\n        fast_path: 7\n        slow_path: 3\n    free: 2\n",
+    /// );
+    /// ```
+    pub fn print_tree<F: Filter>(&self, mut filter: F, to: &mut dyn io::Write) -> io::Result<()> {
+        let events = self.events.borrow();
+        let mut root = Namespace::default();
+        for (key, value) in events.iter() {
+            if !filter.apply(key, *value) {
+                continue;
+            }
+            let mut node = &mut root;
+            for name in key.split("::") {
+                node = node.children.entry(name).or_default();
+                node.total += value;
+            }
+            node.value = Some(node.value.unwrap_or(0) + value);
+        }
+
+        root.print(0, to)
+    }
+
+    /// Print the counters as a tree of namespaces to stdout.
+    pub fn print_tree_to_stdout<F: Filter>(&self, filter: F) {
+        let stdout = io::stdout();
+        let mut to = stdout.lock();
+        self.print_tree(filter, &mut to).unwrap();
+    }
+
     /// Print the counters to an io stream.
-    pub fn print<F: Filter>(&self, mut filter: F, to: &mut io::Write) -> io::Result<()> {
+    ///
+    /// Counters with sample statistics are followed by a line with the mean,
+    /// minimum, maximum and sum of the samples, counters with a histogram by
+    /// one line per bucket and timed counters by the accumulated time.
+    pub fn print<F: Filter>(&self, mut filter: F, to: &mut dyn io::Write) -> io::Result<()> {
         for (key, value) in self.events.borrow().iter() {
             if filter.apply(key, *value) {
                 writeln!(to, "{}: {}", key, value)?;
+                self.print_details(key, to)?;
             }
         }
 
         Ok(())
     }
 
+    /// Print the sample statistics and histogram of a counter, if any.
+    fn print_details(&self, key: &str, to: &mut dyn io::Write) -> io::Result<()> {
+        if let Some(stats) = self.samples.borrow().get(key) {
+            writeln!(
+                to,
+                "    mean: {:.2}, min: {}, max: {}, sum: {}",
+                stats.mean(),
+                stats.min,
+                stats.max,
+                stats.sum
+            )?;
+        }
+        if let Some(histogram) = self.histograms.borrow().get(key) {
+            print_histogram(histogram, to)?;
+        }
+        if let Some(duration) = self.durations.borrow().get(key) {
+            writeln!(to, "    time: {:?}", duration)?;
+        }
+
+        Ok(())
+    }
+
+    /// Print the counters sorted by key or by decreasing value, and at most
+    /// `max` of them if provided.
+    ///
+    /// Sorting by value keeps the key order between equal values, so the output
+    /// is the same from one run to the next and can be diffed.
+    ///
+    /// ```
+    /// use counters::{Counters, SortBy};
+    /// use counters::filters::All;
+    ///
+    /// let counters = Counters::new();
+    /// counters.set("a", 1);
+    /// counters.set("b", 30);
+    /// counters.set("c", 20);
+    ///
+    /// let mut output = Vec::new();
+    /// counters.print_sorted(All, SortBy::Value, Some(2), &mut output).unwrap();
+    /// assert_eq!(String::from_utf8(output).unwrap(), "b: 30\nc: 20\n");
+    /// ```
+    pub fn print_sorted<F: Filter>(
+        &self,
+        mut filter: F,
+        sort_by: SortBy,
+        max: Option<usize>,
+        to: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        let events = self.events.borrow();
+        let mut counters: Vec<(&String, u64)> = events
+            .iter()
+            .filter(|(key, value)| filter.apply(key, **value))
+            .map(|(key, value)| (key, *value))
+            .collect();
+        match sort_by {
+            SortBy::Key => counters.sort(),
+            SortBy::Value => counters.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0))),
+        }
+
+        for (key, value) in counters.iter().take(max.unwrap_or(usize::MAX)) {
+            writeln!(to, "{}: {}", key, value)?;
+            self.print_details(key, to)?;
+        }
+
+        Ok(())
+    }
+
+    /// Print the sorted counters to stdout, see `print_sorted`.
+    pub fn print_sorted_to_stdout<F: Filter>(
+        &self,
+        filter: F,
+        sort_by: SortBy,
+        max: Option<usize>,
+    ) {
+        let stdout = io::stdout();
+        let mut to = stdout.lock();
+        self.print_sorted(filter, sort_by, max, &mut to).unwrap();
+    }
+
     /// Print the counters to stdout.
     pub fn print_to_stdout<F: Filter>(&self, filter: F) {
         let stdout = io::stdout();
@@ -91,10 +684,228 @@ impl Counters {
         self.print(filter, &mut to).unwrap();
     }
 
+    /// Print the counters in the folded stacks format used by flame graph tools
+    /// such as `inferno` or `flamegraph.pl`.
+    ///
+    /// Keys are split into frames at each occurrence of `separator`, so that
+    /// `"frame::layout"` with the `"::"` separator becomes `frame;layout`.
+    /// Each counter is written as the value of its own frame, not including
+    /// the counters nested under it. Counters equal to zero are skipped.
+    ///
+    /// ```
+    /// use counters::Counters;
+    /// use counters::filters::All;
+    ///
+    /// let counters = Counters::new();
+    /// counters.set("frame::layout", 3);
+    /// counters.set("frame::render::upload", 2);
+    ///
+    /// let mut output = Vec::new();
+    /// counters.print_folded_stacks(All, "::", &mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "frame;layout 3\nframe;render;upload 2\n",
+    /// );
+    /// ```
+    pub fn print_folded_stacks<F: Filter>(
+        &self,
+        mut filter: F,
+        separator: &str,
+        to: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        let events = self.events.borrow();
+        let mut stacks: Vec<(&String, u64)> = events
+            .iter()
+            .filter(|(key, value)| **value != 0 && filter.apply(key, **value))
+            .map(|(key, value)| (key, *value))
+            .collect();
+        stacks.sort();
+
+        for (key, value) in stacks {
+            for (i, frame) in key.split(separator).enumerate() {
+                if i != 0 {
+                    write!(to, ";")?;
+                }
+                write!(to, "{}", frame)?;
+            }
+            writeln!(to, " {}", value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Start recording the last `samples` values of the counter for the
+    /// provided event key, each time `sample_all` is called.
+    ///
+    /// The history is not affected by resetting the counters, so that events
+    /// can be counted per frame, sampled and reset.
+    pub fn enable_history(&self, key: &str, samples: usize) {
+        self.histories.borrow_mut().insert(
+            key.into(),
+            History {
+                samples: VecDeque::with_capacity(samples),
+                capacity: samples,
+            },
+        );
+    }
+
+    /// Stop recording the history of a counter and discard it.
+    pub fn disable_history(&self, key: &str) {
+        self.histories.borrow_mut().remove(key);
+    }
+
+    /// Append the current value of each counter with a history to it,
+    /// typically once per frame.
+    pub fn sample_all(&self) {
+        let events = self.events.borrow();
+        for (key, history) in self.histories.borrow_mut().iter_mut() {
+            if history.capacity == 0 {
+                continue;
+            }
+            if history.samples.len() == history.capacity {
+                history.samples.pop_front();
+            }
+            history
+                .samples
+                .push_back(events.get(key).cloned().unwrap_or(0));
+        }
+    }
+
+    /// Get the recorded values of a counter from the oldest to the most
+    /// recent, or an empty vector if its history is not enabled.
+    pub fn history(&self, key: &str) -> Vec<u64> {
+        self.histories
+            .borrow()
+            .get(key)
+            .map(|history| history.samples.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Calls `f` with the key and value of each counter, sorted by key.
+    pub fn for_each(&self, mut f: impl FnMut(&str, u64)) {
+        for (key, value) in self.events.borrow().iter() {
+            f(key, *value);
+        }
+    }
+
     /// Moves all the elements of `other` into `self`, leaving `other` empty.
     pub fn append(&self, other: &Counters) {
-        for (key, value) in other.events.borrow_mut().drain() {
+        let events = std::mem::take(&mut *other.events.borrow_mut());
+        for (key, value) in events {
             *self.events.borrow_mut().entry(key).or_insert(0) += value;
         }
+        for (key, stats) in other.samples.borrow_mut().drain() {
+            self.samples
+                .borrow_mut()
+                .entry(key)
+                .and_modify(|dst| dst.add(&stats))
+                .or_insert(stats);
+        }
+        for (key, duration) in other.durations.borrow_mut().drain() {
+            self.durations
+                .borrow_mut()
+                .entry(key)
+                .and_modify(|dst| *dst = dst.saturating_add(duration))
+                .or_insert(duration);
+        }
+        for (key, histogram) in other.histograms.borrow_mut().drain() {
+            let mut dst = self.histograms.borrow_mut();
+            match dst.get_mut(&key) {
+                // Values can only be merged into the same buckets.
+                Some(dst) if dst.bounds != histogram.bounds => {}
+                Some(dst) => {
+                    if dst.counts.len() < histogram.counts.len() {
+                        dst.counts.resize(histogram.counts.len(), 0);
+                    }
+                    for (dst, count) in dst.counts.iter_mut().zip(histogram.counts) {
+                        *dst += count;
+                    }
+                }
+                None => {
+                    dst.insert(key, histogram);
+                }
+            }
+        }
+        #[cfg(feature = "backtrace")]
+        for (key, stacks) in other.stacks.borrow_mut().drain() {
+            let mut dst = self.stacks.borrow_mut();
+            let dst = dst.entry(key).or_default();
+            for (hash, stack) in stacks {
+                dst.entry(hash)
+                    .and_modify(|dst| dst.count += stack.count)
+                    .or_insert(stack);
+            }
+        }
+    }
+}
+
+impl Default for Counters {
+    fn default() -> Self {
+        Counters::new()
+    }
+}
+
+/// A node of the tree written by `Counters::print_tree`.
+#[derive(Default)]
+struct Namespace<'a> {
+    /// The value of the counter with this exact key, if any.
+    value: Option<u64>,
+    /// The sum of the counters in this namespace, including its own.
+    total: u64,
+    children: BTreeMap<&'a str, Namespace<'a>>,
+}
+
+impl<'a> Namespace<'a> {
+    fn print(&self, depth: usize, to: &mut dyn io::Write) -> io::Result<()> {
+        for (name, node) in &self.children {
+            write!(
+                to,
+                "{:indent$}{}: {}",
+                "",
+                name,
+                node.total,
+                indent = depth * 4
+            )?;
+            match node.value {
+                Some(value) if !node.children.is_empty() => writeln!(to, " ({} own)", value)?,
+                _ => writeln!(to)?,
+            }
+            node.print(depth + 1, to)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes one line per bucket with a bar proportional to its count.
+fn print_histogram(histogram: &Histogram, to: &mut dyn io::Write) -> io::Result<()> {
+    const BAR_WIDTH: u64 = 40;
+    let max = histogram.counts.iter().cloned().max().unwrap_or(0).max(1);
+    for (range, count) in histogram.buckets() {
+        let range = if range.end == u64::MAX {
+            format!("{}..", range.start)
+        } else {
+            format!("{}..{}", range.start, range.end)
+        };
+        let bar = "#".repeat((count * BAR_WIDTH).div_ceil(max) as usize);
+        let line = format!("    {:>24} {:>8} {}", range, count, bar);
+        writeln!(to, "{}", line.trim_end())?;
+    }
+
+    Ok(())
+}
+
+/// Records the time elapsed since its creation when dropped, see
+/// `Counters::scope`.
+pub struct Scope<'a> {
+    counters: &'a Counters,
+    key: &'a str,
+    start: Instant,
+}
+
+impl<'a> Drop for Scope<'a> {
+    fn drop(&mut self) {
+        self.counters
+            .record_duration(self.key, self.start.elapsed());
     }
 }
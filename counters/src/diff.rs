@@ -0,0 +1,131 @@
+use std::io;
+
+#[cfg(not(feature = "noop"))]
+use crate::Counters;
+
+/// The differences between two sets of counters, see `Counters::diff`.
+///
+/// Keys are sorted by name and unchanged counters are omitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CounterDiff {
+    /// The counters that only exist in the new counters.
+    pub added: Vec<(String, u64)>,
+    /// The counters that only exist in the old counters.
+    pub removed: Vec<(String, u64)>,
+    /// The counters which value changed.
+    pub changed: Vec<CounterChange>,
+}
+
+/// The old and new values of a counter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CounterChange {
+    pub key: String,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl CounterChange {
+    /// How much the counter increased, negative if it decreased.
+    pub fn delta(&self) -> i128 {
+        self.after as i128 - self.before as i128
+    }
+}
+
+impl CounterDiff {
+    /// Whether the counters are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Print the differences, one line per counter prefixed with `+` if it was
+    /// added, `-` if it was removed and `~` if it changed.
+    pub fn print(&self, to: &mut dyn io::Write) -> io::Result<()> {
+        for (key, value) in &self.added {
+            writeln!(to, "+ {}: {}", key, value)?;
+        }
+        for (key, value) in &self.removed {
+            writeln!(to, "- {}: {}", key, value)?;
+        }
+        for change in &self.changed {
+            writeln!(
+                to,
+                "~ {}: {} -> {} ({:+})",
+                change.key,
+                change.before,
+                change.after,
+                change.delta()
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Print the differences to stdout.
+    pub fn print_to_stdout(&self) {
+        let stdout = io::stdout();
+        let mut to = stdout.lock();
+        self.print(&mut to).unwrap();
+    }
+}
+
+#[cfg(not(feature = "noop"))]
+impl Counters {
+    /// Return a copy of the current values of the counters, to compare them
+    /// with later values using `diff`.
+    pub fn snapshot(&self) -> Counters {
+        let snapshot = Counters::new();
+        self.for_each(|key, value| snapshot.set(key, value));
+
+        snapshot
+    }
+
+    /// Compare these counters (the old values) with `other` (the new values).
+    ///
+    /// ```
+    /// use counters::Counters;
+    ///
+    /// let counters = Counters::new();
+    /// counters.set("draw calls", 10);
+    /// counters.set("uploads", 3);
+    /// let before = counters.snapshot();
+    ///
+    /// counters.reset_all();
+    /// counters.set("draw calls", 7);
+    /// counters.set("batches", 2);
+    ///
+    /// let diff = before.diff(&counters);
+    /// let mut output = Vec::new();
+    /// diff.print(&mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "+ batches: 2\n- uploads: 3\n~ draw calls: 10 -> 7 (-3)\n",
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Counters) -> CounterDiff {
+        let mut diff = CounterDiff::default();
+        other.for_each(|key, after| {
+            let before = self.events.borrow().get(key).cloned();
+            match before {
+                None => diff.added.push((key.to_string(), after)),
+                Some(before) if before != after => diff.changed.push(CounterChange {
+                    key: key.to_string(),
+                    before,
+                    after,
+                }),
+                Some(_) => {}
+            }
+        });
+        let after = other.events.borrow();
+        self.for_each(|key, before| {
+            if !after.contains_key(key) {
+                diff.removed.push((key.to_string(), before));
+            }
+        });
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort_by(|a, b| a.key.cmp(&b.key));
+
+        diff
+    }
+}
@@ -21,7 +21,7 @@ impl<F: FnMut(&str, u64) -> bool> Filter for Select<F> {
 
 impl Filter for &str {
     fn apply(&mut self, key: &str, _: u64) -> bool {
-        key.contains(&*self)
+        key.contains(*self)
     }
 }
 
@@ -42,6 +42,19 @@ impl<'l> Filter for StartsWith<'l> {
     }
 }
 
+/// Accept the key of a namespace and the keys nested in it, separated by `::`.
+///
+/// `Subtree("tiling")` accepts `"tiling"` and `"tiling::alloc"` but not `"tilings"`.
+pub struct Subtree<'l>(pub &'l str);
+impl<'l> Filter for Subtree<'l> {
+    fn apply(&mut self, key: &str, _: u64) -> bool {
+        match key.strip_prefix(self.0) {
+            Some(rest) => rest.is_empty() || rest.starts_with("::"),
+            None => false,
+        }
+    }
+}
+
 /// Accet keys that end with a given sub-string.
 pub struct EndsWith<'l>(pub &'l str);
 impl<'l> Filter for EndsWith<'l> {
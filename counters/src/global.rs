@@ -0,0 +1,65 @@
+use crate::Counters;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+static GLOBAL: OnceLock<Mutex<Counters>> = OnceLock::new();
+
+/// The counters shared by the whole program, used by the `event!` and `set!` macros.
+///
+/// The counters are created the first time this is called. They stay locked as long
+/// as the returned guard is alive, so the guard should not be held while calling code
+/// that may record events.
+///
+/// ```
+/// counters::event!("load");
+/// counters::global().print_to_stdout(counters::filters::All);
+/// ```
+pub fn global() -> MutexGuard<'static, Counters> {
+    GLOBAL
+        .get_or_init(|| Mutex::new(Counters::new()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Increments the counter for the provided event key in the global counters.
+///
+/// With the `noop` feature this expands to nothing and the key is not evaluated.
+#[cfg(not(feature = "noop"))]
+#[macro_export]
+macro_rules! event {
+    ($key:expr) => {
+        $crate::global().event($key)
+    };
+}
+
+/// Increments the counter for the provided event key in the global counters.
+///
+/// With the `noop` feature this expands to nothing and the key is not evaluated.
+#[cfg(feature = "noop")]
+#[macro_export]
+macro_rules! event {
+    ($key:expr) => {
+        ()
+    };
+}
+
+/// Sets the value of the counter for the provided key in the global counters.
+///
+/// With the `noop` feature this expands to nothing and the arguments are not evaluated.
+#[cfg(not(feature = "noop"))]
+#[macro_export]
+macro_rules! set {
+    ($key:expr, $value:expr) => {
+        $crate::global().set($key, $value)
+    };
+}
+
+/// Sets the value of the counter for the provided key in the global counters.
+///
+/// With the `noop` feature this expands to nothing and the arguments are not evaluated.
+#[cfg(feature = "noop")]
+#[macro_export]
+macro_rules! set {
+    ($key:expr, $value:expr) => {
+        ()
+    };
+}
@@ -0,0 +1,45 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::{Counters, Table};
+
+fn save<T: serde::Serialize>(value: &T, path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut file, value)?;
+    file.flush()
+}
+
+fn load<T: serde::de::DeserializeOwned>(path: &Path) -> io::Result<T> {
+    let file = BufReader::new(File::open(path)?);
+    Ok(serde_json::from_reader(file)?)
+}
+
+impl Counters {
+    /// Save the counters to a file in JSON format, to reload them later with
+    /// `load_json`, for example to compare them with `diff`.
+    ///
+    /// The call stacks of `event_with_backtrace` and the running timers are
+    /// not saved.
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        save(self, path.as_ref())
+    }
+
+    /// Load counters saved with `save_json`.
+    pub fn load_json<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        load(path.as_ref())
+    }
+}
+
+impl Table {
+    /// Save the table to a file in JSON format, to reload it later with
+    /// `load_json`.
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        save(self, path.as_ref())
+    }
+
+    /// Load a table saved with `save_json`.
+    pub fn load_json<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        load(path.as_ref())
+    }
+}
@@ -8,12 +8,117 @@
 //! The implementation is very simple and not particularly fast. Using counters
 //! will affect perfomance measurements.
 //!
+//! The feature flag `fast-hash` replaces the default hasher of the internal maps with
+//! a faster one that is not resistant to collision attacks. The overhead can be
+//! measured with `cargo bench`.
+//!
 //! # Optimizing out
 //!
 //! If the feature flag `noop` is enabled, the counters compile into an empty struct
 //! with empty methods. This way the code for counting events can be kept while opting
 //! out of its overhead in shipping and profiling build configurations.
 //!
+//! # Saving counters
+//!
+//! If the feature flag `serialization` is enabled, `Counters` and `Table` can be saved to
+//! and loaded from JSON files with `save_json` and `load_json`, so that the counters of a
+//! session can be compared with the ones of a later session. With the `noop` feature,
+//! nothing is written and loading returns empty counters.
+//!
+//! # Prometheus
+//!
+//! `Counters::print_prometheus` writes the counters in the Prometheus text exposition
+//! format, so that test rigs that are already scraped by Prometheus can expose them.
+//!
+//! # Namespaces
+//!
+//! Keys such as `"tiling::alloc::slow_path"` are treated as paths in a tree of namespaces
+//! separated by `::`. `Counters::print_tree` shows the tree with the sum of each namespace,
+//! and `Counters::accumulate_subtree` (or the `filters::Subtree` filter) selects a namespace
+//! without matching unrelated keys that happen to contain the same sub-string.
+//!
+//! # Comparing counters
+//!
+//! `Counters::snapshot` copies the current values of the counters and `Counters::diff`
+//! lists the counters that were added, removed or changed between two sets of counters,
+//! for example between two frames or before and after an optimization.
+//!
+//! # Histograms and statistics
+//!
+//! `Counters::record` counts calls like `Counters::event` and also keeps track of how
+//! the recorded values are distributed, which `Counters::print` shows as one line per
+//! bucket. `Counters::sample` similarly keeps the mean, minimum, maximum and sum of the
+//! sampled values, see `Counters::stats`.
+//!
+//! # Timers
+//!
+//! `Counters::start` and `Counters::stop`, or the guard returned by `Counters::scope`,
+//! measure how long a piece of code takes. The number of measurements is counted under
+//! the provided key and the accumulated time is available through `Counters::duration`.
+//! The time is kept apart from the counters, so it isn't summed with them by `accumulate`.
+//!
+//! # Global counters
+//!
+//! `global()` returns counters shared by the whole program, so that code deep in a call
+//! stack can record events without having a `&Counters` passed down to it. The `event!`
+//! and `set!` macros update them, and expand to nothing with the `noop` feature.
+//!
+//! ```rust
+//! fn load_texture(cached: bool) {
+//!     counters::event!("textures");
+//!     if !cached {
+//!         counters::event!("textures::uploads");
+//!     }
+//! }
+//!
+//! load_texture(false);
+//! counters::global().print_to_stdout(counters::filters::StartsWith("textures"));
+//! ```
+//!
+//! # Call stacks
+//!
+//! If the feature flag `backtrace` is enabled, `Counters::event_with_backtrace`
+//! also counts the events per call stack, and `Counters::print_stacks` shows where
+//! the most frequent ones come from, without having to attach a profiler.
+//!
+//! # History
+//!
+//! `Counters::enable_history` keeps the last values of a counter, recorded each time
+//! `Counters::sample_all` is called (for example once per frame). If the feature flag
+//! `svg` is enabled, `Counters::print_svg_charts` draws them as line charts.
+//!
+//! # Instrumenting methods
+//!
+//! If the feature flag `macros` is enabled, the `#[counted(expr)]` attribute on an `impl`
+//! block records an event named `"Type::method"` in the `Counters` evaluated by `expr`
+//! each time one of its methods is called. Without an argument, `#[counted]` records
+//! the events in the global counters.
+//!
+//! ```rust
+//! # #[cfg(feature = "macros")] {
+//! use counters::counted;
+//!
+//! struct Loader;
+//!
+//! #[counted]
+//! impl Loader {
+//!     fn load(path: &str) -> usize {
+//!         path.len()
+//!     }
+//! }
+//!
+//! Loader::load("image.png");
+//! # #[cfg(not(feature = "noop"))]
+//! assert_eq!(counters::global().get("Loader::load"), 1);
+//! # }
+//! ```
+//!
+//! # Tracing
+//!
+//! If the feature flag `tracing` is enabled, `CountersLayer` is a `tracing-subscriber`
+//! layer that counts the events and measures the duration of the spans of code that is
+//! already instrumented with `tracing`.
+//!
 //! # Example
 //!
 //! In the example below we have a function `do_the_thing` which we determined to
@@ -86,6 +191,15 @@ mod counters;
 #[cfg(not(feature = "noop"))]
 pub use crate::counters::*;
 
+#[cfg(all(feature = "svg", not(feature = "noop")))]
+mod svg;
+
+#[cfg(all(feature = "serialization", not(feature = "noop")))]
+mod json;
+
+#[cfg(not(feature = "noop"))]
+mod prometheus;
+
 #[cfg(not(feature = "noop"))]
 mod table;
 #[cfg(not(feature = "noop"))]
@@ -98,6 +212,23 @@ pub use crate::noop::*;
 
 pub mod filters;
 
+mod diff;
+pub use crate::diff::*;
+
+mod stats;
+pub use crate::stats::SampleStats;
+
+mod global;
+pub use crate::global::global;
+
+#[cfg(feature = "macros")]
+pub use counters_macros::counted;
+
+#[cfg(feature = "tracing")]
+mod tracing_layer;
+#[cfg(feature = "tracing")]
+pub use crate::tracing_layer::CountersLayer;
+
 #[test]
 #[cfg(not(feature = "noop"))]
 fn it_works() {
@@ -163,3 +294,558 @@ fn noop() {
     assert_eq!(counters.get("foo::baz"), 0);
     assert_eq!(counters.accumulate("foo::"), 0);
 }
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn folded_stacks() {
+    let counters = Counters::new();
+
+    counters.set("frame", 2);
+    counters.set("frame::render", 5);
+    counters.set("frame::render::batch", 1);
+    counters.set("frame::idle", 0);
+    counters.set("other", 7);
+
+    let mut output = Vec::new();
+    counters
+        .print_folded_stacks("frame", "::", &mut output)
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "frame 2\nframe;render 5\nframe;render;batch 1\n",
+    );
+}
+
+#[test]
+#[cfg(all(feature = "backtrace", not(feature = "noop")))]
+fn backtraces() {
+    #[inline(never)]
+    fn caller_a(counters: &Counters) {
+        counters.event_with_backtrace("foo");
+    }
+
+    #[inline(never)]
+    fn caller_b(counters: &Counters) {
+        counters.event_with_backtrace("foo");
+    }
+
+    let counters = Counters::new();
+    for _ in 0..3 {
+        caller_a(&counters);
+    }
+    caller_b(&counters);
+    counters.event("foo");
+    assert_eq!(counters.get("foo"), 5);
+
+    let mut output = Vec::new();
+    counters.print_stacks("foo", 10, &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let headers: Vec<&str> = output.lines().filter(|l| l.starts_with("foo")).collect();
+    assert_eq!(headers, ["foo: 3 times", "foo: 1 times"]);
+    assert!(output.find("caller_a").unwrap() < output.find("caller_b").unwrap());
+
+    let mut output = Vec::new();
+    counters.print_stacks("foo", 1, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap().matches("foo:").count(),
+        1
+    );
+
+    counters.reset_event("foo");
+    let mut output = Vec::new();
+    counters.print_stacks("foo", 10, &mut output).unwrap();
+    assert!(output.is_empty());
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn history() {
+    let counters = Counters::new();
+    counters.enable_history("foo", 3);
+    counters.enable_history("bar", 2);
+
+    for i in 0..4 {
+        for _ in 0..i {
+            counters.event("foo");
+        }
+        counters.sample_all();
+        counters.reset_all();
+    }
+
+    assert_eq!(counters.history("foo"), vec![1, 2, 3]);
+    assert_eq!(counters.history("bar"), vec![0, 0]);
+    assert!(counters.history("baz").is_empty());
+
+    counters.disable_history("foo");
+    assert!(counters.history("foo").is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "svg", not(feature = "noop")))]
+fn svg_charts() {
+    let counters = Counters::new();
+    counters.enable_history("a<b", 4);
+    counters.enable_history("c", 4);
+    counters.set("a<b", 2);
+    counters.sample_all();
+    counters.set("a<b", 4);
+    counters.sample_all();
+
+    let mut output = Vec::new();
+    counters.print_svg_charts("a", &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.starts_with("<svg"));
+    assert!(output.contains("a&lt;b"));
+    assert!(!output.contains(" c "));
+    assert!(output.contains("M 10 80 L 590 30"));
+}
+
+#[test]
+#[cfg(all(feature = "tracing", not(feature = "noop")))]
+fn tracing_layer() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let layer = CountersLayer::new();
+    let counters = layer.counters();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    ::tracing::subscriber::with_default(subscriber, || {
+        for _ in 0..3 {
+            let _span = ::tracing::info_span!(target: "app", "frame").entered();
+            ::tracing::warn!(target: "loader", "missing texture");
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        ::tracing::info!(target: "loader", "done");
+    });
+
+    let counters = counters.lock().unwrap();
+    assert_eq!(counters.get("WARN loader"), 3);
+    assert_eq!(counters.get("INFO loader"), 1);
+    assert_eq!(counters.get("app::frame"), 3);
+    assert!(counters.duration("app::frame") >= std::time::Duration::from_millis(3));
+}
+
+#[test]
+#[cfg(all(feature = "macros", not(feature = "noop")))]
+fn counted_impl() {
+    struct Parser {
+        counters: Counters,
+    }
+
+    #[counted(self.counters)]
+    impl Parser {
+        fn new() -> Self {
+            Parser {
+                counters: Counters::new(),
+            }
+        }
+
+        fn parse(&self, input: &str) -> usize {
+            input.split(' ').map(|word| self.word(word)).sum()
+        }
+
+        fn word(&self, word: &str) -> usize {
+            word.len()
+        }
+    }
+
+    let parser = Parser::new();
+    assert_eq!(parser.parse("a bb ccc"), 6);
+    assert_eq!(parser.counters.get("Parser::parse"), 1);
+    assert_eq!(parser.counters.get("Parser::word"), 3);
+    assert_eq!(parser.counters.get("Parser::new"), 0);
+
+    let counters = Counters::new();
+    struct Unit;
+    #[counted(counters)]
+    impl Unit {
+        fn run(counters: &Counters) {
+            let _ = counters;
+        }
+    }
+    Unit::run(&counters);
+    assert_eq!(counters.get("Unit::run"), 1);
+}
+
+#[test]
+fn global_macros() {
+    event!("global_macros::a");
+    event!("global_macros::a");
+    set!("global_macros::b", 5);
+
+    let expected = if cfg!(feature = "noop") {
+        (0, 0)
+    } else {
+        (2, 5)
+    };
+    let counters = global();
+    assert_eq!(
+        (
+            counters.get("global_macros::a"),
+            counters.get("global_macros::b")
+        ),
+        expected
+    );
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn timers() {
+    use std::time::Duration;
+
+    let counters = Counters::new();
+    assert_eq!(counters.stop("parse"), Duration::ZERO);
+    assert_eq!(counters.get("parse"), 0);
+
+    counters.start("parse");
+    std::thread::sleep(Duration::from_millis(2));
+    let elapsed = counters.stop("parse");
+    assert!(elapsed >= Duration::from_millis(2));
+    assert_eq!(counters.get("parse"), 1);
+    assert_eq!(counters.duration("parse"), elapsed);
+
+    fn recurse(counters: &Counters, depth: u32) {
+        let _scope = counters.scope("recurse");
+        if depth > 0 {
+            recurse(counters, depth - 1);
+        }
+    }
+    recurse(&counters, 3);
+    assert_eq!(counters.get("recurse"), 4);
+
+    counters.record_duration("io", Duration::from_micros(3));
+    counters.record_duration("io", Duration::from_micros(4));
+    assert_eq!(counters.get("io"), 2);
+    assert_eq!(counters.duration("io"), Duration::from_micros(7));
+
+    // Only the call is counted, the time is not added to the counters.
+    counters.set("net::send", 2);
+    let before = counters.accumulate(filters::Subtree("net"));
+    counters.record_duration("net::recv", Duration::from_secs(1));
+    assert_eq!(counters.accumulate(filters::Subtree("net")), before + 1);
+    assert_eq!(counters.accumulate_subtree("net"), 3);
+    let mut keys = Vec::new();
+    counters.for_each(|key, _| keys.push(key.to_string()));
+    assert_eq!(keys, ["io", "net::recv", "net::send", "parse", "recurse"]);
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn histograms() {
+    let counters = Counters::new();
+    for value in [0, 1, 2, 3, 5, 5, 6, 7] {
+        counters.record("batch", value);
+    }
+    assert_eq!(counters.get("batch"), 8);
+    assert_eq!(
+        counters.histogram("batch"),
+        vec![(0..1, 1), (1..2, 1), (2..4, 2), (4..8, 4)]
+    );
+    assert_eq!(counters.histogram("unknown"), vec![]);
+
+    counters.record("large", u64::MAX);
+    assert_eq!(
+        counters.histogram("large").last(),
+        Some(&(1 << 63..u64::MAX, 1))
+    );
+
+    let mut output = Vec::new();
+    counters
+        .print(filters::Contains("batch"), &mut output)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "batch: 8\n\
+        \x20                       0..1        1 ##########\n\
+        \x20                       1..2        1 ##########\n\
+        \x20                       2..4        2 ####################\n\
+        \x20                       4..8        4 ########################################\n"
+    );
+
+    // Resetting keeps the buckets.
+    counters.set_histogram_buckets("batch", &[100, 10]);
+    counters.record("batch", 50);
+    counters.reset_all();
+    counters.record("batch", 500);
+    assert_eq!(
+        counters.histogram("batch"),
+        vec![(0..10, 0), (10..100, 0), (100..u64::MAX, 1)]
+    );
+
+    let other = Counters::new();
+    other.set_histogram_buckets("batch", &[10, 100]);
+    other.record("batch", 1);
+    other.record("other", 1);
+    counters.append(&other);
+    assert_eq!(counters.histogram("batch")[0], (0..10, 1));
+    assert_eq!(counters.histogram("other"), vec![(0..1, 0), (1..2, 1)]);
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn namespaces() {
+    let counters = Counters::new();
+    counters.set("tiling", 1);
+    counters.set("tiling::alloc::slow_path", 3);
+    counters.set("tiling::alloc::fast_path", 7);
+    counters.set("tilings", 100);
+    counters.set("render::tiling", 1000);
+
+    assert_eq!(counters.accumulate_subtree("tiling"), 11);
+    assert_eq!(counters.accumulate_subtree("tiling::alloc"), 10);
+    assert_eq!(counters.accumulate_subtree("tiling::alloc::slow_path"), 3);
+    assert_eq!(counters.accumulate_subtree("tiling::free"), 0);
+
+    // Only the keys of the namespace are visited, not every key.
+    let mut visited = Vec::new();
+    counters.for_each_in_subtree("tiling", |key, _| visited.push(key.to_string()));
+    assert_eq!(
+        visited,
+        [
+            "tiling",
+            "tiling::alloc::fast_path",
+            "tiling::alloc::slow_path"
+        ]
+    );
+
+    let mut output = Vec::new();
+    counters
+        .print_tree(filters::Subtree("tiling"), &mut output)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "tiling: 11 (1 own)\n    alloc: 10\n        fast_path: 7\n        slow_path: 3\n"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn print_sorted() {
+    let counters = Counters::new();
+    for (key, value) in [("b", 2), ("a", 2), ("d", 10), ("c", 0)] {
+        counters.set(key, value);
+    }
+
+    let print = |filter, sort_by, max| {
+        let mut output = Vec::new();
+        counters
+            .print_sorted(filter, sort_by, max, &mut output)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    };
+    assert_eq!(
+        print(filters::All, SortBy::Key, None),
+        "a: 2\nb: 2\nc: 0\nd: 10\n"
+    );
+    assert_eq!(
+        print(filters::All, SortBy::Value, None),
+        "d: 10\na: 2\nb: 2\nc: 0\n"
+    );
+    assert_eq!(print(filters::All, SortBy::Value, Some(1)), "d: 10\n");
+    assert_eq!(print(filters::All, SortBy::Key, Some(0)), "");
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn diff() {
+    let counters = Counters::new();
+    counters.set("same", 1);
+    counters.set("more", 1);
+    counters.set("less", 5);
+    counters.set("gone", 2);
+    let before = counters.snapshot();
+    assert!(before.diff(&counters).is_empty());
+
+    counters.event("more");
+    counters.set("less", 0);
+    counters.retain(filters::Select(|key, _| key != "gone"));
+    counters.set("new", 3);
+
+    let diff = before.diff(&counters);
+    assert_eq!(diff.added, vec![("new".to_string(), 3)]);
+    assert_eq!(diff.removed, vec![("gone".to_string(), 2)]);
+    assert_eq!(
+        diff.changed
+            .iter()
+            .map(|c| (c.key.as_str(), c.delta()))
+            .collect::<Vec<_>>(),
+        vec![("less", -5), ("more", 1)]
+    );
+
+    // The snapshot is not affected by later changes.
+    assert_eq!(before.get("more"), 1);
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn markdown_table() {
+    let counters = Counters::new();
+    let table = Table::new(&["a", "b|c"]);
+    table.add_row(&counters);
+    counters.set("a", 123456);
+    counters.set("b|c", 7);
+    table.add_row(&counters);
+
+    let mut output = Vec::new();
+    table.print_markdown(&mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "| a      | b\\|c |\n\
+         | -----: | ---: |\n\
+         |      0 |    0 |\n\
+         | 123456 |    7 |\n"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn table_aggregates() {
+    let table = Table::new(&["a", "b"]).with_aggregates(&Aggregate::ALL);
+    let mut output = Vec::new();
+    table.print(&mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        ",a,b\nsum, 0, 0\nmean, -, -\nmin, -, -\nmax, -, -\n"
+    );
+
+    let counters = Counters::new();
+    for (a, b) in [(1, 10), (2, 0), (6, 5)] {
+        counters.set("a", a);
+        counters.set("b", b);
+        table.add_row(&counters);
+    }
+    let mut output = Vec::new();
+    table.print_markdown(&mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "|          | a    | b    |\n\
+         | -------- | ---: | ---: |\n\
+         |          |    1 |   10 |\n\
+         |          |    2 |    0 |\n\
+         |          |    6 |    5 |\n\
+         | **sum**  |    9 |   15 |\n\
+         | **mean** | 3.00 | 5.00 |\n\
+         | **min**  |    1 |    0 |\n\
+         | **max**  |    6 |   10 |\n"
+    );
+
+    // Sums don't overflow.
+    let table = Table::new(&["a"]).with_aggregates(&[Aggregate::Sum]);
+    counters.set("a", u64::MAX);
+    table.add_row(&counters);
+    table.add_row(&counters);
+    let mut output = Vec::new();
+    table.print(&mut output).unwrap();
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .ends_with("sum, 36893488147419103230\n"));
+}
+
+#[test]
+#[cfg(all(feature = "serialization", not(feature = "noop")))]
+fn save_load_json() {
+    let dir = std::env::temp_dir();
+    let counters_path = dir.join(format!("counters-{}.json", std::process::id()));
+    let table_path = dir.join(format!("counters-table-{}.json", std::process::id()));
+
+    let counters = Counters::new();
+    counters.set("a", 3);
+    counters.record("b", 100);
+    counters.save_json(&counters_path).unwrap();
+    let loaded = Counters::load_json(&counters_path).unwrap();
+    assert!(counters.diff(&loaded).is_empty());
+    assert_eq!(loaded.histogram("b"), counters.histogram("b"));
+
+    let table = Table::new(&["a"]);
+    table.add_row(&counters);
+    table.save_json(&table_path).unwrap();
+    let loaded = Table::load_json(&table_path).unwrap();
+    let (mut expected, mut output) = (Vec::new(), Vec::new());
+    table.print(&mut expected).unwrap();
+    loaded.print(&mut output).unwrap();
+    assert_eq!(output, expected);
+
+    let _ = std::fs::remove_file(&counters_path);
+    let _ = std::fs::remove_file(&table_path);
+
+    assert!(Counters::load_json(dir.join("does-not-exist.json")).is_err());
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn sample_stats() {
+    let counters = Counters::new();
+    assert_eq!(counters.stats("tiles"), None);
+    for value in [3, 8, 1] {
+        counters.sample("tiles", value);
+    }
+    assert_eq!(counters.get("tiles"), 3);
+    assert_eq!(
+        counters.stats("tiles"),
+        Some(SampleStats {
+            count: 3,
+            sum: 12,
+            min: 1,
+            max: 8
+        })
+    );
+
+    let mut output = Vec::new();
+    counters.print(filters::All, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "tiles: 3\n    mean: 4.00, min: 1, max: 8, sum: 12\n"
+    );
+
+    let other = Counters::new();
+    other.sample("tiles", 20);
+    counters.append(&other);
+    assert_eq!(counters.stats("tiles").unwrap().max, 20);
+    assert_eq!(counters.stats("tiles").unwrap().mean(), 8.0);
+
+    counters.reset_event("tiles");
+    assert_eq!(counters.stats("tiles"), None);
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn prometheus() {
+    let counters = Counters::new();
+    counters.set("draw calls", 3);
+    counters.set("draw-calls", 4);
+    counters.set("multi\nline", 1);
+
+    let mut output = Vec::new();
+    counters.print_prometheus("", &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "# HELP draw_calls draw calls, draw-calls\n\
+         # TYPE draw_calls gauge\n\
+         draw_calls 7\n\
+         # HELP multi_line multi\\nline\n\
+         # TYPE multi_line gauge\n\
+         multi_line 1\n"
+    );
+}
+
+#[test]
+#[cfg(not(feature = "noop"))]
+fn add_sub() {
+    let counters = Counters::new();
+    counters.add("bytes", 1000);
+    counters.add("bytes", 24);
+    assert_eq!(counters.get("bytes"), 1024);
+
+    counters.sub("bytes", 1000);
+    assert_eq!(counters.get("bytes"), 24);
+    counters.sub("bytes", 100);
+    assert_eq!(counters.get("bytes"), 0);
+
+    counters.sub("new", 1);
+    assert_eq!(counters.get("new"), 0);
+    counters.add("new", u64::MAX);
+    counters.add("new", 1);
+    assert_eq!(counters.get("new"), u64::MAX);
+}
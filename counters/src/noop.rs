@@ -1,5 +1,9 @@
 use crate::filters::Filter;
+use crate::{CounterDiff, SampleStats};
 use std::io;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::time::Duration;
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
@@ -10,20 +14,122 @@ impl Counters {
         Counters
     }
     pub fn event(&self, _key: &str) {}
+    pub fn add(&self, _key: &str, _n: u64) {}
+    pub fn sub(&self, _key: &str, _n: u64) {}
+    pub fn set(&self, _key: &str, _value: u64) {}
     pub fn reset_event(&self, _key: &str) {}
     pub fn reset_events<F: Filter>(&self, _filter: F) {}
     pub fn reset_all(&self) {}
     pub fn retain<F: Filter>(&self, _filter: F) {}
+    pub fn sample(&self, _key: &str, _value: u64) {}
+    pub fn stats(&self, _key: &str) -> Option<SampleStats> {
+        None
+    }
+    pub fn record(&self, _key: &str, _value: u64) {}
+    pub fn set_histogram_buckets(&self, _key: &str, _bounds: &[u64]) {}
+    pub fn histogram(&self, _key: &str) -> Vec<(Range<u64>, u64)> {
+        Vec::new()
+    }
+    pub fn start(&self, _key: &str) {}
+    pub fn stop(&self, _key: &str) -> Duration {
+        Duration::ZERO
+    }
+    pub fn scope<'a>(&'a self, _key: &'a str) -> Scope<'a> {
+        Scope(PhantomData)
+    }
+    pub fn record_duration(&self, _key: &str, _duration: Duration) {}
+    pub fn duration(&self, _key: &str) -> Duration {
+        Duration::ZERO
+    }
+    pub fn for_each_duration(&self, _f: impl FnMut(&str, Duration)) {}
     pub fn get(&self, _key: &str) -> u64 {
         0
     }
     pub fn accumulate<F: Filter>(&self, _filter: F) -> u64 {
         0
     }
-    pub fn print<F: Filter>(&self, _filter: F, _out: &mut io::Write) -> io::Result<()> {
+    pub fn accumulate_subtree(&self, _namespace: &str) -> u64 {
+        0
+    }
+    pub fn print_tree<F: Filter>(&self, _filter: F, _to: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+    pub fn print_tree_to_stdout<F: Filter>(&self, _filter: F) {}
+    pub fn print<F: Filter>(&self, _filter: F, _out: &mut dyn io::Write) -> io::Result<()> {
         Ok(())
     }
     pub fn print_to_stdout<F: Filter>(&self, _filter: F) {}
+    pub fn print_prometheus(&self, _namespace: &str, _to: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+    pub fn print_sorted<F: Filter>(
+        &self,
+        _filter: F,
+        _sort_by: SortBy,
+        _max: Option<usize>,
+        _to: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    pub fn print_sorted_to_stdout<F: Filter>(
+        &self,
+        _filter: F,
+        _sort_by: SortBy,
+        _max: Option<usize>,
+    ) {
+    }
+    pub fn print_folded_stacks<F: Filter>(
+        &self,
+        _filter: F,
+        _separator: &str,
+        _to: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    #[cfg(feature = "backtrace")]
+    pub fn event_with_backtrace(&self, _key: &str) {}
+    #[cfg(feature = "backtrace")]
+    pub fn print_stacks(&self, _key: &str, _max: usize, _to: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+    #[cfg(feature = "backtrace")]
+    pub fn print_stacks_to_stdout(&self, _key: &str, _max: usize) {}
+    pub fn append(&self, _other: &Counters) {}
+    pub fn snapshot(&self) -> Counters {
+        Counters
+    }
+    pub fn diff(&self, _other: &Counters) -> CounterDiff {
+        CounterDiff::default()
+    }
+    pub fn enable_history(&self, _key: &str, _samples: usize) {}
+    pub fn disable_history(&self, _key: &str) {}
+    pub fn sample_all(&self) {}
+    pub fn history(&self, _key: &str) -> Vec<u64> {
+        Vec::new()
+    }
+    #[cfg(feature = "svg")]
+    pub fn print_svg_charts<F: Filter>(
+        &self,
+        _filter: F,
+        _to: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    pub fn for_each(&self, _f: impl FnMut(&str, u64)) {}
+    #[cfg(feature = "serialization")]
+    pub fn save_json<P: AsRef<std::path::Path>>(&self, _path: P) -> io::Result<()> {
+        Ok(())
+    }
+    #[cfg(feature = "serialization")]
+    pub fn load_json<P: AsRef<std::path::Path>>(_path: P) -> io::Result<Self> {
+        Ok(Counters)
+    }
+}
+
+impl Default for Counters {
+    fn default() -> Self {
+        Counters
+    }
 }
 
 #[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
@@ -36,11 +142,61 @@ impl Table {
     {
         Table
     }
+    pub fn with_aggregates(self, _aggregates: &[Aggregate]) -> Self {
+        self
+    }
     pub fn add_row(&self, _row: &Counters) -> usize {
         0
     }
-    pub fn print(&self, _to: &mut io::Write) -> io::Result<()> {
+    pub fn print(&self, _to: &mut dyn io::Write) -> io::Result<()> {
         Ok(())
     }
     pub fn print_to_stdout(&self) {}
+    pub fn print_markdown(&self, _to: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+    pub fn print_markdown_to_stdout(&self) {}
+    #[cfg(feature = "serialization")]
+    pub fn save_json<P: AsRef<std::path::Path>>(&self, _path: P) -> io::Result<()> {
+        Ok(())
+    }
+    #[cfg(feature = "serialization")]
+    pub fn load_json<P: AsRef<std::path::Path>>(_path: P) -> io::Result<Self> {
+        Ok(Table)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum Aggregate {
+    Sum,
+    Mean,
+    Min,
+    Max,
 }
+
+impl Aggregate {
+    pub const ALL: [Aggregate; 4] = [
+        Aggregate::Sum,
+        Aggregate::Mean,
+        Aggregate::Min,
+        Aggregate::Max,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Aggregate::Sum => "sum",
+            Aggregate::Mean => "mean",
+            Aggregate::Min => "min",
+            Aggregate::Max => "max",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    Key,
+    Value,
+}
+
+pub struct Scope<'a>(PhantomData<&'a ()>);
@@ -0,0 +1,101 @@
+use crate::Counters;
+use std::collections::BTreeMap;
+use std::io;
+
+impl Counters {
+    /// Print the counters in the Prometheus text exposition format, for
+    /// example to serve them to a Prometheus scraper.
+    ///
+    /// Each counter is exposed as a gauge (since counters can be set and reset)
+    /// named `{namespace}_{key}`, where the characters that are not allowed in
+    /// metric names are replaced with `_`. Counters which keys end up with the
+    /// same name are added together. The time accumulated with
+    /// `record_duration` is exposed as a separate `{namespace}_{key}_seconds`
+    /// gauge.
+    ///
+    /// ```
+    /// use counters::Counters;
+    /// use std::time::Duration;
+    ///
+    /// let counters = Counters::new();
+    /// counters.set("tiling::alloc::slow path", 3);
+    /// counters.record_duration("upload", Duration::from_millis(1500));
+    ///
+    /// let mut output = Vec::new();
+    /// counters.print_prometheus("renderer", &mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "# HELP renderer_tiling_alloc_slow_path tiling::alloc::slow path\n\
+    ///      ## TYPE renderer_tiling_alloc_slow_path gauge\n\
+    ///      renderer_tiling_alloc_slow_path 3\n\
+    ///      ## HELP renderer_upload upload\n\
+    ///      ## TYPE renderer_upload gauge\n\
+    ///      renderer_upload 1\n\
+    ///      ## HELP renderer_upload_seconds upload (seconds)\n\
+    ///      ## TYPE renderer_upload_seconds gauge\n\
+    ///      renderer_upload_seconds 1.5\n",
+    /// );
+    /// ```
+    pub fn print_prometheus(&self, namespace: &str, to: &mut dyn io::Write) -> io::Result<()> {
+        // Prometheus values are 64 bit floats.
+        let mut metrics: BTreeMap<String, (f64, Vec<String>)> = BTreeMap::new();
+        let mut add = |key: &str, suffix: &str, help: String, value: f64| {
+            let name = if namespace.is_empty() {
+                metric_name(&format!("{}{}", key, suffix))
+            } else {
+                metric_name(&format!("{}_{}{}", namespace, key, suffix))
+            };
+            let metric = metrics.entry(name).or_default();
+            metric.0 += value;
+            metric.1.push(help);
+        };
+        for (key, value) in self.events.borrow().iter() {
+            add(key, "", key.clone(), *value as f64);
+        }
+        for (key, duration) in self.durations.borrow().iter() {
+            let help = format!("{} (seconds)", key);
+            add(key, " seconds", help, duration.as_secs_f64());
+        }
+
+        for (name, (value, mut keys)) in metrics {
+            keys.sort_unstable();
+            let help = keys.join(", ").replace('\\', "\\\\").replace('\n', "\\n");
+            writeln!(to, "# HELP {} {}", name, help)?;
+            writeln!(to, "# TYPE {} gauge", name)?;
+            writeln!(to, "{} {}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces the sequences of characters that are not allowed in Prometheus
+/// metric names with `_`.
+///
+/// Colons are allowed but reserved for recording rules, so they are replaced
+/// as well.
+fn metric_name(key: &str) -> String {
+    let mut name = String::with_capacity(key.len());
+    for c in key.chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c);
+        } else if !name.ends_with('_') {
+            name.push('_');
+        }
+    }
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+#[test]
+fn metric_names() {
+    assert_eq!(metric_name("draw calls"), "draw_calls");
+    assert_eq!(metric_name("tiling::alloc"), "tiling_alloc");
+    assert_eq!(metric_name("frame time (ns)"), "frame_time_ns_");
+    assert_eq!(metric_name("3d"), "_3d");
+    assert_eq!(metric_name("été"), "_t_");
+    assert_eq!(metric_name(""), "_");
+}
@@ -0,0 +1,39 @@
+/// Statistics of the values passed to `Counters::sample` for a key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub struct SampleStats {
+    pub count: u64,
+    /// The sum of the values, saturating at `u64::MAX`.
+    pub sum: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+#[cfg(not(feature = "noop"))]
+impl SampleStats {
+    pub(crate) fn new(value: u64) -> Self {
+        SampleStats {
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    pub(crate) fn add(&mut self, other: &SampleStats) {
+        self.count += other.count;
+        self.sum = self.sum.saturating_add(other.sum);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+impl SampleStats {
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        self.sum as f64 / self.count as f64
+    }
+}
@@ -0,0 +1,111 @@
+use crate::filters::Filter;
+use crate::Counters;
+use std::io;
+use svg_fmt::*;
+
+const WIDTH: f32 = 600.0;
+const MARGIN: f32 = 10.0;
+const TITLE_HEIGHT: f32 = 20.0;
+const CHART_HEIGHT: f32 = 100.0;
+
+impl Counters {
+    /// Print the histories of the counters as line charts in SVG format, one
+    /// chart per key sorted by name.
+    ///
+    /// Only the counters with a history (see `enable_history`) are charted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use counters::Counters;
+    /// use counters::filters::All;
+    ///
+    /// let counters = Counters::new();
+    /// counters.enable_history("draw calls", 60);
+    ///
+    /// for frame in 0..60 {
+    ///     for _ in 0..(frame % 7) {
+    ///         counters.event("draw calls");
+    ///     }
+    ///     counters.sample_all();
+    ///     counters.reset_all();
+    /// }
+    ///
+    /// let mut svg = Vec::new();
+    /// counters.print_svg_charts(All, &mut svg).unwrap();
+    /// ```
+    pub fn print_svg_charts<F: Filter>(
+        &self,
+        mut filter: F,
+        to: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        let histories = self.histories.borrow();
+        let mut keys: Vec<&String> = histories
+            .keys()
+            .filter(|key| filter.apply(key, self.get(key)))
+            .collect();
+        keys.sort();
+
+        let height = MARGIN + keys.len() as f32 * (TITLE_HEIGHT + CHART_HEIGHT + MARGIN);
+        writeln!(
+            to,
+            "{}",
+            BeginSvg {
+                w: WIDTH,
+                h: height
+            }
+        )?;
+
+        let mut layout = VerticalLayout::new(MARGIN, MARGIN, WIDTH - 2.0 * MARGIN);
+        for key in keys {
+            let samples = &histories[key].samples;
+            let max = samples.iter().cloned().max().unwrap_or(0).max(1) as f32;
+
+            let title = layout.push_rectangle(TITLE_HEIGHT);
+            writeln!(
+                to,
+                "    {}",
+                text(title.x, title.y + 14.0, escape(key)).size(12.0)
+            )?;
+            let chart = layout.push_rectangle(CHART_HEIGHT);
+            writeln!(
+                to,
+                "    {}",
+                chart
+                    .clone()
+                    .fill(rgb(245, 245, 245))
+                    .stroke(rgb(200, 200, 200))
+            )?;
+            writeln!(
+                to,
+                "    {}",
+                text(chart.x + chart.w - 4.0, chart.y + 12.0, max.to_string())
+                    .align(Align::Right)
+                    .color(rgb(100, 100, 100))
+            )?;
+
+            let step = chart.w / (samples.len().max(2) - 1) as f32;
+            let mut line = path().fill(Fill::None).stroke(Stroke::Color(blue(), 1.5));
+            for (i, value) in samples.iter().enumerate() {
+                let x = chart.x + i as f32 * step;
+                let y = chart.y + chart.h * (1.0 - *value as f32 / max);
+                line = if i == 0 {
+                    line.move_to(x, y)
+                } else {
+                    line.line_to(x, y)
+                };
+            }
+            writeln!(to, "    {}", line)?;
+
+            layout.advance(MARGIN);
+        }
+
+        writeln!(to, "{}", EndSvg)
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -3,7 +3,7 @@ use crate::Counters;
 use std::cell::RefCell;
 use std::io;
 
-/// Helper to print counters as a table in csv format.
+/// Helper to print counters as a table in csv or markdown format.
 ///
 /// # Example
 ///
@@ -41,6 +41,53 @@ use std::io;
 pub struct Table {
     labels: Vec<String>,
     rows: RefCell<Vec<Counters>>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    aggregates: Vec<Aggregate>,
+}
+
+/// A summary row computed over the rows of a `Table`, see `Table::with_aggregates`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
+pub enum Aggregate {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+impl Aggregate {
+    pub const ALL: [Aggregate; 4] = [
+        Aggregate::Sum,
+        Aggregate::Mean,
+        Aggregate::Min,
+        Aggregate::Max,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Aggregate::Sum => "sum",
+            Aggregate::Mean => "mean",
+            Aggregate::Min => "min",
+            Aggregate::Max => "max",
+        }
+    }
+
+    /// Formats the aggregate of a column, or `-` if it is not defined
+    /// because there are no rows.
+    fn compute(self, values: &[u64]) -> String {
+        let sum: u128 = values.iter().map(|v| *v as u128).sum();
+        let value = match self {
+            Aggregate::Sum => return sum.to_string(),
+            Aggregate::Mean if !values.is_empty() => {
+                return format!("{:.2}", sum as f64 / values.len() as f64)
+            }
+            Aggregate::Mean => None,
+            Aggregate::Min => values.iter().min(),
+            Aggregate::Max => values.iter().max(),
+        };
+
+        value.map_or_else(|| "-".to_string(), u64::to_string)
+    }
 }
 
 impl Table {
@@ -51,9 +98,38 @@ impl Table {
         Table {
             labels: labels.iter().map(|label| label.to_string()).collect(),
             rows: RefCell::new(Vec::new()),
+            aggregates: Vec::new(),
         }
     }
 
+    /// Append rows summarizing each column to the printed table, for example
+    /// `&[Aggregate::Mean]` or `&Aggregate::ALL`.
+    ///
+    /// The summary rows are named in an additional first column, which is
+    /// empty for the other rows.
+    ///
+    /// ```
+    /// use counters::*;
+    ///
+    /// let counters = Counters::new();
+    /// let table = Table::new(&["draw calls"]).with_aggregates(&[Aggregate::Mean, Aggregate::Max]);
+    /// for frame in 1..=4 {
+    ///     counters.set("draw calls", frame * 10);
+    ///     table.add_row(&counters);
+    /// }
+    ///
+    /// let mut output = Vec::new();
+    /// table.print(&mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     ",draw calls\n, 10\n, 20\n, 30\n, 40\nmean, 25.00\nmax, 40\n",
+    /// );
+    /// ```
+    pub fn with_aggregates(mut self, aggregates: &[Aggregate]) -> Self {
+        self.aggregates = aggregates.to_vec();
+        self
+    }
+
     /// Add collected counters as a row, preserving only the counters that match this table's labels.
     pub fn add_row(&self, row: &Counters) -> usize {
         let row = row.clone();
@@ -65,23 +141,51 @@ impl Table {
         self.rows.borrow().len()
     }
 
+    /// The name and values of the summary rows.
+    fn summary_rows(&self) -> Vec<(&'static str, Vec<String>)> {
+        let rows = self.rows.borrow();
+        let columns: Vec<Vec<u64>> = self
+            .labels
+            .iter()
+            .map(|label| rows.iter().map(|row| row.get(label)).collect())
+            .collect();
+
+        self.aggregates
+            .iter()
+            .map(|aggregate| {
+                let values = columns.iter().map(|col| aggregate.compute(col)).collect();
+                (aggregate.name(), values)
+            })
+            .collect()
+    }
+
     /// Print in csv format to an io stream.
-    pub fn print(&self, to: &mut io::Write) -> io::Result<()> {
+    pub fn print(&self, to: &mut dyn io::Write) -> io::Result<()> {
+        let named_rows = !self.aggregates.is_empty();
+        if named_rows {
+            write!(to, ",")?;
+        }
         for (i, label) in self.labels.iter().enumerate() {
             if i != 0 {
                 write!(to, ",")?;
             }
             write!(to, "{}", label)?;
         }
-        writeln!(to, "")?;
+        writeln!(to)?;
         for row in self.rows.borrow().iter() {
+            if named_rows {
+                write!(to, ", ")?;
+            }
             for (i, label) in self.labels.iter().enumerate() {
                 if i != 0 {
                     write!(to, ", ")?;
                 }
                 write!(to, "{}", row.get(label))?;
             }
-            writeln!(to, "")?;
+            writeln!(to)?;
+        }
+        for (name, values) in self.summary_rows() {
+            writeln!(to, "{}, {}", name, values.join(", "))?;
         }
 
         Ok(())
@@ -91,4 +195,98 @@ impl Table {
     pub fn print_to_stdout(&self) {
         self.print(&mut io::stdout()).unwrap();
     }
+
+    /// Print as a GitHub-flavored markdown table to an io stream, with the
+    /// values right-aligned and the cells padded to the width of their column.
+    ///
+    /// ```
+    /// use counters::*;
+    ///
+    /// let counters = Counters::new();
+    /// let table = Table::new(&["draw calls", "uploads"]);
+    /// counters.set("draw calls", 1200);
+    /// counters.set("uploads", 3);
+    /// table.add_row(&counters);
+    ///
+    /// let mut output = Vec::new();
+    /// table.print_markdown(&mut output).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(output).unwrap(),
+    ///     "| draw calls | uploads |\n\
+    ///      | ---------: | ------: |\n\
+    ///      |       1200 |       3 |\n",
+    /// );
+    /// ```
+    pub fn print_markdown(&self, to: &mut dyn io::Write) -> io::Result<()> {
+        let named_rows = !self.aggregates.is_empty();
+        let mut labels: Vec<String> = self
+            .labels
+            .iter()
+            .map(|label| label.replace('|', "\\|"))
+            .collect();
+        let mut rows: Vec<Vec<String>> = self
+            .rows
+            .borrow()
+            .iter()
+            .map(|row| {
+                self.labels
+                    .iter()
+                    .map(|label| row.get(label).to_string())
+                    .collect()
+            })
+            .collect();
+        if named_rows {
+            labels.insert(0, String::new());
+            for row in &mut rows {
+                row.insert(0, String::new());
+            }
+            for (name, mut values) in self.summary_rows() {
+                values.insert(0, format!("**{}**", name));
+                rows.push(values);
+            }
+        }
+        // Separators need at least three dashes.
+        let widths: Vec<usize> = labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                rows.iter()
+                    .map(|row| row[i].len())
+                    .fold(label.chars().count().max(3), usize::max)
+            })
+            .collect();
+
+        write!(to, "|")?;
+        for (label, width) in labels.iter().zip(&widths) {
+            write!(to, " {:<width$} |", label, width = width)?;
+        }
+        writeln!(to)?;
+        write!(to, "|")?;
+        for (i, width) in widths.iter().enumerate() {
+            if named_rows && i == 0 {
+                write!(to, " {} |", "-".repeat(*width))?;
+            } else {
+                write!(to, " {}: |", "-".repeat(width - 1))?;
+            }
+        }
+        writeln!(to)?;
+        for row in &rows {
+            write!(to, "|")?;
+            for (i, (value, width)) in row.iter().zip(&widths).enumerate() {
+                if named_rows && i == 0 {
+                    write!(to, " {:<width$} |", value, width = width)?;
+                } else {
+                    write!(to, " {:>width$} |", value, width = width)?;
+                }
+            }
+            writeln!(to)?;
+        }
+
+        Ok(())
+    }
+
+    /// Print as a markdown table to stdout.
+    pub fn print_markdown_to_stdout(&self) {
+        self.print_markdown(&mut io::stdout()).unwrap();
+    }
 }
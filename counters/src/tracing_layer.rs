@@ -0,0 +1,126 @@
+use crate::Counters;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing_core::span::{Attributes, Id};
+use tracing_core::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// With the `noop` feature, the layer doesn't look at events and spans at all.
+const ENABLED: bool = !cfg!(feature = "noop");
+
+/// A `tracing-subscriber` layer that counts events and measures spans.
+///
+/// - Each event increments the counter `"{level} {target}"`, for example
+///   `"WARN my_crate::loader"`.
+/// - Each closed span increments the counter `"{target}::{name}"` and adds the
+///   time spent inside of the span to it, like `Counters::record_duration`. The
+///   total is available through `Counters::duration`.
+///
+/// # Example
+///
+/// ```
+/// use counters::CountersLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let layer = CountersLayer::new();
+/// let counters = layer.counters();
+/// let subscriber = tracing_subscriber::registry().with(layer);
+///
+/// tracing::subscriber::with_default(subscriber, || {
+///     tracing::warn!(target: "loader", "missing texture");
+/// });
+///
+/// counters.lock().unwrap().print_to_stdout(counters::filters::All);
+/// ```
+pub struct CountersLayer {
+    counters: Arc<Mutex<Counters>>,
+}
+
+/// The time spent inside of a span so far.
+struct SpanTiming {
+    busy: Duration,
+    entered: Option<Instant>,
+}
+
+impl CountersLayer {
+    pub fn new() -> Self {
+        CountersLayer {
+            counters: Arc::new(Mutex::new(Counters::new())),
+        }
+    }
+
+    /// The counters updated by this layer, shared with the subscriber.
+    pub fn counters(&self) -> Arc<Mutex<Counters>> {
+        self.counters.clone()
+    }
+}
+
+impl Default for CountersLayer {
+    fn default() -> Self {
+        CountersLayer::new()
+    }
+}
+
+impl<S> Layer<S> for CountersLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if !ENABLED {
+            return;
+        }
+        let metadata = event.metadata();
+        let key = format!("{} {}", metadata.level(), metadata.target());
+        if let Ok(counters) = self.counters.lock() {
+            counters.event(&key);
+        }
+    }
+
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if !ENABLED {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanTiming {
+                busy: Duration::default(),
+                entered: None,
+            });
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>() {
+                timing.entered = Some(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>() {
+                if let Some(entered) = timing.entered.take() {
+                    timing.busy += entered.elapsed();
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(&id) {
+            Some(span) => span,
+            None => return,
+        };
+        let timing = match span.extensions_mut().remove::<SpanTiming>() {
+            Some(timing) => timing,
+            None => return,
+        };
+
+        let metadata = span.metadata();
+        let key = format!("{}::{}", metadata.target(), metadata.name());
+        if let Ok(counters) = self.counters.lock() {
+            counters.record_duration(&key, timing.busy);
+        }
+    }
+}
@@ -1,77 +1,593 @@
-use stb_truetype_rust::{stbtt_BakeFontBitmap, stbtt_bakedchar as BakedChar};
+use clap::{Parser, ValueEnum};
+use stb_truetype_rust::{
+    stbtt_BakeFontBitmap, stbtt_GetCodepointKernAdvance, stbtt_InitFont, stbtt_ScaleForPixelHeight,
+    stbtt_bakedchar as BakedChar, stbtt_fontinfo as FontInfo,
+};
+use std::fmt;
 use std::io::Write;
+use std::ops::RangeInclusive;
 
-const FONT_HEIGHT: f32 = 18.0;
-const W: i32 = 256;
-const H: i32 = 128;
-const NUM_CHARS: usize = 96;
-const FIRST_CHAR: i32 = 32;
+/// Generates the embedded bitmap font of the debug overlay.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Path to the TrueType font.
+    font: String,
+    /// The output file. The generated module is printed if omitted.
+    output: Option<String>,
+    /// Heights of the font in pixels, separated by commas. Each size is baked
+    /// as a separate face of the atlas, the first one being the default face.
+    #[arg(long, value_delimiter = ',', default_value = "18")]
+    size: Vec<f32>,
+    /// Path to a bold variant of the font, baked at the same sizes.
+    #[arg(long)]
+    bold: Option<String>,
+    /// Width of the atlas in pixels. By default, the smallest power of two
+    /// from 256 to 4096 with which the glyphs fit in a square.
+    #[arg(long)]
+    atlas_width: Option<i32>,
+    /// Maximum height of the atlas in pixels, by default the width of the
+    /// atlas. The generated atlas only contains the rows that are used.
+    #[arg(long)]
+    atlas_height: Option<i32>,
+    /// The characters to bake, as a comma separated list of codepoints
+    /// (`65`, `0x41` or `U+0041`), inclusive ranges of codepoints (`0xA0-0xFF`)
+    /// and named sets (`ascii`, `latin1`, `arrows`, `box`, `blocks`).
+    #[arg(long, default_value = "ascii", value_parser = parse_charset)]
+    chars: Charset,
+    /// Generate a signed distance field atlas, which scales better than
+    /// coverage when the overlay is displayed with a large scale factor.
+    #[arg(long)]
+    distance_field: bool,
+    /// The distance to the edge of the glyphs, in atlas pixels, at which the
+    /// distance field saturates.
+    #[arg(long, default_value_t = 4.0)]
+    spread: f32,
+    /// What to generate. By default, inferred from the extension of the output
+    /// file.
+    #[arg(long, value_enum)]
+    kind: Option<OutputKind>,
+    /// Text displayed with each face in the HTML preview.
+    #[arg(
+        long,
+        default_value = "The quick brown fox jumps over the lazy dog. 0123456789"
+    )]
+    sample: String,
+}
 
-fn main() {
-    let mut args = std::env::args();
-    let _ = args.next().unwrap();
-    let font_name = args.next().unwrap();
-    let output_name = args.next();
-
-    let font = std::fs::read(font_name.as_str()).unwrap();
-
-    let mut pixels = vec![0; (W * H) as usize];
-    let mut char_data = Vec::new();
-    for _ in 0..NUM_CHARS {
-        char_data.push(BakedChar {
-            x0: 0,
-            y0: 0,
-            x1: 0,
-            y1: 0,
-            xoff: 0.0,
-            yoff: 0.0,
-            xadvance: 0.0,
-        });
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum OutputKind {
+    /// A rust module with the glyph metrics and the atlas.
+    Rust,
+    /// A png image of the atlas.
+    Png,
+    /// A JSON description of the glyph metrics and the atlas, for tools and
+    /// runtime font loading.
+    Json,
+    /// An HTML page showing the atlas, the boxes and metrics of the glyphs and
+    /// some sample text, to inspect changes before regenerating the module.
+    Html,
+}
+
+/// Sorted, non-overlapping and non-adjacent ranges of codepoints.
+#[derive(Clone, Debug)]
+struct Charset(Vec<RangeInclusive<u32>>);
+
+const NAMED_CHARSETS: &[(&str, RangeInclusive<u32>)] = &[
+    ("ascii", 0x20..=0x7F),
+    ("latin1", 0xA0..=0xFF),
+    ("arrows", 0x2190..=0x21FF),
+    ("box", 0x2500..=0x257F),
+    ("blocks", 0x2580..=0x259F),
+];
+
+fn parse_codepoint(src: &str) -> Result<u32, String> {
+    let src = src.trim();
+    let hex = src
+        .strip_prefix("0x")
+        .or_else(|| src.strip_prefix("U+"))
+        .or_else(|| src.strip_prefix("u+"));
+    let codepoint = match hex {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => src.parse(),
+    };
+    let codepoint = codepoint.map_err(|_| format!("invalid codepoint {src:?}"))?;
+    if char::from_u32(codepoint).is_none() {
+        return Err(format!("{src:?} is not a valid unicode scalar value"));
     }
 
-    let mut num_rows = unsafe {
-        stbtt_BakeFontBitmap(
-            font.as_ptr(),
-            0,
-            FONT_HEIGHT,
-            pixels.as_mut_ptr(),
-            W,
-            H,
-            FIRST_CHAR,
-            NUM_CHARS as i32,
-            char_data.as_mut_ptr(),
-        )
+    Ok(codepoint)
+}
+
+fn parse_charset(src: &str) -> Result<Charset, String> {
+    let mut ranges = Vec::new();
+    for item in src.split(',') {
+        let item = item.trim();
+        if let Some((_, range)) = NAMED_CHARSETS.iter().find(|(name, _)| *name == item) {
+            ranges.push(range.clone());
+        } else if let Some((first, last)) = item.split_once('-') {
+            let (first, last) = (parse_codepoint(first)?, parse_codepoint(last)?);
+            if last < first {
+                return Err(format!("empty range {item:?}"));
+            }
+            ranges.push(first..=last);
+        } else {
+            let c = parse_codepoint(item)?;
+            ranges.push(c..=c);
+        }
+    }
+
+    ranges.sort_by_key(|r| *r.start());
+    let mut merged: Vec<RangeInclusive<u32>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(prev) if *range.start() <= prev.end().saturating_add(1) => {
+                *prev = *prev.start()..=*range.end().max(prev.end());
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    Ok(Charset(merged))
+}
+
+#[derive(Debug)]
+enum Error {
+    /// The glyphs don't fit in the atlas, which may be solved by a larger
+    /// atlas.
+    AtlasFull,
+    Font(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::AtlasFull => f.write_str("The glyphs don't fit in the atlas"),
+            Error::Font(message) => f.write_str(message),
+        }
+    }
+}
+
+/// A size and weight of the font, baked into the atlas.
+struct Face {
+    size: f32,
+    bold: bool,
+    /// The glyphs, in the order of the codepoints of the charset.
+    char_data: Vec<BakedChar>,
+    kerning: Vec<(u16, u16, f32)>,
+}
+
+/// The atlas shared by all faces.
+struct Atlas {
+    pixels: Vec<u8>,
+    width: i32,
+    height: i32,
+    /// The number of rows of the atlas that are used.
+    num_rows: i32,
+    /// Where the next glyph goes if it fits in the current row.
+    x: i32,
+    y: i32,
+}
+
+impl Atlas {
+    fn new(width: i32, height: i32) -> Self {
+        Atlas {
+            pixels: vec![0; (width * height) as usize],
+            width,
+            height,
+            num_rows: 1,
+            x: 1,
+            y: 1,
+        }
+    }
+
+    /// Packs a glyph the same way stb_truetype does, and returns its metrics
+    /// with its position in the atlas.
+    fn add(&mut self, glyph: GlyphImage) -> Result<BakedChar, Error> {
+        let (w, h) = (self.width, self.height);
+        let (gw, gh) = (glyph.width, glyph.height);
+        if self.x + gw + 1 >= w {
+            self.y = self.num_rows;
+            self.x = 1;
+        }
+        let (x, y) = (self.x, self.y);
+        if y + gh + 1 >= h {
+            return Err(Error::AtlasFull);
+        }
+        for row in 0..gh {
+            let src = (row * gw) as usize;
+            let dst = ((y + row) * w + x) as usize;
+            self.pixels[dst..dst + gw as usize]
+                .copy_from_slice(&glyph.pixels[src..src + gw as usize]);
+        }
+
+        self.x += gw + 1;
+        self.num_rows = self.num_rows.max(y + gh + 1);
+
+        let mut c = glyph.metrics;
+        c.x0 = x as _;
+        c.y0 = y as _;
+        c.x1 = (x + gw) as _;
+        c.y1 = (y + gh) as _;
+
+        Ok(c)
+    }
+
+    /// Adds a fully opaque block of pixels in the first column of the rows
+    /// after the glyphs.
+    fn add_opaque_block(&mut self, size: i32) -> Result<(), Error> {
+        if self.num_rows + size > self.height {
+            return Err(Error::AtlasFull);
+        }
+        for y in self.num_rows..self.num_rows + size {
+            for x in 0..size {
+                self.pixels[(y * self.width + x) as usize] = 255;
+            }
+        }
+        self.num_rows += size;
+
+        Ok(())
+    }
+}
+
+/// The glyphs of distance field atlases are baked at a higher resolution
+/// to compute the distances.
+const DISTANCE_FIELD_OVERSAMPLING: i32 = 4;
+
+/// A glyph before it is packed into the atlas.
+struct GlyphImage {
+    pixels: Vec<u8>,
+    width: i32,
+    height: i32,
+    metrics: BakedChar,
+}
+
+/// Bakes each range of the charset separately with stb_truetype, which only
+/// handles consecutive codepoints, and packs the resulting glyphs into the
+/// atlas.
+///
+/// The glyphs are packed the same way stb_truetype packs them, so a single
+/// range of a single face produces the same coverage atlas as baking it
+/// directly.
+fn bake_glyphs(
+    font: &[u8],
+    size: f32,
+    args: &Args,
+    atlas: &mut Atlas,
+) -> Result<Vec<BakedChar>, Error> {
+    // Generous enough to bake any reasonable range before packing it into the
+    // atlas.
+    const SCRATCH_HEIGHT: i32 = 4096;
+
+    let oversampling = if args.distance_field {
+        DISTANCE_FIELD_OVERSAMPLING
+    } else {
+        1
     };
+    let scratch_width = atlas.width * oversampling;
+    let mut scratch = vec![0; (scratch_width * SCRATCH_HEIGHT) as usize];
+    let mut glyphs = Vec::new();
 
-    assert!(num_rows != 0, "Failed to generate the atlas");
-    assert!(num_rows > 0, "The glyphs don't fit in the atlas");
+    for range in &args.chars.0 {
+        let count = (range.end() - range.start() + 1) as usize;
+        let mut char_data: Vec<BakedChar> = (0..count)
+            .map(|_| BakedChar {
+                x0: 0,
+                y0: 0,
+                x1: 0,
+                y1: 0,
+                xoff: 0.0,
+                yoff: 0.0,
+                xadvance: 0.0,
+            })
+            .collect();
 
-    // Ensure a pixel at coordinate (0, num_rows) is fully opaque it will be used
-    // as a source to display fully opaque shapes.
-    pixels[num_rows as usize * W as usize] = 255;
-    num_rows += 1;
+        scratch.fill(0);
+        let result = unsafe {
+            stbtt_BakeFontBitmap(
+                font.as_ptr(),
+                0,
+                size * oversampling as f32,
+                scratch.as_mut_ptr(),
+                scratch_width,
+                SCRATCH_HEIGHT,
+                *range.start() as i32,
+                count as i32,
+                char_data.as_mut_ptr(),
+            )
+        };
+        if result == 0 {
+            return Err(Error::Font("Failed to generate the atlas".into()));
+        }
+        if result < 0 {
+            // The scratch bitmap is too small, which is the case of the atlas
+            // as well.
+            return Err(Error::AtlasFull);
+        }
 
-    if let Some(output_name) = &output_name {
-        if output_name.ends_with(".png") {
-            dump_png(&pixels, W, H, output_name.as_str());
-        } else if output_name.ends_with(".rs") {
-            let mut output = std::fs::File::create(&output_name).unwrap();
-            generate_code(&pixels, W as i32, num_rows, &char_data, &font_name, &mut output).unwrap();
+        for c in char_data {
+            let glyph = if args.distance_field {
+                distance_field(&scratch, scratch_width, c, args.spread)
+            } else {
+                coverage(&scratch, scratch_width, c)
+            };
+            glyphs.push(atlas.add(glyph)?);
         }
+    }
+
+    if glyphs.len() > u16::MAX as usize {
+        return Err(Error::Font("Too many glyphs".into()));
+    }
+
+    Ok(glyphs)
+}
+
+/// Extracts a glyph baked by stb_truetype.
+fn coverage(scratch: &[u8], stride: i32, c: BakedChar) -> GlyphImage {
+    let (width, height) = ((c.x1 - c.x0) as i32, (c.y1 - c.y0) as i32);
+    let mut pixels = Vec::with_capacity((width * height) as usize);
+    for row in 0..height {
+        let start = ((c.y0 as i32 + row) * stride + c.x0 as i32) as usize;
+        pixels.extend_from_slice(&scratch[start..start + width as usize]);
+    }
+
+    GlyphImage {
+        pixels,
+        width,
+        height,
+        metrics: c,
+    }
+}
+
+/// Computes the signed distance field of a glyph baked by stb_truetype at
+/// `DISTANCE_FIELD_OVERSAMPLING` times the font size.
+///
+/// Distances are positive inside of the glyph, and encoded so that 128 is the
+/// edge, and 0 and 255 are `spread` atlas pixels away from it. The glyph is
+/// padded so that the distances fade out around it.
+fn distance_field(scratch: &[u8], stride: i32, c: BakedChar, spread: f32) -> GlyphImage {
+    let os = DISTANCE_FIELD_OVERSAMPLING;
+    let (src_width, src_height) = ((c.x1 - c.x0) as i32, (c.y1 - c.y0) as i32);
+    let inside = |x: i32, y: i32| {
+        x >= 0
+            && y >= 0
+            && x < src_width
+            && y < src_height
+            && scratch[((c.y0 as i32 + y) * stride + c.x0 as i32 + x) as usize] >= 128
+    };
+
+    let padding = spread.ceil() as i32;
+    let (width, height) = if src_width == 0 || src_height == 0 {
+        (0, 0)
     } else {
-        generate_code(
-            &pixels,
-            W as i32,
-            num_rows,
-            &char_data,
-            &font_name,
-            &mut std::io::stdout(),
+        (
+            (src_width + os - 1) / os + padding * 2,
+            (src_height + os - 1) / os + padding * 2,
         )
-        .unwrap();
+    };
+
+    let radius = (spread * os as f32).ceil() as i32;
+    let mut pixels = Vec::with_capacity((width * height) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            // The high resolution pixel at the center of the atlas pixel.
+            let sx = (x - padding) * os + os / 2;
+            let sy = (y - padding) * os + os / 2;
+            let is_inside = inside(sx, sy);
+
+            let mut nearest = radius as f32;
+            for dy in -radius..=radius {
+                for dx in -radius..=radius {
+                    if inside(sx + dx, sy + dy) != is_inside {
+                        nearest = nearest.min(((dx * dx + dy * dy) as f32).sqrt());
+                    }
+                }
+            }
+
+            // The edge is half way between the nearest pixels of either side.
+            let distance = (nearest - 0.5).max(0.0) / os as f32;
+            let distance = if is_inside { distance } else { -distance };
+            let value = (0.5 + distance / (2.0 * spread)).clamp(0.0, 1.0);
+            pixels.push((value * 255.0).round() as u8);
+        }
+    }
+
+    let scale = 1.0 / os as f32;
+    GlyphImage {
+        pixels,
+        width,
+        height,
+        metrics: BakedChar {
+            // Glyph offsets are whole pixels.
+            xoff: (c.xoff * scale).round() - padding as f32,
+            yoff: (c.yoff * scale).round() - padding as f32,
+            xadvance: c.xadvance * scale,
+            ..c
+        },
+    }
+}
+
+/// The kerning of the pairs of baked characters that have any, as indices of
+/// the glyphs and an advance in pixels, sorted by glyph indices.
+fn kerning_pairs(font: &[u8], size: f32, args: &Args) -> Result<Vec<(u16, u16, f32)>, Error> {
+    // Same as stbtt_fontinfo's zero-initialized C declaration, filled by
+    // stbtt_InitFont.
+    let mut info: FontInfo = unsafe { std::mem::zeroed() };
+    if unsafe { stbtt_InitFont(&mut info, font.as_ptr(), 0) } == 0 {
+        return Err(Error::Font("Failed to read the font".into()));
+    }
+    // The scale used by stbtt_BakeFontBitmap.
+    let scale = unsafe { stbtt_ScaleForPixelHeight(&mut info, size) };
+
+    let codepoints: Vec<u32> = args.chars.0.iter().flat_map(|r| r.clone()).collect();
+    let mut pairs = Vec::new();
+    for (left, &a) in codepoints.iter().enumerate() {
+        for (right, &b) in codepoints.iter().enumerate() {
+            let kern = unsafe { stbtt_GetCodepointKernAdvance(&mut info, a as i32, b as i32) };
+            if kern != 0 {
+                pairs.push((left as u16, right as u16, kern as f32 * scale));
+            }
+        }
+    }
+
+    Ok(pairs)
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let kind = match (args.kind, &args.output) {
+        (Some(kind), _) => kind,
+        (None, Some(output)) if output.ends_with(".png") => OutputKind::Png,
+        (None, Some(output)) if output.ends_with(".json") => OutputKind::Json,
+        (None, Some(output)) if output.ends_with(".html") => OutputKind::Html,
+        (None, Some(output)) if !output.ends_with(".rs") => {
+            eprintln!("Unknown output extension, use --kind to specify the output kind.");
+            std::process::exit(1);
+        }
+        (None, _) => OutputKind::Rust,
+    };
+    if kind == OutputKind::Png && args.output.is_none() {
+        eprintln!("An output file is required to generate an image.");
+        std::process::exit(1);
+    }
+
+    if args.distance_field && args.spread <= 0.0 {
+        eprintln!("The spread of the distance field must be positive.");
+        std::process::exit(1);
+    }
+
+    let (atlas, faces) = bake_faces(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+
+    if kind == OutputKind::Png {
+        let (w, h) = (atlas.width, atlas.num_rows);
+        let pixels = &atlas.pixels[..(w * h) as usize];
+        dump_png(pixels, w, h, args.output.as_deref().unwrap());
+        return;
+    }
+
+    let mut output: Box<dyn Write> = match &args.output {
+        Some(output_name) => Box::new(std::fs::File::create(output_name).unwrap()),
+        None => Box::new(std::io::stdout()),
+    };
+    let generate = match kind {
+        OutputKind::Json => generate_json,
+        OutputKind::Html => generate_html,
+        _ => generate_code,
+    };
+    generate(&atlas, &faces, &args, &mut output).unwrap();
+}
+
+/// Bakes the faces of the font and its bold variant, if any, at each size.
+///
+/// The output only depends on the arguments: faces are in the order of the
+/// fonts then sizes, glyphs in the order of the charset and kerning pairs
+/// sorted by glyph indices.
+fn bake_faces(args: &Args) -> Result<(Atlas, Vec<Face>), Error> {
+    let read = |path: &str| {
+        std::fs::read(path).map_err(|e| Error::Font(format!("Failed to read {path}: {e}")))
+    };
+    let mut fonts = vec![(read(&args.font)?, false)];
+    if let Some(bold) = &args.bold {
+        fonts.push((read(bold)?, true));
+    }
+
+    let (atlas, glyphs) = bake_atlas(&fonts, args)?;
+
+    let mut faces = Vec::new();
+    let face_params = fonts
+        .iter()
+        .flat_map(|(font, bold)| args.size.iter().map(move |&size| (font, *bold, size)));
+    for ((font, bold, size), char_data) in face_params.zip(glyphs) {
+        faces.push(Face {
+            size,
+            bold,
+            char_data,
+            kerning: kerning_pairs(font, size, args)?,
+        });
+    }
+
+    Ok((atlas, faces))
+}
+
+/// Bakes the glyphs of every size of each font into an atlas, growing the
+/// atlas until they fit unless its size is specified.
+///
+/// Returns the atlas and the glyphs of each face.
+fn bake_atlas(
+    fonts: &[(Vec<u8>, bool)],
+    args: &Args,
+) -> Result<(Atlas, Vec<Vec<BakedChar>>), Error> {
+    const MAX_ATLAS_SIZE: i32 = 4096;
+
+    let mut width = args.atlas_width.unwrap_or(256);
+    loop {
+        let height = args.atlas_height.unwrap_or(width);
+        match try_bake_atlas(fonts, args, width, height) {
+            Err(Error::AtlasFull) if args.atlas_width.is_none() && width < MAX_ATLAS_SIZE => {
+                width *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn try_bake_atlas(
+    fonts: &[(Vec<u8>, bool)],
+    args: &Args,
+    width: i32,
+    height: i32,
+) -> Result<(Atlas, Vec<Vec<BakedChar>>), Error> {
+    let mut atlas = Atlas::new(width, height);
+    let mut glyphs = Vec::new();
+    for (font, _) in fonts {
+        for &size in &args.size {
+            glyphs.push(bake_glyphs(font, size, args, &mut atlas)?);
+        }
+    }
+
+    // Add a 2x2 block of fully opaque pixels, used as a source to display fully
+    // opaque shapes. Shapes sample the center of the block, which stays opaque
+    // with bilinear filtering (distance fields and filtered sampling) as well
+    // as with nearest sampling.
+    atlas.add_opaque_block(2)?;
+
+    Ok((atlas, glyphs))
+}
+
+/// The location of the opaque pixel in an atlas of height `h`, at the center
+/// of the opaque block.
+fn opaque_pixel(h: i32) -> (i32, i32) {
+    (1, h - 1)
+}
+
+/// The first and last codepoints of each range of the charset, and the index
+/// of the glyph of the first one.
+fn char_ranges(args: &Args) -> impl Iterator<Item = (u32, u32, u32)> + '_ {
+    args.chars.0.iter().scan(0, |glyph, range| {
+        let first_glyph = *glyph;
+        *glyph += range.end() - range.start() + 1;
+        Some((*range.start(), *range.end(), first_glyph))
+    })
+}
+
+fn distance_spread(args: &Args) -> f32 {
+    if args.distance_field {
+        args.spread
+    } else {
+        0.0
     }
 }
 
 fn dump_png(pixels: &[u8], w: i32, h: i32, file_name: &str) {
+    let file = std::fs::File::create(file_name).unwrap();
+    encode_png(pixels, w, h, std::io::BufWriter::new(file));
+}
+
+fn encode_png(pixels: &[u8], w: i32, h: i32, output: impl Write) {
     let mut rgba_pixels = Vec::with_capacity((w * h * 4) as usize);
     for p in pixels {
         rgba_pixels.push(*p);
@@ -80,8 +596,7 @@ fn dump_png(pixels: &[u8], w: i32, h: i32, file_name: &str) {
         rgba_pixels.push(255);
     }
 
-    let file = std::fs::File::create(file_name).unwrap();
-    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), w as u32, h as u32);
+    let mut encoder = png::Encoder::new(output, w as u32, h as u32);
     encoder.set_color(png::ColorType::Rgba);
     encoder.set_depth(png::BitDepth::Eight);
 
@@ -90,25 +605,40 @@ fn dump_png(pixels: &[u8], w: i32, h: i32, file_name: &str) {
     writer.write_image_data(&rgba_pixels).unwrap();
 }
 
+/// The suffix of the names of the glyph tables of a face. The tables of the
+/// default face keep their unsuffixed names.
+fn face_suffix(idx: usize) -> String {
+    if idx == 0 {
+        String::new()
+    } else {
+        format!("_{idx}")
+    }
+}
+
 fn generate_code(
-    pixels: &[u8],
-    w: i32,
-    h: i32,
-    char_data: &[BakedChar],
-    font_path: &str,
+    atlas: &Atlas,
+    faces: &[Face],
+    args: &Args,
     output: &mut dyn Write,
 ) -> std::io::Result<()> {
-    let pixels = &pixels[..(w * h) as usize];
-    let font_name = font_path.rsplit("/").next().unwrap();
+    let (w, h) = (atlas.width, atlas.num_rows);
+    let pixels = &atlas.pixels[..(w * h) as usize];
+    let font_name = args.font.rsplit("/").next().unwrap();
+    let font_height = faces[0].size.ceil() as u32;
 
-    writeln!(output, "/// An embedded bitmap ascii font for debugging purposes.")?;
-    writeln!(output, "/// Generated from font {font_name}.")?;
+    writeln!(output, "//! An embedded bitmap font for debugging purposes.")?;
+    writeln!(output, "//! Generated from font {font_name}.")?;
     writeln!(output, "")?;
-    writeln!(output, "pub const FIRST_CHAR: u32 = {FIRST_CHAR};")?;
     writeln!(output, "pub const ATLAS_WIDTH: u32 = {w};")?;
     writeln!(output, "pub const ATLAS_HEIGHT: u32 = {h};")?;
-    writeln!(output, "pub const FONT_HEIGHT: u32 = {FONT_HEIGHT};")?;
-    writeln!(output, "pub const OPAQUE_PIXEL: (u16, u16) = (0, {});", h-1)?;
+    writeln!(output, "pub const FONT_HEIGHT: u32 = {font_height};")?;
+    let (opaque_x, opaque_y) = opaque_pixel(h);
+    writeln!(output, "pub const OPAQUE_PIXEL: (u16, u16) = ({opaque_x}, {opaque_y});")?;
+    writeln!(output, "/// Whether `GLYPH_ATLAS` contains signed distances rather than coverage.")?;
+    writeln!(output, "pub const DISTANCE_FIELD: bool = {};", args.distance_field)?;
+    writeln!(output, "/// The distance to the edge of the glyphs, in atlas pixels, at which the")?;
+    writeln!(output, "/// distance field saturates.")?;
+    writeln!(output, "pub const DISTANCE_SPREAD: f32 = {:?};", distance_spread(args))?;
     writeln!(output, "")?;
     writeln!(output, "#[derive(Copy, Clone, Debug)]")?;
     writeln!(output, "pub struct GlyphInfo {{")?;
@@ -118,20 +648,79 @@ fn generate_code(
     writeln!(output, "    pub x_advance: f32,")?;
     writeln!(output, "}}")?;
     writeln!(output, "")?;
-    writeln!(output, "pub const GLYPH_INFO: &[GlyphInfo] = &[")?;
-    for c in char_data {
-        let min_x = c.x0.min(c.x1);
-        let max_x = c.x0.max(c.x1);
-        let min_y = c.y0.min(c.y1);
-        let max_y = c.y0.max(c.y1);
+    writeln!(output, "/// A run of consecutive characters, the glyph of `first` being at index")?;
+    writeln!(output, "/// `glyph` in the glyph table of each face.")?;
+    writeln!(output, "#[derive(Copy, Clone, Debug)]")?;
+    writeln!(output, "pub struct CharRange {{")?;
+    writeln!(output, "    pub first: u32,")?;
+    writeln!(output, "    pub last: u32,")?;
+    writeln!(output, "    pub glyph: u16,")?;
+    writeln!(output, "}}")?;
+    writeln!(output, "")?;
+    writeln!(output, "/// The baked characters, sorted by codepoint.")?;
+    writeln!(output, "pub const CHAR_RANGES: &[CharRange] = &[")?;
+    for (first, last, glyph) in char_ranges(args) {
+        writeln!(output, "    CharRange {{ first: {first}, last: {last}, glyph: {glyph} }},")?;
+    }
+    writeln!(output, "];")?;
+    writeln!(output, "")?;
+    writeln!(output, "/// Adjusts the advance between two glyphs, in pixels. Sorted by `left`, then")?;
+    writeln!(output, "/// `right`, which are indices in the glyph table of the face.")?;
+    writeln!(output, "#[derive(Copy, Clone, Debug)]")?;
+    writeln!(output, "pub struct KerningPair {{")?;
+    writeln!(output, "    pub left: u16,")?;
+    writeln!(output, "    pub right: u16,")?;
+    writeln!(output, "    pub advance: f32,")?;
+    writeln!(output, "}}")?;
+    writeln!(output, "")?;
+    writeln!(output, "/// A size and weight of the font baked in the atlas.")?;
+    writeln!(output, "#[derive(Copy, Clone, Debug)]")?;
+    writeln!(output, "pub struct FontFace {{")?;
+    writeln!(output, "    pub height: u32,")?;
+    writeln!(output, "    pub bold: bool,")?;
+    writeln!(output, "    pub glyphs: &'static [GlyphInfo],")?;
+    writeln!(output, "    pub kerning: &'static [KerningPair],")?;
+    writeln!(output, "}}")?;
+    writeln!(output, "")?;
+    writeln!(output, "/// The faces baked in the atlas. The first one is the default face, with")?;
+    writeln!(output, "/// `FONT_HEIGHT`, `GLYPH_INFO` and `KERNING`.")?;
+    writeln!(output, "pub const FACES: &[FontFace] = &[")?;
+    for (idx, face) in faces.iter().enumerate() {
+        let suffix = face_suffix(idx);
         writeln!(
             output,
-            "    GlyphInfo {{ uv0: ({min_x}, {min_y}), uv1: ({max_x}, {max_y}), offset: ({}, {}), x_advance: {} }},",
-            c.xoff, c.yoff, c.xadvance
+            "    FontFace {{ height: {}, bold: {}, glyphs: GLYPH_INFO{suffix}, kerning: KERNING{suffix} }},",
+            face.size.ceil() as u32,
+            face.bold,
         )?;
     }
     writeln!(output, "];")?;
     writeln!(output, "")?;
+    for (idx, face) in faces.iter().enumerate() {
+        let suffix = face_suffix(idx);
+        writeln!(output, "pub const KERNING{suffix}: &[KerningPair] = &[")?;
+        for (left, right, advance) in &face.kerning {
+            writeln!(output, "    KerningPair {{ left: {left}, right: {right}, advance: {advance:?} }},")?;
+        }
+        writeln!(output, "];")?;
+        writeln!(output, "")?;
+        writeln!(output, "pub const GLYPH_INFO{suffix}: &[GlyphInfo] = &[")?;
+        for c in &face.char_data {
+            let min_x = c.x0.min(c.x1);
+            let max_x = c.x0.max(c.x1);
+            let min_y = c.y0.min(c.y1);
+            let max_y = c.y0.max(c.y1);
+            // Debug formatting always has a decimal point, so that integer
+            // advances are still valid f32 literals.
+            writeln!(
+                output,
+                "    GlyphInfo {{ uv0: ({min_x}, {min_y}), uv1: ({max_x}, {max_y}), offset: ({}, {}), x_advance: {:?} }},",
+                c.xoff, c.yoff, c.xadvance
+            )?;
+        }
+        writeln!(output, "];")?;
+        writeln!(output, "")?;
+    }
     writeln!(output, "pub const GLYPH_ATLAS: &[u8] = &[")?;
     for px in pixels.chunks(16) {
         write!(output, "   ")?;
@@ -145,3 +734,443 @@ fn generate_code(
 
     Ok(())
 }
+
+/// Writes the same data as `generate_code` as a JSON object.
+///
+/// The atlas is an array of `atlas_height` rows of `atlas_width` 8-bit
+/// values.
+fn generate_json(
+    atlas: &Atlas,
+    faces: &[Face],
+    args: &Args,
+    output: &mut dyn Write,
+) -> std::io::Result<()> {
+    let (w, h) = (atlas.width, atlas.num_rows);
+    let font_name = args.font.rsplit("/").next().unwrap();
+    let (opaque_x, opaque_y) = opaque_pixel(h);
+
+    writeln!(output, "{{")?;
+    writeln!(output, "  \"font\": {},", json_string(font_name))?;
+    writeln!(output, "  \"atlas_width\": {w},")?;
+    writeln!(output, "  \"atlas_height\": {h},")?;
+    writeln!(output, "  \"opaque_pixel\": [{opaque_x}, {opaque_y}],")?;
+    writeln!(output, "  \"distance_field\": {},", args.distance_field)?;
+    writeln!(output, "  \"distance_spread\": {},", distance_spread(args))?;
+
+    writeln!(output, "  \"char_ranges\": [")?;
+    let ranges: Vec<String> = char_ranges(args)
+        .map(|(first, last, glyph)| {
+            format!("    {{ \"first\": {first}, \"last\": {last}, \"glyph\": {glyph} }}")
+        })
+        .collect();
+    writeln!(output, "{}", ranges.join(",\n"))?;
+    writeln!(output, "  ],")?;
+
+    writeln!(output, "  \"faces\": [")?;
+    for (idx, face) in faces.iter().enumerate() {
+        writeln!(output, "    {{")?;
+        writeln!(output, "      \"height\": {},", face.size.ceil() as u32)?;
+        writeln!(output, "      \"bold\": {},", face.bold)?;
+
+        writeln!(output, "      \"glyphs\": [")?;
+        let glyphs: Vec<String> = face
+            .char_data
+            .iter()
+            .map(|c| {
+                format!(
+                    "        {{ \"uv0\": [{}, {}], \"uv1\": [{}, {}], \"offset\": [{}, {}], \"x_advance\": {} }}",
+                    c.x0.min(c.x1),
+                    c.y0.min(c.y1),
+                    c.x0.max(c.x1),
+                    c.y0.max(c.y1),
+                    c.xoff,
+                    c.yoff,
+                    c.xadvance,
+                )
+            })
+            .collect();
+        writeln!(output, "{}", glyphs.join(",\n"))?;
+        writeln!(output, "      ],")?;
+
+        writeln!(output, "      \"kerning\": [")?;
+        let pairs: Vec<String> = face
+            .kerning
+            .iter()
+            .map(|(left, right, advance)| {
+                format!(
+                    "        {{ \"left\": {left}, \"right\": {right}, \"advance\": {advance} }}"
+                )
+            })
+            .collect();
+        writeln!(output, "{}", pairs.join(",\n"))?;
+        writeln!(output, "      ]")?;
+
+        let separator = if idx + 1 < faces.len() { "," } else { "" };
+        writeln!(output, "    }}{separator}")?;
+    }
+    writeln!(output, "  ],")?;
+
+    writeln!(output, "  \"atlas\": [")?;
+    let rows: Vec<String> = atlas.pixels[..(w * h) as usize]
+        .chunks(w as usize)
+        .map(|row| {
+            let row: Vec<String> = row.iter().map(u8::to_string).collect();
+            format!("    [{}]", row.join(", "))
+        })
+        .collect();
+    writeln!(output, "{}", rows.join(",\n"))?;
+    writeln!(output, "  ]")?;
+    writeln!(output, "}}")?;
+
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+/// How many screen pixels an atlas pixel covers in the HTML preview.
+const PREVIEW_SCALE: i32 = 3;
+
+/// Colors of the glyph boxes of each face in the HTML preview.
+const PREVIEW_COLORS: &[&str] = &["#e6194b", "#3cb44b", "#4363d8", "#f58231", "#911eb4"];
+
+/// Writes an HTML page showing the atlas with the box of each glyph, some
+/// sample text rendered from the atlas and the metrics of the glyphs.
+///
+/// Hovering a glyph box shows its character and metrics.
+fn generate_html(
+    atlas: &Atlas,
+    faces: &[Face],
+    args: &Args,
+    output: &mut dyn Write,
+) -> std::io::Result<()> {
+    let (w, h) = (atlas.width, atlas.num_rows);
+    let font_name = args.font.rsplit("/").next().unwrap();
+    let (opaque_x, opaque_y) = opaque_pixel(h);
+
+    let mut png = Vec::new();
+    encode_png(&atlas.pixels[..(w * h) as usize], w, h, &mut png);
+    let atlas_url = format!("data:image/png;base64,{}", base64(&png));
+
+    let chars: Vec<u32> = args
+        .chars
+        .0
+        .iter()
+        .flat_map(|range| range.clone())
+        .collect();
+    let color = |idx: usize| PREVIEW_COLORS[idx % PREVIEW_COLORS.len()];
+    let face_name = |face: &Face| {
+        let weight = if face.bold { "bold" } else { "regular" };
+        format!("{}px {weight}", face.size)
+    };
+
+    writeln!(output, "<!DOCTYPE html>")?;
+    writeln!(output, "<html>")?;
+    writeln!(output, "<head>")?;
+    writeln!(output, "<meta charset=\"utf-8\">")?;
+    writeln!(output, "<title>{}</title>", html_escape(font_name))?;
+    writeln!(output, "<style>")?;
+    writeln!(
+        output,
+        "body {{ font-family: sans-serif; background: #202020; color: #e0e0e0; }}"
+    )?;
+    writeln!(output, "svg image {{ image-rendering: pixelated; }}")?;
+    writeln!(output, "svg rect {{ fill: none; stroke-width: 0.15; }}")?;
+    writeln!(output, "svg rect:hover {{ fill: #ffffff40; }}")?;
+    writeln!(
+        output,
+        "table {{ border-collapse: collapse; font-family: monospace; }}"
+    )?;
+    writeln!(output, "td, th {{ padding: 0 8px; text-align: right; }}")?;
+    writeln!(output, "tr:nth-child(even) {{ background: #303030; }}")?;
+    writeln!(output, "</style>")?;
+    writeln!(output, "</head>")?;
+    writeln!(output, "<body>")?;
+
+    writeln!(output, "<h1>{}</h1>", html_escape(font_name))?;
+    writeln!(output, "<p>")?;
+    writeln!(
+        output,
+        "Atlas: {w}x{h} pixels, opaque pixel at ({opaque_x}, {opaque_y})."
+    )?;
+    if args.distance_field {
+        writeln!(
+            output,
+            "The atlas contains signed distances with a spread of {} pixels.",
+            args.spread
+        )?;
+    }
+    writeln!(output, "</p>")?;
+    writeln!(output, "<ul>")?;
+    for (idx, face) in faces.iter().enumerate() {
+        writeln!(
+            output,
+            "<li style=\"color: {}\">{}: {} glyphs, {} kerning pairs</li>",
+            color(idx),
+            face_name(face),
+            face.char_data.len(),
+            face.kerning.len(),
+        )?;
+    }
+    writeln!(output, "</ul>")?;
+
+    // The atlas, with the boxes of the glyphs on top of it.
+    writeln!(output, "<h2>Atlas</h2>")?;
+    writeln!(
+        output,
+        "<svg width=\"{}\" height=\"{}\" viewBox=\"0 0 {w} {h}\">",
+        w * PREVIEW_SCALE,
+        h * PREVIEW_SCALE,
+    )?;
+    writeln!(output, "<defs>")?;
+    writeln!(
+        output,
+        "<image id=\"atlas\" width=\"{w}\" height=\"{h}\" href=\"{atlas_url}\"/>"
+    )?;
+    writeln!(output, "</defs>")?;
+    writeln!(output, "<use href=\"#atlas\"/>")?;
+    for (idx, face) in faces.iter().enumerate() {
+        for (c, glyph) in chars.iter().zip(&face.char_data) {
+            writeln!(
+                output,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" stroke=\"{}\"><title>{}</title></rect>",
+                glyph.x0.min(glyph.x1),
+                glyph.y0.min(glyph.y1),
+                glyph.x0.abs_diff(glyph.x1),
+                glyph.y0.abs_diff(glyph.y1),
+                color(idx),
+                html_escape(&glyph_description(*c, glyph, &face_name(face))),
+            )?;
+        }
+    }
+    writeln!(
+        output,
+        "<rect x=\"{}\" y=\"{}\" width=\"2\" height=\"2\" stroke=\"#ffe119\"><title>opaque pixel</title></rect>",
+        opaque_x - 1,
+        opaque_y - 1,
+    )?;
+    writeln!(output, "</svg>")?;
+
+    // The sample text, laid out the same way as the overlay lays out text.
+    writeln!(output, "<h2>Sample text</h2>")?;
+    for (idx, face) in faces.iter().enumerate() {
+        let height = face.size.ceil() as i32;
+        let mut glyphs = String::new();
+        let mut x = 0;
+        let mut previous: Option<usize> = None;
+        for c in args.sample.chars() {
+            let Some(glyph_idx) = chars.iter().position(|&cp| cp == c as u32) else {
+                previous = None;
+                continue;
+            };
+            if let Some(previous) = previous {
+                x += face
+                    .kerning
+                    .iter()
+                    .find(|&&(left, right, _)| {
+                        (left as usize, right as usize) == (previous, glyph_idx)
+                    })
+                    .map_or(0, |&(_, _, advance)| advance.round() as i32);
+            }
+            previous = Some(glyph_idx);
+
+            let glyph = &face.char_data[glyph_idx];
+            let (x0, y0) = (glyph.x0.min(glyph.x1), glyph.y0.min(glyph.y1));
+            let (gw, gh) = (glyph.x0.abs_diff(glyph.x1), glyph.y0.abs_diff(glyph.y1));
+            if gw > 0 && gh > 0 {
+                glyphs.push_str(&format!(
+                    "<svg x=\"{}\" y=\"{}\" width=\"{gw}\" height=\"{gh}\" viewBox=\"{x0} {y0} {gw} {gh}\"><use href=\"#atlas\"/></svg>\n",
+                    x + glyph.xoff as i32,
+                    height + glyph.yoff as i32,
+                ));
+            }
+            x += glyph.xadvance as i32;
+        }
+
+        let width = x.max(1);
+        let total_height = height + height / 2;
+        writeln!(
+            output,
+            "<p style=\"color: {}\">{}</p>",
+            color(idx),
+            face_name(face)
+        )?;
+        writeln!(
+            output,
+            "<svg width=\"{}\" height=\"{}\" viewBox=\"0 0 {width} {total_height}\">",
+            width * PREVIEW_SCALE,
+            total_height * PREVIEW_SCALE,
+        )?;
+        writeln!(
+            output,
+            "<line x1=\"0\" y1=\"{height}\" x2=\"{width}\" y2=\"{height}\" stroke=\"{}\" stroke-width=\"0.15\"/>",
+            color(idx),
+        )?;
+        write!(output, "{glyphs}")?;
+        writeln!(output, "</svg>")?;
+    }
+
+    // The metrics of each glyph.
+    writeln!(output, "<h2>Glyph metrics</h2>")?;
+    for (idx, face) in faces.iter().enumerate() {
+        writeln!(
+            output,
+            "<h3 style=\"color: {}\">{}</h3>",
+            color(idx),
+            face_name(face)
+        )?;
+        writeln!(output, "<table>")?;
+        writeln!(output, "<tr><th>char</th><th>codepoint</th><th>uv0</th><th>uv1</th><th>offset</th><th>x advance</th></tr>")?;
+        for (c, glyph) in chars.iter().zip(&face.char_data) {
+            writeln!(
+                output,
+                "<tr><td>{}</td><td>U+{c:04X}</td><td>({}, {})</td><td>({}, {})</td><td>({}, {})</td><td>{}</td></tr>",
+                html_escape(&char_label(*c)),
+                glyph.x0.min(glyph.x1),
+                glyph.y0.min(glyph.y1),
+                glyph.x0.max(glyph.x1),
+                glyph.y0.max(glyph.y1),
+                glyph.xoff,
+                glyph.yoff,
+                glyph.xadvance,
+            )?;
+        }
+        writeln!(output, "</table>")?;
+    }
+
+    writeln!(output, "</body>")?;
+    writeln!(output, "</html>")?;
+
+    Ok(())
+}
+
+/// The character, or its name if it isn't printable.
+fn char_label(c: u32) -> String {
+    match char::from_u32(c) {
+        Some(' ') => "space".to_string(),
+        Some(c) if !c.is_control() => c.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn glyph_description(c: u32, glyph: &BakedChar, face_name: &str) -> String {
+    format!(
+        "{} U+{c:04X} ({face_name})\nuv: ({}, {}) - ({}, {})\noffset: ({}, {})\nx advance: {}",
+        char_label(c),
+        glyph.x0.min(glyph.x1),
+        glyph.y0.min(glyph.y1),
+        glyph.x0.max(glyph.x1),
+        glyph.y0.max(glyph.y1),
+        glyph.xoff,
+        glyph.yoff,
+        glyph.xadvance,
+    )
+}
+
+fn html_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Regenerates an embedded font module and compares it to the checked-in
+/// one, reporting the first line that differs.
+#[cfg(test)]
+fn check_embedded_font(args: &[&str], module: &str) {
+    let dir = env!("CARGO_MANIFEST_DIR");
+    let font = format!("{dir}/../assets/Hack-Regular.ttf");
+    let args = Args::parse_from(["generator", &font].iter().chain(args));
+    let (atlas, faces) = bake_faces(&args).unwrap();
+    let mut output = Vec::new();
+    generate_code(&atlas, &faces, &args, &mut output).unwrap();
+    let generated = String::from_utf8(output).unwrap();
+
+    let path = format!("{dir}/../overlay/src/{module}");
+    let expected = std::fs::read_to_string(&path).unwrap();
+    if let Some((line, (generated, expected))) = generated
+        .lines()
+        .zip(expected.lines())
+        .enumerate()
+        .find(|(_, (a, b))| a != b)
+    {
+        panic!(
+            "{module} differs from the generator output at line {}:\n  generated: {generated}\n  expected:  {expected}",
+            line + 1
+        );
+    }
+    assert_eq!(
+        generated.lines().count(),
+        expected.lines().count(),
+        "{module} differs from the generator output"
+    );
+}
+
+#[test]
+fn embedded_font_is_up_to_date() {
+    check_embedded_font(&["--size", "18,14,24"], "embedded_font.rs");
+}
+
+#[test]
+fn output_is_deterministic() {
+    let dir = env!("CARGO_MANIFEST_DIR");
+    let font = format!("{dir}/../assets/Hack-Regular.ttf");
+    let args = Args::parse_from([
+        "generator",
+        &font,
+        "--size",
+        "14,18",
+        "--chars",
+        "ascii,latin1",
+    ]);
+    let generate = || {
+        let (atlas, faces) = bake_faces(&args).unwrap();
+        let mut output = Vec::new();
+        generate_code(&atlas, &faces, &args, &mut output).unwrap();
+        output
+    };
+    assert!(generate() == generate());
+}
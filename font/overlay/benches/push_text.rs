@@ -0,0 +1,70 @@
+//! Measures the cost of building text geometry.
+//!
+//! Run with `cargo bench --bench push_text`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use debug_overlay::{OverlayGeometry, Point, FRONT_LAYER};
+
+const ITERATIONS: u32 = 2000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    // Warm up.
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            f();
+        }
+        best = best.min(start.elapsed() / ITERATIONS);
+    }
+
+    println!("{name:<24} {best:>12.2?}");
+}
+
+fn main() {
+    let mut geometry = OverlayGeometry::new(4);
+
+    // A frame's worth of table cells.
+    let cells: Vec<String> = (0..500)
+        .map(|i| format!("counter {i}: {:.2} ms", i as f32 * 0.37))
+        .collect();
+
+    bench("table cells", || {
+        geometry.begin_frame();
+        let mut y = 0;
+        for cell in &cells {
+            geometry.push_text(FRONT_LAYER, cell, Point { x: 10, y }, (255, 255, 255, 255));
+            y += 20;
+        }
+        black_box(&geometry);
+    });
+
+    let paragraph = "The quick brown fox jumps over the lazy dog. ".repeat(40);
+    bench("long string", || {
+        geometry.begin_frame();
+        geometry.push_text(
+            FRONT_LAYER,
+            black_box(&paragraph),
+            Point { x: 0, y: 0 },
+            (255, 255, 255, 255),
+        );
+        black_box(&geometry);
+    });
+
+    bench("clipped table cells", || {
+        geometry.begin_frame();
+        geometry.set_clip_rect(Some((Point { x: 0, y: 0 }, Point { x: 200, y: 400 })));
+        let mut y = 0;
+        for cell in &cells {
+            geometry.push_text(FRONT_LAYER, cell, Point { x: 10, y }, (255, 255, 255, 255));
+            y += 20;
+        }
+        black_box(&geometry);
+    });
+}
@@ -0,0 +1,70 @@
+use crate::{Counters, Overlay, OverlayItem, Point, FRONT_LAYER};
+use std::fmt::Write;
+
+/// Lists the active alerts of a set of counters, see `Counters::active_alerts`.
+///
+/// # Example
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// let mut counters = Counters::new(0);
+/// let mut overlay = Overlay::new();
+///
+/// overlay.begin_frame();
+/// if overlay.begin_panel("alerts") {
+///     overlay.draw_item(&Alerts(&counters));
+/// }
+/// overlay.finish();
+/// ```
+pub struct Alerts<'a>(pub &'a Counters);
+
+impl<'a> OverlayItem for Alerts<'a> {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let row_height = overlay.style.line_spacing + overlay.style.font.line_height();
+        let mut y = origin.y + overlay.style.font.line_height();
+        let mut max = origin;
+
+        let mut count = 0;
+        for alert in self.0.active_alerts() {
+            let desc = &alert.counter.descriptor;
+            let unit = desc.unit;
+            let val = alert.value;
+
+            overlay.string_buffer.clear();
+            let _ = write!(overlay.string_buffer, "{}: ", alert.counter.name());
+            let val = desc.format_value(val);
+            let _ = write!(overlay.string_buffer, "{val}{unit}");
+            if let Some(range) = &desc.safe_range {
+                let _ = write!(
+                    overlay.string_buffer,
+                    " (safe: {}..{})",
+                    range.start, range.end
+                );
+            }
+
+            let r = overlay.geometry.push_text(
+                FRONT_LAYER,
+                &overlay.string_buffer,
+                Point { x: origin.x, y },
+                overlay.style.highlight_color,
+            );
+            max.x = max.x.max(r.1.x);
+            max.y = max.y.max(r.1.y);
+            y += row_height;
+            count += 1;
+        }
+
+        if count == 0 {
+            let r = overlay.geometry.push_text(
+                FRONT_LAYER,
+                "no alerts",
+                Point { x: origin.x, y },
+                overlay.style.text_color[1],
+            );
+            max = r.1;
+        }
+
+        (origin, max)
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Counter, Overlay, OverlayItem, Point, FRONT_LAYER};
+use std::fmt::Write;
+
+/// The frame budget at 60 frames per second, in milliseconds.
+pub const FRAME_BUDGET_60FPS: f32 = 1000.0 / 60.0;
+
+/// A horizontal bar stacking the last values of several counters, for example
+/// the time spent in each part of the frame, against a budget.
+///
+/// Each counter is drawn with its color. A vertical line marks the budget, and
+/// the total uses the highlight color when it goes over.
+///
+/// # Example
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// declare_counters!(frame = {
+///     update: float = "update" with { unit: "ms", color: (100, 200, 100, 255) },
+///     render: float = "render" with { unit: "ms", color: (100, 100, 200, 255) }
+/// });
+///
+/// let mut counters = Counters::new(0);
+/// let ids = frame::register("frame", &mut counters);
+/// counters.set(ids.update(), 5.0);
+/// counters.set(ids.render(), 14.0);
+///
+/// let mut selection = Vec::new();
+/// counters.select_counters(ids.all(), &mut selection);
+///
+/// let mut overlay = Overlay::new();
+/// overlay.draw_item(&BudgetBar::new(&selection));
+/// ```
+pub struct BudgetBar<'a> {
+    pub counters: &'a [&'a Counter],
+    pub budget: f32,
+    /// The width of the bar, or `None` to use the width of the current group.
+    pub width: Option<i32>,
+}
+
+impl<'a> BudgetBar<'a> {
+    /// A budget bar against the 60 fps frame budget.
+    pub fn new(counters: &'a [&'a Counter]) -> Self {
+        BudgetBar {
+            counters,
+            budget: FRAME_BUDGET_60FPS,
+            width: None,
+        }
+    }
+
+    /// The sum of the last values of the counters.
+    pub fn total(&self) -> f32 {
+        self.counters
+            .iter()
+            .map(|counter| counter.last_value)
+            .filter(|val| val.is_finite())
+            .sum()
+    }
+}
+
+impl<'a> OverlayItem for BudgetBar<'a> {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let w = self.width.unwrap_or_else(|| {
+            let widget = overlay.current_group_width();
+            if widget > 0 {
+                widget
+            } else {
+                200
+            }
+        });
+        let h = overlay.style.font.line_height() + 2;
+        let bar_rect = (
+            origin,
+            Point {
+                x: origin.x + w,
+                y: origin.y + h,
+            },
+        );
+
+        let (r, g, b, _) = overlay.style.text_color[1];
+        let track = (r, g, b, 40);
+        overlay
+            .geometry
+            .push_rectangle(FRONT_LAYER, &bar_rect, track, track);
+
+        // The bar covers the budget, or the total when it goes over.
+        let total = self.total();
+        let range = total.max(self.budget).max(f32::EPSILON);
+        let x_scale = w as f32 / range;
+
+        let mut hovered = None;
+        let mut acc = 0.0;
+        for counter in self.counters {
+            let val = counter.last_value;
+            if !val.is_finite() || val <= 0.0 {
+                continue;
+            }
+            let x0 = origin.x + (acc * x_scale) as i32;
+            acc += val;
+            let x1 = origin.x + (acc * x_scale) as i32;
+            let segment = (
+                Point { x: x0, y: origin.y },
+                Point {
+                    x: x1.max(x0 + 1),
+                    y: origin.y + h,
+                },
+            );
+            let color = counter.descriptor.color;
+            overlay
+                .geometry
+                .push_rectangle(FRONT_LAYER, &segment, color, color);
+
+            if overlay.is_hovered(&segment) {
+                hovered = Some(*counter);
+            }
+        }
+
+        let over_budget = total > self.budget;
+        let budget_x = origin.x + (self.budget * x_scale) as i32;
+        let line_color = if over_budget {
+            overlay.style.highlight_color
+        } else {
+            overlay.style.text_color[0]
+        };
+        let line = (
+            Point {
+                x: budget_x.min(origin.x + w - 1),
+                y: origin.y,
+            },
+            Point {
+                x: budget_x.min(origin.x + w - 1) + 1,
+                y: origin.y + h,
+            },
+        );
+        overlay
+            .geometry
+            .push_rectangle(FRONT_LAYER, &line, line_color, line_color);
+
+        overlay.string_buffer.clear();
+        let unit = self
+            .counters
+            .first()
+            .map(|c| c.descriptor.unit)
+            .unwrap_or("");
+        let _ = write!(overlay.string_buffer, "{total:.2}/{:.2}{unit}", self.budget);
+        let text_color = if over_budget {
+            overlay.style.highlight_color
+        } else {
+            overlay.style.text_color[0]
+        };
+        let text_pos = Point {
+            x: origin.x,
+            y: origin.y + h + overlay.style.line_spacing + overlay.style.font.line_height(),
+        };
+        let text_rect =
+            overlay
+                .geometry
+                .push_text(FRONT_LAYER, &overlay.string_buffer, text_pos, text_color);
+
+        if let Some(counter) = hovered {
+            overlay.show_counter_tooltip(counter);
+        }
+
+        (
+            origin,
+            Point {
+                x: bar_rect.1.x.max(text_rect.1.x),
+                y: text_rect.1.y.max(bar_rect.1.y),
+            },
+        )
+    }
+}
+
+#[test]
+fn budget_bar() {
+    use crate::{color_to_u32, CounterDescriptor, CounterId};
+
+    let mut update = Counter::new(CounterDescriptor::float("update", "ms", CounterId(0)));
+    let mut render = Counter::new(CounterDescriptor::float("render", "ms", CounterId(1)));
+    update.set(5.0);
+    render.set(15.0);
+    let counters = [&update, &render];
+
+    let mut overlay = Overlay::new();
+    let mut bar = BudgetBar::new(&counters);
+    bar.budget = 10.0;
+    bar.width = Some(100);
+    assert_eq!(bar.total(), 20.0);
+    let rect = bar.draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert!(rect.1.x >= 100);
+
+    // Over budget, the bar covers the total.
+    let vertices = &overlay.geometry.vertices;
+    assert_eq!(vertices[4].x, 0.0);
+    assert_eq!(vertices[5].x, 25.0);
+    assert_eq!(vertices[9].x, 100.0);
+    // The budget line is in the middle and uses the highlight color.
+    assert_eq!(vertices[12].x, 50.0);
+    assert_eq!(
+        vertices[12].color,
+        color_to_u32(overlay.style.highlight_color)
+    );
+}
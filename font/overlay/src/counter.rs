@@ -1,4 +1,11 @@
-use std::{collections::VecDeque, f32::NAN, ops::Range};
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt,
+    ops::Range,
+    time::Instant,
+};
 
 #[doc(hidden)]
 #[macro_export]
@@ -35,6 +42,22 @@ macro_rules! _declare_counter {
     };
 }
 
+/// Declares a module containing the descriptors and ids of a group of counters.
+///
+/// Fields of `CounterDescriptor` can be set after `with`, for example:
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// declare_counters!(render = {
+///     frame_time: float = "frame time" with { unit: "ms", history: true, doc: "CPU time per frame" },
+///     draw_calls: int = "draw calls"
+/// });
+///
+/// let mut counters = Counters::new(120);
+/// let ids = render::register("render", &mut counters);
+/// counters.set(ids.draw_calls(), 42.0);
+/// ```
 #[macro_export]
 macro_rules! declare_counters {
     ($group_name:ident = {
@@ -47,6 +70,7 @@ macro_rules! declare_counters {
             #![allow(non_snake_case)]
             #![allow(non_upper_case_globals)]
             #![allow(unused)]
+            #![allow(clippy::upper_case_acronyms)]
 
             use $crate::CounterDescriptor;
             #[repr(u16)]
@@ -87,7 +111,7 @@ macro_rules! declare_counters {
     }
 }
 
-use crate::Color;
+use crate::{Color, FormattedValue, Notation};
 
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -157,22 +181,54 @@ pub struct Counter {
     pub(crate) displayed_max: f32,
     pub(crate) descriptor: CounterDescriptor,
     pub(crate) history: VecDeque<f32>,
+    /// The maximum of the history, growing immediately and shrinking slowly
+    /// so that graphs don't rescale every frame.
+    pub(crate) history_range: f32,
+    /// The finite samples of the history in increasing order, sorted by
+    /// `percentile` at most once per update and reused across frames.
+    sorted_history: RefCell<Vec<f32>>,
+    sorted_history_valid: Cell<bool>,
+    pub(crate) ema: f32,
+    pub(crate) rate: f32,
+    rate_sample: Option<(f32, Instant)>,
+    /// Frames since the last average update, for counters that override the
+    /// averaging window.
+    window_frames: u32,
+    /// Overrides the name of the descriptor for counters named at runtime.
+    owned_name: Option<Box<str>>,
+    /// The value that raised the current alert, if any.
+    alert_value: f32,
+    /// Frames until the current alert expires.
+    alert_frames: u32,
+    /// Frames since the current alert was raised.
+    alert_age: u32,
 }
 
 impl Counter {
     pub fn new(descritpor: CounterDescriptor) -> Self {
         Counter {
-            current_value: NAN,
-            last_value: NAN,
+            current_value: f32::NAN,
+            last_value: f32::NAN,
             sum: 0.0,
             samples: 0.0,
-            min: 0.0,
-            max: 0.0,
-            displayed_avg: NAN,
-            displayed_min: NAN,
-            displayed_max: NAN,
+            min: f32::MAX,
+            max: f32::MIN,
+            displayed_avg: f32::NAN,
+            displayed_min: f32::NAN,
+            displayed_max: f32::NAN,
             descriptor: descritpor,
             history: VecDeque::new(),
+            history_range: f32::NAN,
+            sorted_history: RefCell::new(Vec::new()),
+            sorted_history_valid: Cell::new(false),
+            ema: f32::NAN,
+            rate: f32::NAN,
+            rate_sample: None,
+            window_frames: 0,
+            owned_name: None,
+            alert_value: f32::NAN,
+            alert_frames: 0,
+            alert_age: 0,
         }
     }
 
@@ -181,24 +237,56 @@ impl Counter {
             self.current_value = val;
             self.last_value = val;
         } else {
-            self.current_value = NAN;
+            self.current_value = f32::NAN;
         }
     }
 
+    /// Adds to the value of the counter for the current frame.
+    ///
+    /// Unlike `set`, values from multiple calls in the same frame accumulate.
+    pub fn add(&mut self, value: f32) {
+        if self.current_value.is_finite() {
+            self.current_value += value;
+        } else {
+            self.current_value = value;
+        }
+        self.last_value = self.current_value;
+    }
+
     pub fn update(&mut self, update_avg: bool) {
+        self.update_at(update_avg, Instant::now());
+    }
+
+    /// Like `update`, with the time of the update, taken once per frame by
+    /// `Counters::update` for all of its counters.
+    pub(crate) fn update_at(&mut self, update_avg: bool, now: Instant) {
         if self.current_value.is_finite() {
             self.samples += 1.0;
             self.sum += self.current_value;
             self.min = self.min.min(self.current_value);
             self.max = self.max.max(self.current_value);
+            self.ema = if self.ema.is_finite() {
+                self.ema + (self.current_value - self.ema) * self.descriptor.ema_factor
+            } else {
+                self.current_value
+            };
+            self.update_rate(self.current_value, now);
         }
 
         if !self.history.is_empty() {
             self.history.pop_front();
-            self.history.push_back(self.current_value)
+            self.history.push_back(self.current_value);
+            self.sorted_history_valid.set(false);
+
+            let max = self
+                .history
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold(f32::NAN, |a, &b| a.max(b));
+            self.history_range = smooth_range(self.history_range, max);
         }
 
-        self.current_value = NAN;
+        self.current_value = f32::NAN;
 
         if update_avg {
             if self.samples > 0.0 {
@@ -206,22 +294,96 @@ impl Counter {
                 self.displayed_max = self.max;
                 self.displayed_min = self.min;
             } else {
-                self.displayed_avg = NAN;
-                self.displayed_max = NAN;
-                self.displayed_min = NAN;
+                self.displayed_avg = f32::NAN;
+                self.displayed_max = f32::NAN;
+                self.displayed_min = f32::NAN;
             }
             self.samples = 0.0;
             self.sum = 0.0;
-            self.min = std::f32::MAX;
-            self.max = std::f32::MIN;
+            self.min = f32::MAX;
+            self.max = f32::MIN;
+        }
+    }
+
+    /// Raises an alert if the alerts of the counter are enabled and `value`
+    /// is outside of the safe range, or advances the current alert otherwise.
+    fn update_alert(&mut self, value: f32, duration: u32) {
+        let out_of_range = match &self.descriptor.safe_range {
+            Some(range) if self.descriptor.alert => value.is_finite() && !range.contains(&value),
+            _ => false,
+        };
+        if self.alert_frames > 0 {
+            self.alert_frames -= 1;
+            self.alert_age += 1;
+        }
+        if out_of_range {
+            if self.alert_frames == 0 {
+                self.alert_age = 0;
+            }
+            self.alert_value = value;
+            self.alert_frames = duration;
         }
     }
 
-    pub fn name(&self) -> &'static str {
-        self.descriptor.name
+    /// Whether the counter has an active alert.
+    pub fn is_alerting(&self) -> bool {
+        self.alert_frames > 0
     }
 
-    pub fn history(&self) -> Option<HistoryIter> {
+    /// Whether the counter should currently be highlighted by its alert.
+    ///
+    /// Alternates every few frames while the alert is active, so that the
+    /// highlight flashes.
+    pub(crate) fn alert_flash(&self) -> bool {
+        self.alert_frames > 0 && (self.alert_age / ALERT_FLASH_PERIOD).is_multiple_of(2)
+    }
+
+    pub fn name(&self) -> &str {
+        self.owned_name.as_deref().unwrap_or(self.descriptor.name)
+    }
+
+    /// Clears the values, statistics and history of the counter.
+    pub fn reset(&mut self) {
+        let history_size = self.history.len();
+        let owned_name = self.owned_name.take();
+        *self = Counter::new(self.descriptor.clone());
+        self.owned_name = owned_name;
+        if history_size > 0 {
+            self.enable_history(history_size);
+        }
+    }
+
+    /// Writes a multi-line description of the counter: its name, unit, last
+    /// value and the statistics over the last averaging window.
+    pub fn write_summary(&self, output: &mut dyn fmt::Write) -> fmt::Result {
+        let desc = &self.descriptor;
+        if desc.unit.is_empty() {
+            writeln!(output, "{}", self.name())?;
+        } else {
+            writeln!(output, "{} ({})", self.name(), desc.unit)?;
+        }
+        if !desc.doc.is_empty() {
+            writeln!(output, "{}", desc.doc)?;
+        }
+
+        let values = [
+            ("last", self.last_value),
+            ("avg", self.displayed_avg),
+            ("min", self.displayed_min),
+            ("max", self.displayed_max),
+        ];
+        for (label, val) in values {
+            if !val.is_finite() {
+                writeln!(output, "{label}: -")?;
+                continue;
+            }
+            writeln!(output, "{label}: {}", desc.format_value(val))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn history(&self) -> Option<HistoryIter<'_>> {
         if self.history.is_empty() {
             return None;
         }
@@ -231,19 +393,96 @@ impl Counter {
         })
     }
 
+    fn update_rate(&mut self, value: f32, now: Instant) {
+        if let Some((prev_value, prev_time)) = self.rate_sample {
+            let dt = (now - prev_time).as_secs_f32();
+            if dt > 0.0 {
+                self.rate = (value - prev_value) / dt;
+            }
+        }
+        self.rate_sample = Some((value, now));
+    }
+
+    /// The change per second between the last two samples, for example the
+    /// allocation rate of a counter tracking the total number of allocations.
+    pub fn rate(&self) -> Option<f32> {
+        Some(self.rate).filter(|v| v.is_finite())
+    }
+
+    /// The exponential moving average of the samples, using the descriptor's
+    /// `ema_factor`.
+    pub fn ema(&self) -> Option<f32> {
+        Some(self.ema).filter(|v| v.is_finite())
+    }
+
+    /// Returns the value below which `p` percent of the samples of the history
+    /// fall, for example `percentile(95.0)` for the 95th percentile.
+    ///
+    /// Returns `None` if the history is disabled or empty.
+    pub fn percentile(&self, p: f32) -> Option<f32> {
+        let mut samples = self.sorted_history.borrow_mut();
+        if !self.sorted_history_valid.replace(true) {
+            samples.clear();
+            samples.extend(self.history.iter().copied().filter(|v| v.is_finite()));
+            samples.sort_unstable_by(f32::total_cmp);
+        }
+        if samples.is_empty() {
+            return None;
+        }
+
+        let rank = (p.clamp(0.0, 100.0) / 100.0 * (samples.len() - 1) as f32).round();
+
+        Some(samples[rank as usize])
+    }
+
     pub fn enable_history(&mut self, samples: usize) {
         self.history.clear();
+        self.sorted_history_valid.set(false);
         self.history.reserve(samples);
         for _ in 0..samples {
-            self.history.push_back(NAN);
+            self.history.push_back(f32::NAN);
         }
     }
 
     pub fn disable_history(&mut self) {
         self.history = VecDeque::new();
+        self.sorted_history = RefCell::new(Vec::new());
+        self.sorted_history_valid.set(false);
+        self.history_range = f32::NAN;
+    }
+
+    /// A smoothed maximum of the history, suitable for scaling graphs.
+    ///
+    /// It follows increases of the maximum immediately and decreases slowly.
+    pub fn history_range(&self) -> Option<f32> {
+        Some(self.history_range).filter(|v| v.is_finite())
+    }
+}
+
+const DEFAULT_EMA_FACTOR: f32 = 0.1;
+
+/// How fast `Counter::history_range` moves towards a lower maximum each update.
+const RANGE_SHRINK_RATE: f32 = 0.05;
+
+/// Moves a range towards the current maximum of a graph: immediately if the
+/// maximum is higher, slowly otherwise.
+pub(crate) fn smooth_range(range: f32, max: f32) -> f32 {
+    if max.is_nan() {
+        f32::NAN
+    } else if max >= range || range.is_nan() {
+        max
+    } else {
+        range + (max - range) * RANGE_SHRINK_RATE
     }
 }
 
+/// The number of frames alerts stay active after the value goes back to the
+/// safe range, unless overridden with `Counters::set_alert_duration`.
+const DEFAULT_ALERT_DURATION: u32 = 60;
+
+/// The number of frames the alert highlight stays on, then off.
+const ALERT_FLASH_PERIOD: u32 = 8;
+
 #[derive(Clone, Debug)]
 pub struct CounterDescriptor {
     pub name: &'static str,
@@ -252,6 +491,24 @@ pub struct CounterDescriptor {
     pub format: Format,
     pub color: Color,
     pub safe_range: Option<Range<f32>>,
+    /// The weight of new samples in the exponential moving average, between
+    /// 0 and 1. Higher values make the average more responsive.
+    pub ema_factor: f32,
+    /// Show the rate of change per second instead of the value in `Value`
+    /// table columns.
+    pub show_rate: bool,
+    /// The number of frames the displayed average, min and max are computed
+    /// over, or `None` to use the window of the `Counters`.
+    pub avg_window: Option<u32>,
+    /// Enables the history of the counter when it is registered.
+    pub history: bool,
+    /// A description of the counter, shown in its tooltip.
+    pub doc: &'static str,
+    /// Raise an alert when the value leaves the safe range, see
+    /// `Counters::active_alerts`.
+    pub alert: bool,
+    /// How large values are written, for example `1.2M` instead of `1234567`.
+    pub notation: Notation,
 }
 
 impl CounterDescriptor {
@@ -259,10 +516,17 @@ impl CounterDescriptor {
     pub const _DEFAULT: Self = CounterDescriptor {
         name: "",
         unit: "",
-        id: CounterId(std::u16::MAX),
+        id: CounterId(u16::MAX),
         format: Format::Int,
         color: (255, 255, 255, 255),
         safe_range: None,
+        ema_factor: DEFAULT_EMA_FACTOR,
+        show_rate: false,
+        avg_window: None,
+        history: false,
+        doc: "",
+        alert: false,
+        notation: Notation::Plain,
     };
 
     pub const fn int(name: &'static str, unit: &'static str, id: CounterId) -> Self {
@@ -273,6 +537,13 @@ impl CounterDescriptor {
             format: Format::Int,
             color: (255, 255, 255, 255),
             safe_range: None,
+            ema_factor: DEFAULT_EMA_FACTOR,
+            show_rate: false,
+            avg_window: None,
+            history: false,
+            doc: "",
+            alert: false,
+            notation: Notation::Plain,
         }
     }
 
@@ -284,6 +555,13 @@ impl CounterDescriptor {
             format: Format::Float,
             color: (255, 255, 255, 255),
             safe_range: None,
+            ema_factor: DEFAULT_EMA_FACTOR,
+            show_rate: false,
+            avg_window: None,
+            history: false,
+            doc: "",
+            alert: false,
+            notation: Notation::Plain,
         }
     }
 
@@ -296,6 +574,51 @@ impl CounterDescriptor {
         self.safe_range = Some(range);
         self
     }
+
+    pub fn ema_factor(mut self, factor: f32) -> Self {
+        self.ema_factor = factor.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn show_rate(mut self) -> Self {
+        self.show_rate = true;
+        self
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Formats a value of this counter according to its format and notation.
+    pub fn format_value(&self, value: f32) -> FormattedValue {
+        FormattedValue {
+            value,
+            format: self.format,
+            notation: self.notation,
+        }
+    }
+
+    pub fn avg_window(mut self, frames: u32) -> Self {
+        self.avg_window = Some(frames);
+        self
+    }
+
+    pub fn with_history(mut self) -> Self {
+        self.history = true;
+        self
+    }
+
+    pub fn doc(mut self, doc: &'static str) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Raises alerts when the value leaves the safe range.
+    pub fn alert(mut self) -> Self {
+        self.alert = true;
+        self
+    }
 }
 
 pub struct HistoryIter<'l> {
@@ -311,17 +634,36 @@ impl<'l> Iterator for HistoryIter<'l> {
     }
 }
 
+/// A counter whose value left its safe range, see `Counters::active_alerts`.
+#[derive(Copy, Clone)]
+pub struct Alert<'a> {
+    pub id: CounterId,
+    pub counter: &'a Counter,
+    /// The last value outside of the safe range.
+    pub value: f32,
+    /// The number of frames until the alert expires, if the value stays in
+    /// the safe range.
+    pub remaining_frames: u32,
+}
+
 struct Group {
-    name: &'static str,
+    name: Cow<'static, str>,
     range: Range<u16>,
 }
 
+/// A function polled by `Counters::update` to set the value of a counter.
+type Watch = Box<dyn FnMut() -> f32 + Send>;
+
 pub struct Counters {
     groups: Vec<Group>,
     counters: Vec<Counter>,
+    watches: Vec<(CounterId, Watch)>,
+    /// Slots of unregistered groups, reused by later registrations.
+    free_ranges: Vec<Range<u16>>,
     history_size: usize,
     counter_avg_window: u32,
     frame_idx: u32,
+    alert_duration: u32,
 }
 
 impl Counters {
@@ -329,9 +671,12 @@ impl Counters {
         Counters {
             counters: Vec::new(),
             groups: Vec::new(),
+            watches: Vec::new(),
+            free_ranges: Vec::new(),
             history_size,
             counter_avg_window: 30,
             frame_idx: 0,
+            alert_duration: DEFAULT_ALERT_DURATION,
         }
     }
 
@@ -340,35 +685,129 @@ impl Counters {
         name: &'static str,
         descriptors: &[CounterDescriptor],
     ) -> CounterGroup {
-        let start = self.counters.len();
-        let end = start + descriptors.len();
-        assert!(
-            end < (std::u16::MAX as usize),
-            "Registered too many counters"
-        );
-        let start = start as u16;
-        let end = end as u16;
+        self.register_group_impl(name.into(), descriptors, &mut std::iter::empty())
+    }
+
+    /// Registers a group of counters with names only known at runtime, for
+    /// example one counter per loaded asset pack or per window.
+    ///
+    /// The name of each descriptor is replaced with the corresponding name in
+    /// `counter_names`. Once registered, the counters are accessed by id like
+    /// any other.
+    pub fn register_named_group(
+        &mut self,
+        name: String,
+        descriptors: &[CounterDescriptor],
+        counter_names: impl IntoIterator<Item = String>,
+    ) -> CounterGroup {
+        self.register_group_impl(name.into(), descriptors, &mut counter_names.into_iter())
+    }
+
+    fn register_group_impl(
+        &mut self,
+        name: Cow<'static, str>,
+        descriptors: &[CounterDescriptor],
+        counter_names: &mut dyn Iterator<Item = String>,
+    ) -> CounterGroup {
+        let len = descriptors.len();
+        let free = self.free_ranges.iter().position(|range| range.len() >= len);
+        let start = match free {
+            Some(idx) => {
+                let range = &mut self.free_ranges[idx];
+                let start = range.start as usize;
+                range.start += len as u16;
+                if range.start == range.end {
+                    self.free_ranges.swap_remove(idx);
+                }
+                start
+            }
+            None => {
+                let start = self.counters.len();
+                assert!(
+                    start + len < (u16::MAX as usize),
+                    "Registered too many counters"
+                );
+                self.counters
+                    .resize_with(start + len, || Counter::new(CounterDescriptor::_DEFAULT));
+                start
+            }
+        };
+        let end = start + len;
 
         self.groups.push(Group {
             name,
-            range: start..end,
+            range: start as u16..end as u16,
         });
-        self.counters.reserve(descriptors.len());
-        let mut idx = 0;
-        for desc in descriptors {
+        for (idx, desc) in descriptors.iter().enumerate() {
             assert_eq!(desc.id.index(), idx);
-            idx += 1;
-            self.counters.push(Counter::new(desc.clone()));
+            let mut counter = Counter::new(desc.clone());
+            if desc.history {
+                counter.enable_history(self.history_size);
+            }
+            counter.owned_name = counter_names.next().map(String::into_boxed_str);
+            self.counters[start + idx] = counter;
+        }
+
+        CounterGroup {
+            start: start as u16,
+            end: end as u16,
+        }
+    }
+
+    /// Removes a group of counters.
+    ///
+    /// The ids of the group must not be used afterwards, their slots are
+    /// reused by groups registered later.
+    pub fn unregister_group(&mut self, group: CounterGroup) {
+        let Some(idx) = self
+            .groups
+            .iter()
+            .position(|g| g.range == (group.start..group.end))
+        else {
+            return;
+        };
+        self.groups.swap_remove(idx);
+        self.watches
+            .retain(|(id, _)| !(group.start..group.end).contains(&id.0));
+
+        for counter in &mut self.counters[group.start as usize..group.end as usize] {
+            *counter = Counter::new(CounterDescriptor::_DEFAULT);
         }
+        if group.start < group.end {
+            self.free_ranges.push(group.start..group.end);
+        }
+    }
 
-        CounterGroup { start, end }
+    /// Clears the values, statistics and history of a group of counters.
+    pub fn reset_group(&mut self, group: CounterGroup) {
+        for counter in &mut self.counters[group.start as usize..group.end as usize] {
+            counter.reset();
+        }
     }
 
     pub fn update(&mut self) {
+        for (id, watch) in &mut self.watches {
+            self.counters[id.index()].set(watch());
+        }
+
         self.frame_idx += 1;
         let update_avg = self.frame_idx == self.counter_avg_window;
+        let now = Instant::now();
         for counter in &mut self.counters {
-            counter.update(update_avg);
+            let update_avg = match counter.descriptor.avg_window {
+                Some(window) => {
+                    counter.window_frames += 1;
+                    let update = counter.window_frames >= window;
+                    if update {
+                        counter.window_frames = 0;
+                    }
+                    update
+                }
+                None => update_avg,
+            };
+            let value = counter.current_value;
+            counter.update_at(update_avg, now);
+            counter.update_alert(value, self.alert_duration);
         }
         if update_avg {
             self.frame_idx = 0;
@@ -379,6 +818,75 @@ impl Counters {
         self.counters[id.index()].set(val);
     }
 
+    /// Sets the value of a counter to the result of `f` at each `update`, so
+    /// that values available at any time don't have to be set every frame.
+    ///
+    /// Replaces the previous watch of the counter, if any. State shared with
+    /// the rest of the application can be captured with an `Arc`:
+    ///
+    /// ```
+    /// use debug_overlay::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let mut counters = Counters::new(60);
+    /// let group = counters.register_group(
+    ///     "World",
+    ///     &[CounterDescriptor::int("entities", "", CounterId(0))],
+    /// );
+    /// let entities = group.counter(CounterIndex(0));
+    ///
+    /// let entity_count = Arc::new(AtomicUsize::new(0));
+    /// let count = entity_count.clone();
+    /// counters.watch(entities, move || count.load(Ordering::Relaxed) as f32);
+    ///
+    /// entity_count.store(42, Ordering::Relaxed);
+    /// counters.update();
+    /// ```
+    pub fn watch(&mut self, id: CounterId, f: impl FnMut() -> f32 + Send + 'static) {
+        self.unwatch(id);
+        self.watches.push((id, Box::new(f)));
+    }
+
+    /// Stops polling the function registered with `watch` for a counter.
+    pub fn unwatch(&mut self, id: CounterId) {
+        self.watches.retain(|(watched, _)| *watched != id);
+    }
+
+    /// Adds to the value of a counter for the current frame, see `Counter::add`.
+    pub fn add(&mut self, id: CounterId, val: f32) {
+        self.counters[id.index()].add(val);
+    }
+
+    /// Sets the default number of frames the displayed average, min and max
+    /// are computed over.
+    pub fn set_avg_window(&mut self, frames: u32) {
+        self.counter_avg_window = frames.max(1);
+        self.frame_idx = 0;
+    }
+
+    /// Sets the number of frames alerts stay active after the value goes back
+    /// to the safe range.
+    pub fn set_alert_duration(&mut self, frames: u32) {
+        self.alert_duration = frames.max(1);
+    }
+
+    /// The counters that left their safe range in the last few frames.
+    ///
+    /// Only counters with `CounterDescriptor::alert` set raise alerts.
+    pub fn active_alerts(&self) -> impl Iterator<Item = Alert<'_>> {
+        self.counters
+            .iter()
+            .enumerate()
+            .filter(|(_, counter)| counter.is_alerting())
+            .map(|(idx, counter)| Alert {
+                id: CounterId(idx as u16),
+                counter,
+                value: counter.alert_value,
+                remaining_frames: counter.alert_frames,
+            })
+    }
+
     pub fn enable_history(&mut self, id: CounterId) {
         self.counters[id.index()].enable_history(self.history_size);
     }
@@ -401,13 +909,21 @@ impl Counters {
         &self.counters[id.index()]
     }
 
+    /// Iterates over the registered groups and their counters.
+    pub fn groups(&self) -> impl Iterator<Item = (&str, &[Counter])> {
+        self.groups.iter().map(|group| {
+            let range = group.range.start as usize..group.range.end as usize;
+            (&*group.name, &self.counters[range])
+        })
+    }
+
     pub fn find_group_by_name(&self, group_name: &str) -> Option<CounterGroup> {
         let group = self.groups.iter().find(|g| g.name == group_name)?;
 
-        return Some(CounterGroup {
+        Some(CounterGroup {
             start: group.range.start,
             end: group.range.end,
-        });
+        })
     }
 
     pub fn find_counter_by_name(&self, group_name: &str, counter_name: &str) -> Option<CounterId> {
@@ -450,6 +966,233 @@ fn history() {
     );
 }
 
+#[test]
+fn first_window_min_max() {
+    // The first averaging window doesn't include an implicit zero.
+    let mut c = Counter::new(CounterDescriptor::float("foo", "", CounterId(0)));
+    c.set(5.0);
+    c.update(false);
+    c.set(7.0);
+    c.update(true);
+    assert_eq!((c.displayed_min, c.displayed_max), (5.0, 7.0));
+
+    let mut c = Counter::new(CounterDescriptor::float("foo", "", CounterId(0)));
+    c.set(-3.0);
+    c.update(false);
+    c.set(-1.0);
+    c.update(true);
+    assert_eq!((c.displayed_min, c.displayed_max), (-3.0, -1.0));
+}
+
+#[test]
+fn history_range() {
+    let mut c = Counter::new(CounterDescriptor::float("foo", "", CounterId(0)));
+    c.enable_history(2);
+    assert_eq!(c.history_range(), None);
+    c.set(10.0);
+    c.update(false);
+    assert_eq!(c.history_range(), Some(10.0));
+    c.set(1.0);
+    c.update(false);
+    c.set(1.0);
+    c.update(false);
+    let range = c.history_range().unwrap();
+    assert!(range < 10.0 && range > 5.0);
+    c.set(20.0);
+    c.update(false);
+    assert_eq!(c.history_range(), Some(20.0));
+}
+
+#[test]
+fn ema() {
+    let desc = CounterDescriptor::float("foo", "", CounterId(0)).ema_factor(0.5);
+    let mut c = Counter::new(desc);
+    assert_eq!(c.ema(), None);
+    c.set(10.0);
+    c.update(false);
+    assert_eq!(c.ema(), Some(10.0));
+    c.set(20.0);
+    c.update(false);
+    assert_eq!(c.ema(), Some(15.0));
+    c.set(None);
+    c.update(false);
+    assert_eq!(c.ema(), Some(15.0));
+}
+
+#[test]
+fn accumulate() {
+    let mut c = Counter::new(CounterDescriptor::int("draw calls", "", CounterId(0)));
+    c.add(3.0);
+    c.add(4.0);
+    assert_eq!(c.last_value, 7.0);
+    c.update(false);
+    c.add(1.0);
+    assert_eq!(c.last_value, 1.0);
+    c.set(10.0);
+    c.add(1.0);
+    assert_eq!(c.last_value, 11.0);
+}
+
+#[test]
+fn avg_window() {
+    let descriptors = [
+        CounterDescriptor::float("fast", "", CounterId(0)),
+        CounterDescriptor::float("slow", "", CounterId(1)).avg_window(4),
+    ];
+    let mut counters = Counters::new(0);
+    let group = counters.register_group("test", &descriptors);
+    counters.set_avg_window(2);
+    let fast = group.counter(CounterIndex(0));
+    let slow = group.counter(CounterIndex(1));
+    for val in [1.0, 3.0, 5.0, 7.0] {
+        counters.set(fast, val);
+        counters.set(slow, val);
+        counters.update();
+        if val == 3.0 {
+            assert_eq!(counters.get_counter(fast).displayed_avg, 2.0);
+            assert!(counters.get_counter(slow).displayed_avg.is_nan());
+        }
+    }
+    assert_eq!(counters.get_counter(fast).displayed_avg, 6.0);
+    assert_eq!(counters.get_counter(slow).displayed_avg, 4.0);
+}
+
+#[test]
+fn rate() {
+    let mut c = Counter::new(CounterDescriptor::int("allocations", "", CounterId(0)));
+    let t0 = Instant::now();
+    c.update_rate(100.0, t0);
+    assert_eq!(c.rate(), None);
+    c.update_rate(150.0, t0 + std::time::Duration::from_millis(500));
+    assert_eq!(c.rate(), Some(100.0));
+}
+
+#[test]
+fn percentiles() {
+    let mut c = Counter::new(CounterDescriptor::float("foo", "", CounterId(0)));
+    assert_eq!(c.percentile(50.0), None);
+    c.enable_history(101);
+    for i in (0..=100).rev() {
+        c.set(i as f32);
+        c.update(false);
+    }
+    assert_eq!(c.percentile(50.0), Some(50.0));
+    assert_eq!(c.percentile(95.0), Some(95.0));
+    assert_eq!(c.percentile(99.0), Some(99.0));
+    assert_eq!(c.percentile(100.0), Some(100.0));
+
+    // The sorted samples are refreshed after the next update.
+    c.set(1000.0);
+    c.update(false);
+    assert_eq!(c.percentile(100.0), Some(1000.0));
+    c.disable_history();
+    assert_eq!(c.percentile(50.0), None);
+}
+
+#[test]
+fn unregister_group() {
+    let descriptors = [
+        CounterDescriptor::int("a", "", CounterId(0)),
+        CounterDescriptor::int("b", "", CounterId(1)),
+    ];
+    let mut counters = Counters::new(10);
+    let g0 = counters.register_group("g0", &descriptors);
+    let g1 = counters.register_group("g1", &descriptors);
+
+    counters.set(g1.counter(CounterIndex(0)), 5.0);
+    counters.reset_group(g1);
+    assert!(counters
+        .get_counter(g1.counter(CounterIndex(0)))
+        .last_value
+        .is_nan());
+
+    counters.unregister_group(g0);
+    assert!(counters.find_group_by_name("g0").is_none());
+
+    // The slots of the removed group are reused.
+    let g2 = counters.register_group("g2", &descriptors[..1]);
+    assert_eq!(g2.counter(CounterIndex(0)), g0.counter(CounterIndex(0)));
+    let g3 = counters.register_group("g3", &descriptors);
+    assert_eq!(g3.counter(CounterIndex(0)), CounterId(4));
+    assert_eq!(
+        counters.find_counter_by_name("g1", "b"),
+        Some(g1.counter(CounterIndex(1)))
+    );
+}
+
+#[test]
+fn watch() {
+    let descriptors = [
+        CounterDescriptor::int("a", "", CounterId(0)),
+        CounterDescriptor::int("b", "", CounterId(1)),
+    ];
+    let mut counters = Counters::new(10);
+    let g0 = counters.register_group("g0", &descriptors);
+    let g1 = counters.register_group("g1", &descriptors);
+    let (a, b) = (g0.counter(CounterIndex(0)), g1.counter(CounterIndex(1)));
+
+    let mut frame = 0.0;
+    counters.watch(a, move || {
+        frame += 1.0;
+        frame
+    });
+    counters.watch(b, || 10.0);
+    counters.update();
+    counters.update();
+    assert_eq!(counters.get_counter(a).last_value, 2.0);
+    assert_eq!(counters.get_counter(b).last_value, 10.0);
+
+    // Watching again replaces the function.
+    counters.watch(b, || 20.0);
+    counters.update();
+    assert_eq!(counters.get_counter(b).last_value, 20.0);
+
+    counters.unwatch(a);
+    counters.unregister_group(g1);
+    assert!(counters.watches.is_empty());
+}
+
+#[test]
+fn named_group() {
+    let descriptors = [
+        CounterDescriptor::float("", "ms", CounterId(0)),
+        CounterDescriptor::float("", "ms", CounterId(1)),
+    ];
+    let mut counters = Counters::new(10);
+    let window = format!("window {}", 1);
+    let group = counters.register_named_group(
+        window.clone(),
+        &descriptors,
+        ["cpu".to_string(), "gpu".to_string()],
+    );
+
+    let gpu = counters.find_counter_by_name(&window, "gpu").unwrap();
+    assert_eq!(gpu, group.counter(CounterIndex(1)));
+    counters.set(gpu, 2.0);
+    counters.reset_group(group);
+    assert_eq!(counters.get_counter(gpu).name(), "gpu");
+}
+
+#[test]
+fn decl_counters_with_history() {
+    declare_counters!(render_counters = {
+        frame_time: float = "frame time" with { unit: "ms", history: true, doc: "CPU time per frame" },
+        draw_calls: int = "draw calls"
+    });
+
+    let mut counters = Counters::new(60);
+    let ids = render_counters::register("render", &mut counters);
+    assert!(counters.get_counter(ids.frame_time()).history().is_some());
+    assert!(counters.get_counter(ids.draw_calls()).history().is_none());
+
+    let mut summary = String::new();
+    counters
+        .get_counter(ids.frame_time())
+        .write_summary(&mut summary)
+        .unwrap();
+    assert!(summary.starts_with("frame time (ms)\nCPU time per frame\n"));
+}
+
 #[test]
 fn decl_counters() {
     declare_counters!(wgpu_counters = {
@@ -556,3 +1299,40 @@ pub fn update_wgpu_internal_counters(
         values.hal.memory_allocations.read() as f32,
     );
 }
+
+#[test]
+fn alerts() {
+    let mut counters = Counters::new(0);
+    let group = counters.register_group(
+        "alerts",
+        &[
+            CounterDescriptor::int("a", "", CounterId(0))
+                .safe_range(0.0..10.0)
+                .alert(),
+            CounterDescriptor::int("b", "", CounterId(1)).safe_range(0.0..10.0),
+        ],
+    );
+    let a = group.counter(CounterIndex(0));
+    let b = group.counter(CounterIndex(1));
+    counters.set_alert_duration(2);
+
+    counters.set(a, 5.0);
+    counters.update();
+    assert_eq!(counters.active_alerts().count(), 0);
+
+    // Only counters with alerts enabled raise them.
+    counters.set(a, 20.0);
+    counters.set(b, 20.0);
+    counters.update();
+    let alerts: Vec<Alert> = counters.active_alerts().collect();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].id, a);
+    assert_eq!(alerts[0].value, 20.0);
+    assert_eq!(alerts[0].remaining_frames, 2);
+
+    counters.set(a, 5.0);
+    counters.update();
+    assert!(counters.get_counter(a).is_alerting());
+    counters.update();
+    assert_eq!(counters.active_alerts().count(), 0);
+}
@@ -0,0 +1,155 @@
+//! Displays the overlay inside of an `egui` user interface.
+//!
+//! Items are drawn with the regular `Overlay` API, and the resulting geometry
+//! is converted into an `egui` mesh instead of being rendered with one of the
+//! GPU backends.
+//!
+//! ```no_run
+//! # fn ui(ctx: &::egui::Context, overlay: &mut debug_overlay::Overlay) {
+//! let renderer = debug_overlay::egui::Renderer::new(ctx);
+//!
+//! ::egui::Window::new("Counters").show(ctx, |ui| {
+//!     overlay.begin_frame();
+//!     // Draw tables, graphs, etc.
+//!     overlay.finish();
+//!     renderer.show(ui, overlay);
+//! });
+//! # }
+//! ```
+
+use ::egui;
+
+use crate::{
+    distance_coverage,
+    embedded_font::{
+        ATLAS_HEIGHT, ATLAS_WIDTH, DISTANCE_FIELD, DISTANCE_SPREAD, GLYPH_ATLAS, OPAQUE_PIXEL,
+    },
+    Overlay, OverlayGeometry, Point,
+};
+
+/// Converts overlay geometry into `egui` meshes.
+pub struct Renderer {
+    atlas: egui::TextureHandle,
+}
+
+impl Renderer {
+    /// Uploads the glyph atlas as an `egui` texture.
+    ///
+    /// A distance field atlas is converted to coverage at its native size, since
+    /// `egui` can't evaluate distance fields.
+    pub fn new(ctx: &egui::Context) -> Self {
+        let size = [ATLAS_WIDTH as usize, ATLAS_HEIGHT as usize];
+        let pixels = GLYPH_ATLAS[..size[0] * size[1]]
+            .iter()
+            .map(|&a| {
+                let a = if DISTANCE_FIELD {
+                    (distance_coverage(a as f32 / 255.0, DISTANCE_SPREAD, 1.0) * 255.0).round()
+                        as u8
+                } else {
+                    a
+                };
+                egui::Color32::from_rgba_premultiplied(a, a, a, a)
+            })
+            .collect();
+        let atlas = ctx.load_texture(
+            "Debug overlay atlas",
+            egui::ColorImage { size, pixels },
+            egui::TextureOptions::NEAREST,
+        );
+
+        Renderer { atlas }
+    }
+
+    /// Builds a mesh of the overlay geometry, translated by `offset`.
+    pub fn mesh(&self, geometry: &OverlayGeometry, offset: egui::Vec2, opacity: f32) -> egui::Mesh {
+        let opaque_uv = (OPAQUE_PIXEL.0 as u32) << 16 | OPAQUE_PIXEL.1 as u32;
+        let atlas_size = egui::vec2(ATLAS_WIDTH as f32, ATLAS_HEIGHT as f32);
+
+        let mut mesh = egui::Mesh::with_texture(self.atlas.id());
+        mesh.vertices.reserve(geometry.vertices.len());
+        for vertex in &geometry.vertices {
+            let mut uv = egui::vec2((vertex.uv >> 16) as f32, (vertex.uv & 0xFFFF) as f32);
+            if vertex.uv == opaque_uv {
+                // Sample the center of the texel rather than one of its corners.
+                uv += egui::vec2(0.5, 0.5);
+            }
+            let [r, g, b, a] = vertex.color.to_be_bytes();
+            mesh.vertices.push(egui::epaint::Vertex {
+                pos: egui::pos2(vertex.x, vertex.y) + offset,
+                uv: (uv / atlas_size).to_pos2(),
+                color: egui::Color32::from_rgba_unmultiplied(r, g, b, (a as f32 * opacity) as u8),
+            });
+        }
+        for (base_vertex, indices) in geometry.layers.iter().flat_map(|layer| layer.batches()) {
+            mesh.indices.extend(
+                indices
+                    .iter()
+                    .map(|&idx| (base_vertex + idx as usize) as u32),
+            );
+        }
+
+        mesh
+    }
+
+    /// Allocates space for the overlay in the ui, paints it and forwards the
+    /// mouse to the overlay so that tooltips, panels and scrolling work in the
+    /// next frame.
+    pub fn show(&self, ui: &mut egui::Ui, overlay: &mut Overlay) -> egui::Response {
+        let size = geometry_size(&overlay.geometry);
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click_and_drag());
+        if ui.is_rect_visible(rect) {
+            let mesh = self.mesh(&overlay.geometry, rect.min.to_vec2(), 1.0);
+            ui.painter().add(egui::Shape::mesh(mesh));
+        }
+
+        let mouse = response.hover_pos().map(|pos| Point {
+            x: (pos.x - rect.min.x) as i32,
+            y: (pos.y - rect.min.y) as i32,
+        });
+        overlay.set_mouse_position(mouse);
+        overlay.set_mouse_button(response.is_pointer_button_down_on());
+        if response.hovered() {
+            let wheel = ui.input(|input| input.raw_scroll_delta.y);
+            overlay.set_mouse_wheel(wheel as i32);
+        }
+
+        response
+    }
+}
+
+/// The size of the area covered by the geometry, including its top-left margin.
+fn geometry_size(geometry: &OverlayGeometry) -> egui::Vec2 {
+    let mut size = egui::Vec2::ZERO;
+    for vertex in &geometry.vertices {
+        size = size.max(egui::vec2(vertex.x, vertex.y));
+    }
+
+    size
+}
+
+#[test]
+fn egui_mesh() {
+    let ctx = egui::Context::default();
+    let renderer = Renderer::new(&ctx);
+
+    let mut geometry = OverlayGeometry::new(2);
+    geometry.push_rectangle(
+        1,
+        &(Point { x: 10, y: 10 }, Point { x: 30, y: 20 }),
+        (255, 0, 0, 255),
+        (255, 0, 0, 255),
+    );
+    geometry.push_text(0, "a", Point { x: 0, y: 0 }, (255, 255, 255, 255));
+
+    let mesh = renderer.mesh(&geometry, egui::vec2(100.0, 0.0), 0.5);
+    assert_eq!(mesh.vertices.len(), geometry.vertices.len());
+    assert_eq!(mesh.indices.len(), 12);
+    // Layers are drawn in order: the text of layer 0 comes first.
+    assert_eq!(mesh.indices[0] as usize, 4);
+    assert_eq!(mesh.vertices[0].pos, egui::pos2(110.0, 10.0));
+    assert_eq!(
+        mesh.vertices[0].color,
+        egui::Color32::from_rgba_unmultiplied(255, 0, 0, 127)
+    );
+    assert_eq!(geometry_size(&geometry).x, 30.0);
+}
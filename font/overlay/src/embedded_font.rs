@@ -1,11 +1,15 @@
-/// An embedded bitmap ascii font for debugging purposes.
-/// Generated from font Hack-Regular.ttf.
+//! An embedded bitmap font for debugging purposes.
+//! Generated from font Hack-Regular.ttf.
 
-pub const FIRST_CHAR: u32 = 32;
 pub const ATLAS_WIDTH: u32 = 256;
-pub const ATLAS_HEIGHT: u32 = 68;
+pub const ATLAS_HEIGHT: u32 = 202;
 pub const FONT_HEIGHT: u32 = 18;
-pub const OPAQUE_PIXEL: (u16, u16) = (0, 67);
+pub const OPAQUE_PIXEL: (u16, u16) = (1, 201);
+/// Whether `GLYPH_ATLAS` contains signed distances rather than coverage.
+pub const DISTANCE_FIELD: bool = false;
+/// The distance to the edge of the glyphs, in atlas pixels, at which the
+/// distance field saturates.
+pub const DISTANCE_SPREAD: f32 = 0.0;
 
 #[derive(Copy, Clone, Debug)]
 pub struct GlyphInfo {
@@ -15,6 +19,49 @@ pub struct GlyphInfo {
     pub x_advance: f32,
 }
 
+/// A run of consecutive characters, the glyph of `first` being at index
+/// `glyph` in the glyph table of each face.
+#[derive(Copy, Clone, Debug)]
+pub struct CharRange {
+    pub first: u32,
+    pub last: u32,
+    pub glyph: u16,
+}
+
+/// The baked characters, sorted by codepoint.
+pub const CHAR_RANGES: &[CharRange] = &[
+    CharRange { first: 32, last: 127, glyph: 0 },
+];
+
+/// Adjusts the advance between two glyphs, in pixels. Sorted by `left`, then
+/// `right`, which are indices in the glyph table of the face.
+#[derive(Copy, Clone, Debug)]
+pub struct KerningPair {
+    pub left: u16,
+    pub right: u16,
+    pub advance: f32,
+}
+
+/// A size and weight of the font baked in the atlas.
+#[derive(Copy, Clone, Debug)]
+pub struct FontFace {
+    pub height: u32,
+    pub bold: bool,
+    pub glyphs: &'static [GlyphInfo],
+    pub kerning: &'static [KerningPair],
+}
+
+/// The faces baked in the atlas. The first one is the default face, with
+/// `FONT_HEIGHT`, `GLYPH_INFO` and `KERNING`.
+pub const FACES: &[FontFace] = &[
+    FontFace { height: 18, bold: false, glyphs: GLYPH_INFO, kerning: KERNING },
+    FontFace { height: 14, bold: false, glyphs: GLYPH_INFO_1, kerning: KERNING_1 },
+    FontFace { height: 24, bold: false, glyphs: GLYPH_INFO_2, kerning: KERNING_2 },
+];
+
+pub const KERNING: &[KerningPair] = &[
+];
+
 pub const GLYPH_INFO: &[GlyphInfo] = &[
     GlyphInfo { uv0: (1, 1), uv1: (1, 1), offset: (0, 0), x_advance: 9.309564 },
     GlyphInfo { uv0: (2, 1), uv1: (5, 14), offset: (3, -12), x_advance: 9.309564 },
@@ -114,6 +161,210 @@ pub const GLYPH_INFO: &[GlyphInfo] = &[
     GlyphInfo { uv0: (81, 50), uv1: (90, 64), offset: (0, -11), x_advance: 9.309564 },
 ];
 
+pub const KERNING_1: &[KerningPair] = &[
+];
+
+pub const GLYPH_INFO_1: &[GlyphInfo] = &[
+    GlyphInfo { uv0: (91, 50), uv1: (91, 50), offset: (0, 0), x_advance: 7.240772 },
+    GlyphInfo { uv0: (92, 50), uv1: (95, 60), offset: (2, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (96, 50), uv1: (101, 54), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (102, 50), uv1: (110, 59), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (111, 50), uv1: (117, 62), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (118, 50), uv1: (126, 59), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (127, 50), uv1: (135, 60), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (136, 50), uv1: (138, 54), offset: (3, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (139, 50), uv1: (143, 62), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (144, 50), uv1: (148, 62), offset: (2, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (149, 50), uv1: (156, 57), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (157, 50), uv1: (164, 57), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (165, 50), uv1: (168, 56), offset: (2, -3), x_advance: 7.240772 },
+    GlyphInfo { uv0: (169, 50), uv1: (175, 52), offset: (1, -5), x_advance: 7.240772 },
+    GlyphInfo { uv0: (176, 50), uv1: (179, 53), offset: (2, -2), x_advance: 7.240772 },
+    GlyphInfo { uv0: (180, 50), uv1: (187, 61), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (188, 50), uv1: (195, 60), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (196, 50), uv1: (202, 59), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (203, 50), uv1: (210, 59), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (211, 50), uv1: (218, 60), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (219, 50), uv1: (226, 59), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (227, 50), uv1: (234, 60), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (235, 50), uv1: (242, 60), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (243, 50), uv1: (250, 59), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (1, 67), uv1: (8, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (9, 67), uv1: (16, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (17, 67), uv1: (20, 74), offset: (2, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (21, 67), uv1: (24, 77), offset: (2, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (25, 67), uv1: (32, 74), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (33, 67), uv1: (40, 71), offset: (0, -6), x_advance: 7.240772 },
+    GlyphInfo { uv0: (41, 67), uv1: (48, 74), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (49, 67), uv1: (55, 77), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (56, 67), uv1: (63, 78), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (64, 67), uv1: (72, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (73, 67), uv1: (80, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (81, 67), uv1: (88, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (89, 67), uv1: (96, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (97, 67), uv1: (103, 76), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (104, 67), uv1: (110, 76), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (111, 67), uv1: (118, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (119, 67), uv1: (126, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (127, 67), uv1: (133, 76), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (134, 67), uv1: (140, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (141, 67), uv1: (149, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (150, 67), uv1: (156, 76), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (157, 67), uv1: (164, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (165, 67), uv1: (172, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (173, 67), uv1: (180, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (181, 67), uv1: (187, 76), offset: (1, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (188, 67), uv1: (196, 79), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (197, 67), uv1: (205, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (206, 67), uv1: (213, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (214, 67), uv1: (221, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (222, 67), uv1: (229, 77), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (230, 67), uv1: (237, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (238, 67), uv1: (246, 76), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (1, 80), uv1: (9, 89), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (10, 80), uv1: (18, 89), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (19, 80), uv1: (26, 89), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (27, 80), uv1: (32, 92), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (33, 80), uv1: (40, 91), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (41, 80), uv1: (46, 92), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (47, 80), uv1: (54, 84), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (55, 80), uv1: (62, 81), offset: (0, 1), x_advance: 7.240772 },
+    GlyphInfo { uv0: (63, 80), uv1: (67, 83), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (68, 80), uv1: (75, 88), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (76, 80), uv1: (82, 91), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (83, 80), uv1: (90, 88), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (91, 80), uv1: (98, 91), offset: (0, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (99, 80), uv1: (106, 88), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (107, 80), uv1: (114, 90), offset: (0, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (115, 80), uv1: (122, 90), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (123, 80), uv1: (129, 90), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (130, 80), uv1: (136, 91), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (137, 80), uv1: (141, 93), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (142, 80), uv1: (148, 90), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (149, 80), uv1: (155, 91), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (156, 80), uv1: (163, 87), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (164, 80), uv1: (170, 87), offset: (1, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (171, 80), uv1: (178, 88), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (179, 80), uv1: (185, 90), offset: (1, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (186, 80), uv1: (193, 90), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (194, 80), uv1: (200, 87), offset: (1, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (201, 80), uv1: (207, 88), offset: (1, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (208, 80), uv1: (215, 90), offset: (0, -9), x_advance: 7.240772 },
+    GlyphInfo { uv0: (216, 80), uv1: (222, 88), offset: (1, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (223, 80), uv1: (230, 87), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (231, 80), uv1: (239, 87), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (240, 80), uv1: (247, 87), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (1, 94), uv1: (8, 104), offset: (0, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (9, 94), uv1: (15, 101), offset: (1, -7), x_advance: 7.240772 },
+    GlyphInfo { uv0: (16, 94), uv1: (22, 106), offset: (0, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (23, 94), uv1: (25, 107), offset: (3, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (26, 94), uv1: (32, 106), offset: (1, -10), x_advance: 7.240772 },
+    GlyphInfo { uv0: (33, 94), uv1: (40, 97), offset: (0, -5), x_advance: 7.240772 },
+    GlyphInfo { uv0: (41, 94), uv1: (48, 106), offset: (0, -9), x_advance: 7.240772 },
+];
+
+pub const KERNING_2: &[KerningPair] = &[
+];
+
+pub const GLYPH_INFO_2: &[GlyphInfo] = &[
+    GlyphInfo { uv0: (49, 94), uv1: (49, 94), offset: (0, 0), x_advance: 12.412751 },
+    GlyphInfo { uv0: (50, 94), uv1: (54, 111), offset: (4, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (55, 94), uv1: (62, 101), offset: (3, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (63, 94), uv1: (76, 109), offset: (0, -15), x_advance: 12.412751 },
+    GlyphInfo { uv0: (77, 94), uv1: (88, 114), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (89, 94), uv1: (102, 109), offset: (0, -15), x_advance: 12.412751 },
+    GlyphInfo { uv0: (103, 94), uv1: (116, 111), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (117, 94), uv1: (120, 101), offset: (5, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (121, 94), uv1: (127, 113), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (128, 94), uv1: (134, 113), offset: (4, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (135, 94), uv1: (146, 105), offset: (1, -15), x_advance: 12.412751 },
+    GlyphInfo { uv0: (147, 94), uv1: (159, 105), offset: (0, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (160, 94), uv1: (165, 102), offset: (4, -4), x_advance: 12.412751 },
+    GlyphInfo { uv0: (166, 94), uv1: (175, 96), offset: (2, -7), x_advance: 12.412751 },
+    GlyphInfo { uv0: (176, 94), uv1: (180, 99), offset: (4, -4), x_advance: 12.412751 },
+    GlyphInfo { uv0: (181, 94), uv1: (191, 112), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (192, 94), uv1: (203, 111), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (204, 94), uv1: (214, 110), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (215, 94), uv1: (225, 110), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (226, 94), uv1: (236, 111), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (237, 94), uv1: (248, 110), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (1, 115), uv1: (11, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (12, 115), uv1: (23, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (24, 115), uv1: (34, 131), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (35, 115), uv1: (46, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (47, 115), uv1: (58, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (59, 115), uv1: (63, 127), offset: (4, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (64, 115), uv1: (70, 131), offset: (3, -11), x_advance: 12.412751 },
+    GlyphInfo { uv0: (71, 115), uv1: (83, 126), offset: (0, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (84, 115), uv1: (96, 122), offset: (0, -10), x_advance: 12.412751 },
+    GlyphInfo { uv0: (97, 115), uv1: (109, 126), offset: (0, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (110, 115), uv1: (119, 132), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (120, 115), uv1: (132, 134), offset: (0, -15), x_advance: 12.412751 },
+    GlyphInfo { uv0: (133, 115), uv1: (146, 131), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (147, 115), uv1: (158, 131), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (159, 115), uv1: (169, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (170, 115), uv1: (181, 131), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (182, 115), uv1: (193, 131), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (194, 115), uv1: (204, 131), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (205, 115), uv1: (216, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (217, 115), uv1: (228, 131), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (229, 115), uv1: (238, 131), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (239, 115), uv1: (248, 132), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (1, 135), uv1: (13, 151), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (14, 135), uv1: (24, 151), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (25, 135), uv1: (37, 151), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (38, 135), uv1: (49, 151), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (50, 135), uv1: (61, 152), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (62, 135), uv1: (73, 151), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (74, 135), uv1: (86, 155), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (87, 135), uv1: (99, 151), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (100, 135), uv1: (111, 152), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (112, 135), uv1: (124, 151), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (125, 135), uv1: (135, 152), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (136, 135), uv1: (148, 151), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (149, 135), uv1: (162, 151), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (163, 135), uv1: (176, 151), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (177, 135), uv1: (190, 151), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (191, 135), uv1: (202, 151), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (203, 135), uv1: (210, 155), offset: (3, -17), x_advance: 12.412751 },
+    GlyphInfo { uv0: (211, 135), uv1: (222, 153), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (223, 135), uv1: (231, 155), offset: (2, -17), x_advance: 12.412751 },
+    GlyphInfo { uv0: (232, 135), uv1: (244, 142), offset: (0, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (1, 156), uv1: (13, 159), offset: (0, 1), x_advance: 12.412751 },
+    GlyphInfo { uv0: (14, 156), uv1: (20, 161), offset: (2, -17), x_advance: 12.412751 },
+    GlyphInfo { uv0: (21, 156), uv1: (32, 169), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (33, 156), uv1: (44, 173), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (45, 156), uv1: (55, 169), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (56, 156), uv1: (66, 173), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (67, 156), uv1: (78, 169), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (79, 156), uv1: (89, 172), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (90, 156), uv1: (100, 173), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (101, 156), uv1: (111, 172), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (112, 156), uv1: (121, 173), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (122, 156), uv1: (129, 177), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (130, 156), uv1: (140, 172), offset: (2, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (141, 156), uv1: (151, 173), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (152, 156), uv1: (163, 168), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (164, 156), uv1: (174, 168), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (175, 156), uv1: (186, 169), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (187, 156), uv1: (198, 173), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (199, 156), uv1: (209, 173), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (210, 156), uv1: (219, 168), offset: (3, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (220, 156), uv1: (229, 169), offset: (2, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (230, 156), uv1: (240, 173), offset: (1, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (241, 156), uv1: (251, 169), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (1, 178), uv1: (12, 190), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (13, 178), uv1: (26, 190), offset: (0, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (27, 178), uv1: (39, 190), offset: (0, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (40, 178), uv1: (51, 195), offset: (1, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (52, 178), uv1: (61, 190), offset: (2, -12), x_advance: 12.412751 },
+    GlyphInfo { uv0: (62, 178), uv1: (71, 198), offset: (1, -17), x_advance: 12.412751 },
+    GlyphInfo { uv0: (72, 178), uv1: (75, 199), offset: (5, -16), x_advance: 12.412751 },
+    GlyphInfo { uv0: (76, 178), uv1: (86, 198), offset: (2, -17), x_advance: 12.412751 },
+    GlyphInfo { uv0: (87, 178), uv1: (99, 184), offset: (0, -9), x_advance: 12.412751 },
+    GlyphInfo { uv0: (100, 178), uv1: (111, 197), offset: (1, -15), x_advance: 12.412751 },
+];
+
 pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -920,24 +1171,187 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0xF, 0x74, 0x28,  0x0,  0x0,  0x0,  0x0, 0x63, 0x48,  0x0, 0x22, 0x78, 0x78, 0x78, 0x78, 0x78,
     0x66,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x11, 0x47, 0x1F,  0x0, 0xD0, 0x3F,  0x0,  0x2, 0x42,
     0x11,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x25, 0x37,  0x0,  0x0,  0x0, 0x2D, 0x3B,
-     0x0, 0x31, 0xE3, 0xDD, 0xDD, 0xDD, 0xDD, 0xDD, 0xE0, 0x77,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x31, 0xE3, 0xDD, 0xDD, 0xDD, 0xDD, 0xDD, 0xE0, 0x77,  0x0,  0x0, 0x24, 0xCB, 0x4D,  0x0,
+     0x3, 0xC4,  0x1, 0x96, 0x32,  0x0,  0x0,  0x0,  0x3, 0x94,  0x0, 0x64, 0x34,  0x0,  0x0,  0x0,
+     0x0, 0x1B,  0xA,  0x0,  0x0,  0x0,  0x0, 0x43, 0x50,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x13, 0x95, 0xDC, 0xCD, 0x17,  0x0,  0x0,  0x0, 0xB0, 0x18,  0x0,  0x0,  0x0, 0x11,  0xD,  0x0,
+     0x5, 0x19,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xC4, 0x19,  0x0,  0x0,  0x0,  0x0,  0x2,  0x0,  0x0,  0x2,  0x3,  0x3,  0x3,  0x3,  0x0,  0x0,
+    0x1A, 0xB8, 0x39,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5, 0xB6, 0x1F,  0x0,  0x0,  0x1, 0x51, 0xC8,
+    0x72,  0x7,  0x0,  0x0,  0x0,  0xA, 0xAB, 0x76,  0x0,  0x0,  0x0,  0x3, 0x8A, 0xD1, 0xE2, 0xB1,
+    0x28,  0x0,  0x0,  0x0, 0x8E, 0xD4, 0xE4, 0xBA, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1B, 0xC1,
+    0x61,  0x0,  0x0,  0x0, 0x9A, 0xC4, 0xC4, 0xC4, 0x80,  0x0,  0x0,  0x0,  0x0, 0x2F, 0xA5, 0xE4,
+    0x95,  0x0,  0x0, 0x24, 0xC4, 0xC4, 0xC4, 0xC4, 0xC4, 0x3F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x2, 0xE4, 0x87,  0x0,  0x0,  0x0, 0x38, 0xFF, 0x37,  0x0, 0xC6, 0x94,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x45, 0xFE, 0x16,  0x0,  0x0, 0x6A, 0xF4, 0x27,  0x0,  0x6, 0xCB, 0xB7,  0x2,  0x0,
+     0x0, 0xD6, 0x9E,  0x0,  0x0,  0x0, 0x21, 0xFE, 0x55,  0x0, 0x2F, 0xA3, 0xA3, 0xA3, 0xA3, 0xE9,
+    0xD5,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAC, 0xF9, 0xCB, 0x3E,  0x0, 0xFF, 0x4D,  0x0,  0x6, 0xD1,
+    0xF9, 0xD5,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7A, 0xEE, 0xF4, 0x90,  0x0,  0x0, 0x9D, 0x9C,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0, 0x2D, 0xFF, 0x61,  0x0,
+     0x4, 0xFF,  0x2, 0xC3, 0x41,  0x0,  0x0,  0x0, 0x35, 0xBB,  0x0, 0xD2, 0x1D,  0x0,  0x0,  0x0,
+     0x0, 0xC1, 0x4C,  0x0,  0x0,  0x0, 0x77, 0xAB, 0x97, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x84, 0xAB,  0xD, 0x27,  0xD,  0x0,  0x0,  0x0, 0xE5, 0x1F,  0x0,  0x0,  0x4, 0xD1, 0x22,  0x0,
+     0x4, 0xD2, 0x17,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xDF, 0x1C,  0x0,  0x0,  0x0, 0x30, 0xF4, 0x5E,  0x0, 0xC0, 0xF3, 0xF3, 0xF3, 0xF3,  0x8,  0x0,
+    0x35, 0xFD, 0x65,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5F, 0xBE,  0x0,  0x0,  0x0, 0x84, 0xCD, 0x34,
+    0xA7, 0xCE,  0x0,  0x0,  0x3, 0xB2, 0xF6, 0x9A,  0x0,  0x0,  0x0,  0x8, 0xAC, 0x58, 0x2B, 0xA3,
+    0xDD,  0x1,  0x0,  0x0, 0x5A, 0x2D, 0x23, 0x89, 0xCB,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAC, 0xDD,
+    0x7F,  0x0,  0x0,  0x0, 0xC8, 0x75, 0x3B, 0x3B, 0x27,  0x0,  0x0,  0x0, 0x72, 0xE0, 0x67, 0x1D,
+    0x48,  0x0,  0x0,  0xB, 0x3B, 0x3B, 0x3B, 0x56, 0xF8, 0x13,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x8C, 0xDE,  0x1,  0x0,  0x0, 0x90, 0xDB,  0x0,  0x0, 0x8A, 0xCB,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x7C, 0xD9,  0x0,  0x0,  0x0,  0x0, 0xAA, 0xCF,  0x8, 0x8B, 0xE4, 0x15,  0x0,  0x0,
+     0x0, 0x72, 0xF3,  0xC,  0x0,  0x0, 0x7F, 0xEB,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4E, 0xFC,
+    0x4C,  0x0,  0x0,  0x0,  0x0,  0xE, 0xFA, 0x55,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+    0x14, 0xFC, 0x4E,  0x0,  0x0,  0x0,  0x0,  0x8, 0xFA, 0x30, 0x24, 0xEC, 0x72, 0x11, 0xD6, 0x6C,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0, 0x2C, 0xFF, 0x61,  0x0,
+     0x4, 0xFF,  0x2, 0xC3, 0x41,  0x0, 0x10, 0x32, 0x8B, 0x9A, 0x3E, 0xE6, 0x32,  0xB,  0x0,  0xD,
+    0x72, 0xED, 0xC9, 0x84,  0x0,  0x0, 0xB8, 0x17,  0x0, 0xC9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x84, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE5, 0x1F,  0x0,  0x0, 0x61, 0xA5,  0x0,  0x0,
+     0x0, 0x69, 0x89,  0x0,  0x0, 0x14, 0x63, 0x14, 0xCB,  0xD, 0x52, 0x2F,  0x0, 0x21, 0x45, 0x45,
+    0xE8, 0x59, 0x45, 0x32,  0x0, 0x13, 0xB8, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0xD5, 0x47,  0x0,  0x0,  0x0, 0xC5, 0x38, 0x26,
+     0xD, 0xFE, 0x35,  0x0, 0x66, 0xB8, 0x96, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x25,
+    0xFF, 0x1B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x15, 0xFF, 0x23,  0x0,  0x0,  0x0, 0x51, 0x9B, 0xAE,
+    0x7F,  0x0,  0x0,  0x0, 0xC8, 0x4C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xE6, 0x51,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7B, 0xAA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x32, 0xFF, 0x38,  0x0,  0x3, 0xE6, 0x81,  0x0,  0x0, 0x4F, 0xF9,  0x8, 0x16, 0xFA,
+    0x5F,  0x0, 0xB3, 0x9E,  0x0,  0x0,  0x0,  0x0,  0xF, 0xDC, 0xBD, 0xFA, 0x3E,  0x0,  0x0,  0x0,
+     0x0, 0x14, 0xF8, 0x60,  0x0,  0x1, 0xDD, 0x8E,  0x0,  0x0,  0x0,  0x0,  0x0, 0x28, 0xF1, 0x7D,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x46, 0xFF, 0x22,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xD4, 0x8D,  0x0,  0x0,  0x0,  0x0, 0x36, 0xEF,  0x0,  0x0, 0x3A, 0xF4, 0xF5, 0xA5,  0xF,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0, 0x15, 0xFF, 0x4D,  0x0,
+     0x2, 0x7D,  0x1, 0x60, 0x20,  0x0, 0x39, 0xB5, 0xEF, 0xC0, 0xD3, 0xDC, 0xB5, 0x27,  0x0, 0x87,
+    0x9A, 0xA3, 0x3D, 0x65,  0x0,  0x0, 0x5F, 0xAE, 0xA4, 0x81,  0x3, 0x47, 0x9C,  0x0,  0x0,  0x0,
+    0x35, 0xF3, 0x24,  0x0,  0x0,  0x0,  0x0,  0x0, 0x71,  0xF,  0x0,  0x0, 0xC5, 0x4E,  0x0,  0x0,
+     0x0, 0x12, 0xF1,  0xC,  0x0,  0x6, 0x5D, 0xC8, 0xF9, 0xD6, 0x74, 0x10,  0x0, 0x5A, 0xBA, 0xBA,
+    0xF6, 0xC2, 0xBA, 0x87,  0x0,  0x0, 0x74, 0x5C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4E, 0xCE,  0x1,  0x0,  0x0,  0x5, 0xF6,  0xB, 0xD3,
+    0x15, 0xE6, 0x5B,  0x0,  0x7,  0xD, 0x91, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4A,
+    0xEA,  0x2,  0x0,  0x0,  0x0, 0x1B, 0x3E, 0xA2, 0xC4,  0x5,  0x0,  0x0,  0xE, 0xD2, 0x14, 0xAE,
+    0x7F,  0x0,  0x0,  0x0, 0xC8, 0xCA, 0xC0, 0x8C, 0x12,  0x0,  0x0, 0x17, 0xFF, 0x5F, 0xB2, 0x87,
+    0x21,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xDE, 0x49,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xD6, 0x90,  0x0, 0x42, 0xFF, 0x26,  0x0,  0x0, 0x14, 0xFE, 0x3A, 0x5B, 0xE1,
+    0xA7,  0x0, 0xE9, 0x62,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF, 0x9B,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA8, 0xC1,  0x0, 0x3E, 0xFF, 0x2C,  0x0,  0x0,  0x0,  0x0,  0xE, 0xD7, 0xAF,  0x1,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xFF, 0x1C,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xCD, 0x96,  0x0,  0x0,  0x0,  0x0, 0x18, 0x3A,  0x0,  0x0,  0x0, 0x19, 0x32,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0, 0xF1, 0x2E,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0xE6,  0x7, 0x91, 0x5E,  0x0,  0x0,  0x0, 0xC4,
+    0x4C, 0x94, 0x1F,  0x0,  0x0,  0x0,  0x0, 0x36, 0x65, 0x85, 0xA3, 0x60,  0xC,  0x0,  0x0, 0x10,
+    0xC9, 0x9A, 0xCC,  0x7,  0x0, 0x6B,  0x5,  0x0,  0x0,  0x0,  0x0,  0x3, 0xF7, 0x22,  0x0,  0x0,
+     0x0,  0x0, 0xE3, 0x38,  0x0,  0x0,  0x0, 0x75, 0xC3, 0xAB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xDF, 0x1C,  0x0,  0x0,  0x0, 0x5A, 0x9F,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC6, 0x58,  0x0,  0x0,  0x0, 0x2A, 0xFD,  0x1, 0xF4,
+    0x32, 0xC5, 0x6F,  0x0,  0x0,  0x0, 0x91, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE, 0xD8,
+    0x5F,  0x0,  0x0,  0x0,  0x0, 0x7D, 0xE0, 0xEA, 0x2F,  0x0,  0x0,  0x0, 0x95, 0x69,  0x0, 0xAE,
+    0x7F,  0x0,  0x0,  0x0, 0x61, 0x42, 0x48, 0xBC, 0xAA,  0x0,  0x0, 0x30, 0xF9, 0xAF, 0x31, 0x67,
+    0xE8,  0x3,  0x0,  0x0,  0x0,  0x0, 0x45, 0xE4,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x7B, 0xE6,  0x3, 0x9B, 0xCA,  0x0,  0x0,  0x0,  0x0, 0xD6, 0x71, 0xA2, 0x5E,
+    0xE5, 0x25, 0xFF, 0x26,  0x0,  0x0,  0x0,  0x0,  0x5, 0xC5, 0xE3, 0xF2, 0x26,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x44, 0xFE, 0x24, 0x9D, 0xC7,  0x0,  0x0,  0x0,  0x0,  0x1, 0xB0, 0xD6,  0xD,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x60, 0xFF, 0x11,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xC8, 0xA0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0, 0xCF, 0x10,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x53, 0x6F, 0xD9, 0x54, 0xD9, 0x6F, 0x2E,  0x0,  0x0, 0x5E,
+    0xE5, 0xD6, 0x50,  0x0,  0x0,  0x0, 0x53, 0xA1, 0x7E, 0x2C, 0x90, 0x9A, 0x29,  0x0,  0x0, 0x7E,
+    0x91,  0x0, 0xA8, 0x92,  0x0, 0xE0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x24, 0xF9,  0x3,  0x0,  0x0,
+     0x0,  0x0, 0xBE, 0x62,  0x0,  0x0, 0x25, 0xBF,  0x6, 0x98, 0x52,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xDF, 0x1C,  0x0,  0x0,  0x0, 0x12,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3E, 0xDC,  0x3,  0x0,  0x0,  0x0,  0xC, 0xFC, 0x1B, 0xE1,
+    0x23, 0xDC, 0x61,  0x0,  0x0,  0x0, 0x91, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x7, 0xBC, 0x88,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xFA, 0x26,  0x0, 0x36, 0xD2, 0x11,  0xE, 0xB3,
+    0x86,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0, 0x11, 0xF5, 0x10,  0x0, 0x27, 0xFF, 0x2F,  0x0,  0x0,
+    0xE2, 0x3E,  0x0,  0x0,  0x0,  0x0, 0xA9, 0x87,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x22, 0xFE, 0x47, 0xED, 0x70,  0x0,  0x0,  0x0,  0x0, 0x9B, 0xA9, 0xE2,  0xE,
+    0xB9, 0x90, 0xE9,  0x1,  0x0,  0x0,  0x0,  0x0, 0x8A, 0xE8, 0x18, 0xB1, 0xD0,  0x9,  0x0,  0x0,
+     0x0,  0x0,  0x2, 0xDD, 0x8E, 0xF2, 0x64,  0x0,  0x0,  0x0,  0x0, 0x7E, 0xF0, 0x26,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x6, 0xAA, 0xE0,  0x0,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xA8, 0xE2, 0x15,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x8F, 0xCB, 0xBE, 0xA1, 0xE8, 0x91, 0x4F,  0x0,  0x0,  0x0,
+    0x32, 0xC1, 0xCD, 0xCF,  0xB,  0x0, 0x26,  0x1,  0x0, 0x89, 0x73, 0x32, 0xD7,  0x0,  0x0, 0x9F,
+    0x7E,  0x0,  0xF, 0xDC, 0x75, 0xBF,  0x0,  0x0,  0x0,  0x0,  0x0, 0x35, 0xED,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xAA, 0x72,  0x0,  0x0, 0x2C, 0x1F,  0x0,  0x8, 0x43,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x4B,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB5, 0x68,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD2, 0x42, 0x50,
+     0x6, 0xFC, 0x44,  0x0,  0x0,  0x0, 0x91, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x3, 0xAD, 0x97,  0x1,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xDE, 0x58,  0x0, 0x5D, 0xE8, 0xE8, 0xE8, 0xF8,
+    0xF3, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x0,  0xF, 0xF9, 0x25,  0x0,  0xE, 0xFD, 0x1F,  0x0,  0x0,
+    0xCC, 0x58,  0x0,  0x0,  0x0, 0x15, 0xF8, 0x27,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xC5, 0xDB, 0xFC, 0x18,  0x0,  0x0,  0x0,  0x0, 0x5F, 0xF6, 0xBD,  0x0,
+    0x6F, 0xF6, 0xAE,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xFC, 0x47,  0x0, 0x13, 0xE3, 0x9A,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x7B, 0xFD, 0xF3,  0xD,  0x0,  0x0,  0x0, 0x45, 0xFD, 0x6E, 0x22, 0x22, 0x22,
+    0x1D,  0x0,  0x8, 0xC7, 0xF2, 0xE5, 0x37,  0x0,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0x1E, 0xBE, 0xF3, 0xC0, 0x47,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0xE, 0x8F, 0x2A,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB4, 0x3B, 0x53, 0x9C,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x94, 0x1F, 0xB5, 0x64,  0x0,  0x0,  0x0,  0x0, 0x8D, 0x41,  0x7, 0xDB,  0x3,  0x0, 0x64,
+    0xDF, 0x10,  0x0, 0x4A, 0xFF, 0x54,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE, 0xFE, 0x11,  0x0,  0x0,
+     0x0,  0x0, 0xBF, 0x4B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x2E, 0xE7,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9C, 0xA5,  0x9,
+    0x6E, 0xEA,  0x6,  0x0,  0x0,  0x0, 0x91, 0x9A,  0x0,  0x0,  0x0,  0x0, 0x9F, 0x9B,  0x1,  0x0,
+     0x0,  0x0,  0x0,  0xE, 0x30,  0x0,  0x2, 0x4B, 0xF4, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAE,
+    0x7F,  0x0,  0x0,  0xF, 0x26,  0x0,  0xA, 0x81, 0xC7,  0x0,  0x0,  0x0, 0xB8, 0x71,  0x0, 0x24,
+    0xF6,  0xF,  0x0,  0x0,  0x0, 0x73, 0xC4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x6A, 0xFF, 0xB9,  0x0,  0x0,  0x0,  0x0,  0x0, 0x23, 0xFF, 0x73,  0x0,
+    0x25, 0xFF, 0x72,  0x0,  0x0,  0x0, 0x1F, 0xED, 0x8B,  0x0,  0x0,  0x0, 0x3F, 0xFC, 0x5C,  0x0,
+     0x0,  0x0,  0x0, 0x1A, 0xFD, 0xA1,  0x0,  0x0,  0x0,  0x0, 0x77, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xD9,  0x0,  0x3, 0x4E, 0x80, 0xE6, 0x97,  0x0,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0x48, 0xEF, 0xC2, 0x7A, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0, 0x3B, 0xFF, 0x77,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0xE5,  0x5, 0x94, 0x5C,  0x0,  0x0,  0x0,  0x0, 0x52,
+     0x9, 0x94, 0x2B, 0xD1, 0x3B,  0x0,  0x0,  0x0,  0x0, 0x21, 0xB5, 0xC5, 0x59,  0x0,  0x0,  0x2,
+    0x95, 0xEB, 0xC5, 0xDB, 0xA2, 0xC7,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE1, 0x37,  0x0,  0x0,
+     0x0,  0x2, 0xEE, 0x20,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xA5, 0x79,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x14, 0x90, 0xE5,
+    0xB1, 0x2A,  0x0,  0x0, 0x6A, 0xFF, 0xFF, 0xFF, 0xFF, 0x6C,  0x0, 0x19, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0x38,  0x0, 0x18, 0xE9, 0xE5, 0xE9, 0xF5, 0x6E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAE,
+    0x7F,  0x0,  0x0, 0x22, 0xF5, 0xE5, 0xF3, 0xE4, 0x42,  0x0,  0x0,  0x0, 0x27, 0xDA, 0xD3, 0xD9,
+    0x6C,  0x0,  0x0,  0x0,  0x0, 0xD6, 0x64,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x41, 0xFF, 0x40,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x82, 0xF4,  0x3,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xA5, 0xC8,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x2, 0x3E,  0xA,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x88,
+    0xD8, 0xF0, 0xDC, 0x77,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xC, 0x24,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x84, 0x88,  0x0,  0x0,
+     0x0, 0x4A, 0xC2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x21, 0xED, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x1, 0x1C, 0x23,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x6, 0x23, 0x1C,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A,  0x1,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x13, 0xC3, 0xD2,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x50, 0xFF, 0x1B,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xCA, 0x99,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xC1, 0x4C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x14, 0xE1,  0xA,  0x0,
+     0x0, 0xB8, 0x46,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0xB, 0x16,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x2, 0xE4, 0x87,  0x0,  0x0,  0x0, 0x38, 0xFF, 0x37,  0x0, 0xC6, 0x94,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0x45, 0xFE, 0x16,  0x0,  0x0, 0x6A, 0xF4, 0x27,  0x0,  0x6, 0xCB, 0xB7,  0x2,  0x0,
-     0x0, 0xD6, 0x9E,  0x0,  0x0,  0x0, 0x21, 0xFE, 0x55,  0x0, 0x2F, 0xA3, 0xA3, 0xA3, 0xA3, 0xE9,
-    0xD5,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAC, 0xF9, 0xCB, 0x3E,  0x0, 0xFF, 0x4D,  0x0,  0x6, 0xD1,
-    0xF9, 0xD5,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7A, 0xEE, 0xF4, 0x90,  0x0,  0x0, 0x9D, 0x9C,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x93, 0xFB, 0xEB, 0x2F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xFF, 0x1C,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xCD, 0x96,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x94, 0x3A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x61, 0x2E,  0x0,
+     0xB, 0x83,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -946,13 +1360,11 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x22, 0x2F,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x30, 0xFF, 0x35,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
+     0x0, 0xE1, 0x7E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0xB2, 0x21, 0x21, 0x21, 0x21, 0x21, 0x6D, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0x8C, 0xDE,  0x1,  0x0,  0x0, 0x90, 0xDB,  0x0,  0x0, 0x8A, 0xCB,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0x7C, 0xD9,  0x0,  0x0,  0x0,  0x0, 0xAA, 0xCF,  0x8, 0x8B, 0xE4, 0x15,  0x0,  0x0,
-     0x0, 0x72, 0xF3,  0xC,  0x0,  0x0, 0x7F, 0xEB,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4E, 0xFC,
-    0x4C,  0x0,  0x0,  0x0,  0x0,  0xE, 0xFA, 0x55,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-    0x14, 0xFC, 0x4E,  0x0,  0x0,  0x0,  0x0,  0x8, 0xFA, 0x30, 0x24, 0xEC, 0x72, 0x11, 0xD6, 0x6C,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -963,14 +1375,11 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0x32, 0xFF, 0x38,  0x0,  0x3, 0xE6, 0x81,  0x0,  0x0, 0x4F, 0xF9,  0x8, 0x16, 0xFA,
-    0x5F,  0x0, 0xB3, 0x9E,  0x0,  0x0,  0x0,  0x0,  0xF, 0xDC, 0xBD, 0xFA, 0x3E,  0x0,  0x0,  0x0,
-     0x0, 0x14, 0xF8, 0x60,  0x0,  0x1, 0xDD, 0x8E,  0x0,  0x0,  0x0,  0x0,  0x0, 0x28, 0xF1, 0x7D,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x46, 0xFF, 0x22,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xD4, 0x8D,  0x0,  0x0,  0x0,  0x0, 0x36, 0xEF,  0x0,  0x0, 0x3A, 0xF4, 0xF5, 0xA5,  0xF,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0xE5, 0xA6, 0x41,  0xF,  0x0, 0xFF, 0x4D,  0x0,  0x1, 0x24,
+    0x62, 0xFF, 0x37,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x28, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0x61,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -979,28 +1388,18 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0, 0xD6, 0x90,  0x0, 0x42, 0xFF, 0x26,  0x0,  0x0, 0x14, 0xFE, 0x3A, 0x5B, 0xE1,
-    0xA7,  0x0, 0xE9, 0x62,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF, 0x9B,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0xA8, 0xC1,  0x0, 0x3E, 0xFF, 0x2C,  0x0,  0x0,  0x0,  0x0,  0xE, 0xD7, 0xAF,  0x1,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xFF, 0x1C,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xCD, 0x96,  0x0,  0x0,  0x0,  0x0, 0x18, 0x3A,  0x0,  0x0,  0x0, 0x19, 0x32,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0x9B, 0xD3, 0x4F,  0x0, 0xFF, 0x4D,  0x0,  0x7, 0xF1,
+    0xBD, 0x84,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0, 0x7B, 0xE6,  0x3, 0x9B, 0xCA,  0x0,  0x0,  0x0,  0x0, 0xD6, 0x71, 0xA2, 0x5E,
-    0xE5, 0x25, 0xFF, 0x26,  0x0,  0x0,  0x0,  0x0,  0x5, 0xC5, 0xE3, 0xF2, 0x26,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0x44, 0xFE, 0x24, 0x9D, 0xC7,  0x0,  0x0,  0x0,  0x0,  0x1, 0xB0, 0xD6,  0xD,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x60, 0xFF, 0x11,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xC8, 0xA0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1010,13 +1409,8 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA5, 0x32,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0, 0x22, 0xFE, 0x47, 0xED, 0x70,  0x0,  0x0,  0x0,  0x0, 0x9B, 0xA9, 0xE2,  0xE,
-    0xB9, 0x90, 0xE9,  0x1,  0x0,  0x0,  0x0,  0x0, 0x8A, 0xE8, 0x18, 0xB1, 0xD0,  0x9,  0x0,  0x0,
-     0x0,  0x0,  0x2, 0xDD, 0x8E, 0xF2, 0x64,  0x0,  0x0,  0x0,  0x0, 0x7E, 0xF0, 0x26,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x6, 0xAA, 0xE0,  0x0,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xA8, 0xE2, 0x15,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1027,12 +1421,6 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0, 0xC5, 0xDB, 0xFC, 0x18,  0x0,  0x0,  0x0,  0x0, 0x5F, 0xF6, 0xBD,  0x0,
-    0x6F, 0xF6, 0xAE,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xFC, 0x47,  0x0, 0x13, 0xE3, 0x9A,  0x0,  0x0,
-     0x0,  0x0,  0x0, 0x7B, 0xFD, 0xF3,  0xD,  0x0,  0x0,  0x0, 0x45, 0xFD, 0x6E, 0x22, 0x22, 0x22,
-    0x1D,  0x0,  0x8, 0xC7, 0xF2, 0xE5, 0x37,  0x0,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0x1E, 0xBE, 0xF3, 0xC0, 0x47,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1043,12 +1431,6 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0, 0x6A, 0xFF, 0xB9,  0x0,  0x0,  0x0,  0x0,  0x0, 0x23, 0xFF, 0x73,  0x0,
-    0x25, 0xFF, 0x72,  0x0,  0x0,  0x0, 0x1F, 0xED, 0x8B,  0x0,  0x0,  0x0, 0x3F, 0xFC, 0x5C,  0x0,
-     0x0,  0x0,  0x0, 0x1A, 0xFD, 0xA1,  0x0,  0x0,  0x0,  0x0, 0x77, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-    0xD9,  0x0,  0x3, 0x4E, 0x80, 0xE6, 0x97,  0x0,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0x48, 0xEF, 0xC2, 0x7A, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1056,33 +1438,179 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x26, 0xB6, 0xDF, 0xA5, 0x3F,  0x0,  0x0,  0x0, 0x25, 0x8C, 0xD9, 0xB2, 0x1F,  0x0,
+     0x0,  0x6, 0x6E, 0x1E,  0x0,  0x0, 0x1C,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3A, 0x69,
+     0x0, 0x39, 0x76, 0x76, 0x76, 0x76, 0x76, 0x55,  0x0, 0x4C, 0x52,  0x5,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x3F, 0xC1, 0xED, 0xD6, 0x49,  0x0,  0x0,  0x0,  0x0,  0x0,  0xF, 0x14,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x2A, 0xC4, 0x5A,  0x0,  0x0,  0x0,  0x0,  0x5, 0xC4, 0xC4, 0xBD, 0x81, 0x2F,  0x0,
+     0x0,  0x0,  0x0, 0x4E, 0xAD, 0xE2, 0xBE, 0x27,  0x0, 0x26, 0xC4, 0xC0, 0xA0, 0x5C,  0x1,  0x0,
+     0x0, 0xA5, 0xC4, 0xC4, 0xC4, 0xC4, 0x46,  0x0, 0x7C, 0xC4, 0xC4, 0xC4, 0xC4, 0x68,  0x0,  0x0,
+     0x0, 0x2E, 0xA2, 0xDE, 0x9F, 0x10,  0x0, 0x26, 0xC3,  0x0,  0x0,  0x0, 0x94, 0x55,  0x0, 0xA0,
+    0xC4, 0xC4, 0xC4, 0xC4,  0xA,  0x0,  0x0,  0x0, 0x9E, 0xC4, 0xC4, 0x78,  0x0, 0x26, 0xC3,  0x0,
+     0x0,  0x0, 0x82, 0x8D,  0x1,  0x0, 0x90, 0x59,  0x0,  0x0,  0x0,  0x0,  0x0, 0x61, 0xC4, 0x2B,
+     0x0,  0x6, 0xBD, 0x8E,  0x0, 0x24, 0xC4, 0x5D,  0x0,  0x0, 0x8D, 0x53,  0x0,  0x0,  0x2, 0x5A,
+    0xCF, 0xBC, 0x2C,  0x0,  0x0, 0xC2, 0xC4, 0xBD, 0x7D, 0x2E,  0x0,  0x0,  0x0,  0x2, 0x5A, 0xCF,
+    0xBC, 0x2C,  0x0,  0x0,  0x0, 0x1F, 0xC4, 0xC4, 0xB0, 0x68, 0x19,  0x0,  0x0,  0x0,  0x0, 0x25,
+    0xB0, 0xE1, 0xD6, 0x95,  0x0,  0x0, 0x8E, 0xC4, 0xC4, 0xC4, 0xC4, 0xC4, 0xBD,  0x0, 0x1B, 0xC4,
+     0xB,  0x0,  0x0, 0x9F, 0x49,  0x0, 0x6C, 0x82,  0x0,  0x0,  0x0, 0x53, 0x9B,  0x0, 0xB9, 0x28,
+     0x0,  0x0,  0x0,  0x0, 0xBC, 0x24,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xAA, 0x98, 0x15, 0x64, 0xE3,  0x5,  0x0,  0x6, 0xDF, 0x77,  0xD, 0x79, 0xD1,  0x0,
+     0x0, 0x36, 0xFF, 0x7E,  0x0, 0x45, 0xFF, 0x70,  0x0,  0x0,  0x0, 0x13, 0x70, 0xD4, 0xD0, 0x5B,
+     0x0, 0x42, 0x89, 0x89, 0x89, 0x89, 0x89, 0x63,  0x0, 0x37, 0xB9, 0xE5, 0x88, 0x24,  0x0,  0x0,
+     0x0, 0x59, 0x3C,  0xE, 0x6E, 0xD9,  0x2,  0x0,  0x0, 0x1C, 0xBC, 0xC6, 0xC4, 0xAD, 0x1E,  0x0,
+     0x0,  0x0, 0x7C, 0xDA, 0xBA,  0x0,  0x0,  0x0,  0x0,  0x6, 0xFF, 0x57, 0x3B, 0x7A, 0xE9,  0x8,
+     0x0,  0x0, 0x3F, 0xEB, 0x7A, 0x1C, 0x54, 0x34,  0x0, 0x32, 0xFE, 0x38, 0x59, 0xD1, 0x9A,  0x0,
+     0x0, 0xD7, 0x7E, 0x3B, 0x3B, 0x3B, 0x15,  0x0, 0xA1, 0xA9, 0x3B, 0x3B, 0x3B, 0x1F,  0x0,  0x0,
+    0x76, 0xDD, 0x5F, 0x17, 0x6E, 0x28,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xC1, 0x6F,  0x0, 0x30,
+    0x3B, 0xFB, 0x64, 0x3B,  0x3,  0x0,  0x0,  0x0, 0x30, 0x3B, 0xAB, 0x9D,  0x0, 0x32, 0xFE,  0x0,
+     0x0, 0x80, 0xD0, 0x12,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0xE7, 0x85,
+     0x0, 0x4D, 0xE7, 0xB9,  0x0, 0x2F, 0xFD, 0xD5,  0x1,  0x0, 0xB8, 0x6C,  0x0,  0x0, 0x9C, 0xC2,
+    0x34, 0x9A, 0xE0,  0x0,  0x0, 0xFC, 0x5D, 0x3D, 0x8A, 0xFC, 0x34,  0x0,  0x0, 0x9C, 0xC2, 0x34,
+    0x9A, 0xE0,  0x0,  0x0,  0x0, 0x29, 0xFF, 0x3B, 0x3E, 0xA7, 0xC0,  0x0,  0x0,  0x0,  0x0, 0xB1,
+    0x8F, 0x21, 0x28, 0x75,  0x0,  0x0, 0x2B, 0x3B, 0x3B, 0xF9, 0x66, 0x3B, 0x39,  0x0, 0x23, 0xFF,
+     0xE,  0x0,  0x0, 0xD0, 0x5F,  0x0, 0x4B, 0xE4,  0x0,  0x0,  0x0, 0xA8, 0x88,  0x0, 0xCF, 0x4E,
+     0x0,  0x0,  0x0, 0x11, 0xFF,  0xE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x1, 0xF7, 0x3E,  0x0,  0x6, 0xFA, 0x2B,  0x0, 0x2D, 0xFD, 0x12,  0x0,  0xE, 0xFA, 0x31,
+     0x0,  0x3, 0x55, 0x14,  0x0, 0x23, 0xC3, 0x40,  0x0, 0x2C, 0xA5, 0xE3, 0x91, 0x31,  0x0,  0x0,
+     0x0,  0xA, 0x14, 0x14, 0x14, 0x14, 0x14,  0xE,  0x0,  0x0,  0x0, 0x1D, 0x7A, 0xD8, 0xBD, 0x4A,
+     0x0,  0x0,  0x0,  0x0, 0x31, 0xF8,  0x8,  0x0,  0x8, 0xD6, 0x44,  0x0,  0x0, 0x60, 0x82,  0x0,
+     0x0,  0x0, 0xCA, 0x56, 0xF5,  0xF,  0x0,  0x0,  0x0,  0x6, 0xFF, 0x2B,  0x0,  0x0, 0xEB, 0x4E,
+     0x0,  0x0, 0xCD, 0x82,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE,  0x0,  0x0, 0x38, 0xFD, 0x28,
+     0x0, 0xD7, 0x57,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA1, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xCE, 0x4C,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xC1, 0x6F,  0x0,  0x0,
+     0x0, 0xFA, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0x9D,  0x0, 0x32, 0xFE,  0x0,
+    0x74, 0xD8, 0x17,  0x0,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0xA1, 0xCE,
+     0x0, 0xA4, 0x94, 0xB9,  0x0, 0x2F, 0xF5, 0xBE, 0x40,  0x0, 0xB8, 0x6C,  0x0,  0x0, 0xDC, 0x3C,
+     0x0,  0x3, 0xF9, 0x1B,  0x0, 0xFC, 0x32,  0x0,  0x0, 0xD3, 0x7D,  0x0,  0x0, 0xDC, 0x3C,  0x0,
+     0x3, 0xF9, 0x1B,  0x0,  0x0, 0x29, 0xFF,  0x8,  0x0, 0x1C, 0xFE, 0x26,  0x0,  0x0, 0x15, 0xFA,
+     0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0, 0x23, 0xFF,
+     0xE,  0x0,  0x0, 0xD0, 0x5F,  0x0,  0xA, 0xF6, 0x29,  0x0,  0x1, 0xEA, 0x3E,  0x0, 0xA9, 0x6C,
+     0x4, 0x5C, 0x19, 0x2F, 0xE6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x9F, 0xA9, 0x30, 0x7C, 0xC9,  0x1,  0x0, 0x1E, 0xFA,  0xC,  0x0,  0xA, 0xF7, 0x55,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x64, 0xF5, 0x80, 0x1D,  0x0,  0x0,  0x0,
+     0x0, 0x73, 0xEE, 0xEE, 0xEE, 0xEE, 0xEE, 0xAC,  0x0,  0x0,  0x0,  0x0,  0xE, 0x64, 0xE6, 0x9F,
+     0x0,  0x0,  0x0, 0x15, 0xD4, 0x72,  0x0,  0x0, 0x6C, 0xAE,  0x0, 0x58, 0xAB, 0x73, 0xCE,  0x0,
+     0x0, 0x1B, 0xF5,  0x9, 0xC3, 0x57,  0x0,  0x0,  0x0,  0x6, 0xFF, 0x4A, 0x2D, 0x75, 0xF2, 0x20,
+     0x0,  0x4, 0xF8, 0x4B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x7, 0xF8, 0x5B,
+     0x0, 0xD7, 0x75, 0x2C, 0x2C, 0x2C,  0x8,  0x0, 0xA1, 0x97, 0x11, 0x11, 0x11,  0x3,  0x0, 0x17,
+    0xFD, 0x15,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE, 0x2C, 0x2C, 0x2C, 0xCC, 0x6F,  0x0,  0x0,
+     0x0, 0xFA, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0x9D,  0x0, 0x32, 0xFE, 0x67,
+    0xE5, 0x1C,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0x98, 0xA3,
+    0x3C, 0xD0, 0x60, 0xB9,  0x0, 0x2F, 0xF5, 0x55, 0xA8,  0x0, 0xB8, 0x6C,  0x0, 0x13, 0xFE, 0x17,
+     0x0,  0x0, 0xD8, 0x51,  0x0, 0xFC, 0x32,  0x0,  0x5, 0xE8, 0x4E,  0x0, 0x13, 0xFE, 0x17,  0x0,
+     0x0, 0xD8, 0x51,  0x0,  0x0, 0x29, 0xFF,  0x8,  0x0, 0x40, 0xFD, 0x20,  0x0,  0x0,  0x1, 0xDE,
+    0x93, 0x22,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0, 0x23, 0xFF,
+     0xE,  0x0,  0x0, 0xD0, 0x5F,  0x0,  0x0, 0xB5, 0x6D,  0x0, 0x30, 0xEE,  0x4,  0x0, 0x83, 0x8A,
+    0x30, 0xFC, 0x6B, 0x4D, 0xC0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x19, 0xD4, 0xEC, 0xED, 0x32,  0x0,  0x0,  0x0, 0xCB, 0x70,  0x3, 0x60, 0xFD, 0x66,
+     0x0,  0x1, 0x3A,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1E, 0x80, 0xE0, 0xBB, 0x5C,  0xA,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0x45, 0xA4, 0xE7, 0x98, 0x33,  0x0,
+     0x0,  0x0,  0x0, 0xBB, 0x8B,  0x0,  0x0,  0x0, 0xAB, 0x65, 0x40, 0xD4, 0x43, 0x8D, 0xEA,  0x0,
+     0x0, 0x67, 0xB7,  0x0, 0x7B, 0xA5,  0x0,  0x0,  0x0,  0x6, 0xFF, 0xD7, 0xD6, 0xFA, 0x6E,  0x1,
+     0x0, 0x21, 0xFF, 0x1D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xD0, 0x76,
+     0x0, 0xD7, 0xE2, 0xD2, 0xD2, 0xD2, 0x27,  0x0, 0xA1, 0xF7, 0xED, 0xED, 0xED, 0x26,  0x0, 0x53,
+    0xE2,  0x0,  0x0, 0x75, 0x7F, 0x3D,  0x0, 0x32, 0xFF, 0xD2, 0xD2, 0xD2, 0xF4, 0x6F,  0x0,  0x0,
+     0x0, 0xFA, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0x9D,  0x0, 0x32, 0xFF, 0xE3,
+    0xF3, 0x2C,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0x98, 0x4D,
+    0xD5, 0x87, 0x5F, 0xB9,  0x0, 0x2F, 0xF5,  0x5, 0xE1, 0x17, 0xB8, 0x6C,  0x0, 0x41, 0xF6,  0x0,
+     0x0,  0x0, 0xB8, 0x7F,  0x0, 0xFC, 0x98, 0x83, 0xBE, 0xDA,  0x4,  0x0, 0x41, 0xF6,  0x0,  0x0,
+     0x0, 0xB8, 0x80,  0x0,  0x0, 0x29, 0xFF, 0xB0, 0xBF, 0xD8, 0x51,  0x0,  0x0,  0x0,  0x0, 0x3E,
+    0xC9, 0xFE, 0xC4, 0x43,  0x0,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0, 0x23, 0xFF,
+     0xE,  0x0,  0x0, 0xD0, 0x5F,  0x0,  0x0, 0x6A, 0xB0,  0x0, 0x74, 0xA8,  0x0,  0x0, 0x5C, 0xA8,
+    0x66, 0xAC, 0xA1, 0x6B, 0x9A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xDC, 0x68,  0x1, 0x2C, 0xED, 0x20,  0x0,  0x0, 0x38, 0xB1, 0xE8, 0xAE, 0xDC, 0x53,
+     0x0, 0x34, 0xFF, 0x7C,  0x0, 0x15, 0xB1, 0x40,  0x0,  0x0,  0x0,  0x0,  0x3, 0x4B, 0xAF, 0xB1,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x78, 0xC8, 0x63,  0xC,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x11, 0xFF,  0xB,  0x0,  0x0,  0x0, 0xCB, 0x20, 0x87, 0x6F,  0x0,  0x9, 0xE3,  0x0,
+     0x0, 0xB5, 0x98, 0x3C, 0x6B, 0xEE,  0x5,  0x0,  0x0,  0x6, 0xFF, 0x2B,  0x0, 0x14, 0xDE, 0x55,
+     0x0,  0x3, 0xED, 0x3E,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x2, 0xF1, 0x63,
+     0x0, 0xD7, 0x57,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA1, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0, 0x29,
+    0xE9,  0x0,  0x0, 0x70, 0xD0, 0x7B,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xC1, 0x6F,  0x0,  0x0,
+     0x0, 0xFA, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0x9D,  0x0, 0x32, 0xFF, 0x26,
+    0x84, 0xCD,  0x5,  0x0,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0x98,  0x7,
+    0xD5, 0x2F, 0x5F, 0xB9,  0x0, 0x2F, 0xF5,  0x0, 0x83, 0x7A, 0xB8, 0x6C,  0x0, 0x21, 0xFF,  0xE,
+     0x0,  0x0, 0xCF, 0x5E,  0x0, 0xFC, 0x94, 0x73, 0x33,  0x1,  0x0,  0x0, 0x20, 0xFF,  0xE,  0x0,
+     0x0, 0xCF, 0x6D,  0x0,  0x0, 0x29, 0xFF, 0x51, 0x5B, 0xD7, 0x5C,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x15, 0x69, 0xF9, 0x17,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0, 0x22, 0xFF,
+     0xE,  0x0,  0x0, 0xD0, 0x5E,  0x0,  0x0, 0x20, 0xF0,  0x4, 0xB7, 0x5D,  0x0,  0x0, 0x36, 0xC6,
+    0x9C, 0x40, 0xCF, 0x89, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x24, 0xFE,  0xD,  0x0,  0x0, 0xC7, 0x65,  0x0,  0x0,  0x0,  0x0,  0x1, 0x11, 0xFD, 0x30,
+     0x0,  0xA, 0x89, 0x2A,  0x0, 0x2E, 0xF9, 0xAE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xA,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x6, 0x3A,  0x1,  0x0,  0x0,  0x0, 0xBC, 0x42, 0x76, 0x8C,  0x0, 0x1F, 0xEA,  0x0,
+     0xC, 0xF7, 0xB9, 0xB6, 0xB6, 0xF1, 0x42,  0x0,  0x0,  0x6, 0xFF, 0x2B,  0x0,  0x0, 0x9B, 0x95,
+     0x0,  0x0, 0xAA, 0x75,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFE,  0x0,  0x0, 0x2D, 0xFF, 0x3A,
+     0x0, 0xD7, 0x57,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA1, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xE0, 0x19,  0x0,  0x0, 0xA6, 0x7B,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xC1, 0x6F,  0x0,  0x0,
+     0x0, 0xFA, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA5, 0x8E,  0x0, 0x32, 0xFE,  0x0,
+     0x7, 0xD6, 0x80,  0x0,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0x98,  0x0,
+     0x0,  0x0, 0x5F, 0xB9,  0x0, 0x2F, 0xF5,  0x0, 0x1E, 0xDD, 0xBB, 0x6C,  0x0,  0x0, 0xEA, 0x32,
+     0x0,  0x1, 0xF3, 0x28,  0x0, 0xFC, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE9, 0x32,  0x0,
+     0x1, 0xF3, 0x48,  0x0,  0x0, 0x29, 0xFF,  0x8,  0x0, 0x3D, 0xEC,  0xF,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xC8, 0x5F,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0, 0x1A, 0xFF,
+    0x11,  0x0,  0x0, 0xD3, 0x56,  0x0,  0x0,  0x0, 0xD4, 0x3F, 0xF2, 0x15,  0x0,  0x0, 0x10, 0xE4,
+    0xCB,  0x7, 0xC1, 0xB7, 0x4E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x3, 0xE0, 0x59,  0x0, 0x1F, 0xFA, 0x4B,  0x0,  0x0, 0x17,  0x0, 0x18, 0x9B, 0xCC,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4F, 0x8C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x10, 0x77,  0xC,  0x0,  0x0,  0x0, 0x95, 0x90, 0x17, 0xCC, 0xA8, 0xCB, 0xE2,  0x0,
+    0x53, 0xDC,  0x0,  0x0,  0x0, 0xA0, 0x90,  0x0,  0x0,  0x6, 0xFF, 0x2B,  0x3, 0x34, 0xE3, 0x48,
+     0x0,  0x0, 0x63, 0xD6, 0x3D,  0x0, 0x1A, 0x22,  0x0, 0x32, 0xFE,  0x6, 0x4E, 0xBA, 0xC3,  0x0,
+     0x0, 0xD7, 0x57,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA1, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x9B, 0xB1, 0x22,  0x0, 0xAB, 0x7B,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xC1, 0x6F,  0x0,  0x0,
+     0x0, 0xFA, 0x36,  0x0,  0x0,  0x0, 0x3A, 0x35,  0x0,  0x5, 0xDC, 0x67,  0x0, 0x32, 0xFE,  0x0,
+     0x0, 0x39, 0xF8, 0x32,  0x0,  0x0, 0xBC, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0x98,  0x0,
+     0x0,  0x0, 0x5F, 0xB9,  0x0, 0x2F, 0xF5,  0x0,  0x0, 0xB1, 0xF4, 0x6C,  0x0,  0x0, 0xB4, 0x96,
+     0x7, 0x5F, 0xF0,  0x2,  0x0, 0xFC, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB3, 0x96,  0x7,
+    0x5F, 0xF8, 0x18,  0x0,  0x0, 0x29, 0xFF,  0x8,  0x0,  0x0, 0xBD, 0x7E,  0x0,  0x0,  0xB, 0x57,
+     0x2,  0x0, 0x24, 0xF7, 0x44,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0,  0x2, 0xEE,
+    0x4D,  0x0, 0x1D, 0xF1, 0x2C,  0x0,  0x0,  0x0, 0x89, 0xBB, 0xC7,  0x0,  0x0,  0x0,  0x0, 0xE7,
+    0xC2,  0x0, 0x88, 0xF9, 0x28,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x55, 0xD1, 0xDA, 0xDE, 0x87,  0x1,  0x0,  0x0, 0x8F, 0xE7, 0xE5, 0x83, 0x15,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xBF, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x46, 0xFF, 0x3A,  0x0,  0x0,  0x0, 0x33, 0xDD,  0x2,  0x1, 0x39, 0x18, 0x2D,  0x0,
+    0xA1, 0x95,  0x0,  0x0,  0x0, 0x58, 0xDE,  0x0,  0x0,  0x6, 0xFF, 0xFA, 0xF5, 0xC1, 0x7B,  0x1,
+     0x0,  0x0,  0x5, 0x93, 0xF9, 0xDC, 0xEE, 0x39,  0x0, 0x32, 0xFF, 0xF8, 0xDB, 0x97, 0x10,  0x0,
+     0x0, 0xD7, 0xFF, 0xFF, 0xFF, 0xFF, 0x78,  0x0, 0xA1, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x15, 0xB9, 0xF8, 0xDA, 0xD8, 0x35,  0x0, 0x32, 0xFE,  0x0,  0x0,  0x0, 0xC1, 0x6F,  0x0, 0xD1,
+    0xFF, 0xFF, 0xFF, 0xFF,  0xC,  0x0, 0x41, 0xEA, 0xE1, 0xE5, 0xC0,  0xA,  0x0, 0x32, 0xFE,  0x0,
+     0x0,  0x0, 0x90, 0xD3,  0x7,  0x0, 0xBC, 0xFF, 0xFF, 0xFF, 0xFF, 0xAF,  0x0, 0x7E, 0x98,  0x0,
+     0x0,  0x0, 0x5F, 0xB9,  0x0, 0x2F, 0xF5,  0x0,  0x0, 0x48, 0xFF, 0x6C,  0x0,  0x0, 0x1E, 0x9A,
+    0xE9, 0xF5, 0x5A,  0x0,  0x0, 0xFC, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1D, 0x98, 0xE8,
+    0xFE, 0x64,  0x0,  0x0,  0x0, 0x29, 0xFF,  0x8,  0x0,  0x0, 0x45, 0xEE,  0xF,  0x0,  0xC, 0xD2,
+    0xF0, 0xDA, 0xDA, 0x82,  0x0,  0x0,  0x0,  0x0,  0x0, 0xF7, 0x39,  0x0,  0x0,  0x0,  0x0, 0x58,
+    0xED, 0xD8, 0xF1, 0x82,  0x0,  0x0,  0x0,  0x0, 0x3F, 0xFF, 0x7C,  0x0,  0x0,  0x0,  0x0, 0xC3,
+    0x8B,  0x0, 0x4E, 0xFB,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x17,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1E,  0xC,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x5D, 0x28,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x1, 0x22,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xB6, 0x2F,  0x6, 0x1E,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x6, 0x22,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0, 0x41, 0xFF, 0x40,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x82, 0xF4,  0x3,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xA5, 0xC8,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0xE, 0x19,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0x1E, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x14,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A,
+    0xC6, 0x89,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x11, 0x1D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x5, 0x23, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x49, 0xA7, 0xD2, 0xC4,  0x9,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0, 0x13, 0xC3, 0xD2,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x50, 0xFF, 0x1B,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xCA, 0x99,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x10, 0xCC, 0x83,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1093,15 +1621,12 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x93, 0xFB, 0xEB, 0x2F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xFF, 0x1C,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xCD, 0x96,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xA7,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x5,  0xE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1109,10 +1634,6 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x22, 0x2F,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0, 0x30, 0xFF, 0x35,  0x0,  0x0,  0x0, 0xFF, 0x4D,  0x0,  0x0,  0x0,
-     0x0, 0xE1, 0x7E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x37, 0xB2, 0x21, 0x21, 0x21, 0x21, 0x21, 0x6D, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1125,15 +1646,176 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x33, 0xB9,  0xB,  0x0,  0x0, 0x6E, 0x88,  0x0,  0x0, 0x6E, 0x87,  0x0,  0x0,  0x0, 0x5A,
+    0x9C,  0x0,  0x0, 0x2C, 0xC4, 0xC4, 0xC4, 0xC4, 0xC4, 0x5B,  0x0, 0x10, 0x9B, 0x9B, 0x9B, 0x57,
+     0x0, 0x11, 0xBC,  0xF,  0x0,  0x0,  0x0,  0x0,  0x0, 0x31, 0x99, 0x99, 0x99, 0x35,  0x0,  0x0,
+     0x0, 0x2B, 0xC2, 0x59,  0x0,  0x0,  0x0, 0x51, 0xB4, 0xB4, 0xB4, 0xB4, 0xB4, 0x7B,  0x0, 0x13,
+    0x93,  0xC,  0x0,  0x0,  0x0, 0x47, 0x9B, 0xB3, 0x90, 0x20,  0x0,  0x0, 0x1E,  0x8,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x42, 0x91, 0xAF, 0x75,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x22,  0x4,  0x0,  0x0,  0x2, 0x60, 0xA4, 0x6E, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xF,
+    0x23,  0x3,  0x0,  0x0,  0x5, 0x7A, 0xAC, 0x5E, 0x66, 0x28,  0x0, 0x1E,  0x8,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x1, 0x22,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8, 0x1A,  0x0, 0x18, 0x10,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x21, 0x23, 0x1F,  0x0,  0x0,  0x0,  0x0, 0x35, 0x61, 0x86, 0x40,
+    0x43, 0x8E, 0x14,  0x0, 0x7E, 0x17, 0x58, 0x92, 0x25,  0x0,  0x0,  0x0,  0x1, 0x41, 0x9F, 0x5C,
+     0x6,  0x0,  0x0, 0x82, 0x1C, 0x78, 0x78,  0xD,  0x0,  0x0,  0x0,  0x0, 0x48, 0x8F, 0x1B, 0x74,
+    0x1C,  0x0, 0x21, 0x72, 0x2D, 0x96, 0x98, 0x2B,  0x0,  0x8, 0x62, 0xA6, 0x96, 0x4C,  0x0,  0x0,
+     0x0,  0x0, 0x31, 0x62,  0x0,  0x0,  0x0,  0x0, 0x7B, 0x21,  0x0,  0x0, 0x84, 0x19,  0x0, 0x2E,
+    0x77,  0x0,  0x0,  0x0, 0x54, 0x51,  0x0, 0x89, 0x13,  0x0,  0x0,  0x0,  0x0, 0x83, 0x1A,  0x0,
+    0x12, 0x8E,  0xF,  0x0,  0x1, 0x7E, 0x33,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xB6, 0x84,  0x0, 0x1F, 0xEF, 0x29,  0x0,  0x0, 0x19, 0xF0, 0x30,  0x0,  0xC, 0xE6,
+    0x49,  0x0,  0x0,  0xD, 0x3B, 0x3B, 0x3B, 0x5A, 0xFB, 0x3A,  0x0, 0x1A, 0xFB, 0x3B, 0x3B, 0x21,
+     0x0,  0x0, 0xA7, 0x77,  0x0,  0x0,  0x0,  0x0,  0x0, 0x13, 0x3D, 0x3D, 0xCC, 0x58,  0x0,  0x0,
+    0x13, 0xDA, 0x77, 0xE3, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x65, 0xA2,  0x0,  0x0,  0x0, 0x77, 0x57, 0x3B, 0x87, 0xDF,  0x7,  0x0, 0xDD, 0x37,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x4F, 0xE5, 0x62, 0x3D, 0x8E,  0xB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xF5, 0x1E,  0x0,  0x0, 0x88, 0xBF, 0x45, 0x65, 0xE1,  0x3,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xD8,
+    0xC2,  0xF,  0x0,  0x0, 0x8F, 0xCD, 0x4E, 0x92, 0xF4, 0x48,  0x0, 0xDA, 0x3A,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x1B, 0xFF, 0x18,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0xD9,  0x0, 0xAC, 0x71,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAA, 0xC4, 0xDE,  0x0,  0x0,  0x0,  0x0, 0x5C, 0xDC, 0x51, 0xF8,
+    0x83, 0xA2, 0x6E,  0x0, 0xDA, 0xB8, 0x5D, 0x8E, 0xCF,  0x0,  0x0,  0x0, 0x8A, 0xCB, 0x52, 0xA9,
+    0xC7,  0x0,  0x0, 0xE1, 0xD4, 0x60, 0x8C, 0xDF,  0x1,  0x0,  0x0, 0x8A, 0xCA, 0x58, 0xB8, 0xF7,
+    0x32,  0x0, 0x3A, 0xE4, 0xC1, 0x71, 0x75, 0x4D,  0x0, 0x66, 0xBD, 0x3F, 0x5E, 0x6F,  0x0,  0x0,
+     0x0,  0x0, 0x79, 0x9A,  0x0,  0x0,  0x0,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9, 0x2C,  0x0,  0xF,
+    0xF4, 0x18,  0x0,  0x0, 0xD7, 0x45,  0x0, 0xBF, 0x4D,  0x0,  0x0,  0x0, 0x11, 0xF5,  0x6,  0x0,
+     0x0, 0x84, 0xA9,  0x0, 0x6F, 0xBF,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x22, 0xEE, 0x22, 0xAD, 0x7F,  0x0,  0x0,  0x0,  0x0, 0x77, 0xBB,  0x0, 0x7F, 0xB3,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB7, 0x8E,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x30, 0xE6,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x4,
+    0xBC, 0x66,  0x0, 0x35, 0xD8, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x6A, 0x23,  0x0,  0x0,  0x0,  0x3, 0x1E, 0x2A, 0xF1, 0x2F,  0x0, 0xDD, 0x37,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xCA, 0x5A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xF5, 0x1E,  0x0,  0xA, 0xFA, 0x17,  0x0,  0x0, 0xA7, 0x3E,  0x0,  0x0,  0x0, 0x1F, 0xE7,  0x1,
+     0x0,  0x0,  0x0,  0x2, 0xF1, 0x48,  0x0,  0x3, 0xEF, 0x48,  0x0, 0xDA, 0x3A,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x3, 0x39,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x10, 0x2E,  0x0, 0xAC, 0x71,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x36, 0xDE,  0x0,  0x0,  0x0,  0x0, 0x5C, 0xA6,  0x0, 0xDF,
+    0x2B, 0x59, 0x91,  0x0, 0xDA, 0x6F,  0x0,  0xA, 0xF8, 0x12,  0x0,  0x0, 0xD8, 0x3A,  0x0,  0x6,
+    0xF5, 0x18,  0x0, 0xE1, 0x78,  0x0,  0x0, 0xDF, 0x2E,  0x0,  0x0, 0xDA, 0x37,  0x0, 0x29, 0xFF,
+    0x32,  0x0, 0x3A, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0, 0x9B, 0x8A,  0x1,  0x0,  0x0,  0x0,  0x0,
+    0x22, 0x93, 0xC6, 0xD4, 0x93, 0x93,  0x9,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9, 0x2C,  0x0,  0x0,
+    0xAC, 0x6D,  0x0, 0x30, 0xE6,  0x3,  0x0, 0x83, 0x84,  0x0, 0x9D, 0x1C, 0x46, 0xC0,  0x0,  0x0,
+     0x0,  0x4, 0xC1, 0x8E, 0xE2, 0x19,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x7B, 0xD4, 0xD5,  0x6,  0x0,  0x0,  0x0,  0x0,  0x7, 0xDC, 0x61, 0xED, 0x25,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5E, 0xD9,  0x9,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xB8, 0x66,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0, 0x30,
+    0x5A,  0x0,  0x0,  0x0, 0x3B, 0x4F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x6E, 0xE4, 0xBA, 0xB1, 0xF3, 0x3C,  0x0, 0xDD, 0x5A, 0x9D, 0x9D,
+    0x30,  0x0,  0x0,  0x2, 0xF7, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0x5A, 0xA7, 0x47,
+    0xF5, 0x1E,  0x0, 0x35, 0xFB, 0xD8, 0xD8, 0xD8, 0xE9, 0x7D,  0x0,  0x3, 0x93, 0xB0, 0xEB, 0x93,
+    0x93,  0xB,  0x0, 0x15, 0xFF, 0x16,  0x0,  0x0, 0xD2, 0x48,  0x0, 0xDA, 0x3A, 0x57, 0x9F, 0x36,
+     0x0,  0x0, 0x3F, 0x93, 0x93,  0xE,  0x0,  0x0,  0x0,  0x5, 0x93, 0x93, 0x86,  0x0, 0xAC, 0x71,
+     0x0,  0xD, 0x8A, 0x2B,  0x0,  0x0, 0x36, 0xDE,  0x0,  0x0,  0x0,  0x0, 0x5C, 0x9F,  0x0, 0xD8,
+    0x24, 0x52, 0xA9,  0x0, 0xDA, 0x3C,  0x0,  0x0, 0xE9, 0x2C,  0x0, 0x1C, 0xFC,  0x7,  0x0,  0x0,
+    0xC6, 0x59,  0x0, 0xE1, 0x44,  0x0,  0x0, 0xAF, 0x6D,  0x0, 0x1C, 0xFC,  0x7,  0x0,  0x2, 0xF3,
+    0x32,  0x0, 0x3A, 0xED,  0x2,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xC5, 0xE7, 0xA3, 0x2B,  0x0,  0x0,
+     0xF, 0x43, 0x9C, 0xB5, 0x43, 0x43,  0x4,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9, 0x2C,  0x0,  0x0,
+    0x51, 0xC5,  0x0, 0x89, 0x8F,  0x0,  0x0, 0x47, 0xBB, 0x2B, 0xE4, 0x66, 0x7D, 0x85,  0x0,  0x0,
+     0x0,  0x0, 0x29, 0xFF, 0x66,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x21, 0xFE, 0x7B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4F, 0xFE, 0x8A,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x17, 0xEA, 0x3B,  0x0,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x40, 0xDA,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0xE, 0xF4, 0x1A,  0x0,  0x1, 0xF0, 0x3D,  0x0, 0xDD, 0xD2, 0x47, 0x73,
+    0xEB, 0x10,  0x0,  0x0, 0xE1, 0x3B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA6, 0xA0, 0x41, 0xAE,
+    0xFD, 0x1E,  0x0,  0xD, 0xEE,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0x43, 0x74, 0xDD, 0x43,
+    0x43,  0x5,  0x0,  0x6, 0xFD, 0x39,  0x0,  0x5, 0xF5, 0x48,  0x0, 0xDA, 0xB4, 0x50, 0x8F, 0xE2,
+     0x3,  0x0, 0x1D, 0x44, 0xFD, 0x18,  0x0,  0x0,  0x0,  0x2, 0x43, 0x63, 0xE8,  0x0, 0xAC, 0x71,
+    0x10, 0xC6, 0x78,  0x0,  0x0,  0x0, 0x36, 0xDE,  0x0,  0x0,  0x0,  0x0, 0x5C, 0x9E,  0x0, 0xD8,
+    0x24, 0x52, 0xAA,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9, 0x2C,  0x0,  0x6, 0xF3, 0x22,  0x0,  0x0,
+    0xE3, 0x38,  0x0, 0xE1, 0x60,  0x0,  0x0, 0xC8, 0x4A,  0x0, 0x1E, 0xFF, 0x1D,  0x0, 0x10, 0xFD,
+    0x32,  0x0, 0x3A, 0xDB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x23, 0x97, 0xD8,  0x0,  0x0,
+     0x0,  0x0, 0x79, 0x9A,  0x0,  0x0,  0x0,  0x0, 0xD2, 0x41,  0x0,  0x9, 0xF8, 0x2C,  0x0,  0x0,
+     0x7, 0xEE, 0x20, 0xE0, 0x34,  0x0,  0x0,  0xF, 0xED, 0x73, 0x5E, 0xAA, 0xB4, 0x49,  0x0,  0x0,
+     0x0,  0x0, 0xAB, 0xB5, 0xD9,  0xE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xAE, 0xA9, 0xE8, 0x15,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xFB, 0x36,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAB, 0x8D,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xC8, 0x55,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0xF, 0xF0, 0x18,  0x0, 0x5A, 0xFF, 0x48,  0x0, 0xDD, 0x60,  0x0,  0x0,
+    0xC5, 0x5F,  0x0,  0x0, 0x99, 0xAF,  0x3,  0x0, 0x24,  0x5,  0x0,  0x4, 0xEA,  0xB,  0x0, 0x20,
+    0xFF, 0x1E,  0x0,  0x0, 0xAB, 0x79,  0x5,  0x0, 0x19, 0x19,  0x0,  0x0,  0x0, 0x43, 0xD0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB9, 0x9C,  0xA, 0x62, 0xFF, 0x48,  0x0, 0xDA, 0x5B,  0x0,  0xA, 0xF9,
+    0x1E,  0x0,  0x0,  0x0, 0xFC, 0x18,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xE8,  0x0, 0xAC, 0x84,
+    0xCD, 0x6E,  0x0,  0x0,  0x0,  0x0, 0x36, 0xDE,  0x0,  0x0,  0x0,  0x0, 0x5C, 0x9E,  0x0, 0xD8,
+    0x24, 0x52, 0xAA,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9, 0x2C,  0x0,  0x0, 0xB1, 0x7D,  0x1, 0x41,
+    0xEC,  0x3,  0x0, 0xE1, 0xB1,  0x9, 0x23, 0xF1,  0xB,  0x0,  0x1, 0xDD, 0x71,  0x0, 0x63, 0xFF,
+    0x32,  0x0, 0x3A, 0xDB,  0x0,  0x0,  0x0,  0x0,  0x0, 0x30,  0x9,  0x0, 0x39, 0xD9,  0x1,  0x0,
+     0x0,  0x0, 0x79, 0x9A,  0x0,  0x0,  0x0,  0x0, 0x9B, 0x83,  0x0, 0x5D, 0xFF, 0x2C,  0x0,  0x0,
+     0x0, 0x9B, 0xB0, 0xD8,  0x0,  0x0,  0x0,  0x0, 0xCF, 0xD4,  0xA, 0xB5, 0xE9, 0x10,  0x0,  0x0,
+     0x0, 0x6C, 0xC5,  0x4, 0x8D, 0xA9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x50, 0xE0,  0xB, 0x91, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xFA, 0x36,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x51, 0xD9,  0x9,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x51, 0xCC,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x80, 0xE5, 0xD1, 0xA6, 0xAC, 0x76,  0x0, 0xDD, 0x3C,  0x0,  0x0,
+    0xA1, 0x7F,  0x0,  0x0,  0x9, 0xB1, 0xE8, 0xCB, 0xC8,  0x8,  0x0, 0x36, 0xE1,  0x0,  0x0,  0x0,
+    0xFA, 0x1E,  0x0,  0x0, 0x26, 0x9B, 0xDB, 0xD3, 0xE4, 0x33,  0x0,  0x0,  0x0, 0x43, 0xD0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x21, 0xCA, 0xEE, 0xA8, 0xD0, 0x41,  0x0, 0xDA, 0x3B,  0x0,  0x0, 0xE9,
+    0x2C,  0x0,  0x0,  0x0, 0xFC, 0x18,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xE8,  0x0, 0xAC, 0xF8,
+    0xDF, 0x71,  0x0,  0x0,  0x0,  0x0, 0x36, 0xDE,  0x0,  0x0,  0x0,  0x0, 0x5C, 0x9E,  0x0, 0xD8,
+    0x24, 0x52, 0xAA,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9, 0x2C,  0x0,  0x0, 0x31, 0xB1, 0xDE, 0xCC,
+    0x53,  0x0,  0x0, 0xE1, 0x84, 0xC7, 0xDA, 0x60,  0x0,  0x0,  0x0, 0x46, 0xF4, 0xD4, 0x86, 0xE9,
+    0x32,  0x0, 0x3A, 0xDB,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xEF, 0xCD, 0xD5, 0x52,  0x0,  0x0,
+     0x0,  0x0, 0x78, 0x9B,  0x0,  0x0,  0x0,  0x0, 0x3D, 0xDE, 0xC9, 0x5D, 0xDD, 0x2C,  0x0,  0x0,
+     0x0, 0x41, 0xFF, 0x7E,  0x0,  0x0,  0x0,  0x0, 0x93, 0xB7,  0x0, 0x7A, 0xD1,  0x0,  0x0,  0x0,
+    0x34, 0xE8, 0x20,  0x0,  0x6, 0xCB, 0x6B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xE, 0xE3, 0x4E,  0x0, 0x12, 0xEB, 0x3A,  0x0,  0x0,  0x0,  0x0,  0x0, 0xFA, 0x36,  0x0,
+     0x0,  0x0,  0x0, 0x11, 0xE3, 0x3B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x2, 0xD7, 0x44,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xB, 0x10,  0x0,  0x0,  0x0,  0x0, 0xDD, 0x49,  0x0,  0x0,
+    0xAE, 0x72,  0x0,  0x0,  0x0,  0x0,  0x9, 0x13,  0x0,  0x0,  0x0, 0x31, 0xEE,  0x1,  0x0,  0xA,
+    0xFE, 0x1E,  0x0,  0x0,  0x0,  0x0,  0xB, 0x1C,  0x2,  0x0,  0x0,  0x0,  0x0, 0x43, 0xD0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2,  0x2, 0xE0,  0xA,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9,
+    0x2C,  0x0,  0x0,  0x0, 0xF7, 0x1B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xE8,  0x0, 0xAC, 0x83,
+    0x27, 0xEE, 0x33,  0x0,  0x0,  0x0, 0x31, 0xE1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x11,  0x0,
+     0x0,  0x0,  0x0, 0xE1, 0x34,  0x8,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x7, 0x15,  0x0, 0xE3,
+    0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5, 0x1B,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x48, 0xCA,  0x4,  0x0,  0x0,  0x0,  0x0,  0x3,  0xB,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x93, 0xB1,  0x0,  0x0,  0x0, 0x6E, 0xCF,  0x4,  0x0,  0x0,  0x0,  0x0, 0xFA, 0x36,  0x0,
+     0x0,  0x0,  0x0, 0x5A, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x97,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x61, 0xBB,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xDD, 0x9E,  0x0, 0x15,
+    0xED, 0x30,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5, 0xE9, 0x43,  0x0, 0x5F,
+    0xFF, 0x1E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xD0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x47, 0x7C, 0x54, 0x9E, 0xA5,  0x0,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9,
+    0x2C,  0x0,  0x0,  0x0, 0xBC, 0x5B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xE8,  0x0, 0xAC, 0x71,
+     0x0, 0x65, 0xDA,  0xD,  0x0,  0x0,  0x5, 0xEE, 0x23,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xE1, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE3,
+    0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x8, 0xAB, 0xDE, 0xD6,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xF9,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0xE3, 0x34,  0x0,  0x0,  0x0,  0x0, 0xBC, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xDD, 0x79, 0xB4, 0xEB,
+    0x8A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x50, 0xED, 0xC3, 0x75,
+    0xEC, 0x1E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xD0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x1E, 0x77, 0x86, 0x35,  0x0,  0x0,  0x0, 0xDA, 0x3A,  0x0,  0x0, 0xE9,
+    0x2C,  0x0,  0x0,  0x0, 0x44, 0xD0, 0xDF, 0x5B,  0x0,  0x0,  0x0, 0x2C, 0xE8,  0x0, 0xAC, 0x71,
+     0x0,  0x0, 0xAD, 0xA2,  0x0,  0x0,  0x0, 0x69, 0xDE, 0xDE, 0x2A,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x71, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x77,
+    0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x2,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0xE5, 0xA6, 0x41,  0xF,  0x0, 0xFF, 0x4D,  0x0,  0x1, 0x24,
-    0x62, 0xFF, 0x37,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0, 0x28, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0xBB, 0x61,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xFB, 0x41, 0x41, 0x24,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x13,  0xE,  0x0, 0x15, 0x41, 0x41, 0xCD, 0x58,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x6,  0xF,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0x10,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xC,  0x5,  0x0,  0x0,  0x0, 0x43, 0xCA,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3,  0xC,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1141,9 +1823,1722 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xF, 0x95, 0x95, 0x95, 0x54,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x30, 0x95, 0x95, 0x95, 0x33,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0x9B, 0xD3, 0x4F,  0x0, 0xFF, 0x4D,  0x0,  0x7, 0xF1,
-    0xBD, 0x84,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x40, 0x6D, 0xCB, 0x7A,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4D, 0x7F, 0x3F,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x29, 0x7F,  0x0,  0x0,  0x0, 0x47, 0x61,  0x0, 0x64, 0x96, 0x96, 0x96, 0x96, 0x10,  0x0,
+     0x0,  0x0,  0x0, 0x29, 0x6E, 0x6C,  0x0, 0x2B,  0x6,  0x0, 0x42, 0x6D, 0x32,  0x0,  0x0,  0x0,
+     0x0,  0x4, 0x7C, 0xDB, 0x57,  0x0, 0x36, 0xAA,  0x0, 0x30, 0x7A, 0x7A, 0x7A, 0x7A, 0x7A, 0x4C,
+     0x0,  0x0,  0x3, 0x15, 0x15,  0xA,  0x0,  0x5,  0x8,  0x1,  0x0,  0x6,  0x8,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xA8, 0xA0,  0x0,  0x0, 0x96, 0xB2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x4B, 0xA9, 0x3F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x27, 0x5B, 0x38,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1C, 0x43, 0x26,  0x1,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x5,  0x8,  0x1,  0x0,  0x0,  0x0,  0x0, 0x20, 0xA4, 0x48,  0x0,
+     0x9, 0xA0, 0x5F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1C, 0x37,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x82, 0xC9,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x50, 0x5C,  0x4,  0x0,  0x0, 0xCF, 0xDF, 0xDF, 0xDF, 0xDF, 0xDF, 0xDF, 0xDF, 0x4C,  0x0,
+     0x0,  0x1,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8,  0x7,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x23, 0x39,  0x7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1,
+     0x8,  0x7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0x27, 0x44, 0x2F,  0xB,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x7, 0x2D, 0x44, 0x35, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x7,  0x8,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x9, 0xEE, 0x2A,  0x0,  0x0, 0xC6, 0x5A,  0x0, 0x2F, 0x46, 0x46, 0x81, 0xE9,  0xD,  0x0,
+     0x0,  0x0,  0x0, 0xD1, 0x7F, 0x2C,  0x0, 0xE2, 0x1F,  0x0, 0x21, 0x6A, 0xF2,  0xE,  0x0,  0x0,
+     0x0, 0x3B, 0xB7, 0x1C, 0xD6, 0x48, 0x83, 0x8C,  0x0, 0x63, 0x6D, 0x32, 0x32, 0x32, 0x3B, 0x9F,
+     0x0,  0x0, 0x28, 0xFF, 0xFF, 0x82,  0x0, 0x98, 0xFF, 0x27,  0x0, 0xBD, 0xFF,  0x3,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x10, 0xFC, 0x8F,  0x0,  0x4, 0xF2, 0xA4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x71, 0xFF, 0x5E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0x98, 0xFF, 0xFE, 0xFF, 0xAD,  0x4,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x64, 0xC8, 0xFE, 0xFF, 0xFF, 0xBD,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0xA7, 0xF0, 0x12,  0x0,
+     0x0, 0x9C, 0xED,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x66, 0xC2,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x41, 0xFF, 0xFF, 0xA0,  0x0,  0x0, 0xB7, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0xC6, 0x43,  0x0,
+    0x13, 0xD9, 0xF2, 0x52,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF, 0x9D,  0x0,
+     0x0,  0x0, 0x45, 0xE6, 0xFF, 0xFF, 0xF8, 0x98,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9C,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0, 0x21, 0x9D, 0xF1, 0xFF, 0xFF, 0xFF, 0xF7, 0x89,  0xD,
+     0x0,  0x0,  0x8, 0xC7, 0xF8, 0xFF, 0xFF, 0xFF, 0xFD, 0xA4, 0x1D,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x45, 0xFF, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x92, 0x8A,  0x0, 0x27, 0xED,  0x8,  0x0,  0x0,  0x0, 0x1B, 0xE2, 0x3E,  0x0,  0x0,
+     0x0,  0x0, 0x15, 0xFB,  0x6,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0xC7, 0x4B,  0x0,  0x0,
+     0x0, 0x5E, 0x6C,  0x0, 0x24, 0xCA, 0x98, 0x11,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0, 0x28, 0xFF, 0xFF, 0x82,  0x0, 0x98, 0xFF, 0x27,  0x0, 0xBD, 0xFF,  0x3,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x4D, 0xFF, 0x4F,  0x0, 0x38, 0xFF, 0x64,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x1, 0x8B, 0xFF, 0x85, 0x1B,  0x0,  0x0,  0x0,  0x0, 0x54, 0xFF, 0x6D,  0xF, 0x5B, 0xFD, 0x6F,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x42, 0xFE, 0xE1, 0x79, 0x4B, 0x73, 0xA8,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x11,  0x0,  0x0,  0x0, 0x37, 0xFE, 0x82,  0x0,  0x0,
+     0x0, 0x21, 0xFB, 0x77,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x72, 0xCE,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x33, 0xFF, 0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x5C, 0xFF, 0xFF, 0xB3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC3, 0xFD, 0x28,  0x0,
+     0x0, 0x41, 0xF7, 0xF3, 0x86, 0x69, 0xCA, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x80, 0xFF,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0, 0x59, 0xFF, 0xDE, 0x9E, 0x77, 0xB0, 0xF8, 0xFF, 0xB2,
+     0x1,  0x0,  0xC, 0xFB, 0xBF, 0x8C, 0x73, 0x9E, 0xE7, 0xFF, 0xD4,  0xB,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xA, 0xDD, 0xFD, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x2E, 0xE7,  0x4, 0x85, 0x93,  0x0,  0x0,  0x0,  0x7, 0xC7, 0x6D,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x1F, 0xF9,  0x0,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0xBB, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0, 0x28, 0xFF, 0xFF, 0x82,  0x0, 0x98, 0xFF, 0x27,  0x0, 0xBD, 0xFF,  0x3,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x8D, 0xFD, 0x11,  0x0, 0x78, 0xFF, 0x23,  0x0,  0x0,  0x0,  0x0,  0x4, 0x70,
+    0xD9, 0xFF, 0xFF, 0xFF, 0xFF, 0xE2, 0x2E,  0x0,  0x0, 0x93, 0xD4,  0x0,  0x0,  0x0, 0xBA, 0xB1,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x89, 0xFF, 0x5A,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x11,  0x0,  0x0,  0x0, 0xA8, 0xFE, 0x1F,  0x0,  0x0,
+     0x0,  0x0, 0xB6, 0xE9,  0xA,  0x0,  0x0, 0x15,  0x8,  0x0,  0x0, 0x7E, 0xDA,  0x0,  0x0,  0x0,
+    0x1C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x4A, 0xC3, 0xE4,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x1D, 0xF2, 0xFE, 0x68,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3C, 0xFF, 0xAE,  0x0,  0x0,
+     0x0, 0xBE, 0xFF, 0x5A,  0x0,  0x0, 0x10, 0xDE, 0xFF, 0x29,  0x0,  0x0,  0x0, 0x64, 0xFF, 0xE4,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3B, 0x59,  0x3,  0x0,  0x0,  0x0, 0x24, 0xFD, 0xFF,
+    0x42,  0x0,  0x2, 0x11,  0x0,  0x0,  0x0,  0x0,  0x4, 0xE4, 0xFF, 0x64,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x89, 0xEB, 0xAC, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xC9, 0x4F, 0xE2, 0x30,  0x0,  0x0,  0x0, 0x9B, 0xA0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x43, 0xE1,  0x0,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0xB2, 0x71,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0, 0x28, 0xFF, 0xFF, 0x82,  0x0, 0x98, 0xFF, 0x27,  0x0, 0xBD, 0xFF,  0x3,  0x0,  0x0,
+    0x82, 0x9E, 0x9E, 0xE4, 0xF4, 0x9E, 0x9E, 0xDD, 0xFB, 0x9E, 0x9E, 0x3B,  0x0,  0x0, 0x53, 0xFF,
+    0xAF, 0x5D, 0xFF, 0x3B, 0x5F, 0xB8, 0x3E,  0x0,  0x0, 0x63, 0xEE,  0x9,  0x0,  0x1, 0xC8, 0x7B,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x87, 0xFF, 0x47,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x11,  0x0,  0x0, 0x17, 0xF9, 0xC2,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x59, 0xFF, 0x70,  0x0,  0x0, 0x8C, 0xF1, 0xA6, 0x52, 0x94, 0xE7, 0x34, 0x88, 0xDB,
+    0xE6,  0x2,  0x0,  0x9, 0x51, 0x51, 0x51, 0x51, 0xC1, 0xFF, 0x59, 0x51, 0x51, 0x51, 0x2B,  0x0,
+     0x0,  0x0, 0xC7, 0x95,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xD, 0x21,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB3, 0xFF, 0x37,  0x0,  0x0,
+    0x20, 0xFD, 0xE5,  0x1,  0x0,  0x0,  0x0, 0x7C, 0xFF, 0x89,  0x0,  0x0, 0x49, 0xFB, 0xE4, 0x3C,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBD, 0xFF,
+    0x79,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x84, 0xFF, 0x93,  0x0,  0x0,  0x0,  0x0,
+    0x30, 0xFB, 0x65, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x65, 0xE0, 0xCC,  0x0,  0x0,  0x0, 0x68, 0xCB,  0x8,  0x0,  0x0,  0x0,  0x0,
+    0x20, 0x8A, 0xD3, 0x6E,  0x0,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0x4B, 0xDE, 0x8A, 0x3C,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0, 0x22, 0xFF, 0xFF, 0x7D,  0x0, 0x98, 0xFF, 0x27,  0x0, 0xBD, 0xFF,  0x3,  0x0,  0x0,
+    0xC3, 0xED, 0xF0, 0xFF, 0xF5, 0xED, 0xEE, 0xFF, 0xF7, 0xED, 0xED, 0x58,  0x0,  0x0, 0xBF, 0xF4,
+     0xE, 0x24, 0xFF, 0x11,  0x0,  0x0,  0x1,  0x0,  0x0,  0xB, 0xF0, 0xB3, 0x6A, 0xBC, 0xFA, 0x19,
+     0x0,  0x0, 0x18, 0x75, 0xD5,  0x0,  0x0,  0x0,  0x0, 0x4A, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x11,  0x0,  0x0, 0x5B, 0xFF, 0x86,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x1B, 0xFF, 0xC4,  0x0,  0x0,  0x2, 0x4E, 0xBD, 0xFF, 0xFF, 0xFF, 0xFF, 0xE1, 0x77,
+    0x12,  0x0,  0x0, 0x1D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x86,  0x0,
+     0x0, 0x46, 0xFE, 0x46,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xFE, 0xBE,  0x0,  0x0,  0x0,
+    0x63, 0xFF, 0xA0,  0x0, 0x46, 0xA3,  0x0, 0x39, 0xFF, 0xCC,  0x0,  0x0, 0x2C, 0xE4, 0x2F, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF,
+    0x6D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB4, 0xFF, 0x81,  0x0,  0x0,  0x0,  0x3,
+    0xCB, 0xCA,  0x2, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0xD, 0xF4, 0x6B,  0x0,  0x0,  0x0, 0xCE, 0xEC, 0xE1, 0xE1, 0xE1, 0x18,  0x0,
+    0x17, 0x65, 0xC2, 0x88,  0x0,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0x4C, 0xDF, 0x91, 0x37,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0,  0x5, 0xFC, 0xFF, 0x61,  0x0, 0x55, 0x8E, 0x16,  0x0, 0x69, 0x8E,  0x1,  0x0,  0x0,
+     0x0,  0x0, 0x49, 0xFF, 0x52,  0x0, 0x35, 0xFF, 0x66,  0x0,  0x0,  0x0,  0x0,  0x5, 0xF1, 0xDD,
+     0x1, 0x24, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xCD, 0xF7, 0xD1, 0x4F,  0x6,
+    0x52, 0xB2, 0xF4, 0xAB, 0x4C,  0x0,  0x0,  0x0,  0x0,  0x1, 0xD1, 0xFD, 0x42,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x61, 0x8E,  0xA,  0x0,  0x0, 0x85, 0xFF, 0x61,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x1, 0xF4, 0xEE,  0x0,  0x0,  0x0,  0x0,  0x0, 0x55, 0xFF, 0xFF, 0xAE,  0x4,  0x0,
+     0x0,  0x0,  0x0,  0xB, 0x64, 0x64, 0x64, 0x64, 0xC8, 0xFF, 0x6B, 0x64, 0x64, 0x64, 0x35,  0x0,
+    0x7E, 0xF7, 0x77,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA2, 0xFF, 0x47,  0x0,  0x0,  0x0,
+    0x7A, 0xFF, 0x81,  0x0, 0x9A, 0xF7,  0x5, 0x19, 0xFF, 0xE3,  0x0,  0x0,  0x0,  0xC,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0xDB, 0xFF,
+    0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1C, 0x69, 0xFE, 0xF2, 0x21,  0x0,  0x0,  0x0, 0x70,
+    0xFD, 0x36,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x27, 0xF4, 0x12,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x3A, 0xE7,  0x0,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0xA7, 0x6F,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0,  0x0, 0xDE, 0xFF, 0x43,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x8A, 0xFE, 0x14,  0x0, 0x75, 0xFF, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0, 0x95, 0xFF,
+    0x6A, 0x28, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2F, 0x8F, 0xEA,
+    0xCA, 0x6B, 0x12,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x55, 0xFC, 0xFE, 0xE4, 0x15,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x3C,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xD1, 0xFF, 0x19,  0x0,  0x0,  0x0,  0x2, 0xC5, 0xDF, 0x9F, 0xF9, 0x2B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x8A, 0x33,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F, 0xFA, 0xCF,  0x1,  0x0,  0x0,  0x0,
+    0x91, 0xFF, 0x71,  0x0, 0xBE, 0xFF, 0x24,  0x9, 0xFF, 0xF9,  0x1,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x64, 0xFF, 0xC1,
+     0x1,  0x0,  0x0,  0x0,  0x0, 0xC8, 0xE2, 0xFF, 0xFE, 0xA0, 0x19,  0x0,  0x0,  0x0, 0x1F, 0xF3,
+    0x9B,  0x0,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x62, 0xC6, 0x7C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x1F, 0xF9,  0x0,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0xBB, 0x58,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0,  0x0, 0xBC, 0xFF, 0x25,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xCA, 0xD1,  0x0,  0x0, 0xB4, 0xE5,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2A, 0xF4,
+    0xFF, 0xED, 0xFF, 0x65,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0, 0x13, 0x6C, 0xCD, 0xE6, 0x8A, 0x2B,
+    0x16, 0x55, 0x2A,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFA, 0xA9, 0x63, 0xFF, 0xB6,  0x1,  0x0,
+     0x0, 0x5C, 0xA6,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD9, 0xFF, 0x17,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xAD, 0xFF, 0x43,  0x0,  0x0,  0x0, 0x6F, 0xF9, 0x36,  0x7, 0xCC, 0xC8,  0x3,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0xFF, 0x58,  0x0,  0x0,  0x0,  0x0,
+    0xA3, 0xFF, 0x66,  0x0, 0xCA, 0xFF, 0x34,  0x0, 0xFB, 0xFF,  0xD,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2E, 0xF5, 0xEC, 0x20,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xB9, 0xD1, 0xFC, 0xFF, 0xB1, 0x1F,  0x0,  0x0,  0x0, 0xB5, 0xED,
+    0x14,  0x0,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x75, 0x31,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x11, 0xFA,  0xB,  0x0,  0x0, 0xE2, 0x1F,  0x0,  0x0,  0x0, 0xC8, 0x4A,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0x49,  0x0,  0x0,  0x0,  0xC, 0x9F,
+     0x0,  0x0,  0x0, 0x99, 0xFE,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB1,
+    0xB5, 0xB5, 0xFC, 0xE6, 0xB5, 0xB5, 0xF5, 0xEB, 0xB5, 0xB5, 0x27,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0x95, 0xDD, 0xFF, 0xFF, 0xEA, 0x86,  0xD,  0x0,  0x0, 0x6E, 0xF0, 0xA9, 0x4A,  0x4,  0x0, 0x51,
+    0xFA, 0xFC, 0xFE, 0xBD, 0x27,  0x0,  0x0,  0x8, 0xEB, 0xD6,  0x7,  0x0, 0xA4, 0xFF, 0x76,  0x0,
+     0x0, 0x8C, 0xF8,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0xF9, 0xF9,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x8E, 0xFF, 0x64,  0x0,  0x0, 0x21, 0xF4, 0x6E,  0x0,  0x0, 0x22, 0xEF, 0x73,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x14, 0xF5, 0xDD,  0x3,  0x0,  0x0,  0x0,  0x0,
+    0x91, 0xFF, 0x72,  0x0, 0xBA, 0xFF, 0x28,  0x9, 0xFF, 0xF9,  0x1,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1D, 0xE2, 0xF8, 0x40,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x15, 0x62, 0xEB, 0xF5, 0x40,  0x0, 0x58, 0xFF, 0x69,
+     0x0,  0x0,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xC7, 0x9C, 0x44,  0x0, 0xE2, 0x1F,  0x0, 0x25, 0x71, 0xF2,  0xC,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x63, 0xAC, 0x8B, 0x8B, 0x8B, 0x90, 0x9F,
+     0x0,  0x0,  0x0, 0x77, 0xE7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD0,
+    0xD4, 0xE5, 0xFF, 0xDD, 0xD4, 0xE2, 0xFF, 0xE0, 0xD4, 0xD4, 0x2E,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x24, 0xFF, 0x66, 0xC6, 0xFF, 0x75,  0x0,  0x0, 0x31, 0x11,  0x0,  0x0,  0x0,  0xB, 0xF8,
+    0xA6, 0x17, 0x32, 0xDA, 0xC2,  0x0,  0x0, 0x4A, 0xFF, 0x89,  0x0,  0x0,  0xD, 0xDA, 0xFA, 0x3A,
+     0x0, 0xA3, 0xD9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xDB, 0xFF, 0x15,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x99, 0xFF, 0x46,  0x0,  0x0, 0x21, 0x83,  0x0,  0x0,  0x0,  0x0, 0x4E, 0x57,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x8E, 0xDC,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x81, 0xFF, 0x69,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x7A, 0xFF, 0x82,  0x0, 0x92, 0xFC,  0xB, 0x19, 0xFF, 0xE3,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x14, 0xD6, 0xFC, 0x55,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x86, 0xFF, 0xBE,  0x0, 0xE4, 0xE6, 0x3F,
+    0x3C, 0x3C, 0x3C, 0xB1, 0xFF, 0x8E, 0x3C, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x13, 0x4D, 0x55,  0x0, 0xE2, 0x1F,  0x0, 0x40, 0x67, 0x2C,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xC, 0x20, 0x20, 0x20, 0x20, 0x20, 0x14,
+     0x0,  0x0,  0x0,  0xB, 0x17,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x8C, 0xFD, 0x11,  0x0, 0x76, 0xFF, 0x23,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x24, 0xFF, 0x11,  0xC, 0xF3, 0xE0,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xFF,
+    0x24,  0x0,  0x0, 0x6F, 0xFB,  0x7,  0x0, 0x65, 0xFF, 0x78,  0x0,  0x0,  0x0, 0x32, 0xF8, 0xE1,
+    0x14, 0xD7, 0xAE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB1, 0xFF, 0x3A,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xAC, 0xFF, 0x1C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xC, 0xED, 0xE8,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x62, 0xFF, 0xA2,  0x0, 0x3F, 0xAC,  0x0, 0x39, 0xFF, 0xCC,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xD, 0xCA, 0xFD, 0x62,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x26, 0xFF, 0xED,  0x0, 0xF8, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x6D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBD, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xCC, 0xCD,  0x0,  0x0, 0xB7, 0xE2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x24, 0xFF, 0x11,  0x0, 0xC8, 0xFB, 0x17,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x18, 0xFA,
+    0x4B,  0x0,  0x0, 0x96, 0xF0,  0x1,  0x0, 0x3F, 0xFF, 0xD8,  0x1,  0x0,  0x0,  0x0, 0x6E, 0xFF,
+    0xD9, 0xFE, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x87, 0xFF, 0x5F,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xD6, 0xF0,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x71, 0xFF, 0x7A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x20, 0xFD, 0xE6,  0x1,  0x0,  0x0,  0x0, 0x7C, 0xFF, 0x89,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8, 0xBD, 0xFE, 0x65,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5F, 0xFF, 0xDC,  0x0, 0x67, 0x6A, 0x6A,
+    0x6A, 0x6A, 0x6A, 0xC4, 0xFF, 0xA9, 0x6A, 0x2D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x4, 0x92, 0xB8, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x10, 0xFC, 0x8D,  0x0,  0x5, 0xF3, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x10, 0x6A,  0x7,
+     0x0, 0x24, 0xFF, 0x11, 0x26, 0xFE, 0xA6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAC,
+    0xE7, 0x82, 0xA0, 0xF9, 0x85,  0x0,  0x0,  0xC, 0xEB, 0xFF, 0x49,  0x0,  0x0,  0x0,  0x1, 0xC0,
+    0xFF, 0xBE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5D, 0xFF, 0x84,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x10, 0xFD, 0xC7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0xE2, 0xF1, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xBD, 0xFF, 0x5C,  0x0,  0x0, 0x11, 0xDF, 0xFF, 0x28,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F,
+    0xFF, 0xE4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5, 0xB2, 0xFE, 0x69,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x32, 0x14,  0x0,  0x0,  0x0,  0x0,  0xA, 0xCB, 0xFF, 0xA6,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x49, 0xFF, 0xFF, 0xB0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x4E, 0xFF, 0x4C,  0x0, 0x3A, 0xFF, 0x62,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xEC,
+    0xAD, 0x93, 0xFF, 0xAB, 0xF4, 0xFA, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x13,
+    0x74, 0xD9, 0xBC, 0x55,  0x4,  0x0,  0x0,  0x0, 0x50, 0xFE, 0xF9, 0x90, 0x55, 0x58, 0xA8, 0xFD,
+    0xFC, 0xF9, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x17, 0xF6, 0xC6,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5C, 0xFF, 0x79,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x60, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x3F, 0xF6, 0xF4, 0x89, 0x6C, 0xCC, 0xFF, 0x9F,  0x0,  0x0,  0x0, 0x33, 0xB5, 0xB5, 0xBE,
+    0xFF, 0xF7, 0xB5, 0xB5, 0xB5,  0x2,  0x0, 0x62, 0xFF, 0xF0, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5,
+    0x78,  0x0, 0x80, 0xF8, 0xB4, 0x7F, 0x76, 0xB5, 0xF6, 0xFF, 0xED, 0x1E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x32, 0xFF, 0xFF, 0x98,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0x43, 0x95,
+    0xD3, 0xF9, 0xFF, 0xE6, 0x80, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x42, 0xDA, 0xFF, 0xFF, 0xFF, 0xEE, 0x7D,
+    0x3E, 0xFB, 0xDC,  0xF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x91, 0xFF, 0x35,  0x0,  0x0,
+     0x0,  0x0, 0xCA, 0xEE,  0xE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xD6, 0xF8, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x43, 0xE4, 0xFF, 0xFF, 0xF6, 0x95,  0x2,  0x0,  0x0,  0x0, 0x48, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF,  0x3,  0x0, 0x78, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xA9,  0x0, 0x4F, 0xD7, 0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xB1, 0x2A,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x99, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x50, 0x76, 0x13,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x71, 0xFF, 0x5E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1C, 0x44, 0x34,  0x5,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1C, 0xF9, 0xA4,  0x0,  0x0,
+     0x0, 0x3A, 0xFF, 0x80,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x50, 0xFF, 0x9B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x21, 0x36,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x13, 0x38, 0x46, 0x2F,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x71, 0xFF, 0x5E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x99, 0xF9, 0x19,  0x0,
+     0x0, 0xA8, 0xF1, 0x12,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xB1, 0xE8, 0x27,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x71, 0xFF, 0x5E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xB7, 0x52,  0x0,
+     0xA, 0xB3, 0x6D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x3,  0x8,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x7,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x6, 0x32, 0x46, 0x25,  0x0,  0x0,  0x0,  0x0,  0x5,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,
+     0x8,  0x7,  0x0,  0x0,  0x0,  0x0,  0xF, 0x33, 0x33,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0xF, 0x39, 0x31,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0x19,  0x0,  0x0,
+     0x0,  0x4, 0x63, 0x7F, 0x16,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x1D, 0x36,  0x0,  0xB, 0x5C, 0x5C, 0x5C, 0x5C, 0x5C, 0x5C, 0x5C, 0x5C, 0x5C, 0x5C, 0x31,
+     0x0,  0xE, 0x43,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x1A, 0x43, 0x57, 0x35,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8,  0x8,  0x3,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x3,  0x8,  0x8,  0x8,  0x8,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0xC, 0x41, 0x30,  0xB,  0x0,  0x0,  0x5,  0x8,  0x8,  0x4,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x7,
+     0x0,  0x0,  0x5,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x1,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x4, 0x2F, 0x3E, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x5,  0x8,  0x3,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x8,  0x8,  0x0,  0x0,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x3,  0x0,  0x0,
+     0x0,  0x2,  0x8,  0x8,  0x8,  0x8,  0x8,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xB1,  0x0,  0x0,  0x0,  0x0, 0x10, 0xAE,
+    0xF8, 0xFF, 0xFF, 0xFF, 0xD9,  0x0,  0x0,  0x0, 0x99, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xDB,  0x0,  0x0,  0xC, 0x97, 0xFB, 0xFF, 0xFF, 0xE3, 0x93, 0x33,  0x0,  0x0,  0x0,  0x0,
+    0x13, 0xA6, 0xFF, 0xFF, 0xFF, 0xF5, 0x92,  0x3,  0x0,  0x0,  0x0,  0xD, 0xE2, 0xFC, 0x6F,  0x0,
+     0x0, 0x53, 0xFF, 0xFF, 0x9C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0x4F,
+    0xB4, 0xFC, 0x86,  0x0, 0x1D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x86,
+     0x0, 0x1D, 0xFF, 0xDC, 0x79, 0x18,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x33, 0xB6,
+    0xFE, 0xFF, 0xFF, 0xFF, 0xDE, 0x3B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0x71, 0xB2, 0xE8, 0xF7,
+    0xD0, 0x74,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x30, 0xFF, 0xFF, 0x99,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xFF, 0xFF, 0xFF, 0xF9, 0xDC, 0x9B, 0x22,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x1, 0x6D, 0xBC, 0xF9, 0xFF, 0xFF, 0xF2, 0x82,  0x0, 0x9E, 0xFF, 0xFF, 0xFC, 0xDD, 0xB6,
+    0x79,  0x7,  0x0,  0x0,  0x0,  0x0,  0x4, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE6,
+     0x0,  0x0, 0xA7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x32,  0x0,  0x0,  0x0, 0x13,
+    0xB0, 0xF6, 0xFF, 0xFF, 0xFF, 0xC2, 0x39,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0, 0xF9, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x5E,  0x0,  0x0,
+     0x0, 0x3E, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xFA, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0x79,  0x0,  0x0,  0x0, 0x1B, 0xD8, 0xFF,
+    0xCE, 0x8C, 0x6F, 0x97, 0xE8,  0x0,  0x0,  0x0, 0x68, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0xD4,
+    0xFF, 0x9C,  0x0,  0x0, 0x70, 0xFF, 0xDD, 0x82, 0x62, 0xB6, 0xFC, 0xF1, 0x24,  0x0,  0x0,  0x1,
+    0xC3, 0xFF, 0xC0, 0x60, 0x66, 0xC6, 0xFF, 0xA0,  0x0,  0x0,  0x0, 0x4B, 0xFF, 0xFF, 0xC6,  0x0,
+     0x0, 0x6B, 0xFF, 0xFF, 0xB4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x21, 0x84, 0xE5, 0xFF,
+    0xFC, 0xB6, 0x39,  0x0,  0xA, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x59, 0x2F,
+     0x0,  0xA, 0x8E, 0xE9, 0xFF, 0xFA, 0xAE, 0x49,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0, 0x8B, 0xF0,
+    0x97, 0x60, 0x6A, 0xC0, 0xFF, 0xC3,  0x0,  0x0,  0x0,  0x0, 0x17, 0xBF, 0xFB, 0xBE, 0x83, 0x8D,
+    0xE9, 0xFF, 0xB3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7E, 0xFF, 0xFB, 0xE6,  0x2,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xE5, 0xA3, 0xA3, 0xBD, 0xF4, 0xFF, 0xF0, 0x25,  0x0,  0x0,  0x0,
+     0x0, 0x77, 0xFF, 0xE1, 0x8F, 0x66, 0x8A, 0xD6, 0xCD,  0x0, 0x9E, 0xFF, 0xCA, 0xAA, 0xCC, 0xF2,
+    0xFF, 0xD4, 0x31,  0x0,  0x0,  0x0,  0x4, 0xFF, 0xFF, 0xAF, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0x9D,
+     0x0,  0x0, 0xA7, 0xFF, 0xCD, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0x22,  0x0,  0x0, 0x29, 0xDF,
+    0xFF, 0xC0, 0x7B, 0x63, 0x95, 0xF1, 0x99,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0, 0xAA, 0xAE, 0xAE, 0xF1, 0xFF, 0xBF, 0xAE, 0xAE, 0x40,  0x0,  0x0,
+     0x0, 0x2B, 0xAE, 0xAE, 0xAE, 0xCF, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xEF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x90, 0xFF, 0x9B,
+     0x4,  0x0,  0x0,  0x0,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB9,
+    0xFF, 0x3B,  0x0,  0x2, 0xDC, 0xFF, 0x23,  0x0,  0x0,  0x0, 0xB7, 0xFF, 0x89,  0x0,  0x0, 0x4E,
+    0xFF, 0xE1,  0x4,  0x0,  0x0,  0x5, 0xE0, 0xFE, 0x23,  0x0,  0x0,  0xC, 0xDD, 0xFA, 0x6A,  0x0,
+     0x0, 0x10, 0xA7, 0xC3, 0x36,  0x0,  0x0,  0x0,  0x0,  0x6, 0x55, 0xBA, 0xFD, 0xFF, 0xD6, 0x77,
+    0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x5, 0x4F, 0xAF, 0xFA, 0xFF, 0xE1, 0x7E, 0x1D,  0x0,  0x0,  0x0, 0x42, 0x13,
+     0x0,  0x0,  0x0,  0x1, 0xC7, 0xFF, 0x32,  0x0,  0x0,  0xC, 0xE1, 0xEF, 0x44,  0x0,  0x0,  0x0,
+     0x5, 0x83, 0xFF, 0x55,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCC, 0xF6, 0x9F, 0xFF, 0x37,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x5, 0xA8, 0xFF, 0xAA,  0x0,  0x0,  0x0,
+    0x42, 0xFC, 0xD3, 0x18,  0x0,  0x0,  0x0,  0x4, 0x4C,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0xC,
+    0x94, 0xFF, 0xD9,  0x4,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBB, 0xFF,
+    0x8E,  0x1,  0x0,  0x0,  0x0, 0x18, 0x5F,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xEF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE, 0xF1, 0xEA,  0x7,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x20, 0xFD,
+    0xD8,  0x1,  0x0, 0x3F, 0xFF, 0xCE,  0x0,  0x0,  0x0,  0x0, 0x65, 0xFF, 0xAD,  0x0,  0x0, 0x8A,
+    0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0, 0x97, 0xFF, 0x84,  0x0,  0x0,  0x0,  0x2, 0x14,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x9, 0x8A, 0xE9, 0xFF, 0xEF, 0x98, 0x38,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x8, 0x46, 0x46, 0x46, 0x46, 0x46, 0x46, 0x46, 0x46, 0x46, 0x46, 0x25,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x15, 0x70, 0xD0, 0xFF, 0xFC, 0xB4, 0x36,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xA3, 0xFF, 0x61,  0x0,  0x0, 0x7A, 0xFD, 0x31,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x15, 0xFB, 0xA5,  0x0,  0x0,  0x0,  0x0, 0x1C, 0xFE, 0xB7, 0x50, 0xFF, 0x85,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x0, 0x44, 0xFF, 0xDB,  0x0,  0x0,  0x0,
+    0xE0, 0xFF, 0x45,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0xC2, 0xFF, 0x64,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x39, 0xFF, 0xE4,
+     0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xEF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x53, 0xFF, 0x8B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x82, 0xFF,
+    0x78,  0x0,  0x0, 0x2E, 0xFF, 0xEC,  0x5,  0x0,  0x0,  0x0, 0x88, 0xFF, 0x6F,  0x0,  0x0, 0xAC,
+    0xFF, 0x5F,  0x0,  0x0,  0x0,  0x0, 0x52, 0xFF, 0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1D, 0xFF, 0xFF, 0x9A,  0xA,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x1D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x86,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4D, 0xEC, 0xFF, 0x86,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x1B, 0xEC, 0xF9, 0x21,  0x0, 0x10, 0xEE, 0xA8,  0x0,  0x0,  0x6, 0x7B, 0xBC,
+    0xAA, 0x4D, 0xB8, 0xCC,  0x0,  0x0,  0x0,  0x0, 0x69, 0xFF, 0x70,  0xE, 0xFA, 0xD3,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x0, 0x61, 0xFF, 0xD3,  0x0,  0x0, 0x15,
+    0xFF, 0xE1,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0x5A, 0xFF, 0xC3,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x99, 0xFF, 0x86,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xF9, 0xC6, 0xE8, 0xCB, 0x9D, 0x2A,  0x0,  0x0,  0x0, 0x6F, 0xFF, 0x60, 0x47,
+    0xA6, 0xDF, 0xC5, 0x83,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0xE3, 0xFC,
+    0x1B,  0x0,  0x0,  0x0, 0xBC, 0xFF, 0x71,  0x8,  0x0, 0x2D, 0xE1, 0xF6, 0x1D,  0x0,  0x0, 0xA0,
+    0xFF, 0x75,  0x0,  0x0,  0x0,  0x0, 0x6A, 0xFF, 0xD9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xB, 0x9D, 0xF4, 0xFF, 0xDF, 0x82, 0x24,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0xD, 0x74, 0x74, 0x74, 0x74, 0x74, 0x74, 0x74, 0x74, 0x74, 0x74, 0x3D,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xA, 0x5B, 0xBA, 0xFD, 0xFF, 0xC7, 0x40,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x17, 0xD4, 0xFF, 0x66,  0x0,  0x0, 0x54, 0xFF, 0x34,  0x0,  0x4, 0xC5, 0xFF, 0xE4,
+    0xD6, 0xFD, 0xDE, 0xDB,  0x0,  0x0,  0x0,  0x0, 0xB8, 0xFF, 0x29,  0x0, 0xC1, 0xFF, 0x22,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x3, 0x36, 0xCC, 0xFF, 0x82,  0x0,  0x0, 0x41,
+    0xFF, 0xB4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0x30, 0xFF, 0xE3,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBA, 0xFF, 0x56,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xEA, 0xF7, 0xD8, 0xD5, 0xF8, 0xFF, 0xF8, 0x60,  0x0,  0x0, 0x89, 0xFF, 0x87, 0xF9,
+    0xD7, 0xB4, 0xE4, 0xFF, 0xD0,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF, 0xB6,
+     0x0,  0x0,  0x0,  0x0,  0xD, 0x9E, 0xF7, 0xF1, 0xDA, 0xFF, 0xB4, 0x22,  0x0,  0x0,  0x0, 0x7C,
+    0xFF, 0xB8,  0x0,  0x0,  0x0,  0x0, 0xB2, 0xFF, 0xF0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE, 0x68, 0xCC, 0xFF, 0xFE, 0xC3, 0x64,
+     0xE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x1, 0x3C, 0x9B, 0xF1, 0xFF, 0xEE, 0x91, 0x2D,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x8, 0xCE, 0xFF, 0x84,  0x0,  0x0,  0x0, 0x76, 0xFD,  0x7,  0x0, 0x69, 0xFF, 0x7E,  0x6,
+     0x1, 0x56, 0xFF, 0xDB,  0x0,  0x0,  0x0,  0xD, 0xF8, 0xE0,  0x0,  0x0, 0x7A, 0xFF, 0x70,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xF3, 0xD3, 0xD3, 0xEE, 0xFF, 0xE4, 0x84,  0x5,  0x0,  0x0, 0x6D,
+    0xFF, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0x19, 0xFF, 0xFD,  0x6,  0x0,  0x4, 0xFF, 0xFF, 0xDE, 0xDE, 0xDE, 0xDE, 0xDE, 0xDE, 0x85,
+     0x0,  0x0, 0xA7, 0xFF, 0xCE, 0xAF, 0xAF, 0xAF, 0xAF, 0xAF, 0x62,  0x0,  0x0, 0xD9, 0xFF, 0x3E,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0xEC, 0xDE, 0xDE, 0xDE, 0xDE,
+    0xDE, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x51,  0xA,  0x0,  0x0,  0x9, 0x84, 0xFF, 0xF3, 0x1E,  0x0, 0xA1, 0xFF, 0xFD, 0x7A,
+     0x0,  0x0,  0x9, 0xBC, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x55,
+     0x0,  0x0,  0x0,  0x0,  0x5, 0x71, 0xF0, 0xFE, 0xF3, 0xFF, 0xAF, 0x22,  0x0,  0x0,  0x0, 0x26,
+    0xFA, 0xF4, 0x50,  0x3,  0x0, 0x31, 0xF4, 0xFD, 0xFA,  0x0,  0x0,  0x5, 0xB1, 0xE0, 0x4D,  0x0,
+     0x0,  0xB, 0xD4, 0xE5, 0x5C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x32, 0x97, 0xF1, 0xFF,
+    0xF5, 0xA4, 0x30,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x8, 0x7D, 0xDB, 0xFF, 0xFE, 0xC1, 0x5C,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x91, 0xFF, 0x98,  0x0,  0x0,  0x0,  0x0, 0x98, 0xE4,  0x0,  0x0, 0xB2, 0xF8,  0xD,  0x0,
+     0x0,  0x0, 0xDC, 0xDB,  0x0,  0x0,  0x0, 0x55, 0xFF, 0x99,  0x0,  0x0, 0x33, 0xFF, 0xBE,  0x0,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xF2, 0xD2, 0xD2, 0xEB, 0xFF, 0xE3, 0x63,  0x3,  0x0,  0x0, 0x90,
+    0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0,  0x8, 0xFF, 0xFF, 0x1A,  0x0,  0x4, 0xFF, 0xFF, 0xD7, 0xD7, 0xD7, 0xD7, 0xD7, 0xD7, 0x81,
+     0x0,  0x0, 0xA7, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x8F,  0x0,  0x0, 0xF0, 0xFF, 0x2C,
+     0x0,  0x0,  0x2, 0xB5, 0xB5, 0xB5, 0xB5, 0x15,  0x0, 0x9E, 0xFF, 0xE8, 0xD7, 0xD7, 0xD7, 0xD7,
+    0xD7, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAF, 0xFF, 0x7E,  0x0, 0x97, 0xFF, 0xDA,  0x2,
+     0x0,  0x0,  0x0, 0x5D, 0xFF, 0xD6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x19, 0xFB, 0xEC,  0x7,
+     0x0,  0x0,  0x0,  0x2, 0xD2, 0xFF, 0x7D, 0x1F,  0x8, 0x55, 0xD3, 0xF8, 0x48,  0x0,  0x0,  0x0,
+    0x80, 0xFF, 0xFF, 0xDC, 0xC4, 0xF8, 0xB0, 0xEF, 0xE3,  0x0,  0x0, 0x47, 0xFF, 0xFF, 0xC2,  0x0,
+     0x0, 0x51, 0xFF, 0xFF, 0xE3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xB, 0x62,
+    0xC7, 0xFF, 0x86,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x1D, 0xFF, 0xEA, 0x8B, 0x27,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xE5, 0xF7,  0x8,  0x0,  0x0,  0x0,  0x0, 0xB1, 0xCD,  0x0,  0x0, 0xCF, 0xC9,  0x0,  0x0,
+     0x0,  0x0, 0xA0, 0xDB,  0x0,  0x0,  0x0, 0xA3, 0xFF, 0x52,  0x0,  0x0,  0x2, 0xE9, 0xFB, 0x11,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x2, 0x33, 0x9A, 0xFF, 0xA9,  0x0,  0x0, 0x7A,
+    0xFF, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0x1A, 0xFF, 0xFC,  0x6,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x67,  0x6,  0x6,  0x6,  0x6,  0x6,  0x3,  0x0,  0x0, 0xDA, 0xFF, 0x3D,
+     0x0,  0x0,  0x3, 0xF5, 0xF5, 0xFD, 0xFF, 0x1D,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x66, 0xFF, 0xA7,  0x0, 0x80, 0xFF, 0xA6,  0x0,
+     0x0,  0x0,  0x0, 0x1B, 0xFF, 0xF8,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x79, 0xFF, 0x92,  0x0,
+     0x0,  0x0,  0x0, 0x3A, 0xFF, 0xCC,  0x0,  0x0,  0x0,  0x0, 0x36, 0xFF, 0xA6,  0x0,  0x0,  0x0,
+     0x0, 0x50, 0xB3, 0xDD, 0xBA, 0x75, 0x10, 0xFF, 0xCA,  0x0,  0x0, 0x18, 0xF9, 0xFF, 0x8E,  0x0,
+     0x0, 0x14, 0xE3, 0xFF, 0xFF, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x2D, 0x40,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x10, 0x56,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x6, 0xFF, 0xE2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x93, 0xE8,  0x0,  0x0, 0xB1, 0xF7,  0xC,  0x0,
+     0x0,  0x0, 0xDA, 0xDB,  0x0,  0x0,  0x4, 0xED, 0xFF, 0x99, 0x8B, 0x8B, 0x8B, 0xDE, 0xFF, 0x5B,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x0, 0x10, 0xF7, 0xF7,  0xC,  0x0, 0x5B,
+    0xFF, 0xB4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0x31, 0xFF, 0xE3,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBB, 0xFF, 0x53,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x1D,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x68, 0xFF, 0x9F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x66, 0xFF, 0xB3,  0x0, 0x69, 0xFF, 0x9B,  0x0,
+     0x0,  0x0,  0x0,  0xA, 0xFA, 0xFD,  0x7,  0x0,  0x0,  0x0,  0x0,  0x1, 0xDC, 0xFF, 0x32,  0x0,
+     0x0,  0x0,  0x0, 0x89, 0xFF, 0x79,  0x0,  0x0,  0x0,  0x0,  0x5, 0xFC, 0xEF,  0x5,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x35, 0xFF, 0xAE,  0x0,  0x0,  0x0, 0x1C, 0x44,  0x2,  0x0,
+     0x0,  0x0,  0x2, 0x94, 0xE6,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x2, 0x3F, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6E, 0xFF,  0xC,  0x0, 0x6F, 0xFF, 0x76,  0x3,
+     0x0, 0x51, 0xFF, 0xDB,  0x0,  0x0, 0x40, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xA9,
+     0x0,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB3, 0xFF, 0x52,  0x0, 0x3A,
+    0xFF, 0xE2,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0x5E, 0xFF, 0xC2,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9A, 0xFF, 0x7F,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x1D,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x71, 0xFF, 0x8F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC0, 0xFF, 0x8A,  0x0, 0x2B, 0xFF, 0xBF,  0x0,
+     0x0,  0x0,  0x0, 0x46, 0xFF, 0xE2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xFF, 0xD0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x96, 0xFF, 0x9D,  0x0,  0x0,  0x0,  0x0, 0x32, 0xFF, 0xF8,  0x5,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x99, 0xFF, 0x59,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xD8, 0x99,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x3B,  0x0,  0x6, 0xBD, 0xFF, 0xDD,
+    0xCE, 0xFE, 0xE2, 0xDB,  0x0,  0x0, 0x8E, 0xFF, 0x7B, 0x15, 0x15, 0x15, 0x15, 0x23, 0xFC, 0xF1,
+     0x6,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x0,  0x3, 0xE4, 0xFF, 0x54,  0x0,  0x3,
+    0xD8, 0xFF, 0x47,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0, 0xC8, 0xFF, 0x60,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3A, 0xFF, 0xDE,
+     0x1,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x1D,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x87, 0xFF, 0x69,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x2B,  0xC,  0x0,  0x0,  0x0,  0x0, 0x3E, 0xFE, 0xFF, 0x42,  0x0,  0x0, 0xC7, 0xFD, 0x32,
+     0x0,  0x0,  0x0, 0x92, 0xFF, 0xA6,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA7, 0xFF, 0x6F,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x67, 0xFF, 0xEE,  0x8,  0x0,  0x0,  0x0, 0x8C, 0xFF, 0xCC,  0x0,  0x0,  0x0,
+     0xA,  0x0,  0x0,  0x0,  0x0, 0x51, 0xF7, 0xE4,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x3, 0x83, 0xFE, 0x43,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x9, 0x88, 0x70,  0x3,  0x0,  0x0,  0x0,  0x0,  0xF, 0xEB, 0xB6,  0x0,  0x0,  0x0, 0x37, 0xA8,
+    0xB4, 0x57, 0x61, 0x8C,  0x0,  0x0, 0xDC, 0xFF, 0x2C,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC5, 0xFF,
+    0x46,  0x0,  0x0, 0x54, 0xFF, 0xB6,  0x0,  0x0,  0x0,  0x9, 0x66, 0xFF, 0xFB, 0x20,  0x0,  0x0,
+    0x5F, 0xFF, 0xD5, 0x1B,  0x0,  0x0,  0x0,  0x4, 0x4E,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0xE,
+    0x9E, 0xFF, 0xD6,  0x3,  0x0,  0x0,  0x4, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF,
+    0x84,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x1D,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0, 0xA1,
+    0x21,  0x0,  0x0,  0x0,  0x2, 0xD1, 0xFF, 0x42,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x8F, 0xF1, 0xA7, 0x82, 0x89, 0xDC, 0xFF, 0xFF, 0x9F,  0x0,  0x0,  0x0, 0x4A, 0xFA, 0xEE,
+    0x7B, 0x66, 0xCC, 0xFF, 0xF8, 0x23,  0x0,  0x0,  0x0,  0x0, 0x13, 0xF8, 0xF9, 0x14,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x9, 0xD4, 0xFF, 0xE9, 0x90, 0x6A, 0xC3, 0xFE, 0xFD, 0x45,  0x0,  0x0,  0x0,
+    0x9D, 0xB9, 0x83, 0x7D, 0xB2, 0xFC, 0xF7, 0x52,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xBB, 0xE6, 0x47,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x5D, 0xFF, 0xFF, 0x48,  0x0,  0x0,  0x0,  0x0,  0x0, 0x69, 0xFF, 0x43,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x2B, 0xFF, 0xE3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7C, 0xFF,
+    0x94,  0x0,  0x0, 0x54, 0xFF, 0xE7, 0xAB, 0xAB, 0xC3, 0xF9, 0xFF, 0xFF, 0x75,  0x0,  0x0,  0x0,
+     0x4, 0xA7, 0xFF, 0xE4, 0x93, 0x68, 0x8C, 0xD6, 0xCD,  0x0, 0x9E, 0xFF, 0xCF, 0xB2, 0xD4, 0xF7,
+    0xFF, 0xCF, 0x2D,  0x0,  0x0,  0x0,  0x4, 0xFF, 0xFF, 0xB7, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5,
+    0x11,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2B, 0xE0,
+    0xFF, 0xBD, 0x7B, 0x6B, 0xA3, 0xFD, 0xF9, 0x17,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0, 0xB1, 0xB5, 0xB5, 0xF2, 0xFF, 0xC5, 0xB5, 0xB5, 0x43,  0x0, 0xE6,
+    0xF4, 0xAA, 0x77, 0x6E, 0xBF, 0xFF, 0xCA,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x6A, 0xF4, 0xFF, 0xFF, 0xFF, 0xFF, 0xEE, 0x6F,  0x4,  0x0,  0x0,  0x0,  0x0, 0x4A, 0xE4,
+    0xFF, 0xFF, 0xFF, 0xD5, 0x36,  0x0,  0x0,  0x0,  0x0,  0x0, 0x70, 0xFF, 0xAC,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x1E, 0xA6, 0xFE, 0xFF, 0xFF, 0xFF, 0xD8, 0x4E,  0x0,  0x0,  0x0,  0x0,
+    0x7D, 0xFA, 0xFF, 0xFF, 0xFD, 0xD3, 0x37,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x69, 0x15,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x43, 0xFF, 0xFF, 0x2F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0xD1, 0xF4, 0x5C,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x79, 0xFF, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x34, 0xFF,
+    0xE2,  0x1,  0x0, 0x54, 0xFF, 0xFF, 0xFF, 0xFF, 0xF4, 0xD7, 0xAC, 0x3D,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x73, 0xE6, 0xFF, 0xFF, 0xFF, 0xEF, 0x80,  0x0, 0x9E, 0xFF, 0xFF, 0xF9, 0xD5, 0xAE,
+    0x71,  0x5,  0x0,  0x0,  0x0,  0x0,  0x4, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x18,  0x0, 0xA7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x15,
+    0xB2, 0xF5, 0xFF, 0xFF, 0xFD, 0xBE, 0x2A,  0x0,  0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,
+     0x3, 0xFF, 0xFF,  0x9,  0x0, 0xF9, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x5E,  0x0, 0x7D,
+    0xDD, 0xFF, 0xFF, 0xFF, 0xFB, 0xC7, 0x16,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x4, 0x29, 0x42, 0x41, 0x1D,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x1C, 0x3E, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xF, 0x32, 0x3E, 0x1C,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xF, 0x3D, 0x3E,  0xF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x36, 0x2E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x12, 0xA8, 0xFF, 0xB9, 0x7E, 0x4D,
+    0x5A, 0x89, 0x1C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x1A, 0x41, 0x2C,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x4, 0x2E, 0x36,  0xB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x1, 0x1E, 0x3B, 0x35,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5A, 0xBF, 0xED, 0xFF,
+    0xFF, 0xFC, 0x59,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1E,
+    0x2F,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x5,  0x8,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2,  0x8,  0x8,  0x1,  0x0,  0x6,  0x8,
+     0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1,  0x8,  0x8,  0x5,  0x0,  0x0,  0x0,
+     0x0,  0x2,  0x8,  0x8,  0x4,  0x0,  0x5,  0x8,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x7,  0x8,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0x26, 0x3B,  0xB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2,  0x8,
+     0x8,  0x8,  0x8,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0x26, 0x3B,
+     0xB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4,  0x8,  0x8,  0x8,  0x6,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8, 0x2C, 0x45, 0x2E,  0xB,  0x0,  0x0,  0x0,  0x0,
+     0x4,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x7,  0x0,  0x4,  0x8,  0x4,
+     0x0,  0x0,  0x0,  0x0,  0x1,  0x8,  0x7,  0x0,  0x3,  0x8,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x2,  0x8,  0x6,  0x0,  0x8,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4,
+     0x8,  0x3,  0x0,  0x1,  0x8,  0x8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2,  0x8,  0x7,  0x0,
+     0x0,  0x5,  0x8,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1,  0x8,  0x8,  0x0,  0x0,  0x5,
+     0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x8,  0x1,  0x0, 0x5A, 0x78, 0x78, 0x78, 0x78,
+    0x78, 0x40,  0x0,  0x5,  0x8,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE,
+    0x75, 0x75, 0x75, 0x75, 0x75, 0x75, 0x13,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5,  0x8,  0x1,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x6, 0xB4, 0xFF, 0xA5,  0x2,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xFF, 0xC3,  0x0,  0x0,  0x0,
+     0x0, 0x62, 0xFF, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0xFF, 0x31,  0x0,  0x0,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0,  0x0,  0x0, 0x5D, 0xEC, 0xFF, 0xFF, 0xFB, 0xAF,  0xB,  0x0,  0x0,  0x0, 0x44, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xF8, 0xD9, 0xA1, 0x24,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5D, 0xEC, 0xFF, 0xFF,
+    0xFB, 0xAF,  0xB,  0x0,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0xFF, 0xFF, 0xFF, 0xEA, 0xC8, 0x65,  0x4,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0xC, 0x87, 0xF8, 0xFF, 0xFF, 0xFF, 0xFA, 0xBD, 0x1A,  0x0,  0x0,
+    0x86, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0, 0x45, 0xFF, 0xD1,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x67, 0xFF, 0xAE,  0x0, 0xEB, 0xFF,  0xC,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA1,
+    0xFF, 0x55,  0x0,  0x3, 0xCB, 0xFF, 0x5C,  0x0,  0x0,  0x0,  0x0,  0x0, 0x99, 0xFF, 0x8F,  0x0,
+     0x0, 0x52, 0xFF, 0xD1,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF, 0xBA,  0x0,  0x0, 0xAB,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x15,  0x0, 0xBF, 0xFF, 0xF8, 0xF7, 0xF7,
+    0xF7, 0x84,  0x0, 0x76, 0xFF, 0x75,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1E,
+    0xFA, 0xFA, 0xFA, 0xFA, 0xFD, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2B, 0xF2, 0xFF, 0x88,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x3, 0xAA, 0xFF, 0xAF,  0x4,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xFC, 0xFD, 0x1C,  0x0,  0x0,
+     0x0, 0xB9, 0xFC, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0xFF, 0x9A,  0x0,  0x0,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0,  0x0, 0x63, 0xFE, 0xEC, 0x89, 0x70, 0xC3, 0xFF, 0xC7,  0x6,  0x0,  0x0, 0x44, 0xFF,
+    0xE9, 0xA3, 0xA3, 0xC4, 0xFD, 0xFF, 0xFA, 0x37,  0x0,  0x0,  0x0, 0x63, 0xFE, 0xEC, 0x89, 0x70,
+    0xC3, 0xFF, 0xC7,  0x6,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0xD0, 0xA3, 0xA6, 0xC3, 0xFB, 0xFF, 0xB6,
+     0x1,  0x0,  0x0,  0x0,  0x0, 0xAD, 0xFF, 0xEA, 0xA1, 0x67, 0x84, 0xBB, 0xFD, 0x30,  0x0,  0x0,
+    0x5C, 0xAE, 0xAE, 0xAE, 0xAE, 0xEF, 0xFF, 0xC1, 0xAE, 0xAE, 0xAE, 0xA3,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x7, 0xF3, 0xFE, 0x16,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xAB, 0xFF, 0x64,  0x0, 0xC5, 0xFF, 0x2A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF,
+    0xFF, 0x2F,  0x0,  0x0, 0x34, 0xFC, 0xE4,  0xC,  0x0,  0x0,  0x0, 0x34, 0xFD, 0xE0,  0xC,  0x0,
+     0x0,  0x0, 0xBD, 0xFF, 0x61,  0x0,  0x0,  0x0,  0x0, 0x10, 0xEC, 0xFB, 0x2B,  0x0,  0x0, 0x75,
+    0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0xAE, 0xCB, 0xFF, 0xF4,  0xC,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0xE, 0xF0, 0xE5,  0x7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0, 0x13, 0xDC, 0xFB, 0xDD, 0xFE,
+    0x5A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x1, 0x9F, 0xFF, 0xB9,  0x7,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xC7, 0xF4, 0x71,  0x0,  0x0,
+    0x15, 0xFB, 0xBC, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0xF8, 0xF4,  0xF,  0x0,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0,  0x3, 0xE4, 0xFC, 0x3D,  0x0,  0x0,  0x7, 0xC7, 0xFF, 0x54,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0, 0x1B, 0xB4, 0xFF, 0xD4,  0x0,  0x0,  0x3, 0xE4, 0xFC, 0x3D,  0x0,  0x0,
+     0x7, 0xC7, 0xFF, 0x54,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0, 0x2E, 0xFC, 0xFF,
+    0x5D,  0x0,  0x0,  0x0, 0x45, 0xFF, 0xEC,  0xA,  0x0,  0x0,  0x0,  0x0, 0x2D, 0x18,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0, 0xAF, 0xFF, 0x59,  0x0,  0x0,  0x0,  0x0,
+     0x2, 0xEC, 0xFE, 0x1A,  0x0, 0x9F, 0xFF, 0x48,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xDD,
+    0xFE,  0xA,  0x0,  0x0,  0x0, 0x93, 0xFF, 0x86,  0x0,  0x0,  0x1, 0xC7, 0xFF, 0x4A,  0x0,  0x0,
+     0x0,  0x0, 0x2D, 0xFB, 0xE4,  0xA,  0x0,  0x0,  0x0, 0x89, 0xFF, 0x91,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xC5, 0xFF, 0x6E,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x87, 0xFF, 0x64,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x4, 0xBD, 0xFE, 0x5F, 0x19, 0xDC,
+    0xF6, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0, 0x93, 0xFF, 0xC2,  0xA,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC, 0xA8, 0xC7,  0x0,  0x0,
+    0x68, 0xF9, 0x6C, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0xA4, 0xFF, 0x6C,  0x0,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0, 0x4B, 0xFF, 0xC9,  0x0,  0x0,  0x0,  0x0, 0x5D, 0xFF, 0xB5,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0, 0x40, 0xFF, 0xFF, 0x14,  0x0, 0x4B, 0xFF, 0xC9,  0x0,  0x0,  0x0,
+     0x0, 0x5D, 0xFF, 0xB5,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0, 0xB7, 0xFF,
+    0x99,  0x0,  0x0,  0x0, 0x7B, 0xFF, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0, 0x64, 0xFF, 0x9C,  0x0,  0x0,  0x0,  0x0,
+    0x33, 0xFF, 0xCD,  0x0,  0x0, 0x79, 0xFF, 0x66,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xF9,
+    0xE2,  0x0,  0x0,  0x0,  0x0,  0xF, 0xE6, 0xF8, 0x23,  0x0, 0x61, 0xFF, 0xA6,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x94, 0xFF, 0x7C,  0x0,  0x0, 0x1F, 0xF7, 0xEB, 0x11,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x6E, 0xFF, 0xC1,  0x2,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x18, 0xF7, 0xD9,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0, 0x93, 0xFF, 0x71,  0x0,  0x0, 0x22,
+    0xE6, 0xE3, 0x19,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0, 0x87, 0xFF, 0xCA,  0xE,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC, 0x51, 0xFE, 0x20,  0x0,
+    0xC0, 0xB4, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5E, 0xDC, 0xD4,  0x0,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0, 0x8C, 0xFF, 0x88,  0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xF6,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x4, 0xF7, 0xFF, 0x31,  0x0, 0x8C, 0xFF, 0x88,  0x0,  0x0,  0x0,
+     0x0, 0x1C, 0xFF, 0xF6,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0, 0x80, 0xFF,
+    0xB2,  0x0,  0x0,  0x0, 0x76, 0xFF, 0x76,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0, 0x1A, 0xFE, 0xDF,  0x0,  0x0,  0x0,  0x0,
+    0x77, 0xFF, 0x83,  0x0,  0x0, 0x52, 0xFF, 0x84,  0x0,  0x2, 0xDB, 0xE6, 0x52,  0x0, 0x1B, 0xFF,
+    0xBC,  0x0,  0x0,  0x0,  0x0,  0x0, 0x58, 0xFF, 0xB0, 0x10, 0xE8, 0xED, 0x16,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x13, 0xED, 0xF2, 0x17,  0x0, 0xA3, 0xFF, 0x67,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x20, 0xF3, 0xF4, 0x23,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x97, 0xFF, 0x53,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0, 0x64, 0xFF, 0x83,  0x0,  0x0,  0x0,  0x0,
+    0x2C, 0xED, 0xC6,  0x7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C, 0x7A, 0xFF, 0xD2, 0x12,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x8, 0xF1, 0x76, 0x1A,
+    0xFD, 0x5E, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C, 0x75, 0xFF, 0x3E,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0, 0xA3, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x3, 0xFE, 0xFF,  0xE,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0xFF, 0x11,  0x0, 0xA3, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0,  0x3, 0xFE, 0xFF,  0xE,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0, 0xD1, 0xFF,
+    0x8B,  0x0,  0x0,  0x0, 0x1C, 0xFC, 0xEC, 0x67,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0,  0x0, 0xCE, 0xFF, 0x24,  0x0,  0x0,  0x0,
+    0xBB, 0xFF, 0x38,  0x0,  0x0, 0x2C, 0xFF, 0xA2,  0x0, 0x2A, 0xFF, 0xFF, 0x8E,  0x0, 0x39, 0xFF,
+    0x96,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBA, 0xFF, 0xC2, 0xFF, 0x60,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x6C, 0xFF, 0x97, 0x32, 0xFD, 0xCF,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x1, 0xBA, 0xFF, 0x6D,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x23, 0xFC, 0xCA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0xC, 0x8D, 0x73,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x37, 0x93, 0x42,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0xCB, 0xFF, 0xFF, 0x95,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0, 0xA3, 0xCC, 0x6F,
+    0xF8,  0xF, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C, 0x14, 0xF7, 0xA7,  0x0,  0x0, 0xF2, 0xFF,
+     0x3,  0x0, 0xBB, 0xFF, 0x5C,  0x0,  0x0,  0x0,  0x0,  0x0, 0xF2, 0xFF, 0x25,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0xE, 0xBC, 0xFF, 0xCE,  0x0,  0x0, 0xBB, 0xFF, 0x5C,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xF2, 0xFF, 0x25,  0x0,  0x0, 0x8F, 0xFF, 0x7E,  0x6,  0x9, 0x43, 0x94, 0xFF, 0xEA,
+    0x1D,  0x0,  0x0,  0x0,  0x0, 0xAE, 0xFF, 0xFF, 0xED, 0xA6, 0x49,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0,  0x0, 0x83, 0xFF, 0x67,  0x0,  0x0,  0x8,
+    0xF6, 0xEA,  0x2,  0x0,  0x0,  0x8, 0xFD, 0xC0,  0x0, 0x60, 0xFF, 0xCD, 0xC5,  0x0, 0x57, 0xFF,
+    0x70,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x25, 0xF7, 0xFF, 0xBC,  0x1,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x4, 0xD3, 0xFB, 0xD0, 0xFE, 0x3E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x61, 0xFF, 0xC0,  0x2,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA8, 0xFF, 0x43,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0xFF, 0xDA, 0xEB, 0xFE, 0x42,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0, 0x4D, 0xFF, 0xDE,
+    0xB0,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0, 0xA3, 0xF9, 0x17,  0x0, 0xF2, 0xFF,
+     0x3,  0x0, 0xCC, 0xFF, 0x51,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE7, 0xFF, 0x36,  0x0, 0x44, 0xFF,
+    0xEE, 0xB5, 0xB5, 0xC5, 0xF1, 0xFF, 0xF4, 0x30,  0x0,  0x0, 0xCC, 0xFF, 0x51,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xE7, 0xFF, 0x34,  0x0,  0x0, 0x8F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xF1, 0x79,  0xC,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0x76, 0xD8, 0xFF, 0xFF, 0xFF, 0xC7, 0x47,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0,  0x0, 0x39, 0xFF, 0xAB,  0x0,  0x0, 0x43,
+    0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0, 0xDF, 0xDE,  0x0, 0x96, 0xDF, 0x78, 0xF6,  0x5, 0x75, 0xFF,
+    0x49,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F, 0xF4, 0xFF, 0xAE,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x43, 0xFF, 0xFF, 0xA8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x19, 0xEE, 0xF4, 0x23,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x31, 0xFE, 0xBA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0xE2, 0x1F, 0x5B, 0xFF, 0xE0,  0xE,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x7, 0xEF, 0xFF,
+    0x5A,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0, 0x3A, 0xFF, 0x79,  0x0, 0xF2, 0xFF,
+     0x3,  0x0, 0xBB, 0xFF, 0x5D,  0x0,  0x0,  0x0,  0x0,  0x0, 0xF2, 0xFF, 0x25,  0x0, 0x44, 0xFF,
+    0xFD, 0xF5, 0xF5, 0xE8, 0xC8, 0x91, 0x1A,  0x0,  0x0,  0x0, 0xBB, 0xFF, 0x5D,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xF2, 0xFF, 0x17,  0x0,  0x0, 0x8F, 0xFF, 0xD1, 0xA5, 0xAA, 0xDB, 0xFD, 0x9E,  0xA,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x27, 0x67, 0xB7, 0xFF, 0xFF, 0x3E,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xEB,  0x0,  0x0,  0x0,  0x2, 0xEA, 0xEC,  0x2,  0x0, 0x87,
+    0xFF, 0x57,  0x0,  0x0,  0x0,  0x0, 0xB9, 0xFA,  0x3, 0xCC, 0xA5, 0x3E, 0xFF, 0x33, 0x93, 0xFF,
+    0x23,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB5, 0xFF, 0xDD, 0xFF, 0x49,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD7, 0xFF, 0x3C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xAD, 0xFF, 0x6D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB8, 0xFF, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6E,  0x0,  0x0, 0xB4, 0xFF, 0x9B,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x0, 0x70, 0xA4,
+     0xD,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0,  0x0, 0xD1, 0xDF,  0x3, 0xF2, 0xFF,
+     0x3,  0x0, 0xA4, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x3, 0xFE, 0xFF,  0xE,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA5, 0xFF, 0x6C,  0x0,  0x0,  0x0,
+     0x0,  0x3, 0xFE, 0xF1,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x2, 0x9E, 0xFF, 0x94,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x76, 0xFF, 0x95,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x82, 0xFF, 0x85,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xE8,  0x0,  0x0,  0x0,  0x0, 0xA2, 0xFF, 0x33,  0x0, 0xCA,
+    0xFB, 0x11,  0x0,  0x0,  0x0,  0x0, 0x93, 0xFF, 0x24, 0xFA, 0x6B,  0x9, 0xF9, 0x6A, 0xB1, 0xF9,
+     0x3,  0x0,  0x0,  0x0,  0x0,  0x0, 0x57, 0xFF, 0xC0, 0x21, 0xF7, 0xDB,  0x8,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x54, 0xFF, 0xC0,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x41, 0xFF, 0xA9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0, 0x1C, 0xF1, 0xFE, 0x49,  0x0,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0,  0x0, 0x68, 0xFF, 0x4B, 0xF2, 0xFF,
+     0x3,  0x0, 0x8D, 0xFF, 0x88,  0x0,  0x0,  0x0,  0x0, 0x1C, 0xFF, 0xF6,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x8E, 0xFF, 0x88,  0x0,  0x0,  0x0,
+     0x0, 0x1C, 0xFF, 0xCD,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0xC, 0xE7, 0xFC,
+    0x2A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x13, 0xFA, 0xE9,  0x2,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x79, 0xFF, 0x89,
+     0x0,  0x0,  0x0,  0x0, 0x20, 0xFF, 0xDF,  0x0,  0x0,  0x0,  0x0, 0x58, 0xFF, 0x76, 0x12, 0xFC,
+    0xC1,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6D, 0xFF, 0x72, 0xFF, 0x31,  0x0, 0xC8, 0xA0, 0xCF, 0xD6,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x11, 0xE7, 0xF9, 0x2A,  0x0, 0x86, 0xFF, 0x7F,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x12, 0xE7, 0xF4, 0x23,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC9, 0xFC, 0x24,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0, 0x67, 0xFF, 0xE4, 0x11,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0,  0x0,  0xD, 0xF2, 0xB4, 0xF2, 0xFF,
+     0x3,  0x0, 0x4D, 0xFF, 0xCA,  0x0,  0x0,  0x0,  0x0, 0x5E, 0xFF, 0xB4,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4A, 0xFF, 0xCA,  0x0,  0x0,  0x0,
+     0x0, 0x5E, 0xFF, 0xA8,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0, 0x6D, 0xFF,
+    0xAB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x21, 0xFC, 0xEF,  0x1,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x65, 0xFF, 0x95,
+     0x0,  0x0,  0x0,  0x0, 0x2C, 0xFF, 0xCD,  0x0,  0x0,  0x0,  0x0, 0x11, 0xFB, 0xBA, 0x53, 0xFF,
+    0x76,  0x0,  0x0,  0x0,  0x0,  0x0, 0x47, 0xFF, 0xC7, 0xF3,  0x3,  0x0, 0x8E, 0xD7, 0xED, 0xB0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x9B, 0xFF, 0x86,  0x0,  0x0,  0xD, 0xE7, 0xF7, 0x22,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xA0, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x52, 0xFF, 0x98,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x1, 0xBF, 0xFF, 0xA3,  0x0,  0x0,  0x0, 0xD5, 0xFF,
+    0x35,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0,  0x0,  0x0, 0x96, 0xFC, 0xFB, 0xFF,
+     0x3,  0x0,  0x4, 0xE6, 0xFD, 0x3E,  0x0,  0x0,  0x7, 0xC8, 0xFF, 0x53,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0xE4, 0xFD, 0x3E,  0x0,  0x0,
+     0x7, 0xC8, 0xFF, 0x67,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0,  0xA, 0xE9,
+    0xFD, 0x2D,  0x0,  0x0, 0x47, 0x55,  0x2,  0x0,  0x0,  0x0,  0x0, 0x8D, 0xFF, 0xC0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2F, 0xFF, 0xDB,
+    0x11,  0x0,  0x0,  0x0, 0x83, 0xFF, 0x95,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC1, 0xF6, 0x9E, 0xFF,
+    0x2C,  0x0,  0x0,  0x0,  0x0,  0x0, 0x21, 0xFF, 0xFE, 0xBC,  0x0,  0x0, 0x53, 0xFE, 0xFF, 0x8A,
+     0x0,  0x0,  0x0,  0x0, 0x3E, 0xFE, 0xDF,  0xA,  0x0,  0x0,  0x0, 0x62, 0xFF, 0xB4,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x47,
+    0xFF, 0xC0,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xD7, 0xF7, 0x18,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0, 0x24, 0xF5, 0xFF, 0x51,  0x0,  0x0, 0xD5, 0xFF,
+    0xC5, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0x55,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0,  0x0,  0x0, 0x2E, 0xFF, 0xFF, 0xFF,
+     0x3,  0x0,  0x0, 0x66, 0xFE, 0xED, 0x8C, 0x73, 0xC5, 0xFF, 0xC5,  0x6,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x62, 0xFD, 0xED, 0x8C, 0x73,
+    0xC5, 0xFF, 0xD3,  0x4,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7A,
+    0xFF, 0xAA,  0x0,  0x0, 0x68, 0xFF, 0xD6, 0x95, 0x6C, 0x8B, 0xD3, 0xFF, 0xFB, 0x3B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB8, 0xFF,
+    0xD7, 0x80, 0x6D, 0xA9, 0xFF, 0xEF, 0x17,  0x0,  0x0,  0x0,  0x0,  0x0, 0x77, 0xFF, 0xF9, 0xE0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xF7, 0xFF, 0x82,  0x0,  0x0, 0x19, 0xFF, 0xFF, 0x64,
+     0x0,  0x0,  0x0,  0x7, 0xD7, 0xFF, 0x4C,  0x0,  0x0,  0x0,  0x0,  0x2, 0xCE, 0xFF, 0x50,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3,
+    0xFF, 0xCE, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0xB5, 0x37,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x62, 0xFF, 0x87,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9E, 0xFF, 0x6C,  0x0,  0x0,  0x0,  0x0,  0x0, 0x73, 0xFF, 0xE9, 0x15,  0x0, 0xD5, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x77,  0x0, 0x22, 0xFF, 0xBC,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5B, 0xFF, 0x86,  0x0, 0x99, 0xFF, 0x5C,  0x0,  0x0,  0x0,  0x0, 0xC4, 0xFF, 0xFF,
+     0x3,  0x0,  0x0,  0x0, 0x5F, 0xEB, 0xFF, 0xFF, 0xFA, 0xAD,  0xA,  0x0,  0x0,  0x0, 0x44, 0xFF,
+    0xC3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5E, 0xEE, 0xFF, 0xFF,
+    0xFF, 0xCA, 0x14,  0x0,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0x7B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x10,
+    0xF1, 0xFD, 0x2C,  0x0, 0x2F, 0xAB, 0xF3, 0xFF, 0xFF, 0xFF, 0xFF, 0xCD, 0x45,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0xCD, 0xFF, 0x3D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xE, 0x9D,
+    0xFA, 0xFF, 0xFF, 0xFF, 0xD1, 0x3A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0xFF, 0xFF, 0x95,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD4, 0xFF, 0x48,  0x0,  0x0,  0x0, 0xDE, 0xFF, 0x3E,
+     0x0,  0x0,  0x0, 0x80, 0xFF, 0xAE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3F, 0xFF, 0xE0,  0xA,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD3, 0xFF, 0x38,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE4,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x4E,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0xE4, 0xF0,  0xF,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0x24, 0x38,  0x9,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0x25, 0x5C,
+    0xF3, 0xEF, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x7, 0x24, 0x40, 0x36, 0x16,  0x1,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0xA, 0x2F, 0x44, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x73, 0xFF, 0x76,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x42, 0xF6, 0xED, 0x31,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0x1B,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xD, 0xDD, 0xD0,  0x4,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xB0, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x49, 0xF9, 0xEB, 0x2D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xFF, 0xBF, 0xB8, 0xB8,
+    0xB8, 0x62,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16,
+    0xB8, 0xB8, 0xB8, 0xB8, 0xE9, 0xFF, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x46, 0x85, 0x6F,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x89, 0xB7, 0xB7, 0xB7, 0xB7,
+    0xB7, 0x62,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16,
+    0xB7, 0xB7, 0xB7, 0xB7, 0xB7, 0xB7, 0x1D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x1, 0x16, 0x16, 0x16, 0x16, 0x16, 0x16, 0x16, 0x16, 0x16, 0x16,  0xA,  0x0,  0x5, 0x75,
+    0x76,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0, 0x27, 0x60, 0x7D, 0x79, 0x54,  0xD,  0x0,  0x0,  0x0,
+     0x0,  0xA, 0xA9, 0x87,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x23, 0x63, 0x7E, 0x5D, 0x14,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3D,
+    0xA9, 0x53,  0x0,  0x0,  0x0,  0x0,  0x9, 0x47, 0x7D, 0x5C, 0x21,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x1C, 0x6F, 0xA8, 0xA9, 0x47,  0x0,  0x0,  0x0,  0x2, 0x32, 0x71, 0x67,
+    0x25,  0x0, 0x37, 0x2E,  0x0,  0x6, 0xA9, 0x8A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x4, 0xA0, 0x9F,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x71, 0xA9,
+    0x2A,  0x0, 0x7B, 0xA9, 0x20,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x20, 0xA9, 0xA9,
+    0xA9, 0xA9, 0x6B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3F, 0x2D, 0x22, 0x74, 0x58,  0x5, 0x17, 0x6B,
+    0x42,  0x0,  0x0,  0x0,  0x3, 0x46, 0x2E,  0x0, 0x23, 0x64, 0x79, 0x3F,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x39, 0x6A, 0x7C, 0x4E, 0x12,  0x0,  0x0,  0x0,  0x0,  0x6, 0x46, 0x2B,  0x1, 0x3C,
+    0x77, 0x65, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0x3A, 0x6F, 0x53, 0x14,  0x5, 0x3C,
+    0x25,  0x0, 0x43, 0x33,  0x0,  0x4, 0x3C, 0x78, 0x49,  0x9,  0x0,  0x0,  0x0,  0x0,  0xA, 0x49,
+    0x7D, 0x56, 0x24,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x2, 0x41, 0x35,  0x0,  0x0,  0x0,  0x0, 0x12, 0x41, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x74,  0x0,  0x0, 0x57,
+    0xFE, 0x8A,  0x0,  0x0,  0x0,  0x0, 0xB5, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xE9, 0x5A,  0x0,  0x0,
+     0x0,  0xF, 0xFF, 0xCB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x31,
+    0xE1, 0xFF, 0xFF, 0xFF, 0xFF, 0xF7, 0x47,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5C,
+    0xFF, 0x7D,  0x0,  0x0,  0x0, 0x8E, 0xF6, 0xFF, 0xFF, 0xFF, 0xF8, 0x6D,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x7F, 0xFE, 0xFF, 0xE3, 0xDF, 0x5E,  0x0,  0x0,  0x4, 0xAA, 0xFF, 0xFF, 0xFF,
+    0xF9, 0x49, 0xFA, 0xC5,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x9, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0xFF,
+    0x50,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x26, 0xC8, 0xC8,
+    0xD4, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xD5, 0xE8, 0xFF, 0xFF, 0x92, 0xD4, 0xFF,
+    0xFF, 0xB1,  0x0,  0x0,  0x9, 0xFF, 0xB7, 0x5B, 0xFE, 0xFF, 0xFF, 0xFF, 0x88,  0x0,  0x0,  0x0,
+     0xF, 0xAC, 0xFF, 0xFF, 0xFF, 0xFF, 0xE6, 0x3F,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xB1, 0x94, 0xFF,
+    0xFF, 0xFF, 0xFA, 0x63,  0x0,  0x0,  0x0,  0x0,  0xC, 0xB5, 0xFF, 0xFF, 0xFF, 0xEA, 0x51, 0xFF,
+    0x9E,  0x0, 0xF5, 0xC8, 0x17, 0xE2, 0xFF, 0xFF, 0xFF, 0xF5,  0x5,  0x0,  0x0, 0xA4, 0xF7, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xC2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3F, 0xBB, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x2, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F,  0xE,  0x0,  0x0,  0x0,
+    0x78, 0xFE, 0x4B,  0x0,  0x0,  0x0, 0xBA, 0x88, 0x3D, 0x1A, 0x26, 0x6C, 0xED, 0xF6, 0x1D,  0x0,
+     0x0,  0xF, 0xFF, 0xCB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F, 0xE6,
+    0xFA, 0x83, 0x3D, 0x15, 0x3F, 0xB2, 0x5E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5C,
+    0xFF, 0x7D,  0x0,  0x0, 0x6D, 0xFF, 0xC5, 0x4B, 0x17, 0x49, 0xCC, 0xFF, 0x48,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xDC, 0xE2, 0x1F,  0x0,  0x0,  0x0,  0x0,  0x0, 0x89, 0xFF, 0xE3, 0x67, 0x1F,
+    0x75, 0xF7, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x4, 0xA5, 0xA3,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x75, 0xAE,
+    0x2B,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xFF, 0x5D, 0x3B, 0xEC, 0xFF, 0xA6, 0x25,
+    0xB7, 0xFF, 0x26,  0x0,  0x9, 0xFF, 0xEE, 0xEC, 0x8C, 0x2D, 0x7A, 0xF1, 0xFC, 0x24,  0x0,  0x0,
+    0xA1, 0xFF, 0xE4, 0x66, 0x33, 0xB5, 0xFF, 0xF4, 0x16,  0x0,  0x0, 0x16, 0xFF, 0xFB, 0xFB, 0x99,
+    0x29, 0x89, 0xF7, 0xFF, 0x35,  0x0,  0x0,  0x0, 0xA1, 0xFF, 0xE3, 0x5F, 0x51, 0xDA, 0xF9, 0xFF,
+    0x9E,  0x0, 0xF5, 0xE0, 0xBF, 0xEF, 0x8F, 0x42, 0x86, 0xDD,  0x6,  0x0, 0x3A, 0xFF, 0xB5, 0x3A,
+    0x11, 0x49, 0x90, 0xA6,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x9B, 0xEC, 0x1D,  0x0,  0x0,  0x2,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0xFF, 0x84,  0x0,
+     0x0,  0xF, 0xFF, 0xCB,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBC, 0xFF,
+    0x75,  0x0,  0x0,  0x0,  0x0,  0x0,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5C,
+    0xFF, 0x7D,  0x0, 0x26, 0xFB, 0xDB, 0x11,  0x0,  0x0,  0x0, 0x1F, 0xF6, 0xCB,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x2C, 0xFF, 0x9D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x9, 0xEE, 0xFF, 0x18,  0x0,  0x0,
+     0x0, 0x9C, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xE4,  0x0,  0x0, 0xB3, 0xFF, 0x36,  0x0,
+    0x5D, 0xFF, 0x50,  0x0,  0x9, 0xFF, 0xFF, 0x62,  0x0,  0x0,  0x0, 0xA4, 0xFF, 0x6A,  0x0, 0x25,
+    0xFC, 0xF7,  0xC,  0x0,  0x0,  0x0, 0x9C, 0xFF, 0x8B,  0x0,  0x0, 0x16, 0xFF, 0xFF, 0x6E,  0x0,
+     0x0,  0x0, 0x61, 0xFF, 0xAB,  0x0,  0x0, 0x1E, 0xFA, 0xF4,  0xB,  0x0,  0x0,  0x6, 0xE1, 0xFF,
+    0x9E,  0x0, 0xF5, 0xFF, 0xC7,  0xB,  0x0,  0x0,  0x0,  0x1,  0x0,  0x0, 0xA8, 0xFF, 0x2E,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x5, 0x4A, 0x27,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5, 0x1C, 0x1E, 0x55, 0xFF, 0x9D,  0x0,
+     0x0,  0xF, 0xFF, 0xCB,  0x0, 0x38, 0x73, 0x64, 0x23,  0x0,  0x0,  0x0,  0x0,  0x3, 0xF4, 0xE8,
+     0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xC, 0x4E, 0x7C, 0x56, 0x13, 0x5C,
+    0xFF, 0x7D,  0x0, 0x60, 0xFF, 0x7F,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0xFC,  0x6,  0x0, 0x16,
+    0x46, 0x46, 0x7F, 0xFF, 0xAB, 0x46, 0x46, 0x46, 0x1D,  0x0, 0x46, 0xFF, 0xDC,  0x0,  0x0,  0x0,
+     0x0, 0x47, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xD0,  0x0, 0x23, 0x63, 0x6F, 0x23,  0x0,  0x0,  0x0,
+    0x15, 0x46, 0x46, 0x46, 0x46,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0x46, 0x46, 0x46, 0x46,
+    0x1D,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x0,  0x7, 0x46, 0x46,  0xB,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xD0,  0x0,  0x0, 0xA1, 0xFF, 0x23,  0x0,
+    0x4D, 0xFF, 0x5F,  0x0,  0x9, 0xFF, 0xFF, 0x1E,  0x0,  0x0,  0x0, 0x67, 0xFF, 0x86,  0x0, 0x63,
+    0xFF, 0xC4,  0x0,  0x0,  0x0,  0x0, 0x5C, 0xFF, 0xCC,  0x0,  0x0, 0x16, 0xFF, 0xFF, 0x30,  0x0,
+     0x0,  0x0, 0x2B, 0xFF, 0xEE,  0x0,  0x0, 0x63, 0xFF, 0xC0,  0x0,  0x0,  0x0,  0x0, 0xA8, 0xFF,
+    0x9E,  0x0, 0xF5, 0xFF, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x91, 0xFF, 0x66,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x30, 0x46, 0x46, 0xC2, 0xFF, 0x68, 0x46, 0x46, 0x46, 0x1B,
+     0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x9, 0x85, 0xDD, 0xFB, 0xFF, 0xFF, 0xFF, 0xFF, 0xAD,  0x0,
+     0x0,  0xF, 0xFF, 0xCB, 0x8B, 0xFF, 0xFF, 0xFF, 0xFF, 0x70,  0x0,  0x0,  0x0, 0x2C, 0xFF, 0xBE,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x21, 0xD5, 0xFF, 0xFF, 0xFF, 0xDF, 0x7B,
+    0xFF, 0x7D,  0x0, 0x91, 0xFF, 0xC0, 0xA4, 0xA4, 0xA4, 0xA4, 0xA4, 0xE2, 0xFF, 0x26,  0x0, 0x51,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x6B,  0x0, 0x64, 0xFF, 0xA3,  0x0,  0x0,  0x0,
+     0x0, 0x22, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xD0, 0x5B, 0xFD, 0xFF, 0xFF, 0xFF, 0x81,  0x0,  0x0,
+    0x4D, 0xFF, 0xFF, 0xFF, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA0, 0xFF, 0xFF, 0xFF, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x9, 0xB7, 0xFF, 0x7E,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC8,  0x0,  0x0, 0x99, 0xFF, 0x1A,  0x0,
+    0x45, 0xFF, 0x6B,  0x0,  0x9, 0xFF, 0xDD,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0, 0x83,
+    0xFF, 0x8A,  0x0,  0x0,  0x0,  0x0, 0x21, 0xFF, 0xEC,  0x0,  0x0, 0x16, 0xFF, 0xF3,  0x4,  0x0,
+     0x0,  0x0,  0x3, 0xF3, 0xFF,  0xE,  0x0, 0x81, 0xFF, 0x89,  0x0,  0x0,  0x0,  0x0, 0x70, 0xFF,
+    0x9E,  0x0, 0xF5, 0xFF, 0x2A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x33, 0xFB, 0xFF, 0xD4,
+    0x93, 0x56,  0x5,  0x0,  0x0,  0x0, 0xAE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x63,
+     0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0xDA, 0xFF, 0xC0, 0x72, 0x58, 0x55, 0x73, 0xFF, 0xB0,  0x0,
+     0x0,  0xF, 0xFF, 0xFC, 0xED, 0x53, 0x19, 0x4F, 0xE8, 0xFE, 0x47,  0x0,  0x0, 0x4B, 0xFF, 0xA5,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1, 0xCE, 0xFF, 0x8C, 0x25, 0x32, 0xAE, 0xFC,
+    0xFF, 0x7D,  0x0, 0xB5, 0xFF, 0xD7, 0xCD, 0xCD, 0xCD, 0xCD, 0xCD, 0xCD, 0xCD, 0x2A,  0x0,  0xD,
+    0x2A, 0x2A, 0x6C, 0xFF, 0x9E, 0x2A, 0x2A, 0x2A, 0x12,  0x0, 0x72, 0xFF, 0x8B,  0x0,  0x0,  0x0,
+     0x0, 0x24, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xF0, 0xDF, 0x64, 0x28, 0x7B, 0xF1, 0xF8, 0x24,  0x0,
+     0xD, 0x2C, 0x2C, 0xDC, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0x2A, 0x2A, 0x87, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0xC, 0xBE, 0xFE, 0x73,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC7,  0x0,  0x0, 0x97, 0xFF, 0x19,  0x0,
+    0x44, 0xFF, 0x6D,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0, 0x96,
+    0xFF, 0x67,  0x0,  0x0,  0x0,  0x0,  0x3, 0xFA, 0xFD,  0x2,  0x0, 0x16, 0xFF, 0xD5,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0xD7, 0xFF, 0x1F,  0x0, 0x97, 0xFF, 0x65,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF,
+    0x9E,  0x0, 0xF5, 0xEC,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x20, 0x90, 0xF0,
+    0xFF, 0xFF, 0xDE, 0x56,  0x0,  0x0, 0x1D, 0x2A, 0x2A, 0xB9, 0xFF, 0x51, 0x2A, 0x2A, 0x2A, 0x10,
+     0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x46, 0xFF, 0x9E,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF, 0xB0,  0x0,
+     0x0,  0xF, 0xFF, 0xFF, 0x58,  0x0,  0x0,  0x0, 0x4F, 0xFF, 0xB0,  0x0,  0x0, 0x20, 0xFF, 0xCA,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x49, 0xFF, 0xBA,  0x0,  0x0,  0x0,  0xA, 0xE1,
+    0xFF, 0x7D,  0x0, 0x97, 0xFF, 0x64,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x57, 0xFF, 0xC2,  0x0,  0x0,  0x0,
+     0x0, 0x59, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xFF, 0x51,  0x0,  0x0,  0x0, 0xA4, 0xFF, 0x63,  0x0,
+     0x0,  0x0,  0x0, 0xD5, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0xF, 0xC5, 0xFD, 0x69,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC7,  0x0,  0x0, 0x97, 0xFF, 0x19,  0x0,
+    0x44, 0xFF, 0x6D,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0, 0x7B,
+    0xFF, 0x9A,  0x0,  0x0,  0x0,  0x0, 0x31, 0xFF, 0xE5,  0x0,  0x0, 0x16, 0xFF, 0xFB,  0xB,  0x0,
+     0x0,  0x0,  0x9, 0xFB, 0xFD,  0x6,  0x0, 0x83, 0xFF, 0x93,  0x0,  0x0,  0x0,  0x0, 0x7B, 0xFF,
+    0x9E,  0x0, 0xF5, 0xE6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2,
+    0x3F, 0xA2, 0xFF, 0xED,  0x6,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x8, 0xFF, 0xD2,  0x0,  0x0,  0x0,  0x0, 0x62, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x8A, 0xFF, 0x50,  0x0,  0x0,  0x0,  0x0, 0x7D, 0xFF, 0xB8,  0x0,
+     0x0,  0xF, 0xFF, 0xF8,  0xA,  0x0,  0x0,  0x0,  0x6, 0xF3, 0xF9,  0x3,  0x0,  0x0, 0xEB, 0xF3,
+     0xE,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x8B, 0xFF, 0x67,  0x0,  0x0,  0x0,  0x0, 0x94,
+    0xFF, 0x7D,  0x0, 0x6F, 0xFF, 0xB8,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2A, 0xFF, 0xF6,  0x6,  0x0,  0x0,
+     0x0, 0x90, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xF7,  0x2,  0x0,  0x0,  0x0, 0x67, 0xFF, 0x84,  0x0,
+     0x0,  0x0,  0x0, 0xD5, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x43, 0xCB, 0xFC, 0x5F,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC7,  0x0,  0x0, 0x97, 0xFF, 0x19,  0x0,
+    0x44, 0xFF, 0x6D,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0, 0x5C,
+    0xFF, 0xD5,  0x0,  0x0,  0x0,  0x0, 0x6C, 0xFF, 0xC6,  0x0,  0x0, 0x16, 0xFF, 0xFF, 0x3F,  0x0,
+     0x0,  0x0, 0x38, 0xFF, 0xE4,  0x0,  0x0, 0x60, 0xFF, 0xCA,  0x0,  0x0,  0x0,  0x0, 0xB2, 0xFF,
+    0x9E,  0x0, 0xF5, 0xE6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xBA, 0xFF, 0x43,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xF3, 0xF8,  0x9,  0x0,  0x0,  0x0, 0xA6, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x4C, 0xFF, 0x94,  0x0,  0x0,  0x0, 0x21, 0xF0, 0xFF, 0xC4,  0x0,
+     0x0,  0xF, 0xFF, 0xDA,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD1, 0xFF, 0x1B,  0x0,  0x0, 0x9F, 0xFF,
+    0xA8,  0x0,  0x0,  0x0,  0x0,  0xD, 0x23,  0x0, 0xAA, 0xFF, 0x40,  0x0,  0x0,  0x0,  0x0, 0x6C,
+    0xFF, 0x7D,  0x0, 0x14, 0xED, 0xFA, 0x31,  0x0,  0x0,  0x0,  0x0,  0x2, 0x32,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC9, 0xFF, 0x75,  0x4,  0x0,
+    0x2A, 0xDB, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xD7,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0, 0xD5, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0xE9, 0xFF, 0xFD, 0x42,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC7,  0x0,  0x0, 0x97, 0xFF, 0x19,  0x0,
+    0x44, 0xFF, 0x6D,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0, 0x12,
+    0xF1, 0xFE, 0x35,  0x0,  0x0,  0xD, 0xBC, 0xFF, 0x6F,  0x0,  0x0, 0x16, 0xFF, 0xFF, 0x96,  0x4,
+     0x0,  0x1, 0x87, 0xFF, 0x8A,  0x0,  0x0, 0x11, 0xF7, 0xF9, 0x23,  0x0,  0x0, 0x1A, 0xEB, 0xFF,
+    0x9E,  0x0, 0xF5, 0xE6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2D,  0x3,  0x0,  0x0,
+     0x0,  0x1, 0xCD, 0xF2,  0xF,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xD5, 0xFF, 0x42,  0x0,  0x0, 0x10, 0xE9, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0xE4, 0xF5, 0x96, 0x56, 0x89, 0xE1, 0xB7, 0xF5, 0xEA,  0x1,
+     0x0,  0xF, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC6, 0xFF, 0x2A,  0x0,  0x0,  0xA, 0xC7,
+    0xFF, 0xC5, 0x7E, 0x55, 0x7E, 0xE1, 0x5E,  0x0, 0xBA, 0xFF, 0x36,  0x0,  0x0,  0x0,  0x0, 0x61,
+    0xFF, 0x7D,  0x0,  0x0, 0x62, 0xFD, 0xFF, 0xCA, 0x6A, 0x62, 0x8D, 0xDD, 0xC5,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3F, 0xF5, 0xFF, 0xD8, 0xA2,
+    0xFC, 0xBA, 0xFF, 0xC5,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0, 0xD5, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0xF4, 0x76, 0xFB, 0xE5, 0x15,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC7,  0x0,  0x0, 0x97, 0xFF, 0x19,  0x0,
+    0x44, 0xFF, 0x6D,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,
+    0x7F, 0xFF, 0xFE, 0xA7, 0x74, 0xE9, 0xFF, 0xE1,  0x9,  0x0,  0x0, 0x16, 0xFF, 0xEE, 0xFB, 0xD7,
+    0x6A, 0xC9, 0xFF, 0xF0, 0x19,  0x0,  0x0,  0x0, 0x8F, 0xFF, 0xF8, 0x8B, 0x7E, 0xF3, 0xEC, 0xFF,
+    0x9E,  0x0, 0xF5, 0xE6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xDA, 0xED, 0xA8, 0x60,
+    0x69, 0xB4, 0xFF, 0x8E,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x79, 0xFF, 0xF0, 0x8C, 0x8A, 0xF1, 0xC2, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x49, 0xBA, 0xFB, 0xFF, 0xF6, 0x9C,  0x5, 0xBC, 0xFF, 0x2A,
+     0x0,  0xF, 0xFF, 0xE0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xD6, 0xFF, 0x13,  0x0,  0x0,  0x0, 0x15,
+    0xA4, 0xE4, 0xFF, 0xFF, 0xE9, 0xA7, 0x2D,  0x0, 0xA2, 0xFF, 0x46,  0x0,  0x0,  0x0,  0x0, 0x71,
+    0xFF, 0x7D,  0x0,  0x0,  0x0, 0x3F, 0xD6, 0xFF, 0xFF, 0xFF, 0xFF, 0xD7, 0x6D,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x41, 0xBB, 0xF5, 0xEA,
+    0xA1, 0x20, 0xFF, 0xBC,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0, 0xD5, 0xFF,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x4F,  0x0, 0x7D, 0xFF, 0xB5,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x38, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,  0x0, 0xE6, 0xC7,  0x0,  0x0, 0x97, 0xFF, 0x19,  0x0,
+    0x44, 0xFF, 0x6D,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,  0x0,
+     0x1, 0x6E, 0xF4, 0xFF, 0xFF, 0xFE, 0xBA, 0x18,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xC6, 0x5C, 0xF5,
+    0xFF, 0xFF, 0xDC, 0x2C,  0x0,  0x0,  0x0,  0x0,  0x2, 0x9C, 0xFB, 0xFF, 0xFF, 0xCE, 0x51, 0xFF,
+    0x9E,  0x0, 0xF5, 0xE6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0xD6, 0xFC, 0xFF,
+    0xFF, 0xD4, 0x89, 0x17,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAB, 0xFF, 0x2E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0xB, 0xAF, 0xFF, 0xFF, 0xF9, 0x9A, 0x29, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x17, 0x34,  0x5,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xF, 0xFF, 0xFE, 0x16,  0x0,  0x0,  0x0,  0xF, 0xFB, 0xED,  0x1,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x25, 0x2F,  0x1,  0x0,  0x0,  0x0, 0x7D, 0xFF, 0x78,  0x0,  0x0,  0x0,  0x0, 0xA6,
+    0xFF, 0x7D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x12, 0x3B, 0x37, 0x18,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x5,  0x1,
+     0x0, 0x37, 0xFF, 0x9B,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0, 0xAE, 0xFF, 0x24,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x3, 0xC2, 0xFF, 0x71,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x14, 0xFC, 0xC2,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x3, 0x29, 0x3B, 0x10,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xC6,  0x0,  0x6,
+    0x36, 0x23,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xD, 0x43, 0x24,  0x0, 0x3E, 0xFF,
+    0x9E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xA, 0x36,
+    0x20,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA4, 0xFF, 0x3E,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x1F, 0x3B,  0xA,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xF, 0xFF, 0xFF, 0x7E,  0x0,  0x0,  0x0, 0x75, 0xFF, 0x94,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x26, 0xFF, 0xD6,  0xA,  0x0,  0x0, 0x20, 0xF1,
+    0xFF, 0x7D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x7D, 0xFF, 0x68,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0, 0x62, 0xFF, 0x68,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0, 0x1E, 0xEF, 0xF9, 0x33,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xC3, 0xF8,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xC6,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3E, 0xFF,
+    0x9E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x86, 0xFF, 0x80,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xF, 0xFF, 0xEC, 0xF6, 0x94, 0x5A, 0x90, 0xFA, 0xEF, 0x27,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA8, 0xFF, 0xC3, 0x66, 0x73, 0xD9, 0xE6,
+    0xFF, 0x7D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x34, 0xAB, 0x52, 0x26, 0x26,
+    0x6B, 0xF9, 0xE3,  0x8,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0, 0x17, 0xFD, 0xFC, 0xA7, 0x7D, 0x7C,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x0, 0x57, 0xFF, 0xDA,  0xD,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x77, 0xFF, 0xE7, 0x89, 0x7D, 0x4C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xC6,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3E, 0xFF,
+    0x9E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x37, 0xFE, 0xF4, 0xAD, 0x74, 0x70, 0x2C,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xF, 0xFF, 0xAD, 0x52, 0xF3, 0xFF, 0xFF, 0xE0, 0x3A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x7, 0xA8, 0xF9, 0xFF, 0xFE, 0xAE, 0x45,
+    0xFF, 0x7D,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4F, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x33, 0xFD, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xD2, 0x2C,  0x0,  0x0,  0x9, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0x48, 0xFF, 0x94,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x31, 0x9A, 0xF4, 0xFF, 0xFD,  0x0,  0x0,  0x0,  0x0,  0x0, 0x6F, 0xFF,
+    0x6A,  0x0, 0xB9, 0xFF, 0x30,  0x0,  0x0,  0x0,  0x0, 0x9F, 0xFF, 0xA2,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x6, 0x59, 0xC3, 0xFF, 0xFF, 0x9C,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x16, 0xFF, 0xC6,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x3E, 0xFF,
+    0x9E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5B, 0xD2, 0xF6, 0xFF, 0xFF, 0x63,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0x32, 0x23,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xB, 0x3A, 0x14,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x11, 0x47, 0x61, 0x5A,
+    0x31,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x9, 0x15, 0x14,  0x0,  0x0,  0x0,  0x0,  0x0, 0x83, 0xFF,
+    0x52,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x11, 0x15,  0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x6, 0x4A, 0x39,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x14, 0x54,
+    0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x1,  0xA,  0xA,  0x4,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBF, 0xF9,
+     0xD,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2B, 0x48, 0x4C, 0xA0, 0xFC, 0xB9,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x9A, 0xFF, 0xFF, 0xF8, 0xA4, 0x2D,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2C, 0x4A, 0x49, 0x16,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x42, 0x44,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x28, 0x46, 0x19,  0x0, 0x44, 0x3C,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x20, 0x46, 0x1B,  0x0,  0x0, 0x34, 0x46, 0x16,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x41, 0x46,  0xB,  0x0, 0x3F, 0x46,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x1D, 0x46, 0x27,  0x0, 0x36, 0x4B, 0x4B, 0x4B, 0x4B, 0x4B, 0x4B, 0x4B, 0x23,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x16, 0x56, 0x6C, 0x60,  0x0, 0x80, 0xC1,  0xD,  0x0, 0x25, 0x5D, 0x47, 0x20,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0x64, 0x55,  0xD,  0x0,  0x0,  0x0,
+     0x2, 0x74, 0x42,  0x0, 0x82, 0x89, 0x89, 0x89, 0x89, 0x89, 0x89, 0x89, 0x89, 0x89, 0x31,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0xB7, 0xFF, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC8, 0xFE, 0x22,  0x0, 0xD1, 0xF8,  0x7,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x96, 0xFF, 0x3B,  0x0,  0x0, 0x45, 0xFD, 0xC3,  0x4,
+     0x0,  0x0,  0x0, 0x64, 0xFF, 0xAC,  0x0,  0x0, 0xA5, 0xFF, 0x4D,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xA4, 0xFF, 0x4E,  0x0, 0xB6, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x78,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x54, 0xF7, 0xFF, 0xFF, 0xC0,  0x0, 0xA9, 0xFF, 0x11,  0x0, 0x5F, 0xFF, 0xFF, 0xFD,
+    0x98,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x25, 0xE8, 0xFF, 0xFF, 0xD8, 0x17,  0x0,  0x0,
+    0x21, 0xFF, 0x7F,  0x0, 0xF3, 0xB2, 0x9E, 0x9E, 0x9E, 0x9E, 0x9E, 0x9E, 0x9E, 0xEB, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x5C, 0xFF, 0x8B,  0x0,  0x0,  0x0,  0x0, 0x22, 0xFE, 0xC6,  0x0,  0x0, 0x95, 0xFF, 0x37,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCC, 0xF7,  0x7,  0x0,  0x0,  0x0, 0x83, 0xFF, 0x83,
+     0x0,  0x0, 0x29, 0xF5, 0xDD,  0xF,  0x0,  0x0, 0x41, 0xFF, 0xAE,  0x0,  0x0,  0x0,  0x0,  0xE,
+    0xF5, 0xE6,  0x4,  0x0, 0x22, 0x2F, 0x2F, 0x2F, 0x2F, 0x32, 0xD4, 0xFF, 0x53,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xD6, 0xFF, 0x6F, 0x19,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x5, 0x1B, 0x55, 0xE1,
+    0xFF, 0x32,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x94, 0xF4, 0x5D, 0x52, 0xF6, 0xC7,  0x8,  0x0,
+    0x48, 0xFF, 0x5F,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0xC, 0xF5, 0xE1,  0x1,  0x0,  0x0,  0x0, 0x7A, 0xFF, 0x6B,  0x0,  0x0, 0x59, 0xFF, 0x6E,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x9, 0xFA, 0xC3,  0x0,  0x0,  0x0,  0x0,  0x3, 0xC0, 0xFC,
+    0x41,  0x8, 0xD1, 0xF9, 0x34,  0x0,  0x0,  0x0,  0x1, 0xDB, 0xFA, 0x15,  0x0,  0x0,  0x0, 0x64,
+    0xFF, 0x87,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x88, 0xFF, 0x96,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x2, 0xF9, 0xF7,  0x6,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x99,
+    0xFF, 0x5B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xC7, 0xB8,  0x0,  0x0, 0x57, 0xFD, 0xB5, 0x49,
+    0xD3, 0xFB, 0x15,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xA6, 0xFF, 0x3C,  0x0,  0x0,  0x0, 0xD3, 0xFA, 0x15,  0x0,  0x0, 0x1E, 0xFF, 0xA5,
+     0x0,  0x0, 0xB7, 0xFB, 0x1F,  0x0, 0x3B, 0xFF, 0x87,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1A, 0xE9,
+    0xE5, 0xA1, 0xFF, 0x6E,  0x0,  0x0,  0x0,  0x0,  0x0, 0x78, 0xFF, 0x70,  0x0,  0x0,  0x0, 0xC3,
+    0xFE, 0x25,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x56, 0xFE, 0xC3,  0x6,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x10, 0xFF, 0xD2,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x6A,
+    0xFF, 0x71,  0x0,  0x0,  0x0,  0x0,  0x0,  0x4, 0xF6, 0x8E,  0x0,  0x0,  0x0, 0x6C, 0xFF, 0xFF,
+    0xFF, 0x71,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x4C, 0xFF, 0x94,  0x0,  0x0, 0x2C, 0xFF, 0xB5,  0x0,  0x0,  0x0,  0x0, 0xE1, 0xDC,
+     0x0,  0x9, 0xF7, 0xFD, 0x67,  0x0, 0x72, 0xFF, 0x4B,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x47,
+    0xFD, 0xFF, 0xAE,  0x1,  0x0,  0x0,  0x0,  0x0,  0x0, 0x18, 0xFA, 0xD1,  0x0,  0x0, 0x24, 0xFE,
+    0xC0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2D, 0xF4, 0xE4, 0x18,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x11, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x67,
+    0xFF, 0x73,  0x0,  0x0,  0x0,  0x0,  0x0,  0xB, 0x5D, 0x2A,  0x0,  0x0,  0x0,  0x0, 0x2D, 0x5F,
+    0x2F,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x5, 0xEB, 0xE9,  0x4,  0x0, 0x85, 0xFF, 0x5A,  0x0,  0x0,  0x0,  0x0, 0xA6, 0xFE,
+    0x14, 0x48, 0xF6, 0xA3, 0xAE,  0x0, 0xA9, 0xFD, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x25,
+    0xF4, 0xFF, 0x83,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xAE, 0xFF, 0x33,  0x0, 0x82, 0xFF,
+    0x5D,  0x0,  0x0,  0x0,  0x0,  0x0, 0x11, 0xDC, 0xF8, 0x37,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x15, 0xFF, 0xCF,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x66,
+    0xFF, 0x74,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x95, 0xFF, 0x45,  0x1, 0xDC, 0xF3,  0xB,  0x0,  0x0,  0x0,  0x0, 0x6A, 0xFF,
+    0x4A, 0x8E, 0xB6, 0x4F, 0xF1,  0x5, 0xE0, 0xD3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x8, 0xCF,
+    0xF9, 0xD2, 0xFD, 0x44,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x4A, 0xFF, 0x93,  0x2, 0xE0, 0xF0,
+     0xA,  0x0,  0x0,  0x0,  0x0,  0x3, 0xB7, 0xFF, 0x63,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x49, 0xFF, 0xAB,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x59,
+    0xFF, 0x93,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x3B, 0xFF, 0x9E, 0x36, 0xFF, 0xA4,  0x0,  0x0,  0x0,  0x0,  0x0, 0x2E, 0xFF,
+    0x81, 0xD5, 0x6C,  0xC, 0xF8, 0x56, 0xFF, 0x97,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x98, 0xFF,
+    0x75, 0x1F, 0xEE, 0xE8, 0x19,  0x0,  0x0,  0x0,  0x0,  0x0,  0x3, 0xE2, 0xED, 0x49, 0xFF, 0x96,
+     0x0,  0x0,  0x0,  0x0,  0x0, 0x87, 0xFF, 0x96,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0xB, 0x9E, 0xFF, 0x78,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x2E,
+    0xFF, 0xEB, 0x26,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x1, 0xDE, 0xEF, 0x96, 0xFF, 0x49,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xEF,
+    0xD4, 0xFF, 0x22,  0x0, 0xB9, 0xD3, 0xFF, 0x5C,  0x0,  0x0,  0x0,  0x0,  0x0, 0x5A, 0xFF, 0xB9,
+     0x2,  0x0, 0x53, 0xFF, 0xBF,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0, 0x81, 0xFF, 0xE3, 0xFF, 0x33,
+     0x0,  0x0,  0x0,  0x0, 0x54, 0xFE, 0xC3,  0x6,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x81, 0xBE,
+    0xF7, 0xFD, 0x96, 0x10,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x6A, 0xF0, 0xFA, 0xBC, 0xA0, 0x1E,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x85, 0xFF, 0xFC, 0xE9,  0x4,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xB6,
+    0xFF, 0xD7,  0x0,  0x0, 0x6F, 0xFF, 0xFF, 0x20,  0x0,  0x0,  0x0,  0x0, 0x27, 0xF2, 0xE8, 0x17,
+     0x0,  0x0,  0x0, 0x97, 0xFF, 0x84,  0x0,  0x0,  0x0,  0x0,  0x0, 0x1F, 0xFD, 0xFF, 0xCF,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xEB, 0xFF, 0x9A, 0x82, 0x82, 0x82, 0x82, 0x82, 0x3D,  0x0, 0x88, 0xC8,
+    0xFB, 0xFA, 0x88,  0xA,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0x32, 0xD1, 0xFF, 0xFF, 0xDD, 0x29,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x2A, 0xFF, 0xFF, 0x93,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x7A,
+    0xFF, 0x8D,  0x0,  0x0, 0x24, 0xFF, 0xE3,  0x0,  0x0,  0x0,  0x0,  0x9, 0xD2, 0xFE, 0x47,  0x0,
+     0x0,  0x0,  0x0,  0x9, 0xD3, 0xFD, 0x47,  0x0,  0x0,  0x0,  0x0,  0x0, 0xBB, 0xFF, 0x73,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF4, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x78,  0x0,  0x0,  0x0,
+    0x12, 0xA5, 0xFF, 0x75,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0,  0xB,
+    0xFA, 0xFB, 0x67, 0x1A,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x2, 0xDD, 0xFA, 0x16,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x4D, 0xFF, 0xA8,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x3A,
+    0xFF, 0xA1,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x52, 0xFF, 0xAA,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x17, 0xFF, 0xCE,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x63,
+    0xFF, 0x78,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xA, 0x43, 0x76, 0xE8, 0xFA, 0x32,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x11, 0xFF, 0xD0,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x67,
+    0xFF, 0x73,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x26, 0xFF, 0xFF, 0xF7, 0x59,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0, 0x11, 0xFF, 0xD1,  0x0,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x67,
+    0xFF, 0x73,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0xB, 0x4A, 0x3C,  0x8,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x3, 0xFA, 0xF5,  0x5,  0x0,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0,  0x0, 0x8B,
+    0xFF, 0x62,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x34,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xCA, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0xDA, 0xFF, 0x62,  0xD,  0x0,  0x0, 0xA9, 0xFF, 0x11,  0x0,  0x0,  0x0, 0x19, 0xCE,
+    0xFF, 0x42,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0xF3, 0x9A, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xE5, 0x5B,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0, 0x5D, 0xFC, 0xFF, 0xFC, 0xB5,  0x0, 0xA9, 0xFF, 0x11,  0x0, 0x4D, 0xDE, 0xFF, 0xFF,
+    0xCE,  0x3,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0, 0x9D, 0xA4, 0xA4, 0xA4, 0xA4, 0xA4, 0xA4, 0xA4, 0xA4, 0xA4, 0x3A,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0, 0x20, 0x66, 0x7D, 0x6D,  0x0, 0xA9, 0xFF, 0x11,  0x0, 0x3D, 0x9C, 0x86, 0x5B,
+     0x7,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0x92, 0xDC,  0xF,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1158,7 +3553,6 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-     0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0, 0xA5, 0x32,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1172,6 +3566,7 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xFF, 0xFF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -1187,7 +3582,7 @@ pub const GLYPH_ATLAS: &[u8] = &[
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
-    0xFF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
+    0xFF, 0xFF,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
      0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,  0x0,
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+
+use crate::{
+    Column, Counter, CounterDescriptor, CounterGroup, CounterId, CounterIndex, Counters, Overlay,
+    OverlayItem, Point, Row, SortBy, Table,
+};
+
+/// String-keyed counters, each in its own group named after the key, along
+/// with some per-key state.
+///
+/// Shared by `EventCounters` and `ProfilerScopes`.
+pub(crate) struct KeyedCounters<T> {
+    pub counters: Counters,
+    pub keys: HashMap<String, Keyed<T>>,
+}
+
+pub(crate) struct Keyed<T> {
+    group: CounterGroup,
+    // Only `EventCounters` has per-key state.
+    #[cfg_attr(not(feature = "counters"), allow(dead_code))]
+    pub state: T,
+}
+
+impl<T> Keyed<T> {
+    pub fn id(&self) -> CounterId {
+        self.group.counter(CounterIndex(0))
+    }
+}
+
+impl<T> KeyedCounters<T> {
+    pub fn new(history_size: usize) -> Self {
+        KeyedCounters {
+            counters: Counters::new(history_size),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// The entry of a key, registering a counter with the provided descriptor
+    /// the first time the key is seen.
+    pub fn entry(
+        &mut self,
+        key: &str,
+        descriptor: &CounterDescriptor,
+        state: impl FnOnce() -> T,
+    ) -> &mut Keyed<T> {
+        if !self.keys.contains_key(key) {
+            let group = self.counters.register_named_group(
+                key.to_string(),
+                std::slice::from_ref(descriptor),
+                [key.to_string()],
+            );
+            let state = state();
+            self.keys.insert(key.to_string(), Keyed { group, state });
+        }
+
+        self.keys.get_mut(key).unwrap()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Counter> {
+        let keyed = self.keys.get(key)?;
+        Some(self.counters.get_counter(keyed.id()))
+    }
+
+    pub fn clear(&mut self) {
+        for (_, keyed) in self.keys.drain() {
+            self.counters.unregister_group(keyed.group);
+        }
+    }
+
+    /// Draws the counters in a table sorted by name.
+    pub fn draw(&self, columns: &[Column], origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let rows: Vec<Row> = self
+            .keys
+            .values()
+            .map(|keyed| Row::Counter(self.counters.get_counter(keyed.id())))
+            .collect();
+
+        Table {
+            columns,
+            rows: &rows,
+            labels: true,
+            sort: Some(SortBy::Name),
+        }
+        .draw(origin, overlay)
+    }
+}
+
+#[cfg(feature = "counters")]
+struct Event {
+    previous: u64,
+    frame: u32,
+}
+
+/// Displays the string-keyed event counts of the `counters` crate in the overlay.
+///
+/// Each frame, `update` records how much each event count increased since the
+/// previous frame. The counts themselves keep accumulating in the `counters`
+/// crate, only the per-frame deltas are shown.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn frame(overlay: &mut debug_overlay::Overlay, events: &mut debug_overlay::EventCounters, counters: &counters::Counters) {
+/// counters.event("cache miss");
+///
+/// // Once per frame:
+/// events.update(counters);
+/// overlay.draw_item(events);
+/// # }
+/// ```
+#[cfg(feature = "counters")]
+pub struct EventCounters {
+    events: KeyedCounters<Event>,
+    frame: u32,
+    pub columns: &'static [Column],
+}
+
+#[cfg(feature = "counters")]
+const DEFAULT_COLUMNS: &[Column] = &[
+    Column::name(),
+    Column::value(),
+    Column::avg(),
+    Column::max(),
+];
+
+#[cfg(feature = "counters")]
+impl EventCounters {
+    pub fn new(history_size: usize) -> Self {
+        EventCounters {
+            events: KeyedCounters::new(history_size),
+            frame: 0,
+            columns: DEFAULT_COLUMNS,
+        }
+    }
+
+    /// Records the per-frame deltas of the event counts.
+    ///
+    /// Must be called once per frame.
+    pub fn update(&mut self, events: &::counters::Counters) {
+        self.frame = self.frame.wrapping_add(1);
+        let frame = self.frame;
+        let tracked = &mut self.events;
+        let descriptor = CounterDescriptor::int("", "", CounterId(0));
+        events.for_each(|key, value| {
+            let event = tracked.entry(key, &descriptor, || Event { previous: 0, frame });
+            // The count goes down when the event is reset.
+            let delta = if value >= event.state.previous {
+                value - event.state.previous
+            } else {
+                value
+            };
+            event.state.previous = value;
+            event.state.frame = frame;
+            let id = event.id();
+            tracked.counters.set(id, delta as f32);
+        });
+
+        // Events that are no longer present have been reset.
+        for event in tracked.keys.values_mut() {
+            if event.state.frame != frame {
+                tracked.counters.set(event.id(), 0.0);
+                event.state.previous = 0;
+            }
+        }
+
+        tracked.counters.update();
+    }
+
+    /// The counter tracking the per-frame delta of an event, if any.
+    pub fn get(&self, key: &str) -> Option<&Counter> {
+        self.events.get(key)
+    }
+
+    /// Stops tracking all events.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+#[cfg(feature = "counters")]
+impl OverlayItem for EventCounters {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        self.events.draw(self.columns, origin, overlay)
+    }
+}
+
+#[test]
+fn keyed_counters() {
+    let mut keyed = KeyedCounters::new(0);
+    let descriptor = CounterDescriptor::int("", "", CounterId(0));
+
+    let id = keyed.entry("b", &descriptor, || 1).id();
+    keyed.counters.set(id, 3.0);
+    // Existing entries keep their state.
+    assert_eq!(keyed.entry("b", &descriptor, || 2).state, 1);
+    keyed.entry("a", &descriptor, || 0);
+    keyed.counters.update();
+
+    let b = keyed.get("b").unwrap();
+    assert_eq!(b.name(), "b");
+    assert_eq!(b.last_value, 3.0);
+    assert!(keyed.get("c").is_none());
+
+    let mut overlay = Overlay::new();
+    let columns = [Column::name()];
+    let rect = keyed.draw(&columns, Point { x: 0, y: 0 }, &mut overlay);
+    assert!(rect.1.y > rect.0.y);
+
+    keyed.clear();
+    assert!(keyed.get("b").is_none());
+}
+
+#[cfg(feature = "counters")]
+#[test]
+fn event_counters() {
+    let events = ::counters::Counters::new();
+    let mut counters = EventCounters::new(0);
+
+    events.event("a");
+    events.event("a");
+    counters.update(&events);
+    assert_eq!(counters.get("a").unwrap().last_value, 2.0);
+    assert!(counters.get("b").is_none());
+
+    events.event("a");
+    events.event("b");
+    counters.update(&events);
+    assert_eq!(counters.get("a").unwrap().last_value, 1.0);
+    assert_eq!(counters.get("b").unwrap().last_value, 1.0);
+
+    events.reset_events("a");
+    counters.update(&events);
+    assert_eq!(counters.get("a").unwrap().last_value, 0.0);
+    assert_eq!(counters.get("b").unwrap().last_value, 0.0);
+}
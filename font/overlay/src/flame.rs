@@ -0,0 +1,237 @@
+use crate::{Color, Font, Overlay, OverlayItem, Point, FRONT_LAYER};
+use std::{fmt::Write, ops::Range};
+
+/// A timed span, see `FlameGraph`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    name: Range<usize>,
+    pub start: f32,
+    pub end: f32,
+    pub depth: u32,
+}
+
+/// Nested spans of a frame, rendered as a horizontal flame graph.
+///
+/// Spans are typically cleared at the beginning of each frame and added as
+/// the application measures the time spent in its systems. Depth 0 spans are
+/// drawn at the top and nested spans below their parent.
+///
+/// # Example
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// let mut spans = FlameGraph::new();
+/// spans.add_span("frame", 0.0, 16.0, 0);
+/// spans.add_span("update", 0.0, 4.0, 1);
+/// spans.add_span("render", 4.0, 15.0, 1);
+/// spans.add_span("shadows", 5.0, 8.0, 2);
+///
+/// let mut overlay = Overlay::new();
+/// overlay.begin_frame();
+/// overlay.draw_item(&spans);
+/// overlay.finish();
+/// ```
+#[derive(Clone, Debug)]
+pub struct FlameGraph {
+    spans: Vec<Span>,
+    // Span names are stored in a single buffer to avoid an allocation per span.
+    names: String,
+    /// The width of the graph, or `None` to use the width of the current group.
+    pub width: Option<i32>,
+    /// The unit of the span times, shown in tooltips.
+    pub unit: &'static str,
+}
+
+/// The height of a row of spans with text in `font`.
+fn row_height(font: Font) -> i32 {
+    font.line_height() + 2
+}
+
+/// Spans narrower than this are drawn without a label.
+const MIN_LABEL_WIDTH: i32 = 20;
+
+const PALETTE: [Color; 6] = [
+    (230, 120, 60, 255),
+    (220, 160, 60, 255),
+    (200, 90, 70, 255),
+    (230, 190, 90, 255),
+    (190, 120, 90, 255),
+    (210, 140, 40, 255),
+];
+
+impl FlameGraph {
+    pub fn new() -> Self {
+        FlameGraph {
+            spans: Vec::new(),
+            names: String::new(),
+            width: None,
+            unit: "ms",
+        }
+    }
+
+    /// Removes all spans, keeping the allocations.
+    pub fn clear(&mut self) {
+        self.spans.clear();
+        self.names.clear();
+    }
+
+    pub fn add_span(&mut self, name: &str, start: f32, end: f32, depth: u32) {
+        let name_start = self.names.len();
+        self.names.push_str(name);
+        self.spans.push(Span {
+            name: name_start..self.names.len(),
+            start,
+            end,
+            depth,
+        });
+    }
+
+    pub fn spans(&self) -> impl Iterator<Item = (&str, &Span)> {
+        self.spans
+            .iter()
+            .map(|span| (&self.names[span.name.clone()], span))
+    }
+
+    /// The start of the earliest span and the end of the latest one.
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        let start = self.spans.iter().map(|s| s.start).reduce(f32::min)?;
+        let end = self.spans.iter().map(|s| s.end).reduce(f32::max)?;
+
+        Some((start, end))
+    }
+}
+
+impl Default for FlameGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OverlayItem for FlameGraph {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let Some((t0, t1)) = self.time_range() else {
+            return (origin, origin);
+        };
+
+        let w = self.width.unwrap_or_else(|| {
+            let widget = overlay.current_group_width();
+            if widget > 0 {
+                widget
+            } else {
+                300
+            }
+        });
+        let depth = self.spans.iter().map(|s| s.depth).max().unwrap_or(0);
+        let row_height = row_height(overlay.style.font);
+        let rect = (
+            origin,
+            Point {
+                x: origin.x + w,
+                y: origin.y + (depth as i32 + 1) * row_height,
+            },
+        );
+
+        let x_scale = w as f32 / (t1 - t0).max(f32::EPSILON);
+        let prev_clip = overlay.geometry.clip_rect();
+        let mut hovered = None;
+        for (idx, (name, span)) in self.spans().enumerate() {
+            let x0 = origin.x + ((span.start - t0) * x_scale) as i32;
+            let x1 = (origin.x + ((span.end - t0) * x_scale) as i32).max(x0 + 1);
+            let y0 = origin.y + span.depth as i32 * row_height;
+            let span_rect = (
+                Point { x: x0, y: y0 },
+                Point {
+                    x: x1,
+                    y: y0 + row_height - 1,
+                },
+            );
+
+            let color = PALETTE[name_hash(name) as usize % PALETTE.len()];
+            overlay
+                .geometry
+                .push_rectangle(FRONT_LAYER, &span_rect, color, color);
+
+            if x1 - x0 >= MIN_LABEL_WIDTH {
+                let clip = match prev_clip {
+                    Some(clip) => intersection(&clip, &span_rect),
+                    None => span_rect,
+                };
+                overlay.geometry.set_clip_rect(Some(clip));
+                let text_pos = Point {
+                    x: x0 + 2,
+                    y: y0 + overlay.style.font.line_height(),
+                };
+                overlay
+                    .geometry
+                    .push_text(FRONT_LAYER, name, text_pos, (0, 0, 0, 255));
+                overlay.geometry.set_clip_rect(prev_clip);
+            }
+
+            if overlay.is_hovered(&span_rect) {
+                hovered = Some(idx);
+            }
+        }
+
+        if let Some(idx) = hovered {
+            let span = &self.spans[idx];
+            let name = &self.names[span.name.clone()];
+            let mut tooltip = String::new();
+            let _ = write!(tooltip, "{name}: {:.2}{}", span.end - span.start, self.unit);
+            overlay.show_tooltip(&tooltip);
+        }
+
+        rect
+    }
+}
+
+fn intersection(a: &(Point, Point), b: &(Point, Point)) -> (Point, Point) {
+    (
+        Point {
+            x: a.0.x.max(b.0.x),
+            y: a.0.y.max(b.0.y),
+        },
+        Point {
+            x: a.1.x.min(b.1.x).max(a.0.x.max(b.0.x)),
+            y: a.1.y.min(b.1.y).max(a.0.y.max(b.0.y)),
+        },
+    )
+}
+
+/// FNV-1a, used to pick a stable color per span name.
+fn name_hash(name: &str) -> u32 {
+    name.bytes().fold(0x811c9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+#[test]
+fn flame_graph() {
+    let mut spans = FlameGraph::new();
+    spans.width = Some(100);
+    spans.add_span("frame", 0.0, 10.0, 0);
+    spans.add_span("update", 0.0, 5.0, 1);
+    spans.add_span("x", 5.0, 5.5, 1);
+    assert_eq!(spans.time_range(), Some((0.0, 10.0)));
+    let names: Vec<&str> = spans.spans().map(|(name, _)| name).collect();
+    assert_eq!(names, ["frame", "update", "x"]);
+
+    let mut overlay = Overlay::new();
+    let row_height = row_height(Font::DEFAULT);
+    overlay.set_mouse_position(Some(Point {
+        x: 20,
+        y: row_height + 5,
+    }));
+    let rect = spans.draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert_eq!(
+        rect.1,
+        Point {
+            x: 100,
+            y: 2 * row_height
+        }
+    );
+    assert_eq!(overlay.tooltip, "update: 5.00ms");
+
+    spans.clear();
+    assert_eq!(spans.time_range(), None);
+}
@@ -0,0 +1,150 @@
+use crate::{Counter, CounterDescriptor, CounterId, Overlay, OverlayItem, Point, FRONT_LAYER};
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+/// Tracks the frame rate and frame times.
+///
+/// Call `tick` once per frame. `FrameStats` can be drawn as a single line, and
+/// its frame time counter can be passed to graphs and tables.
+///
+/// # Example
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// let mut frame_stats = FrameStats::new();
+/// let mut overlay = Overlay::new();
+///
+/// // In the render loop:
+/// frame_stats.tick();
+/// overlay.begin_frame();
+/// overlay.draw_item(&frame_stats);
+/// overlay.draw_item(&Graph::new(frame_stats.counter()));
+/// overlay.finish();
+/// ```
+pub struct FrameStats {
+    last_tick: Option<Instant>,
+    counter: Counter,
+    avg_window: u32,
+    frame_idx: u32,
+}
+
+impl FrameStats {
+    pub fn new() -> Self {
+        let mut counter = Counter::new(CounterDescriptor::float("frame time", "ms", CounterId(0)));
+        counter.enable_history(120);
+
+        FrameStats {
+            last_tick: None,
+            counter,
+            avg_window: 30,
+            frame_idx: 0,
+        }
+    }
+
+    /// Sets the number of frames the average, min and max are computed over.
+    pub fn set_avg_window(&mut self, frames: u32) {
+        self.avg_window = frames.max(1);
+    }
+
+    /// Records the time elapsed since the previous call.
+    pub fn tick(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.last_tick {
+            self.add_frame(now - last);
+        }
+        self.last_tick = Some(now);
+    }
+
+    /// Records the duration of a frame.
+    pub fn add_frame(&mut self, frame_time: Duration) {
+        self.counter.set(frame_time.as_secs_f32() * 1000.0);
+        self.frame_idx += 1;
+        let update_avg = self.frame_idx >= self.avg_window;
+        if update_avg {
+            self.frame_idx = 0;
+        }
+        self.counter.update(update_avg);
+    }
+
+    /// The frame time counter, in milliseconds.
+    pub fn counter(&self) -> &Counter {
+        &self.counter
+    }
+
+    /// The average number of frames per second over the last averaging window.
+    pub fn fps(&self) -> Option<f32> {
+        self.avg_frame_time().map(|ms| 1000.0 / ms)
+    }
+
+    /// The duration of the last frame in milliseconds.
+    pub fn frame_time(&self) -> Option<f32> {
+        Some(self.counter.last_value).filter(|v| v.is_finite())
+    }
+
+    pub fn avg_frame_time(&self) -> Option<f32> {
+        Some(self.counter.displayed_avg).filter(|v| v.is_finite() && *v > 0.0)
+    }
+
+    pub fn min_frame_time(&self) -> Option<f32> {
+        Some(self.counter.displayed_min).filter(|v| v.is_finite())
+    }
+
+    pub fn max_frame_time(&self) -> Option<f32> {
+        Some(self.counter.displayed_max).filter(|v| v.is_finite())
+    }
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OverlayItem for FrameStats {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        overlay.string_buffer.clear();
+        let s = &mut overlay.string_buffer;
+        let _ = match (self.fps(), self.avg_frame_time()) {
+            (Some(fps), Some(avg)) => write!(s, "{fps:.1} fps  {avg:.2} ms"),
+            _ => write!(s, "- fps"),
+        };
+        if let (Some(min), Some(max)) = (self.min_frame_time(), self.max_frame_time()) {
+            let _ = write!(s, " (min {min:.2}, max {max:.2})");
+        }
+
+        let p = Point {
+            x: origin.x,
+            y: origin.y + overlay.style.font.line_height(),
+        };
+        let rect = overlay.geometry.push_text(
+            FRONT_LAYER,
+            &overlay.string_buffer,
+            p,
+            overlay.style.text_color[0],
+        );
+
+        if overlay.is_hovered(&rect) {
+            overlay.show_counter_tooltip(&self.counter);
+        }
+
+        rect
+    }
+}
+
+#[test]
+fn frame_stats() {
+    let mut stats = FrameStats::new();
+    stats.set_avg_window(2);
+    assert_eq!(stats.fps(), None);
+
+    stats.add_frame(Duration::from_millis(10));
+    stats.add_frame(Duration::from_millis(30));
+    assert_eq!(stats.frame_time(), Some(30.0));
+    assert_eq!(stats.avg_frame_time(), Some(20.0));
+    assert_eq!(stats.fps(), Some(50.0));
+    assert_eq!(stats.min_frame_time(), Some(10.0));
+    assert_eq!(stats.max_frame_time(), Some(30.0));
+}
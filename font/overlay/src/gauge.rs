@@ -0,0 +1,131 @@
+use crate::{Color, Counter, Overlay, OverlayItem, Point, FRONT_LAYER};
+use std::fmt::Write;
+
+/// A horizontal bar showing the last value of a counter relative to a budget.
+///
+/// The bar uses the highlight color when the value exceeds the budget or
+/// leaves the counter's safe range.
+pub struct Gauge<'a> {
+    pub counter: &'a Counter,
+    pub budget: f32,
+    /// The width of the gauge, or `None` to use the width of the current group.
+    pub width: Option<i32>,
+    /// The color of the bar, or `None` to use the counter's color.
+    pub color: Option<Color>,
+}
+
+impl<'a> Gauge<'a> {
+    pub fn new(counter: &'a Counter, budget: f32) -> Self {
+        Gauge {
+            counter,
+            budget,
+            width: None,
+            color: None,
+        }
+    }
+}
+
+impl<'a> OverlayItem for Gauge<'a> {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let w = self.width.unwrap_or_else(|| {
+            let widget = overlay.current_group_width();
+            if widget > 0 {
+                widget
+            } else {
+                100
+            }
+        });
+        let h = overlay.style.font.line_height() + 2;
+        let rect = (
+            origin,
+            Point {
+                x: origin.x + w,
+                y: origin.y + h,
+            },
+        );
+
+        let (r, g, b, _) = overlay.style.text_color[1];
+        let track = (r, g, b, 40);
+        overlay
+            .geometry
+            .push_rectangle(FRONT_LAYER, &rect, track, track);
+
+        let desc = &self.counter.descriptor;
+        let val = self.counter.last_value;
+        if val.is_finite() {
+            let over_budget = val > self.budget;
+            let unsafe_value = desc
+                .safe_range
+                .as_ref()
+                .map(|range| !range.contains(&val))
+                .unwrap_or(false);
+            let color = if over_budget || unsafe_value {
+                overlay.style.highlight_color
+            } else {
+                self.color.unwrap_or(desc.color)
+            };
+
+            let ratio = if self.budget > 0.0 {
+                (val / self.budget).clamp(0.0, 1.0)
+            } else {
+                1.0
+            };
+            let bar = (
+                origin,
+                Point {
+                    x: origin.x + (w as f32 * ratio) as i32,
+                    y: origin.y + h,
+                },
+            );
+            overlay
+                .geometry
+                .push_rectangle(FRONT_LAYER, &bar, color, color);
+        }
+
+        overlay.string_buffer.clear();
+        let unit = desc.unit;
+        let budget = self.budget;
+        let budget = desc.format_value(budget);
+        let _ = if val.is_finite() {
+            let val = desc.format_value(val);
+            write!(overlay.string_buffer, "{val}/{budget}{unit}")
+        } else {
+            write!(overlay.string_buffer, "-/{budget}{unit}")
+        };
+        let text_pos = Point {
+            x: origin.x + 2,
+            y: origin.y + overlay.style.font.line_height(),
+        };
+        overlay.geometry.push_text(
+            FRONT_LAYER,
+            &overlay.string_buffer,
+            text_pos,
+            overlay.style.text_color[0],
+        );
+
+        if overlay.is_hovered(&rect) {
+            overlay.show_counter_tooltip(self.counter);
+        }
+
+        rect
+    }
+}
+
+#[test]
+fn gauge_over_budget() {
+    use crate::{color_to_u32, CounterDescriptor, CounterId};
+
+    let mut counter = Counter::new(CounterDescriptor::int("memory", "MB", CounterId(0)));
+    counter.set(150.0);
+
+    let mut overlay = Overlay::new();
+    let mut gauge = Gauge::new(&counter, 100.0);
+    gauge.width = Some(50);
+    let rect = gauge.draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert_eq!(rect.1.x, 50);
+
+    // The bar is clamped to the gauge and uses the highlight color.
+    let bar = &overlay.geometry.vertices[4..8];
+    assert_eq!(bar[1].x, 50.0);
+    assert_eq!(bar[0].color, color_to_u32(overlay.style.highlight_color));
+}
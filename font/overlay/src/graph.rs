@@ -1,6 +1,83 @@
-use std::f32::NAN;
+use crate::{
+    counter::smooth_range, Color, Counter, Format, FormattedValue, Layer, LineJoin, Notation,
+    Orientation, Overlay, OverlayItem, Point, PointF, FRONT_LAYER,
+};
+use std::fmt::Write;
 
-use crate::{Color, Counter, Layer, Orientation, Overlay, OverlayItem, Point, FRONT_LAYER};
+/// The size and spacing of the bars of a graph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BarLayout {
+    /// The width of each bar, or `None` to fit all samples in the graph.
+    ///
+    /// If the samples don't fit, only the most recent ones are shown.
+    pub width: Option<i32>,
+    /// The space between two bars.
+    pub gap: i32,
+    /// The minimum height of the bars of non-zero samples.
+    pub min_height: i32,
+}
+
+impl BarLayout {
+    /// Fits all samples in the graph without gaps.
+    pub const AUTO: Self = BarLayout {
+        width: None,
+        gap: 0,
+        min_height: 0,
+    };
+
+    pub const fn new(width: i32, gap: i32) -> Self {
+        BarLayout {
+            width: Some(width),
+            gap,
+            min_height: 0,
+        }
+    }
+
+    pub const fn min_height(mut self, min_height: i32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
+    /// Returns the width of the bars, the distance between the start of two
+    /// consecutive bars and the number of old samples that don't fit.
+    fn resolve(&self, available: i32, samples: u32) -> (i32, i32, usize) {
+        let gap = self.gap.max(0);
+        let stride = match self.width {
+            Some(w) => w.max(1) + gap,
+            None => (available / samples.max(1) as i32).max(1 + gap),
+        };
+        let bar = (stride - gap).max(1);
+        let visible = ((available + gap) / stride).max(1) as usize;
+
+        (bar, stride, (samples as usize).saturating_sub(visible))
+    }
+
+    /// The width needed to show the provided number of samples.
+    pub fn width_for(&self, samples: usize) -> i32 {
+        let w = self.width.unwrap_or(1).max(1);
+        let n = samples as i32;
+        (n * (w + self.gap) - self.gap).max(0)
+    }
+}
+
+impl Default for BarLayout {
+    fn default() -> Self {
+        Self::AUTO
+    }
+}
+
+/// How the samples of a graph are rendered.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GraphStyle {
+    /// One bar per sample.
+    #[default]
+    Bars,
+    /// A line connecting the samples.
+    Line,
+    /// A line connecting the samples, with the area under it filled with a
+    /// translucent version of the graph's color.
+    FilledLine,
+}
 
 pub struct Graph<'a> {
     pub color: Color,
@@ -9,6 +86,41 @@ pub struct Graph<'a> {
     pub counter: &'a Counter,
     pub reference_value: f32,
     pub orientation: Orientation,
+    pub style: GraphStyle,
+    pub bars: BarLayout,
+    /// The color of the line drawn at the reference value, if any.
+    pub reference_color: Option<Color>,
+    /// The number of samples and color of a moving average drawn over the
+    /// graph, if any.
+    pub moving_average: Option<(usize, Color)>,
+    /// Scales the graph using the counter's `history_range` rounded up to a
+    /// round number, instead of the maximum of the visible samples.
+    pub auto_range: bool,
+    /// Draws guide lines, the range of the graph and the current value.
+    ///
+    /// Only supported with the vertical orientation.
+    pub axes: bool,
+}
+
+impl<'a> Graph<'a> {
+    /// A vertical bar graph using the counter's color and the size of the
+    /// current group.
+    pub fn new(counter: &'a Counter) -> Self {
+        Graph {
+            color: counter.descriptor.color,
+            width: None,
+            height: None,
+            counter,
+            reference_value: 0.0,
+            orientation: Orientation::Vertical,
+            style: GraphStyle::Bars,
+            bars: BarLayout::AUTO,
+            reference_color: None,
+            moving_average: None,
+            auto_range: true,
+            axes: false,
+        }
+    }
 }
 
 impl<'a> OverlayItem for Graph<'a> {
@@ -38,15 +150,17 @@ impl<'a> OverlayItem for Graph<'a> {
             },
         );
 
-        draw_graph(
-            FRONT_LAYER,
-            rect,
-            self.counter,
-            self.reference_value,
-            self.color,
-            self.orientation,
-            overlay,
-        );
+        let stats = draw_graph(FRONT_LAYER, rect, self, overlay);
+
+        if overlay.is_hovered(&rect) {
+            overlay.show_counter_tooltip(self.counter);
+        }
+
+        if self.axes && self.orientation == Orientation::Vertical {
+            let current = Some(self.counter.last_value).filter(|v| v.is_finite());
+            let format = self.counter.descriptor.format_value(0.0);
+            return draw_axes(FRONT_LAYER, rect, stats.scale, current, format, overlay);
+        }
 
         rect
     }
@@ -58,6 +172,34 @@ pub struct Graphs<'a> {
     pub counters: &'a [&'a Counter],
     pub reference_value: f32,
     pub orientation: Orientation,
+    pub bars: BarLayout,
+    /// The color of the line drawn at the reference value, if any.
+    pub reference_color: Option<Color>,
+    /// Scales the graph using a range that follows the maximum of the stacked
+    /// samples like `Counter::history_range`, rounded up to a round number,
+    /// which avoids rescaling it every frame.
+    pub auto_range: bool,
+    /// Draws guide lines and the range of the graph.
+    ///
+    /// Only supported with the vertical orientation.
+    pub axes: bool,
+}
+
+impl<'a> Graphs<'a> {
+    /// Vertical stacked graphs using the size of the current group.
+    pub fn new(counters: &'a [&'a Counter]) -> Self {
+        Graphs {
+            width: None,
+            height: None,
+            counters,
+            reference_value: 0.0,
+            orientation: Orientation::Vertical,
+            bars: BarLayout::AUTO,
+            reference_color: None,
+            auto_range: true,
+            axes: false,
+        }
+    }
 }
 
 impl<'a> OverlayItem for Graphs<'a> {
@@ -87,43 +229,117 @@ impl<'a> OverlayItem for Graphs<'a> {
             },
         );
 
-        draw_graphs(
-            FRONT_LAYER,
-            rect,
-            self.counters,
-            self.reference_value,
-            self.orientation,
-            overlay,
-        );
+        let scale = draw_graphs(FRONT_LAYER, rect, self, overlay);
+
+        if self.axes && self.orientation == Orientation::Vertical {
+            let format = self
+                .counters
+                .first()
+                .map(|c| c.descriptor.format_value(0.0));
+            let format = format.unwrap_or(FormattedValue {
+                value: 0.0,
+                format: Format::Float,
+                notation: Notation::Plain,
+            });
+            return draw_axes(FRONT_LAYER, rect, scale, None, format, overlay);
+        }
 
         rect
     }
 }
 
+/// Names the counters of stacked graphs, with a color swatch and their
+/// current value.
+pub struct Legend<'a> {
+    pub counters: &'a [&'a Counter],
+    /// Whether to show the last value of each counter after its name.
+    pub values: bool,
+}
+
+impl<'a> Legend<'a> {
+    pub fn new(counters: &'a [&'a Counter]) -> Self {
+        Legend {
+            counters,
+            values: true,
+        }
+    }
+}
+
+impl<'a> OverlayItem for Legend<'a> {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let mut bounds = (origin, origin);
+        let line_height = overlay.style.font.line_height();
+        let mut y = origin.y + line_height;
+        let text_color = overlay.style.text_color[0];
+        for counter in self.counters {
+            let swatch = ((origin.x, y - 11).into(), (origin.x + 10, y - 1).into());
+            let color = counter.descriptor.color;
+            overlay
+                .geometry
+                .push_rectangle(FRONT_LAYER, &swatch, color, color);
+
+            overlay.string_buffer.clear();
+            let _ = write!(overlay.string_buffer, "{}", counter.name());
+            let val = counter.last_value;
+            if self.values && val.is_finite() {
+                let unit = counter.descriptor.unit;
+                let val = counter.descriptor.format_value(val);
+                let _ = write!(overlay.string_buffer, ": {val}{unit}");
+            }
+            let position = (origin.x + 14, y).into();
+            let r = overlay.geometry.push_text(
+                FRONT_LAYER,
+                &overlay.string_buffer,
+                position,
+                text_color,
+            );
+
+            bounds.1.x = bounds.1.x.max(r.1.x);
+            bounds.1.y = bounds.1.y.max(r.1.y).max(y);
+            y += line_height + overlay.style.line_spacing;
+        }
+
+        bounds
+    }
+}
+
 pub struct GraphStats {
     pub avg: f32,
     pub min: f32,
     pub max: f32,
     pub samples_active: u32,
     pub samples_total: u32,
+    /// The value at the top of the graph.
+    pub scale: f32,
 }
 
 pub(crate) fn draw_graph(
     layer: Layer,
     rect: (Point, Point),
-    counter: &Counter,
-    reference_value: f32,
-    color: Color,
-    orientation: Orientation,
+    graph: &Graph,
     overlay: &mut Overlay,
 ) -> GraphStats {
+    let Graph {
+        counter,
+        reference_value,
+        reference_color,
+        color,
+        orientation,
+        style,
+        bars,
+        moving_average,
+        auto_range,
+        ..
+    } = *graph;
+
     if counter.history().is_none() {
         return GraphStats {
-            avg: NAN,
-            min: NAN,
-            max: NAN,
+            avg: f32::NAN,
+            min: f32::NAN,
+            max: f32::NAN,
             samples_active: 0,
             samples_total: 0,
+            scale: f32::NAN,
         };
     }
 
@@ -142,8 +358,8 @@ pub(crate) fn draw_graph(
         rect
     };
 
-    let mut max = std::f32::MIN;
-    let mut min = std::f32::MAX;
+    let mut max = f32::MIN;
+    let mut min = f32::MAX;
     let mut sum = 0.0;
     let mut total_count = 0;
     let mut sample_count = 0;
@@ -160,37 +376,131 @@ pub(crate) fn draw_graph(
 
     if sample_count == 0 {
         return GraphStats {
-            avg: NAN,
-            min: NAN,
-            max: NAN,
+            avg: f32::NAN,
+            min: f32::NAN,
+            max: f32::NAN,
             samples_active: 0,
             samples_total: 0,
+            scale: f32::NAN,
         };
     }
 
     let avg = if sample_count > 0 {
         sum / sample_count as f32
     } else {
-        NAN
+        f32::NAN
     };
 
-    let w = ((rect.1.x - rect.0.x) as f32 / total_count as f32).max(1.0) as i32;
-    let y_scale = (rect.1.y - rect.0.y) as f32 / max.max(reference_value);
+    let (bar_width, w, skip) = bars.resolve(rect.1.x - rect.0.x, total_count);
+    let range = match counter.history_range() {
+        Some(range) if auto_range => round_range(range),
+        _ => max,
+    };
+    let scale = range.max(reference_value);
+    let y_scale = (rect.1.y - rect.0.y) as f32 / scale;
+
+    let point = |x: f32, y: f32| {
+        if orientation == Orientation::Horizontal {
+            PointF { x: y, y: x }
+        } else {
+            PointF { x, y }
+        }
+    };
 
     let mut x0 = rect.0.x;
     let y0 = rect.1.y;
-    for val in counter.history().unwrap() {
-        let x1 = x0 + w;
-        if let Some(val) = val {
-            let y1 = (y0 as f32 - val * y_scale) as i32;
-            let rect = if orientation == Orientation::Horizontal {
-                (Point { x: y0, y: x0 }, Point { x: y1, y: x1 })
+    match style {
+        GraphStyle::Bars => {
+            for val in counter.history().unwrap().skip(skip) {
+                let x1 = x0 + bar_width;
+                if let Some(val) = val {
+                    let mut h = (val * y_scale) as i32;
+                    if val > 0.0 {
+                        h = h.max(bars.min_height);
+                    }
+                    let y1 = y0 - h;
+                    let rect = if orientation == Orientation::Horizontal {
+                        (Point { x: y0, y: x0 }, Point { x: y1, y: x1 })
+                    } else {
+                        (Point { x: x0, y: y0 }, Point { x: x1, y: y1 })
+                    };
+                    overlay.geometry.push_rectangle(layer, &rect, color, color);
+                }
+                x0 += w;
+            }
+        }
+        GraphStyle::Line | GraphStyle::FilledLine => {
+            let fill_color = (color.0, color.1, color.2, color.3 / 3);
+            let mut line = Vec::with_capacity(total_count as usize);
+            let mut history = counter.history().unwrap().skip(skip).peekable();
+            while history.peek().is_some() {
+                // Missing samples split the line.
+                line.clear();
+                for val in history.by_ref() {
+                    let x = x0 as f32 + bar_width as f32 * 0.5;
+                    x0 += w;
+                    match val {
+                        Some(val) => line.push((x, y0 as f32 - val * y_scale)),
+                        None => break,
+                    }
+                }
+
+                if style == GraphStyle::FilledLine {
+                    for segment in line.windows(2) {
+                        let ((xa, ya), (xb, yb)) = (segment[0], segment[1]);
+                        let quad = [
+                            point(xa, ya),
+                            point(xb, yb),
+                            point(xb, y0 as f32),
+                            point(xa, y0 as f32),
+                        ];
+                        overlay
+                            .geometry
+                            .push_mesh(layer, &quad, &[0, 1, 2, 0, 2, 3], fill_color);
+                    }
+                }
+
+                let points: Vec<PointF> = line.iter().map(|&(x, y)| point(x, y)).collect();
+                overlay
+                    .geometry
+                    .push_polyline(layer, &points, 1.5, LineJoin::Bevel, color);
+            }
+        }
+    }
+
+    if let Some((window, color)) = moving_average {
+        let samples: Vec<Option<f32>> = counter.history().unwrap().collect();
+        let mut line = Vec::with_capacity(samples.len());
+        for (idx, window) in moving_windows(&samples, window).enumerate().skip(skip) {
+            let idx = (idx - skip) as i32;
+            let (sum, count) = window
+                .iter()
+                .flatten()
+                .fold((0.0, 0), |(sum, count), val| (sum + val, count + 1));
+            if count > 0 {
+                let x = (rect.0.x + idx * w) as f32 + bar_width as f32 * 0.5;
+                line.push(point(x, y0 as f32 - sum / count as f32 * y_scale));
             } else {
-                (Point { x: x0, y: y0 }, Point { x: x1, y: y1 })
-            };
-            overlay.geometry.push_rectangle(layer, &rect, color, color);
+                overlay
+                    .geometry
+                    .push_polyline(layer, &line, 1.5, LineJoin::Bevel, color);
+                line.clear();
+            }
         }
-        x0 = x1;
+        overlay
+            .geometry
+            .push_polyline(layer, &line, 1.5, LineJoin::Bevel, color);
+    }
+
+    if let Some(color) = reference_color {
+        draw_reference_line(
+            layer,
+            rect,
+            reference_value * y_scale,
+            color,
+            orientation,
+            overlay,
+        );
     }
 
     GraphStats {
@@ -199,17 +509,64 @@ pub(crate) fn draw_graph(
         avg,
         samples_active: sample_count,
         samples_total: total_count,
+        scale,
+    }
+}
+
+/// The smoothed range of stacked graphs, which `Overlay` keeps from one frame
+/// to the next like `Counter::history_range` for a single counter.
+pub(crate) struct StackedRange {
+    /// The addresses of the stacked counters.
+    counters: Vec<usize>,
+    range: f32,
+    /// Whether the graphs were drawn this frame. Ranges of graphs that are not
+    /// drawn anymore are dropped in `Overlay::begin_frame`.
+    pub drawn: bool,
+}
+
+impl Overlay {
+    /// Updates the smoothed range of stacked graphs with the maximum of their
+    /// sum.
+    fn stacked_range(&mut self, counters: &[&Counter], max: f32) -> f32 {
+        let addresses = counters.iter().map(|c| *c as *const Counter as usize);
+        let idx = self
+            .stacked_ranges
+            .iter()
+            .position(|r| r.counters.iter().copied().eq(addresses.clone()));
+        let entry = match idx {
+            Some(idx) => &mut self.stacked_ranges[idx],
+            None => {
+                self.stacked_ranges.push(StackedRange {
+                    counters: addresses.collect(),
+                    range: f32::NAN,
+                    drawn: false,
+                });
+                self.stacked_ranges.last_mut().unwrap()
+            }
+        };
+        entry.range = smooth_range(entry.range, max);
+        entry.drawn = true;
+
+        entry.range
     }
 }
 
 pub(crate) fn draw_graphs(
     layer: Layer,
     rect: (Point, Point),
-    counters: &[&Counter],
-    reference_value: f32,
-    orientation: Orientation,
+    graphs: &Graphs,
     overlay: &mut Overlay,
-) {
+) -> f32 {
+    let Graphs {
+        counters,
+        reference_value,
+        reference_color,
+        auto_range,
+        orientation,
+        bars,
+        ..
+    } = *graphs;
+
     let rect = if orientation == Orientation::Horizontal {
         (
             Point {
@@ -225,13 +582,13 @@ pub(crate) fn draw_graphs(
         rect
     };
 
-    let mut max = std::f32::MIN;
+    let mut max = f32::NAN;
     let mut total_count = 0;
 
     let mut iters = Vec::with_capacity(counters.len());
     for counter in counters {
         if let Some(it) = counter.history() {
-            iters.push((it, counter.descriptor.color))
+            iters.push((it, *counter))
         }
     }
 
@@ -253,24 +610,37 @@ pub(crate) fn draw_graphs(
     iters.clear();
     for counter in counters {
         if let Some(it) = counter.history() {
-            iters.push((it, counter.descriptor.color))
+            iters.push((it, *counter))
         }
     }
 
-    let w = ((rect.1.x - rect.0.x) as f32 / total_count as f32).max(1.0) as i32;
-    let y_scale = (rect.1.y - rect.0.y) as f32 / max.max(reference_value);
+    let (bar_width, w, skip) = bars.resolve(rect.1.x - rect.0.x, total_count);
+    let range = if auto_range {
+        round_range(overlay.stacked_range(counters, max))
+    } else {
+        max
+    };
+    let scale = range.max(reference_value);
+    let y_scale = (rect.1.y - rect.0.y) as f32 / scale;
+
+    for iter in &mut iters {
+        for _ in 0..skip {
+            iter.0.next();
+        }
+    }
 
     let mut x0 = rect.0.x;
 
     'outer: loop {
         let mut y0 = rect.1.y;
-        let x1 = x0 + w;
+        let x1 = x0 + bar_width;
         for iter in &mut iters {
             let Some(val) = iter.0.next() else {
                 break 'outer;
             };
             if let Some(val) = val {
-                let color = iter.1;
+                let counter = iter.1;
+                let color = counter.descriptor.color;
                 let y1 = (y0 as f32 - val * y_scale) as i32;
                 let rect = if orientation == Orientation::Horizontal {
                     ((y0, x0).into(), (y1, x1).into())
@@ -278,9 +648,339 @@ pub(crate) fn draw_graphs(
                     ((x0, y0).into(), (x1, y1).into())
                 };
                 overlay.geometry.push_rectangle(layer, &rect, color, color);
+                if overlay.is_hovered(&normalized_rect(rect)) {
+                    overlay.show_counter_tooltip(counter);
+                }
                 y0 = y1;
             }
         }
-        x0 = x1;
+        x0 += w;
+    }
+
+    if let Some(color) = reference_color {
+        draw_reference_line(
+            layer,
+            rect,
+            reference_value * y_scale,
+            color,
+            orientation,
+            overlay,
+        );
+    }
+
+    scale
+}
+
+/// Draws a horizontal line at the provided height from the bottom of a graph.
+///
+/// The rectangle is in the graph's vertical space, like in `draw_graph`.
+fn draw_reference_line(
+    layer: Layer,
+    rect: (Point, Point),
+    height: f32,
+    color: Color,
+    orientation: Orientation,
+    overlay: &mut Overlay,
+) {
+    if height <= 0.0 || !height.is_finite() {
+        return;
+    }
+
+    let y = rect.1.y - height as i32;
+    let line = if orientation == Orientation::Horizontal {
+        ((y, rect.0.x).into(), (y + 1, rect.1.x).into())
+    } else {
+        ((rect.0.x, y).into(), (rect.1.x, y + 1).into())
+    };
+    overlay.geometry.push_rectangle(layer, &line, color, color);
+}
+
+/// Draws guide lines and the range of a vertical graph, as well as the current
+/// value to the right of the graph.
+///
+/// The labels are formatted like `format`, whose value is ignored.
+///
+/// Returns the area covered by the graph and its labels.
+fn draw_axes(
+    layer: Layer,
+    rect: (Point, Point),
+    scale: f32,
+    current: Option<f32>,
+    format: FormattedValue,
+    overlay: &mut Overlay,
+) -> (Point, Point) {
+    if !scale.is_finite() || scale <= 0.0 {
+        return rect;
+    }
+
+    let (c0, c1, c2, _) = overlay.style.text_color[1];
+    let guide_color = (c0, c1, c2, 60);
+    let text_color = overlay.style.text_color[1];
+    let h = rect.1.y - rect.0.y;
+    for i in 1..4 {
+        let y = rect.1.y - h * i / 4;
+        let line = ((rect.0.x, y).into(), (rect.1.x, y + 1).into());
+        overlay
+            .geometry
+            .push_rectangle(layer, &line, guide_color, guide_color);
+    }
+
+    let label = |overlay: &mut Overlay, val: f32, position: Point| {
+        overlay.string_buffer.clear();
+        let value = match format.format {
+            Format::Int => val.round(),
+            Format::Float => val,
+        };
+        let _ = write!(
+            overlay.string_buffer,
+            "{}",
+            FormattedValue { value, ..format }
+        );
+        overlay
+            .geometry
+            .push_text(layer, &overlay.string_buffer, position, text_color)
+    };
+
+    let line_height = overlay.style.font.line_height();
+    let x = rect.0.x + 2;
+    label(overlay, scale, (x, rect.0.y + line_height).into());
+    label(overlay, 0.0, (x, rect.1.y - 2).into());
+
+    let mut bounds = rect;
+    if let Some(val) = current {
+        let y = rect.1.y - (val / scale * h as f32) as i32;
+        let y = (y + line_height / 2).clamp(rect.0.y + line_height, rect.1.y);
+        let r = label(overlay, val, (rect.1.x + 4, y).into());
+        bounds.1.x = bounds.1.x.max(r.1.x);
+    }
+
+    bounds
+}
+
+/// Iterates over the windows of up to `size` samples ending at each sample.
+fn moving_windows<T>(samples: &[T], size: usize) -> impl Iterator<Item = &[T]> {
+    let size = size.max(1);
+    (0..samples.len()).map(move |idx| &samples[(idx + 1).saturating_sub(size)..=idx])
+}
+
+/// Rounds a positive value up to 1, 2, 2.5 or 5 times a power of ten.
+pub(crate) fn round_range(val: f32) -> f32 {
+    if !val.is_finite() || val <= 0.0 {
+        return val;
     }
+
+    let magnitude = 10f32.powf(val.log10().floor());
+    let mantissa = val / magnitude;
+    let rounded = [1.0, 2.0, 2.5, 5.0, 10.0]
+        .into_iter()
+        .find(|&m| mantissa <= m * 1.0001)
+        .unwrap_or(10.0);
+
+    rounded * magnitude
+}
+
+fn normalized_rect(rect: (Point, Point)) -> (Point, Point) {
+    (
+        Point {
+            x: rect.0.x.min(rect.1.x),
+            y: rect.0.y.min(rect.1.y),
+        },
+        Point {
+            x: rect.0.x.max(rect.1.x),
+            y: rect.0.y.max(rect.1.y),
+        },
+    )
+}
+
+#[test]
+fn line_graph() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut counter = Counter::new(CounterDescriptor::float("frame", "ms", CounterId(0)));
+    counter.enable_history(3);
+    for val in [1.0, 2.0, 3.0] {
+        counter.set(val);
+        counter.update(false);
+    }
+
+    let mut overlay = Overlay::new();
+    let rect = (Point { x: 0, y: 0 }, Point { x: 30, y: 30 });
+    let mut graph = Graph::new(&counter);
+    graph.style = GraphStyle::Line;
+    graph.auto_range = false;
+    let stats = draw_graph(FRONT_LAYER, rect, &graph, &mut overlay);
+    assert_eq!(stats.samples_active, 3);
+    // Two collinear segments, the last one ending on the highest sample at
+    // the top of the graph.
+    let vertices = &overlay.geometry.vertices;
+    assert_eq!(vertices.len(), 8);
+    assert!(((vertices[5].x + vertices[6].x) * 0.5 - 25.0).abs() < 0.01);
+    assert!(((vertices[5].y + vertices[6].y) * 0.5).abs() < 0.01);
+}
+
+#[test]
+fn graph_axes() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut counter = Counter::new(CounterDescriptor::float("frame", "ms", CounterId(0)));
+    counter.enable_history(4);
+    for val in [1.0, 4.0, 2.0] {
+        counter.set(val);
+        counter.update(false);
+    }
+
+    let mut overlay = Overlay::new();
+    let mut graph = Graph::new(&counter);
+    graph.width = Some(40);
+    graph.height = Some(40);
+    overlay.begin_frame();
+    let without_axes = overlay.draw_item(&graph);
+    graph.axes = true;
+    let with_axes = overlay.draw_item(&graph);
+    overlay.finish();
+
+    let r0 = overlay.item_rect(without_axes).unwrap();
+    let r1 = overlay.item_rect(with_axes).unwrap();
+    // The current value is printed to the right of the graph.
+    assert_eq!(r0.1.x - r0.0.x, 40);
+    assert!(r1.1.x - r1.0.x > 40);
+}
+
+#[test]
+fn reference_line() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut counter = Counter::new(CounterDescriptor::float("frame", "ms", CounterId(0)));
+    counter.enable_history(2);
+    counter.set(10.0);
+    counter.update(false);
+
+    let mut overlay = Overlay::new();
+    let mut graph = Graph::new(&counter);
+    graph.reference_value = 20.0;
+    graph.reference_color = Some((255, 0, 0, 255));
+    let rect = (Point { x: 0, y: 0 }, Point { x: 20, y: 40 });
+    draw_graph(FRONT_LAYER, rect, &graph, &mut overlay);
+
+    // A bar for the sample and the reference line at the top of the graph.
+    let vertices = &overlay.geometry.vertices;
+    assert_eq!(vertices.len(), 8);
+    assert_eq!(vertices[4].y, 0.0);
+    assert_eq!(vertices[4].color, crate::color_to_u32((255, 0, 0, 255)));
+}
+
+#[test]
+fn round_ranges() {
+    assert_eq!(round_range(3.2), 5.0);
+    assert_eq!(round_range(16.6), 20.0);
+    assert_eq!(round_range(200.0), 200.0);
+    assert_eq!(round_range(0.7), 1.0);
+    assert_eq!(round_range(2.1), 2.5);
+    assert_eq!(round_range(0.0), 0.0);
+}
+
+#[test]
+fn moving_average() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let samples = [1, 2, 3, 4];
+    let windows: Vec<&[i32]> = moving_windows(&samples, 2).collect();
+    assert_eq!(windows, [&[1][..], &[1, 2], &[2, 3], &[3, 4]]);
+
+    let mut counter = Counter::new(CounterDescriptor::float("frame", "ms", CounterId(0)));
+    counter.enable_history(4);
+    for val in [2.0, 4.0, 2.0, 4.0] {
+        counter.set(val);
+        counter.update(false);
+    }
+
+    let mut overlay = Overlay::new();
+    let mut graph = Graph::new(&counter);
+    graph.auto_range = false;
+    graph.moving_average = Some((2, (255, 0, 0, 255)));
+    let rect = (Point { x: 0, y: 0 }, Point { x: 40, y: 40 });
+    draw_graph(FRONT_LAYER, rect, &graph, &mut overlay);
+
+    // The averages are 2, 3, 3 and 3. After the first sample, the line is flat
+    // at 3/4 of the height, y = 10. The last vertex is the outer corner of the
+    // join where the line becomes flat at the second sample, on the upper edge
+    // of the line 0.75 pixels above.
+    let red = crate::color_to_u32((255, 0, 0, 255));
+    let last = overlay
+        .geometry
+        .vertices
+        .iter()
+        .rev()
+        .find(|v| v.color == red)
+        .unwrap();
+    assert!((last.x - 15.0).abs() < 0.01);
+    assert!((last.y - 9.25).abs() < 0.01);
+}
+
+#[test]
+fn stacked_range() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut a = Counter::new(CounterDescriptor::float("cpu", "ms", CounterId(0)));
+    let mut b = Counter::new(CounterDescriptor::float("gpu", "ms", CounterId(1)));
+    a.enable_history(2);
+    b.enable_history(2);
+    let push = |counters: [&mut Counter; 2], val| {
+        for counter in counters {
+            counter.set(val);
+            counter.update(false);
+        }
+    };
+    push([&mut a, &mut b], 40.0);
+    push([&mut a, &mut b], 40.0);
+
+    let mut overlay = Overlay::new();
+    let rect = (Point { x: 0, y: 0 }, Point { x: 30, y: 30 });
+    let counters = [&a, &b];
+    let scale = draw_graphs(FRONT_LAYER, rect, &Graphs::new(&counters), &mut overlay);
+    assert_eq!(scale, 100.0);
+
+    // The range shrinks slowly after the spike instead of following the
+    // maximum of the visible samples.
+    push([&mut a, &mut b], 1.0);
+    push([&mut a, &mut b], 1.0);
+    let counters = [&a, &b];
+    for _ in 0..5 {
+        overlay.begin_frame();
+        let scale = draw_graphs(FRONT_LAYER, rect, &Graphs::new(&counters), &mut overlay);
+        assert_eq!(scale, 100.0);
+    }
+
+    // Ranges of graphs that are not drawn anymore are dropped.
+    overlay.begin_frame();
+    overlay.begin_frame();
+    assert!(overlay.stacked_ranges.is_empty());
+}
+
+#[test]
+fn legend() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let a = Counter::new(CounterDescriptor::float("cpu", "ms", CounterId(0)));
+    let b = Counter::new(CounterDescriptor::float("gpu", "ms", CounterId(1)));
+    let counters = [&a, &b];
+
+    let mut overlay = Overlay::new();
+    let legend = Legend::new(&counters);
+    let rect = legend.draw(Point { x: 0, y: 0 }, &mut overlay);
+    // Two rows.
+    assert!(rect.1.y >= 2 * overlay.style.font.line_height());
+    assert!(rect.1.x > 14);
+}
+
+#[test]
+fn bar_layout() {
+    // 10 samples fit in 100 pixels with 8 pixel wide bars and a 2 pixel gap.
+    assert_eq!(BarLayout::new(8, 2).resolve(100, 10), (8, 10, 0));
+    // Only the 10 most recent samples out of 240 fit.
+    assert_eq!(BarLayout::new(8, 2).resolve(100, 240), (8, 10, 230));
+    // Automatic width.
+    assert_eq!(BarLayout::AUTO.resolve(100, 50), (2, 2, 0));
+    assert_eq!(BarLayout::AUTO.resolve(100, 200), (1, 1, 100));
+    assert_eq!(BarLayout::new(3, 1).width_for(4), 15);
 }
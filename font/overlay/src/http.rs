@@ -0,0 +1,405 @@
+//! A minimal HTTP endpoint exposing the counters to external tools.
+//!
+//! The server does not spawn any thread: call [`MetricsServer::poll`] once per
+//! frame (typically after `Counters::update`) to answer pending requests.
+//!
+//! - `GET /metrics` returns the counters in the Prometheus text format.
+//! - `GET /` or `GET /counters.json` returns the counters as JSON.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use crate::{Counter, Counters};
+
+/// The maximum number of connections waiting for their request. New
+/// connections stay in the listen queue of the system until older ones are
+/// answered or time out, which bounds the work done by each `poll`.
+const MAX_PENDING: usize = 8;
+
+/// How long a connection may take to send its request line.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How long a connection may take to receive its response.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct MetricsServer {
+    listener: TcpListener,
+    pending: Vec<PendingRequest>,
+    buffer: String,
+}
+
+/// A connection which request line hasn't fully arrived yet, or which
+/// response hasn't been fully sent yet.
+struct PendingRequest {
+    stream: TcpStream,
+    request: [u8; 1024],
+    len: usize,
+    /// The response and how many bytes of it were sent, once the request line
+    /// has arrived.
+    response: Option<(Vec<u8>, usize)>,
+    deadline: Instant,
+}
+
+impl PendingRequest {
+    /// Reads what has arrived of the request. Returns whether the request line
+    /// is complete.
+    fn read(&mut self) -> io::Result<bool> {
+        let len = self.stream.read(&mut self.request[self.len..])?;
+        if len == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        self.len += len;
+        // Only the request line matters.
+        let full = self.len == self.request.len();
+        Ok(full || self.request[..self.len].contains(&b'\n'))
+    }
+
+    /// Sends as much of the response as the socket accepts. Returns whether
+    /// all of it was sent.
+    fn send(&mut self) -> io::Result<bool> {
+        let Some((response, sent)) = &mut self.response else {
+            return Ok(false);
+        };
+        while *sent < response.len() {
+            match self.stream.write(&response[*sent..])? {
+                0 => return Err(io::ErrorKind::WriteZero.into()),
+                len => *sent += len,
+            }
+        }
+        self.stream.flush()?;
+
+        Ok(true)
+    }
+}
+
+impl MetricsServer {
+    /// Starts listening on the provided address, for example `"127.0.0.1:9100"`.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(MetricsServer {
+            listener,
+            pending: Vec::new(),
+            buffer: String::new(),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<std::net::SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Answers the requests that have arrived, if any. Never blocks: clients
+    /// which haven't sent their request line yet are answered by a later call,
+    /// and responses that don't fit in the send buffer of the socket are
+    /// finished by later calls.
+    pub fn poll(&mut self, counters: &Counters) {
+        while self.pending.len() < MAX_PENDING {
+            let Ok((stream, _)) = self.listener.accept() else {
+                break;
+            };
+            if stream.set_nonblocking(true).is_ok() {
+                self.pending.push(PendingRequest {
+                    stream,
+                    request: [0; 1024],
+                    len: 0,
+                    response: None,
+                    deadline: Instant::now() + REQUEST_TIMEOUT,
+                });
+            }
+        }
+
+        let now = Instant::now();
+        let mut idx = 0;
+        while idx < self.pending.len() {
+            let pending = &mut self.pending[idx];
+            let result = match pending.response {
+                Some(_) => pending.send(),
+                None => pending.read().and_then(|complete| {
+                    if !complete {
+                        return Ok(false);
+                    }
+                    let request = &pending.request[..pending.len];
+                    pending.response = Some((response(request, counters, &mut self.buffer), 0));
+                    pending.deadline = now + RESPONSE_TIMEOUT;
+                    pending.send()
+                }),
+            };
+
+            match result {
+                Ok(false) => idx += 1,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock && now < pending.deadline => {
+                    idx += 1;
+                }
+                // Answered, or the client went away or took too long. A
+                // misbehaving client only affects its own request.
+                _ => {
+                    self.pending.swap_remove(idx);
+                }
+            }
+        }
+    }
+}
+
+/// Builds the response to a request, using `buffer` to format the body.
+fn response(request: &[u8], counters: &Counters, buffer: &mut String) -> Vec<u8> {
+    let request = String::from_utf8_lossy(request);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("GET "))
+        .and_then(|line| line.split(' ').next());
+
+    buffer.clear();
+    let (status, content_type) = match path {
+        Some("/metrics") => {
+            write_prometheus(counters, buffer);
+            ("200 OK", "text/plain; version=0.0.4")
+        }
+        Some("/") | Some("/counters.json") => {
+            write_json(counters, buffer);
+            ("200 OK", "application/json")
+        }
+        _ => ("404 Not Found", "text/plain"),
+    };
+
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        buffer.len(),
+    )
+    .into_bytes();
+    response.extend_from_slice(buffer.as_bytes());
+
+    response
+}
+
+/// Writes the last value of each counter in the Prometheus text format, as
+/// gauges.
+///
+/// Metric names are `<group>_<counter>` with characters that are not valid in
+/// Prometheus names replaced by underscores, and an underscore in front of
+/// names that would start with a digit. Counters which names end up the same
+/// get a `_2`, `_3`, etc. suffix in registration order.
+pub fn write_prometheus(counters: &Counters, output: &mut String) {
+    let mut names = HashSet::new();
+    let mut name = String::new();
+    for (group, group_counters) in counters.groups() {
+        for counter in group_counters {
+            name.clear();
+            push_metric_name(&mut name, group);
+            name.push('_');
+            push_metric_name(&mut name, counter.name());
+            if name.starts_with(|c: char| c.is_ascii_digit()) {
+                name.insert(0, '_');
+            }
+            // Counters without a value keep their name so that the names of
+            // the others don't change from one frame to the next.
+            let len = name.len();
+            let mut suffix = 1;
+            while !names.insert(name.clone()) {
+                suffix += 1;
+                name.truncate(len);
+                let _ = write!(name, "_{suffix}");
+            }
+
+            if !counter.last_value.is_finite() {
+                continue;
+            }
+            let _ = writeln!(output, "# TYPE {name} gauge");
+            let _ = writeln!(output, "{name} {}", counter.last_value);
+        }
+    }
+}
+
+/// Writes the counters as a JSON object of groups, each containing an object
+/// of counters with their value and statistics.
+pub fn write_json(counters: &Counters, output: &mut String) {
+    output.push('{');
+    for (group_idx, (group, group_counters)) in counters.groups().enumerate() {
+        if group_idx > 0 {
+            output.push(',');
+        }
+        push_json_string(output, group);
+        output.push_str(":{");
+        for (idx, counter) in group_counters.iter().enumerate() {
+            if idx > 0 {
+                output.push(',');
+            }
+            push_json_counter(output, counter);
+        }
+        output.push('}');
+    }
+    output.push('}');
+}
+
+fn push_json_counter(output: &mut String, counter: &Counter) {
+    push_json_string(output, counter.name());
+    output.push_str(":{\"unit\":");
+    push_json_string(output, counter.descriptor.unit);
+    for (name, value) in [
+        ("value", counter.last_value),
+        ("avg", counter.displayed_avg),
+        ("min", counter.displayed_min),
+        ("max", counter.displayed_max),
+    ] {
+        let _ = write!(output, ",\"{name}\":");
+        if value.is_finite() {
+            let _ = write!(output, "{value}");
+        } else {
+            output.push_str("null");
+        }
+    }
+    output.push('}');
+}
+
+fn push_json_string(output: &mut String, s: &str) {
+    output.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(output, "\\u{:04x}", c as u32);
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+fn push_metric_name(output: &mut String, name: &str) {
+    for c in name.chars() {
+        output.push(if c.is_ascii_alphanumeric() { c } else { '_' });
+    }
+}
+
+#[test]
+fn metrics_server() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut counters = Counters::new(0);
+    let group = counters.register_group(
+        "renderer",
+        &[
+            CounterDescriptor::int("draw calls", "", CounterId(0)),
+            CounterDescriptor::float("frame", "ms", CounterId(1)),
+        ],
+    );
+    counters.set(group.counter(crate::CounterIndex(0)), 12.0);
+    counters.update();
+
+    let mut server = MetricsServer::bind("127.0.0.1:0").unwrap();
+    let addr = server.local_addr().unwrap();
+    let get = |server: &mut MetricsServer, path: &str| {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        server.poll(&counters);
+        while !server.pending.is_empty() {
+            std::thread::sleep(Duration::from_millis(1));
+            server.poll(&counters);
+        }
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+
+    let metrics = get(&mut server, "/metrics");
+    assert!(metrics.starts_with("HTTP/1.1 200 OK"));
+    assert!(metrics.ends_with("\r\n\r\n# TYPE renderer_draw_calls gauge\nrenderer_draw_calls 12\n"));
+
+    let json = get(&mut server, "/counters.json");
+    assert!(json.contains(r#"{"renderer":{"draw calls":{"unit":"","value":12,"avg":"#));
+    assert!(
+        json.ends_with(r#""frame":{"unit":"ms","value":null,"avg":null,"min":null,"max":null}}}"#)
+    );
+
+    assert!(get(&mut server, "/nope").starts_with("HTTP/1.1 404"));
+
+    // A client that doesn't send anything doesn't hold up the others.
+    let idle = TcpStream::connect(addr).unwrap();
+    let start = Instant::now();
+    server.poll(&counters);
+    assert!(start.elapsed() < Duration::from_millis(50));
+    assert_eq!(server.pending.len(), 1);
+    drop(idle);
+    assert!(get(&mut server, "/metrics").starts_with("HTTP/1.1 200 OK"));
+}
+
+#[test]
+fn prometheus_names() {
+    use crate::{CounterDescriptor, CounterId, CounterIndex};
+
+    let mut counters = Counters::new(0);
+    let group = counters.register_group(
+        "3d",
+        &[
+            CounterDescriptor::int("draw calls", "", CounterId(0)),
+            CounterDescriptor::int("draw-calls", "", CounterId(1)),
+            CounterDescriptor::int("draw.calls", "", CounterId(2)),
+        ],
+    );
+    // The first counter has no value but still takes the first name.
+    counters.set(group.counter(CounterIndex(1)), 1.0);
+    counters.set(group.counter(CounterIndex(2)), 2.0);
+    counters.update();
+
+    let mut output = String::new();
+    write_prometheus(&counters, &mut output);
+    assert_eq!(
+        output,
+        "# TYPE _3d_draw_calls_2 gauge\n\
+         _3d_draw_calls_2 1\n\
+         # TYPE _3d_draw_calls_3 gauge\n\
+         _3d_draw_calls_3 2\n"
+    );
+}
+
+#[test]
+fn large_response() {
+    use crate::{CounterDescriptor, CounterId};
+
+    // Enough counters with long enough names for the response not to fit in
+    // the socket buffers.
+    let count = 40_000;
+    let mut counters = Counters::new(0);
+    let descriptors: Vec<CounterDescriptor> = (0..count)
+        .map(|idx| CounterDescriptor::int("", "", CounterId(idx)))
+        .collect();
+    let padding = "x".repeat(100);
+    let names = (0..count).map(|idx| format!("{padding} {idx}"));
+    let group = counters.register_named_group("group".into(), &descriptors, names);
+    for id in group.all() {
+        counters.set(id, 1.0);
+    }
+    counters.update();
+
+    let mut server = MetricsServer::bind("127.0.0.1:0").unwrap();
+    let mut stream = TcpStream::connect(server.local_addr().unwrap()).unwrap();
+    write!(stream, "GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+    server.poll(&counters);
+    while server.pending[0].response.is_none() {
+        std::thread::sleep(Duration::from_millis(1));
+        server.poll(&counters);
+    }
+    // The client hasn't read anything yet, the rest is sent by later polls.
+    let (response, sent) = server.pending[0].response.as_ref().unwrap();
+    assert!(*sent < response.len());
+
+    let client = std::thread::spawn(move || {
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    });
+    while !server.pending.is_empty() {
+        std::thread::sleep(Duration::from_millis(1));
+        server.poll(&counters);
+    }
+    let response = client.join().unwrap();
+
+    let (header, body) = response.split_once("\r\n\r\n").unwrap();
+    assert!(header.contains(&format!("Content-Length: {}", body.len())));
+    assert!(body.ends_with(&format!("group_{padding}_{} 1\n", count - 1)));
+}
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+#[cfg(feature = "winit")]
+use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
+#[cfg(feature = "winit")]
+use winit::keyboard::{Key, NamedKey};
+
+use crate::{Overlay, OverlayVisibility, Pages, Point};
+
+/// Translates window events into overlay interactions: showing and hiding the
+/// overlay, cycling pages, scrolling and hovering.
+///
+/// Forward the relevant events of the windowing library as they arrive, then
+/// call `apply` once per frame before drawing the overlay. With the `winit`
+/// feature, `window_event` forwards `winit`'s window events:
+///
+/// ```ignore
+/// // For each window event:
+/// if input.window_event(&event) {
+///     // The overlay handled the key press.
+///     return;
+/// }
+///
+/// // Each frame, before drawing the overlay:
+/// input.apply(&mut overlay);
+/// ```
+pub struct OverlayInput {
+    pub visibility: OverlayVisibility,
+    pub pages: Pages,
+    /// The number of pixels scrolled per line of mouse wheel.
+    pub line_height: f32,
+    /// The key that shows and hides the overlay in `window_event`.
+    #[cfg(feature = "winit")]
+    pub toggle_key: NamedKey,
+    scale_factor: f64,
+    cursor: Option<(f64, f64)>,
+    button: bool,
+    wheel: f32,
+}
+
+impl OverlayInput {
+    pub fn new(pages: Pages) -> Self {
+        OverlayInput {
+            visibility: OverlayVisibility::new(true, Duration::from_millis(150)),
+            pages,
+            line_height: 20.0,
+            #[cfg(feature = "winit")]
+            toggle_key: NamedKey::F12,
+            scale_factor: 1.0,
+            cursor: None,
+            button: false,
+            wheel: 0.0,
+        }
+    }
+
+    /// Sets the scale factor of the overlay, to convert cursor positions from
+    /// physical pixels to overlay coordinates.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+    }
+
+    /// The position of the cursor in physical pixels.
+    pub fn cursor_moved(&mut self, x: f64, y: f64) {
+        self.cursor = Some((x, y));
+    }
+
+    pub fn cursor_left(&mut self) {
+        self.cursor = None;
+        self.button = false;
+    }
+
+    /// Whether the primary mouse button is pressed.
+    pub fn mouse_button(&mut self, pressed: bool) {
+        self.button = pressed;
+    }
+
+    /// Mouse wheel motion in lines. Positive values scroll up, like `winit`.
+    pub fn mouse_wheel_lines(&mut self, delta: f32) {
+        self.wheel -= delta * self.line_height;
+    }
+
+    /// Mouse wheel motion in physical pixels. Positive values scroll up, like
+    /// `winit`.
+    pub fn mouse_wheel_pixels(&mut self, delta: f64) {
+        self.wheel -= (delta / self.scale_factor) as f32;
+    }
+
+    pub fn toggle(&mut self) {
+        self.visibility.toggle();
+    }
+
+    pub fn next_page(&mut self) {
+        self.pages.next_page();
+    }
+
+    pub fn previous_page(&mut self) {
+        self.pages.previous_page();
+    }
+
+    /// Forwards a `winit` window event: mouse motion, buttons and wheel, scale
+    /// factor changes, the toggle key and page up and down to cycle pages.
+    ///
+    /// Returns true if the event was a key press handled by the overlay, which
+    /// the application should then ignore.
+    #[cfg(feature = "winit")]
+    pub fn window_event(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.set_scale_factor(*scale_factor)
+            }
+            WindowEvent::CursorMoved { position, .. } => self.cursor_moved(position.x, position.y),
+            WindowEvent::CursorLeft { .. } => self.cursor_left(),
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => self.mouse_button(state.is_pressed()),
+            WindowEvent::MouseWheel {
+                delta: MouseScrollDelta::LineDelta(_, y),
+                ..
+            } => self.mouse_wheel_lines(*y),
+            WindowEvent::MouseWheel {
+                delta: MouseScrollDelta::PixelDelta(delta),
+                ..
+            } => self.mouse_wheel_pixels(delta.y),
+            WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
+                match &event.logical_key {
+                    Key::Named(key) if *key == self.toggle_key => self.toggle(),
+                    Key::Named(NamedKey::PageDown) if self.visibility.is_visible() => {
+                        self.next_page()
+                    }
+                    Key::Named(NamedKey::PageUp) if self.visibility.is_visible() => {
+                        self.previous_page()
+                    }
+                    _ => return false,
+                }
+                return true;
+            }
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Forwards the mouse state accumulated since the previous frame to the
+    /// overlay.
+    ///
+    /// The mouse is ignored while the overlay is hidden, so that it doesn't
+    /// interact with invisible panels.
+    pub fn apply(&mut self, overlay: &mut Overlay) {
+        let shown = self.visibility.is_drawn();
+        let mouse = self.cursor.filter(|_| shown).map(|(x, y)| Point {
+            x: (x / self.scale_factor) as i32,
+            y: (y / self.scale_factor) as i32,
+        });
+        overlay.set_mouse_position(mouse);
+        overlay.set_mouse_button(shown && self.button);
+        overlay.set_mouse_wheel(if shown { self.wheel as i32 } else { 0 });
+        self.wheel = 0.0;
+    }
+}
+
+#[test]
+fn overlay_input() {
+    let mut input = OverlayInput::new(Pages::new(&["a", "b"]));
+    let mut overlay = Overlay::new();
+
+    input.set_scale_factor(2.0);
+    input.cursor_moved(100.0, 50.0);
+    input.mouse_wheel_lines(-1.0);
+    input.mouse_wheel_pixels(-10.0);
+    input.apply(&mut overlay);
+    assert_eq!(overlay.mouse_position(), Some(Point { x: 50, y: 25 }));
+    assert_eq!(overlay.mouse_wheel, 25);
+
+    input.apply(&mut overlay);
+    assert_eq!(overlay.mouse_wheel, 0);
+
+    input.next_page();
+    assert_eq!(input.pages.active_page(), Some("b"));
+    input.previous_page();
+    assert_eq!(input.pages.active_page(), Some("a"));
+
+    input.toggle();
+    input.visibility.advance(Duration::from_secs(1));
+    input.mouse_button(true);
+    input.apply(&mut overlay);
+    assert_eq!(overlay.mouse_position(), None);
+    assert!(!overlay.mouse_pressed);
+}
+
+#[cfg(feature = "winit")]
+#[test]
+fn winit_events() {
+    use winit::dpi::PhysicalPosition;
+    use winit::event::DeviceId;
+
+    let mut input = OverlayInput::new(Pages::new(&[]));
+    let mut overlay = Overlay::new();
+    let device_id = DeviceId::dummy();
+
+    input.set_scale_factor(2.0);
+    let moved = WindowEvent::CursorMoved {
+        device_id,
+        position: PhysicalPosition::new(100.0, 50.0),
+    };
+    assert!(!input.window_event(&moved));
+    input.window_event(&WindowEvent::MouseInput {
+        device_id,
+        state: ElementState::Pressed,
+        button: MouseButton::Left,
+    });
+    input.window_event(&WindowEvent::MouseWheel {
+        device_id,
+        delta: MouseScrollDelta::LineDelta(0.0, -1.0),
+        phase: winit::event::TouchPhase::Moved,
+    });
+    input.apply(&mut overlay);
+    assert_eq!(overlay.mouse_position(), Some(Point { x: 50, y: 25 }));
+    assert!(overlay.mouse_pressed);
+    assert_eq!(overlay.mouse_wheel, 20);
+
+    input.window_event(&WindowEvent::CursorLeft { device_id });
+    input.apply(&mut overlay);
+    assert_eq!(overlay.mouse_position(), None);
+}
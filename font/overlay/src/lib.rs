@@ -5,31 +5,286 @@
 //! Enable one or several or the builtin runderers using the following cargo features:
 //! - `wgpu`
 //! - `wgpu-core` (TODO)
+//! - `quickstart`: a `wgpu` renderer wired to `winit` window events, to add the overlay
+//!   to an application in a few lines (see the `quickstart` module).
+//! - `egui`: converts the overlay into `egui` meshes (see the `egui` module).
+//! - `tiny-skia`: rasterizes the overlay into an image on the CPU (see the `tiny_skia` module).
+//!
+//! Other features:
+//! - `winit`: forwards `winit` window events to the overlay (see `OverlayInput::window_event`).
+//! - `serialization`: implements serde's `Serialize` and `Deserialize` for the persistent
+//!   state of the overlay (for example panel positions).
+//! - `counters`: displays the event counts of the `counters` crate (see `EventCounters`).
+//! - `http`: a minimal HTTP endpoint serving the counters as JSON or in the Prometheus
+//!   text format (see the `http` module).
+//! - `svg`: writes the history of the counters as an SVG report (see the `svg` module).
+//! - `wgpu_profiler`: records the results of `wgpu_profiler` (see `ProfilerScopes::record_tree`).
 //!
 
+mod alerts;
+mod budget;
 mod counter;
+#[cfg(feature = "egui")]
+pub mod egui;
 pub mod embedded_font;
+mod event_counters;
+mod flame;
+mod frame_stats;
+mod gauge;
 mod graph;
+#[cfg(feature = "http")]
+pub mod http;
+mod input;
+mod notation;
+mod pages;
+mod panel;
+mod profiler;
+#[cfg(feature = "quickstart")]
+pub mod quickstart;
+mod scroll;
+mod shapes;
+pub mod software;
+#[cfg(feature = "svg")]
+pub mod svg;
 mod table;
+mod text;
+#[cfg(feature = "tiny-skia")]
+pub mod tiny_skia;
+mod visibility;
 #[cfg(feature = "wgpu")]
 pub mod wgpu;
+#[cfg(any(feature = "wgpu", feature = "wgpu-core"))]
+mod wgpu_common;
 #[cfg(feature = "wgpu-core")]
 pub mod wgpu_core;
 #[cfg(any(feature = "wgpu", feature = "wgpu-core"))]
-mod wgpu_common;
+mod wgpu_panel;
+#[cfg(feature = "wgpu")]
+pub mod wgpu_timers;
 
 use bytemuck::{Pod, Zeroable};
 use embedded_font::*;
 
+pub use alerts::Alerts;
+pub use budget::{BudgetBar, FRAME_BUDGET_60FPS};
 pub use counter::*;
+#[cfg(feature = "counters")]
+pub use event_counters::EventCounters;
+pub use flame::{FlameGraph, Span};
+pub use frame_stats::FrameStats;
+pub use gauge::Gauge;
 pub use graph::*;
+pub use input::OverlayInput;
+pub use notation::{FormattedValue, Notation};
+pub use pages::*;
+pub use panel::PanelState;
+pub use profiler::ProfilerScopes;
+pub use shapes::LineJoin;
 pub use table::*;
+pub use text::*;
+pub use visibility::OverlayVisibility;
+#[cfg(any(feature = "wgpu", feature = "wgpu-core"))]
+pub use wgpu_panel::*;
 
 pub const BACKGROUND_LAYER: Layer = 0;
 pub const FRONT_LAYER: Layer = 1;
+pub const TOOLTIP_BACKGROUND_LAYER: Layer = 2;
+pub const TOOLTIP_LAYER: Layer = 3;
+
+/// Marks the characters of `ASCII_GLYPHS` that have no glyph.
+const NO_GLYPH: u16 = u16::MAX;
+
+/// The index in `GLYPH_INFO` of each ASCII character, so that looking the most
+/// common glyphs up doesn't need to search `CHAR_RANGES`.
+static ASCII_GLYPHS: [u16; 128] = {
+    let mut lut = [NO_GLYPH; 128];
+    let mut r = 0;
+    while r < CHAR_RANGES.len() {
+        let range = CHAR_RANGES[r];
+        let mut c = range.first;
+        while c <= range.last && c < 128 {
+            lut[c as usize] = range.glyph + (c - range.first) as u16;
+            c += 1;
+        }
+        r += 1;
+    }
+    lut
+};
+
+/// The index of the glyph of a character in the glyph tables of the faces.
+#[inline]
+fn glyph_index(c: char) -> Option<usize> {
+    let c = c as u32;
+    let idx = match ASCII_GLYPHS.get(c as usize) {
+        Some(&NO_GLYPH) => return None,
+        Some(&idx) => idx as usize,
+        None => {
+            let range = CHAR_RANGES[CHAR_RANGES.partition_point(|r| r.last < c)..].first()?;
+            if c < range.first {
+                return None;
+            }
+            range.glyph as usize + (c - range.first) as usize
+        }
+    };
+
+    Some(idx)
+}
+
+/// The adjustment of the advance between two glyphs, in whole pixels like the
+/// advances in `push_glyphs`.
+#[inline]
+fn kerning(pairs: &[KerningPair], left: usize, right: usize) -> i32 {
+    if pairs.is_empty() {
+        return 0;
+    }
+
+    pairs
+        .binary_search_by_key(&(left as u16, right as u16), |k| (k.left, k.right))
+        .map_or(0, |i| pairs[i].advance.round() as i32)
+}
+
+/// Selects a size and weight of the embedded font.
+///
+/// Text is drawn with the face of the atlas that best matches it: the closest
+/// height among the faces of the requested weight if there are any, among all
+/// faces otherwise. The embedded font has regular faces of 14, 18 (the
+/// default) and 24 pixels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Font {
+    /// The height of the font in pixels.
+    pub height: u32,
+    pub bold: bool,
+}
+
+impl Font {
+    /// The default font of `Style` and `push_text`.
+    pub const DEFAULT: Font = Font {
+        height: FONT_HEIGHT,
+        bold: false,
+    };
+
+    pub fn with_height(height: u32) -> Self {
+        Font {
+            height,
+            bold: false,
+        }
+    }
+
+    pub fn bold(self) -> Self {
+        Font { bold: true, ..self }
+    }
+
+    /// The face of the atlas that text in this font is drawn with.
+    pub fn face(self) -> &'static FontFace {
+        select_face(FACES, self)
+    }
+
+    /// The distance between two lines of text, in pixels.
+    pub fn line_height(self) -> i32 {
+        self.face().height as i32
+    }
+
+    /// The metrics of the glyph of a character, or `None` if the atlas doesn't
+    /// have it, in which case `push_text` skips the character.
+    pub fn glyph_metrics(self, c: char) -> Option<GlyphMetrics> {
+        let glyph = self.face().glyphs.get(glyph_index(c)?)?;
+        Some(GlyphMetrics {
+            advance: glyph.x_advance as i32,
+            offset: (glyph.offset.0 as i32, glyph.offset.1 as i32),
+            size: (
+                (glyph.uv1.0 - glyph.uv0.0) as i32,
+                (glyph.uv1.1 - glyph.uv0.1) as i32,
+            ),
+        })
+    }
+
+    /// The adjustment of the advance when `right` follows `left`, in pixels.
+    pub fn kerning(self, left: char, right: char) -> i32 {
+        match (glyph_index(left), glyph_index(right)) {
+            (Some(left), Some(right)) => kerning(self.face().kerning, left, right),
+            _ => 0,
+        }
+    }
+
+    /// The horizontal distance `push_text` moves by to draw the longest line of
+    /// `text`, in pixels.
+    ///
+    /// ```
+    /// use debug_overlay::Font;
+    ///
+    /// let font = Font::DEFAULT;
+    /// let column_width = ["fps", "frame time"]
+    ///     .iter()
+    ///     .map(|label| font.text_width(label))
+    ///     .max()
+    ///     .unwrap();
+    /// assert!(column_width >= font.text_width("fps"));
+    /// ```
+    pub fn text_width(self, text: &str) -> i32 {
+        let face = self.face();
+        let mut width = 0;
+        for line in text.split('\n') {
+            let mut x = 0;
+            let mut previous = None;
+            for idx in line.chars().filter_map(glyph_index) {
+                let Some(glyph) = face.glyphs.get(idx) else {
+                    continue;
+                };
+                if let Some(previous) = previous {
+                    x += kerning(face.kerning, previous, idx);
+                }
+                previous = Some(idx);
+                x += glyph.x_advance as i32;
+            }
+            width = width.max(x);
+        }
+
+        width
+    }
+}
+
+/// The metrics of a glyph, in pixels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GlyphMetrics {
+    /// How far the glyph moves the position of the next one, before kerning.
+    pub advance: i32,
+    /// The position of the top-left corner of the glyph relative to the
+    /// position of the glyph on the baseline.
+    pub offset: (i32, i32),
+    /// The size of the glyph, zero for glyphs that don't cover any pixel such
+    /// as spaces.
+    pub size: (i32, i32),
+}
+
+impl Default for Font {
+    fn default() -> Self {
+        Font::DEFAULT
+    }
+}
+
+fn select_face(faces: &'static [FontFace], font: Font) -> &'static FontFace {
+    faces
+        .iter()
+        .min_by_key(|face| (face.bold != font.bold, face.height.abs_diff(font.height)))
+        .unwrap()
+}
+
+/// Converts a sample of a distance field atlas into coverage, for glyphs drawn
+/// with `scale` device pixels per atlas pixel, like the shader does.
+fn distance_coverage(sample: f32, spread: f32, scale: f32) -> f32 {
+    let distance = (sample - 0.5) * 2.0 * spread * scale;
+    (distance + 0.5).clamp(0.0, 1.0)
+}
+
+/// Darkens the background behind group titles.
+const HEADER_SHADE: Color = (0, 0, 0, 60);
 
 /// A 2D position (in pixels).
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
@@ -57,6 +312,21 @@ pub struct PointF {
     pub y: f32,
 }
 
+impl From<(f32, f32)> for PointF {
+    fn from(val: (f32, f32)) -> Self {
+        PointF { x: val.0, y: val.1 }
+    }
+}
+
+impl From<Point> for PointF {
+    fn from(val: Point) -> Self {
+        PointF {
+            x: val.x as f32,
+            y: val.y as f32,
+        }
+    }
+}
+
 /// An 8-bit per channel RGBA color value.
 pub type Color = (u8, u8, u8, u8);
 /// The index of an overlay layer.
@@ -66,8 +336,18 @@ fn color_to_u32(color: Color) -> u32 {
     (color.0 as u32) << 24 | (color.1 as u32) << 16 | (color.2 as u32) << 8 | color.3 as u32
 }
 
+fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    (
+        lerp(a.0, b.0),
+        lerp(a.1, b.1),
+        lerp(a.2, b.2),
+        lerp(a.3, b.3),
+    )
+}
+
 #[repr(C)]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Vertex {
     pub x: f32,
     pub y: f32,
@@ -78,13 +358,45 @@ pub struct Vertex {
 unsafe impl Pod for Vertex {}
 unsafe impl Zeroable for Vertex {}
 
+/// The number of vertices that `u16` indices can address.
+const MAX_BATCH_VERTICES: usize = 1 << 16;
+
 pub(crate) struct LayerGeometry {
     pub indices: Vec<u16>,
+    /// Where each batch starts in `indices`, and the first vertex its indices
+    /// are relative to.
+    pub batch_starts: Vec<(usize, usize)>,
+}
+
+impl LayerGeometry {
+    /// The batches of indices of the layer, each with the vertex its indices
+    /// are relative to.
+    pub fn batches(&self) -> impl Iterator<Item = (usize, &[u16])> {
+        self.batch_starts
+            .iter()
+            .enumerate()
+            .map(|(i, &(start, base_vertex))| {
+                let end = match self.batch_starts.get(i + 1) {
+                    Some(&(end, _)) => end,
+                    None => self.indices.len(),
+                };
+                (base_vertex, &self.indices[start..end])
+            })
+    }
 }
 
+/// The vertices and indices of the overlay.
+///
+/// Indices are `u16`, so large overlays are split into batches of at most
+/// 65536 vertices, see `LayerGeometry::batches`.
 pub struct OverlayGeometry {
     vertices: Vec<Vertex>,
     layers: Vec<LayerGeometry>,
+    /// The first vertex of the current batch.
+    base_vertex: usize,
+    clip: Option<(Point, Point)>,
+    text_shadow: Option<TextShadow>,
+    font: Font,
 }
 
 impl OverlayGeometry {
@@ -93,11 +405,16 @@ impl OverlayGeometry {
         for _ in 0..layer_count {
             layers.push(LayerGeometry {
                 indices: Vec::new(),
+                batch_starts: Vec::new(),
             });
         }
         OverlayGeometry {
             vertices: Vec::new(),
             layers,
+            base_vertex: 0,
+            clip: None,
+            text_shadow: None,
+            font: Font::DEFAULT,
         }
     }
 
@@ -105,32 +422,176 @@ impl OverlayGeometry {
         self.vertices.clear();
         for layer in &mut self.layers {
             layer.indices.clear();
+            layer.batch_starts.clear();
         }
+        self.base_vertex = 0;
+        self.clip = None;
+    }
+
+    /// Returns the index of the next vertex in the current batch, starting a
+    /// new batch if `count` more vertices don't fit in `u16` indices.
+    ///
+    /// Must be called before pushing vertices that the indices of `layer`
+    /// refer to.
+    pub(crate) fn next_index(&mut self, layer: Layer, count: usize) -> u16 {
+        assert!(
+            count <= MAX_BATCH_VERTICES,
+            "Too many vertices in a single shape"
+        );
+        if self.vertices.len() + count - self.base_vertex > MAX_BATCH_VERTICES {
+            self.base_vertex = self.vertices.len();
+        }
+        let layer = &mut self.layers[layer];
+        if layer.batch_starts.last().map(|&(_, base)| base) != Some(self.base_vertex) {
+            layer
+                .batch_starts
+                .push((layer.indices.len(), self.base_vertex));
+        }
+
+        (self.vertices.len() - self.base_vertex) as u16
+    }
+
+    /// Restricts text, rectangles, lines and polylines pushed afterwards to the
+    /// provided area.
+    ///
+    /// Meshes are not clipped.
+    pub fn set_clip_rect(&mut self, clip: Option<(Point, Point)>) {
+        self.clip = clip;
+    }
+
+    pub fn clip_rect(&self) -> Option<(Point, Point)> {
+        self.clip
+    }
+
+    /// Draws a shadow behind text pushed afterwards.
+    pub fn set_text_shadow(&mut self, shadow: Option<TextShadow>) {
+        self.text_shadow = shadow;
+    }
+
+    pub fn text_shadow(&self) -> Option<TextShadow> {
+        self.text_shadow
+    }
+
+    /// Sets the font of the text pushed afterwards with `push_text` and
+    /// `push_markup`.
+    pub fn set_font(&mut self, font: Font) {
+        self.font = font;
+    }
+
+    pub fn font(&self) -> Font {
+        self.font
     }
 
     pub fn push_text(
+        &mut self,
+        layer: Layer,
+        text: &str,
+        position: Point,
+        color: Color,
+    ) -> (Point, Point) {
+        self.push_text_with_font(layer, text, position, color, self.font)
+    }
+
+    /// Like `push_text`, with the face of the embedded font that best matches
+    /// `font`.
+    pub fn push_text_with_font(
         &mut self,
         layer: Layer,
         text: &str,
         mut position: Point,
         color: Color,
+        font: Font,
     ) -> (Point, Point) {
-        let color = color_to_u32(color);
-        let mut min = position;
-        let mut max = min;
+        let mut bounds = (position, position);
+        let line_start = position.x;
+        self.push_glyphs(
+            layer,
+            text,
+            font.face(),
+            &mut position,
+            line_start,
+            color_to_u32(color),
+            &mut bounds,
+        );
+
+        bounds
+    }
+
+    /// Pushes the glyphs of a string, advancing `position` as it goes and
+    /// extending `bounds` to include them.
+    ///
+    /// The text shadow, if any, is pushed first so that it is behind the whole
+    /// string. It doesn't extend the bounds.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn push_glyphs(
+        &mut self,
+        layer: Layer,
+        text: &str,
+        face: &FontFace,
+        position: &mut Point,
+        line_start: i32,
+        color: u32,
+        bounds: &mut (Point, Point),
+    ) {
+        if let Some(shadow) = self.text_shadow {
+            // The shadow fades along with the text.
+            let (r, g, b, a) = shadow.color();
+            let alpha = a as u32 * (color & 0xFF) / 255;
+            let shadow_color = color_to_u32((r, g, b, alpha as u8));
+            for &(dx, dy) in shadow.offsets() {
+                let mut p = Point {
+                    x: position.x + dx,
+                    y: position.y + dy,
+                };
+                let mut shadow_bounds = (p, p);
+                self.push_glyph_quads(
+                    layer,
+                    text,
+                    face,
+                    &mut p,
+                    line_start + dx,
+                    shadow_color,
+                    &mut shadow_bounds,
+                );
+            }
+        }
+
+        self.push_glyph_quads(layer, text, face, position, line_start, color, bounds);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn push_glyph_quads(
+        &mut self,
+        layer: Layer,
+        text: &str,
+        face: &FontFace,
+        position: &mut Point,
+        line_start: i32,
+        color: u32,
+        bounds: &mut (Point, Point),
+    ) {
+        let (min, max) = bounds;
+        // Most strings are ASCII, in which case each byte is a glyph.
+        self.vertices.reserve(text.len() * 4);
+        self.layers[layer].indices.reserve(text.len() * 6);
 
+        let mut previous = None;
         for c in text.chars() {
             if c == '\n' {
-                position.x = min.x;
-                position.y += FONT_HEIGHT as i32;
+                position.x = line_start;
+                position.y += face.height as i32;
+                previous = None;
                 continue;
             }
 
-            let idx = c as usize - FIRST_CHAR as usize;
-            if idx >= GLYPH_INFO.len() {
+            let Some((idx, glyph)) = glyph_index(c).and_then(|i| Some((i, face.glyphs.get(i)?)))
+            else {
                 continue;
+            };
+            if let Some(previous) = previous {
+                position.x += kerning(face.kerning, previous, idx);
             }
-            let glyph = &GLYPH_INFO[idx];
+            previous = Some(idx);
 
             let uv0x = (glyph.uv0.0 as u32) << 16;
             let uv0y = glyph.uv0.1 as u32;
@@ -142,99 +603,193 @@ impl OverlayGeometry {
             let x1 = x0 + (glyph.uv1.0 - glyph.uv0.0) as i32;
             let y1 = y0 + (glyph.uv1.1 - glyph.uv0.1) as i32;
 
-            let offset = self.vertices.len() as u16;
-            self.vertices.push(Vertex {
-                x: x0 as f32,
-                y: y0 as f32,
-                uv: uv0x | uv0y,
-                color,
-            });
-            self.vertices.push(Vertex {
-                x: x1 as f32,
-                y: y0 as f32,
-                uv: uv1x | uv0y,
-                color,
-            });
-            self.vertices.push(Vertex {
-                x: x1 as f32,
-                y: y1 as f32,
-                uv: uv1x | uv1y,
-                color,
-            });
-            self.vertices.push(Vertex {
-                x: x0 as f32,
-                y: y1 as f32,
-                uv: uv0x | uv1y,
-                color,
-            });
-            let layer = &mut self.layers[layer];
-            for i in [0u16, 1, 2, 0, 2, 3] {
-                layer.indices.push(offset + i);
-            }
-
             position.x += glyph.x_advance as i32;
 
             min.x = min.x.min(x0);
             min.y = min.y.min(y0);
             max.x = max.x.max(x1);
             max.y = max.y.max(y1);
-        }
 
-        (min, max)
+            // Glyphs such as spaces don't cover any pixel.
+            if x0 == x1 || y0 == y1 {
+                continue;
+            }
+
+            let (x0, y0, x1, y1, uv0x, uv0y, uv1x, uv1y) = match self.clip {
+                Some(clip) => {
+                    let cx0 = x0.max(clip.0.x);
+                    let cy0 = y0.max(clip.0.y);
+                    let cx1 = x1.min(clip.1.x);
+                    let cy1 = y1.min(clip.1.y);
+                    if cx0 >= cx1 || cy0 >= cy1 {
+                        continue;
+                    }
+                    // Glyphs are mapped 1:1 to atlas texels.
+                    (
+                        cx0,
+                        cy0,
+                        cx1,
+                        cy1,
+                        uv0x + (((cx0 - x0) as u32) << 16),
+                        uv0y + (cy0 - y0) as u32,
+                        uv1x - (((x1 - cx1) as u32) << 16),
+                        uv1y - (y1 - cy1) as u32,
+                    )
+                }
+                None => (x0, y0, x1, y1, uv0x, uv0y, uv1x, uv1y),
+            };
+
+            let (x0, y0, x1, y1) = (x0 as f32, y0 as f32, x1 as f32, y1 as f32);
+            let offset = self.next_index(layer, 4);
+            self.vertices.extend_from_slice(&[
+                Vertex {
+                    x: x0,
+                    y: y0,
+                    uv: uv0x | uv0y,
+                    color,
+                },
+                Vertex {
+                    x: x1,
+                    y: y0,
+                    uv: uv1x | uv0y,
+                    color,
+                },
+                Vertex {
+                    x: x1,
+                    y: y1,
+                    uv: uv1x | uv1y,
+                    color,
+                },
+                Vertex {
+                    x: x0,
+                    y: y1,
+                    uv: uv0x | uv1y,
+                    color,
+                },
+            ]);
+            self.layers[layer].indices.extend_from_slice(&[
+                offset,
+                offset + 1,
+                offset + 2,
+                offset,
+                offset + 2,
+                offset + 3,
+            ]);
+        }
     }
 
+    /// Pushes a rectangle with a vertical gradient from `color0` at the top to
+    /// `color1` at the bottom.
     pub fn push_rectangle(
         &mut self,
         layer: Layer,
         rect: &(Point, Point),
         color0: Color,
         color1: Color,
+    ) {
+        self.push_rectangle_with_corner_colors(layer, rect, [color0, color0, color1, color1]);
+    }
+
+    /// Pushes a rectangle with a horizontal gradient from `color0` on the left
+    /// to `color1` on the right.
+    pub fn push_horizontal_gradient(
+        &mut self,
+        layer: Layer,
+        rect: &(Point, Point),
+        color0: Color,
+        color1: Color,
+    ) {
+        self.push_rectangle_with_corner_colors(layer, rect, [color0, color1, color1, color0]);
+    }
+
+    /// Pushes a rectangle with colors specified in the order: top-left,
+    /// top-right, bottom-right, bottom-left.
+    pub fn push_rectangle_with_corner_colors(
+        &mut self,
+        layer: Layer,
+        rect: &(Point, Point),
+        colors: [Color; 4],
     ) {
         let uv = (OPAQUE_PIXEL.0 as u32) << 16 | OPAQUE_PIXEL.1 as u32;
-        let x0 = rect.0.x;
-        let y0 = rect.0.y;
-        let x1 = rect.1.x;
-        let y1 = rect.1.y;
-        let color0 = color_to_u32(color0);
-        let color1 = color_to_u32(color1);
-
-        let offset = self.vertices.len() as u16;
-        self.vertices.push(Vertex {
-            x: x0 as f32,
-            y: y0 as f32,
-            uv,
-            color: color0,
-        });
-        self.vertices.push(Vertex {
-            x: x1 as f32,
-            y: y0 as f32,
-            uv,
-            color: color0,
-        });
-        self.vertices.push(Vertex {
-            x: x1 as f32,
-            y: y1 as f32,
-            uv,
-            color: color1,
-        });
-        self.vertices.push(Vertex {
-            x: x0 as f32,
-            y: y1 as f32,
-            uv,
-            color: color1,
-        });
+        let mut x0 = rect.0.x;
+        let mut y0 = rect.0.y;
+        let mut x1 = rect.1.x;
+        let mut y1 = rect.1.y;
+        let mut colors = colors;
+        if let Some(clip) = self.clip {
+            let clamp_x = |x: i32| x.max(clip.0.x).min(clip.1.x);
+            let clamp_y = |y: i32| y.max(clip.0.y).min(clip.1.y);
+            let (cx0, cy0, cx1, cy1) = (clamp_x(x0), clamp_y(y0), clamp_x(x1), clamp_y(y1));
+            if cx0 == cx1 || cy0 == cy1 {
+                return;
+            }
+            let t = |v: i32, min: i32, max: i32| {
+                if max != min {
+                    (v - min) as f32 / (max - min) as f32
+                } else {
+                    0.0
+                }
+            };
+            let (tx0, tx1) = (t(cx0, x0, x1), t(cx1, x0, x1));
+            let (ty0, ty1) = (t(cy0, y0, y1), t(cy1, y0, y1));
+            let [tl, tr, br, bl] = colors;
+            let sample =
+                |tx: f32, ty: f32| lerp_color(lerp_color(tl, tr, tx), lerp_color(bl, br, tx), ty);
+            colors = [
+                sample(tx0, ty0),
+                sample(tx1, ty0),
+                sample(tx1, ty1),
+                sample(tx0, ty1),
+            ];
+            (x0, y0, x1, y1) = (cx0, cy0, cx1, cy1);
+        }
+
+        let offset = self.next_index(layer, 4);
+        let corners = [(x0, y0), (x1, y0), (x1, y1), (x0, y1)];
+        for ((x, y), color) in corners.into_iter().zip(colors) {
+            self.vertices.push(Vertex {
+                x: x as f32,
+                y: y as f32,
+                uv,
+                color: color_to_u32(color),
+            });
+        }
         let layer = &mut self.layers[layer];
         for i in [0u16, 1, 2, 0, 2, 3] {
             layer.indices.push(offset + i);
         }
     }
 
+    /// Moves all of the geometry pushed so far.
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        self.translate_vertices(.., dx, dy);
+    }
+
+    /// Moves a range of vertices, typically the ones pushed after a call to `vertex_count`.
+    pub fn translate_vertices(
+        &mut self,
+        range: impl std::ops::RangeBounds<usize>,
+        dx: i32,
+        dy: i32,
+    ) {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        for vertex in &mut self.vertices[range] {
+            vertex.x += dx as f32;
+            vertex.y += dy as f32;
+        }
+    }
+
+    /// The number of vertices pushed so far in the current frame.
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
     pub fn push_mesh(&mut self, layer: Layer, vertices: &[PointF], indices: &[u16], color: Color) {
         let uv = (OPAQUE_PIXEL.0 as u32) << 16 | OPAQUE_PIXEL.1 as u32;
+        let offset = self.next_index(layer, vertices.len());
         let layer = &mut self.layers[layer];
         self.vertices.reserve(vertices.len());
         layer.indices.reserve(indices.len());
-        let offset = self.vertices.len() as u16;
         let color = color_to_u32(color);
         for vertex in vertices {
             self.vertices.push(Vertex {
@@ -261,6 +816,27 @@ pub struct Overlay {
     in_group: bool,
     max_x: i32,
     max_y: i32,
+    item_rects: Vec<(Point, Point)>,
+    mouse: Option<Point>,
+    hovered_item: Option<ItemId>,
+    tooltip: String,
+    panels: Vec<panel::Panel>,
+    current_panel: Option<panel::CurrentPanel>,
+    drag: Option<panel::Drag>,
+    mouse_pressed: bool,
+    mouse_was_pressed: bool,
+    mouse_wheel: i32,
+    scroll_regions: Vec<scroll::ScrollRegion>,
+    current_scroll: Option<scroll::CurrentScrollRegion>,
+    stacked_ranges: Vec<graph::StackedRange>,
+    anchor: Corner,
+    target_size: Option<(i32, i32)>,
+    anchor_offset: Point,
+    max_height: Option<i32>,
+    group_first_vertex: usize,
+    group_first_item: usize,
+    /// Area of the title of the current group, if it has one.
+    group_title: Option<(Point, Point)>,
 }
 
 impl Overlay {
@@ -271,7 +847,7 @@ impl Overlay {
             y: style.margin,
         };
         Overlay {
-            geometry: OverlayGeometry::new(2),
+            geometry: OverlayGeometry::new(4),
             style,
             cursor,
             item_flow: Orientation::Horizontal,
@@ -281,9 +857,36 @@ impl Overlay {
             in_group: false,
             max_x: 0,
             max_y: 0,
+            item_rects: Vec::new(),
+            mouse: None,
+            hovered_item: None,
+            tooltip: String::new(),
+            panels: Vec::new(),
+            current_panel: None,
+            drag: None,
+            mouse_pressed: false,
+            mouse_was_pressed: false,
+            mouse_wheel: 0,
+            scroll_regions: Vec::new(),
+            current_scroll: None,
+            stacked_ranges: Vec::new(),
+            anchor: Corner::TopLeft,
+            target_size: None,
+            anchor_offset: Point { x: 0, y: 0 },
+            max_height: None,
+            group_first_vertex: 0,
+            group_first_item: 0,
+            group_title: None,
         }
     }
 
+    /// Switches the colors of the overlay to the ones of a theme.
+    ///
+    /// Takes effect for the items drawn after the call.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.style.set_theme(theme);
+    }
+
     pub fn begin_frame(&mut self) {
         self.geometry.begin_frame();
 
@@ -295,6 +898,15 @@ impl Overlay {
         self.max_x = 0;
         self.max_y = 0;
         self.in_group = false;
+        self.item_rects.clear();
+        self.tooltip.clear();
+        self.current_panel = None;
+        self.current_scroll = None;
+        for panel in &mut self.panels {
+            panel.rect = None;
+        }
+        self.stacked_ranges
+            .retain_mut(|range| std::mem::take(&mut range.drawn));
     }
 
     pub fn current_group_width(&self) -> i32 {
@@ -305,12 +917,56 @@ impl Overlay {
         self.group_area.1.y - self.group_area.0.y
     }
 
-    pub fn draw_item(&mut self, item: &dyn OverlayItem) {
-        let first = !self.in_group;
+    /// Draws an item and records its area for hit-testing.
+    ///
+    /// Item ids are assigned in drawing order, they are stable from one frame to
+    /// the next as long as the same items are drawn in the same order.
+    pub fn draw_item(&mut self, item: &dyn OverlayItem) -> ItemId {
+        self.geometry.set_text_shadow(self.style.text_shadow);
+        self.geometry.set_font(self.style.font);
+        let first = !self.in_group
+            || (self.group_title.is_some() && self.item_rects.len() == self.group_first_item);
         if !self.in_group {
             self.begin_group();
         }
 
+        if let Some(scroll) = &self.current_scroll {
+            let margin = if scroll.content_height == 0 {
+                0
+            } else {
+                self.style.margin
+            };
+            let offset = self.scroll_regions[scroll.index].offset;
+            let origin = scroll.origin;
+            self.cursor = Point {
+                x: origin.x,
+                y: origin.y + scroll.content_height + margin - offset,
+            };
+
+            let rect = item.draw(self.cursor, self);
+
+            let scroll = self.current_scroll.as_mut().unwrap();
+            scroll.content_height = scroll.content_height.max(rect.1.y + offset - origin.y);
+            scroll.max_x = scroll.max_x.max(rect.1.x);
+
+            let clip = self.geometry.clip_rect().unwrap();
+            let visible = (
+                Point {
+                    x: rect.0.x,
+                    y: rect.0.y.max(clip.0.y),
+                },
+                Point {
+                    x: rect.1.x,
+                    y: rect.1.y.min(clip.1.y),
+                },
+            );
+
+            let id = ItemId(self.item_rects.len() as u32);
+            self.item_rects.push(visible);
+
+            return id;
+        }
+
         let margin = if first { 0 } else { self.style.margin };
         self.cursor = match self.item_flow {
             Orientation::Vertical => Point {
@@ -329,6 +985,100 @@ impl Overlay {
         self.group_area.0.y = self.group_area.0.y.min(rect.0.y);
         self.group_area.1.x = self.group_area.1.x.max(rect.1.x);
         self.group_area.1.y = self.group_area.1.y.max(rect.1.y);
+
+        let id = ItemId(self.item_rects.len() as u32);
+        self.item_rects.push(rect);
+
+        id
+    }
+
+    /// Returns the last item drawn this frame that contains the provided position.
+    pub fn hit_test(&self, position: Point) -> Option<ItemId> {
+        self.item_rects
+            .iter()
+            .rposition(|rect| rect_contains(rect, position))
+            .map(|idx| ItemId(idx as u32))
+    }
+
+    /// Returns the area covered by an item drawn this frame.
+    pub fn item_rect(&self, id: ItemId) -> Option<(Point, Point)> {
+        self.item_rects.get(id.0 as usize).cloned()
+    }
+
+    /// Sets the position of the mouse in overlay coordinates (in other words
+    /// divided by the scale factor), or `None` if the mouse is outside of the
+    /// target.
+    pub fn set_mouse_position(&mut self, position: Option<Point>) {
+        self.mouse = position;
+    }
+
+    /// The position of the mouse in the layout space of the items being drawn.
+    ///
+    /// It differs from the position passed to `set_mouse_position` when the
+    /// overlay is anchored to a corner other than the top-left one.
+    pub fn mouse_position(&self) -> Option<Point> {
+        self.mouse.map(|pos| Point {
+            x: pos.x - self.anchor_offset.x,
+            y: pos.y - self.anchor_offset.y,
+        })
+    }
+
+    /// Selects the corner of the target from which the content is laid out.
+    ///
+    /// Anchoring to a corner other than the top-left one requires the target
+    /// size: until `set_target_size` is called, the content stays in the
+    /// top-left corner.
+    pub fn anchor(&mut self, corner: Corner) {
+        self.anchor = corner;
+    }
+
+    /// Groups that would extend below this height are moved to a new column.
+    ///
+    /// Only applies when groups flow vertically.
+    pub fn set_max_height(&mut self, height: impl Into<Option<i32>>) {
+        self.max_height = height.into();
+    }
+
+    /// Sets the size of the target in overlay coordinates (in other words
+    /// divided by the scale factor).
+    pub fn set_target_size(&mut self, size: (u32, u32)) {
+        self.target_size = Some((size.0 as i32, size.1 as i32));
+    }
+
+    /// The item under the mouse as of the last call to `finish`.
+    pub fn hovered_item(&self) -> Option<ItemId> {
+        self.hovered_item
+    }
+
+    /// Shows a tooltip next to the mouse for this frame.
+    ///
+    /// The tooltip is drawn on top of everything else in `finish`. If several
+    /// tooltips are requested in the same frame, the last one is shown.
+    pub fn show_tooltip(&mut self, text: &str) {
+        self.tooltip.clear();
+        self.tooltip.push_str(text);
+    }
+
+    /// Shows a tooltip describing a counter for this frame.
+    pub fn show_counter_tooltip(&mut self, counter: &Counter) {
+        self.tooltip.clear();
+        let _ = counter.write_summary(&mut self.tooltip);
+    }
+
+    /// Returns true if the mouse is inside the provided rectangle.
+    ///
+    /// Useful for items that want to highlight part of their content.
+    pub fn is_hovered(&self, rect: &(Point, Point)) -> bool {
+        let Some(pos) = self.mouse_position() else {
+            return false;
+        };
+        if let Some(clip) = self.geometry.clip_rect() {
+            if !rect_contains(&clip, pos) {
+                return false;
+            }
+        }
+
+        rect_contains(rect, pos)
     }
 
     pub fn push_separator(&mut self) {
@@ -360,6 +1110,8 @@ impl Overlay {
     }
 
     fn begin_group(&mut self) {
+        self.geometry.set_text_shadow(self.style.text_shadow);
+        self.geometry.set_font(self.style.font);
         match self.group_flow {
             Orientation::Vertical => {
                 let margin = if self.group_area.1.y > self.style.margin {
@@ -383,12 +1135,87 @@ impl Overlay {
 
         self.group_area = (self.cursor, self.cursor);
         self.in_group = true;
+        self.group_first_vertex = self.geometry.vertex_count();
+        self.group_first_item = self.item_rects.len();
+        self.group_title = None;
+    }
+
+    /// Starts a new group with a title row drawn above its content.
+    ///
+    /// The group ends with the next call to `end_group`, `push_column` or `finish`.
+    pub fn begin_titled_group(&mut self, title: &str) {
+        if self.in_group {
+            self.end_group();
+        }
+        self.begin_group();
+
+        let p = Point {
+            x: self.cursor.x,
+            y: self.cursor.y + self.style.font.line_height(),
+        };
+        let mut title = self
+            .geometry
+            .push_text(FRONT_LAYER, title, p, self.style.title_color);
+        title.0 = self.cursor;
+        self.group_title = Some(title);
+
+        // The content is laid out below the title.
+        let content = Point {
+            x: self.cursor.x,
+            y: title.1.y + self.style.margin / 2,
+        };
+        self.group_area = (content, content);
+        self.cursor = content;
+    }
+
+    /// Moves the current group to a new column if it extends past the maximum height.
+    fn wrap_group(&mut self) {
+        let Some(max_height) = self.max_height else {
+            return;
+        };
+        let margin = self.style.margin;
+        if self.group_flow != Orientation::Vertical
+            || self.group_area.0.y <= margin
+            || self.group_area.1.y + margin <= max_height
+        {
+            return;
+        }
+
+        let dx = self.max_x + margin * 3 - self.group_area.0.x;
+        let dy = margin - self.group_area.0.y;
+        self.geometry
+            .translate_vertices(self.group_first_vertex.., dx, dy);
+        let group_area = &mut self.group_area;
+        let rects = self.item_rects[self.group_first_item..]
+            .iter_mut()
+            .chain(Some(group_area));
+        for rect in rects {
+            rect.0.x += dx;
+            rect.0.y += dy;
+            rect.1.x += dx;
+            rect.1.y += dy;
+        }
     }
 
     pub fn end_group(&mut self) {
+        self.end_scroll_region();
         self.in_group = false;
+        let title = self.group_title.take();
+        if let Some(title) = title {
+            self.group_area.0.y = title.0.y;
+            self.group_area.1.x = self.group_area.1.x.max(title.1.x);
+        }
+        let panel = self.current_panel.take();
+        let floating = panel
+            .as_ref()
+            .map(|p| p.flow_area.is_some())
+            .unwrap_or(false);
+
         if self.group_area.0.x >= self.group_area.1.x || self.group_area.0.y >= self.group_area.1.y
         {
+            if let Some(panel) = panel {
+                self.end_panel(panel);
+            }
             return;
         }
 
@@ -403,8 +1230,11 @@ impl Overlay {
             .y
             .max(self.group_area.0.y + self.style.min_group_height);
 
-        self.max_x = self.max_x.max(self.group_area.1.x);
-        self.max_y = self.max_y.max(self.group_area.1.y);
+        if !floating {
+            self.wrap_group();
+            self.max_x = self.max_x.max(self.group_area.1.x);
+            self.max_y = self.max_y.max(self.group_area.1.y);
+        }
 
         let margin = self.style.margin;
         let mut bg = self.group_area;
@@ -419,24 +1249,129 @@ impl Overlay {
             self.style.background[0],
             self.style.background[1],
         );
+
+        if let Some(title) = title {
+            let mut header = bg;
+            header.1.y = self.group_area.0.y + title.1.y - title.0.y + margin / 4;
+            self.geometry
+                .push_rectangle(BACKGROUND_LAYER, &header, HEADER_SHADE, HEADER_SHADE);
+        }
+
+        if let Some(panel) = panel {
+            self.end_panel(panel);
+        }
     }
 
     pub fn finish(&mut self) {
         if self.in_group {
             self.end_group();
         }
+
+        self.apply_anchor();
+
+        self.hovered_item = self.mouse.and_then(|pos| self.hit_test(pos));
+        self.update_drag();
+        self.mouse_wheel = 0;
+
+        if let Some(mouse) = self.mouse {
+            if !self.tooltip.is_empty() {
+                self.draw_tooltip(mouse);
+            }
+        }
+    }
+
+    /// Moves the content laid out from the top-left corner to the anchor corner.
+    fn apply_anchor(&mut self) {
+        let margin = self.style.margin;
+        // Without the target size, the other corners are unknown.
+        let Some((w, h)) = self.target_size else {
+            self.anchor_offset = Point { x: 0, y: 0 };
+            return;
+        };
+        let dx = match self.anchor {
+            Corner::TopRight | Corner::BottomRight if self.max_x > 0 => w - margin - self.max_x,
+            _ => 0,
+        };
+        let dy = match self.anchor {
+            Corner::BottomLeft | Corner::BottomRight if self.max_y > 0 => h - margin - self.max_y,
+            _ => 0,
+        };
+        self.anchor_offset = Point { x: dx, y: dy };
+        if dx == 0 && dy == 0 {
+            return;
+        }
+
+        self.geometry.translate(dx, dy);
+        let rects = self
+            .item_rects
+            .iter_mut()
+            .chain(self.panels.iter_mut().filter_map(|p| p.rect.as_mut()));
+        for rect in rects {
+            rect.0.x += dx;
+            rect.0.y += dy;
+            rect.1.x += dx;
+            rect.1.y += dy;
+        }
+    }
+
+    fn draw_tooltip(&mut self, mouse: Point) {
+        let margin = self.style.margin;
+        let position = Point {
+            x: mouse.x + margin * 2,
+            y: mouse.y + margin * 2 + self.style.font.line_height(),
+        };
+        let mut rect = self.geometry.push_text_with_font(
+            TOOLTIP_LAYER,
+            &self.tooltip,
+            position,
+            self.style.text_color[0],
+            self.style.font,
+        );
+        rect.0.x -= margin;
+        rect.0.y -= margin;
+        rect.1.x += margin;
+        rect.1.y += margin;
+        self.geometry.push_rectangle(
+            TOOLTIP_BACKGROUND_LAYER,
+            &rect,
+            self.style.background[0],
+            self.style.background[0],
+        );
     }
 }
 
+impl Default for Overlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A corner of the target.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Identifies an item drawn in the overlay, see `Overlay::draw_item`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ItemId(pub u32);
+
+fn rect_contains(rect: &(Point, Point), p: Point) -> bool {
+    p.x >= rect.0.x && p.x < rect.1.x && p.y >= rect.0.y && p.y < rect.1.y
+}
+
 pub trait OverlayItem {
     fn draw(&self, position: Point, output: &mut Overlay) -> (Point, Point);
 }
 
-impl<'a> OverlayItem for &'a str {
+impl OverlayItem for &str {
     fn draw(&self, position: Point, output: &mut Overlay) -> (Point, Point) {
         let p = Point {
             x: position.x,
-            y: position.y + FONT_HEIGHT as i32,
+            y: position.y + output.style.font.line_height(),
         };
 
         output
@@ -445,6 +1380,41 @@ impl<'a> OverlayItem for &'a str {
     }
 }
 
+/// A dark copy of the text drawn behind it, so that text stays readable over
+/// bright content without an opaque background.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextShadow {
+    /// The text is drawn one pixel to the bottom right.
+    Drop(Color),
+    /// The text is drawn one pixel away in all eight directions, which makes
+    /// text nine times as expensive to draw.
+    Outline(Color),
+}
+
+impl TextShadow {
+    pub fn color(&self) -> Color {
+        match *self {
+            TextShadow::Drop(color) | TextShadow::Outline(color) => color,
+        }
+    }
+
+    fn offsets(&self) -> &'static [(i32, i32)] {
+        match self {
+            TextShadow::Drop(_) => &[(1, 1)],
+            TextShadow::Outline(_) => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Style {
     pub margin: i32,
@@ -456,20 +1426,504 @@ pub struct Style {
     pub text_color: [Color; 2],
     pub title_color: Color,
     pub highlight_color: Color,
+    /// Drawn behind all text, `None` by default.
+    pub text_shadow: Option<TextShadow>,
+    /// The font of all text, `Font::DEFAULT` by default. Items are laid out
+    /// with its line height.
+    pub font: Font,
 }
 
 impl Default for Style {
     fn default() -> Self {
-        Style {
+        Style::with_theme(Theme::Dark)
+    }
+}
+
+impl Style {
+    pub fn with_theme(theme: Theme) -> Self {
+        let mut style = Style {
             margin: 10,
             line_spacing: 2,
             min_group_width: 0,
             min_group_height: 0,
             column_spacing: 20,
-            background: [(0, 0, 0, 255), (0, 0, 0, 200)],
-            text_color: [(255, 255, 255, 255), (200, 200, 200, 255)],
-            title_color: (120, 150, 255, 255),
-            highlight_color: (255, 100, 100, 255),
+            background: [(0, 0, 0, 0); 2],
+            text_color: [(0, 0, 0, 0); 2],
+            title_color: (0, 0, 0, 0),
+            highlight_color: (0, 0, 0, 0),
+            text_shadow: None,
+            font: Font::DEFAULT,
+        };
+        style.set_theme(theme);
+
+        style
+    }
+
+    /// Replaces the colors with the ones of a theme, leaving the spacing untouched.
+    pub fn set_theme(&mut self, theme: Theme) {
+        let (background, text_color, title_color, highlight_color) = match theme {
+            Theme::Dark => (
+                [(0, 0, 0, 255), (0, 0, 0, 200)],
+                [(255, 255, 255, 255), (200, 200, 200, 255)],
+                (120, 150, 255, 255),
+                (255, 100, 100, 255),
+            ),
+            Theme::Light => (
+                [(255, 255, 255, 255), (240, 240, 240, 220)],
+                [(20, 20, 20, 255), (80, 80, 80, 255)],
+                (30, 70, 200, 255),
+                (210, 30, 30, 255),
+            ),
+            Theme::HighContrast => (
+                [(0, 0, 0, 255), (0, 0, 0, 255)],
+                [(255, 255, 255, 255), (255, 255, 0, 255)],
+                (0, 255, 255, 255),
+                (255, 0, 0, 255),
+            ),
+            Theme::Transparent => (
+                [(0, 0, 0, 120), (0, 0, 0, 60)],
+                [(255, 255, 255, 255), (220, 220, 220, 255)],
+                (140, 170, 255, 255),
+                (255, 100, 100, 255),
+            ),
+        };
+        self.background = background;
+        self.text_color = text_color;
+        self.title_color = title_color;
+        self.highlight_color = highlight_color;
+    }
+}
+
+/// Color presets for the `Style`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Opaque backgrounds and saturated colors, for readability over busy scenes.
+    HighContrast,
+    /// Translucent backgrounds that let the scene show through.
+    Transparent,
+}
+
+#[test]
+fn hit_test() {
+    let mut overlay = Overlay::new();
+    overlay.item_flow = Orientation::Vertical;
+    let draw = |overlay: &mut Overlay| {
+        overlay.begin_frame();
+        let a = overlay.draw_item(&"first");
+        let b = overlay.draw_item(&"second");
+        overlay.finish();
+        (a, b)
+    };
+    let (a, b) = draw(&mut overlay);
+
+    let rect_a = overlay.item_rect(a).unwrap();
+    let rect_b = overlay.item_rect(b).unwrap();
+    assert!(rect_a.1.y <= rect_b.0.y);
+    assert_eq!(overlay.hit_test(rect_a.0), Some(a));
+    assert_eq!(overlay.hit_test(rect_b.0), Some(b));
+    assert_eq!(overlay.hit_test(Point { x: -1, y: -1 }), None);
+    assert_eq!(overlay.hovered_item(), None);
+
+    overlay.set_mouse_position(Some(Point {
+        x: rect_b.0.x + 1,
+        y: (rect_b.0.y + rect_b.1.y) / 2,
+    }));
+    draw(&mut overlay);
+    assert_eq!(overlay.hovered_item(), Some(b));
+}
+
+#[test]
+fn anchor_bottom_right() {
+    let mut overlay = Overlay::new();
+    overlay.anchor(Corner::BottomRight);
+    overlay.set_target_size((800, 600));
+
+    for text in ["short", "a much longer string"] {
+        overlay.begin_frame();
+        let item = overlay.draw_item(&text);
+        overlay.finish();
+
+        let rect = overlay.item_rect(item).unwrap();
+        assert_eq!(rect.1.x, 800 - overlay.style.margin);
+        assert_eq!(rect.1.y, 600 - overlay.style.margin);
+    }
+}
+
+#[test]
+fn anchor_without_target_size() {
+    let mut overlay = Overlay::new();
+    overlay.anchor(Corner::BottomRight);
+
+    overlay.begin_frame();
+    let item = overlay.draw_item(&"text");
+    overlay.finish();
+
+    // The content stays where it was laid out instead of moving off-screen.
+    let rect = overlay.item_rect(item).unwrap();
+    assert_eq!(rect.0.x, overlay.style.margin);
+    assert!(rect.0.y >= overlay.style.margin);
+}
+
+#[test]
+fn wrap_columns() {
+    let mut overlay = Overlay::new();
+    overlay.set_max_height(100);
+
+    overlay.begin_frame();
+    let mut items = Vec::new();
+    for _ in 0..4 {
+        items.push(overlay.draw_item(&"group"));
+        overlay.end_group();
+    }
+    overlay.finish();
+
+    let rects: Vec<(Point, Point)> = items
+        .iter()
+        .map(|id| overlay.item_rect(*id).unwrap())
+        .collect();
+    assert!(rects.iter().all(|r| r.1.y <= 100));
+    assert!(rects[0].0.x < rects[3].0.x);
+}
+
+#[test]
+fn clipped_corner_colors() {
+    let mut geom = OverlayGeometry::new(1);
+    geom.set_clip_rect(Some((Point { x: 0, y: 0 }, Point { x: 50, y: 100 })));
+    let rect = (Point { x: 0, y: 0 }, Point { x: 100, y: 100 });
+    geom.push_horizontal_gradient(0, &rect, (0, 0, 0, 255), (200, 0, 0, 255));
+
+    // The right edge is clipped halfway through the gradient.
+    assert_eq!(geom.vertices[1].x, 50.0);
+    assert_eq!(geom.vertices[1].color, color_to_u32((100, 0, 0, 255)));
+    assert_eq!(geom.vertices[0].color, color_to_u32((0, 0, 0, 255)));
+}
+
+#[test]
+fn text_glyphs() {
+    let mut geom = OverlayGeometry::new(1);
+    let white = (255, 255, 255, 255);
+    // Characters without a glyph are skipped and spaces produce no quad.
+    let r = geom.push_text(0, "a\tb é", Point { x: 0, y: 20 }, white);
+    assert_eq!(geom.vertices.len(), 8);
+    assert_eq!(geom.layers[0].indices.len(), 12);
+    assert_eq!(&geom.layers[0].indices[6..], &[4, 5, 6, 4, 6, 7]);
+    assert!(r.1.x > geom.vertices[4].x as i32);
+}
+
+#[test]
+fn glyph_lookup() {
+    for range in CHAR_RANGES {
+        for c in range.first..=range.last {
+            let expected = range.glyph as usize + (c - range.first) as usize;
+            assert_eq!(char::from_u32(c).and_then(glyph_index), Some(expected));
         }
     }
+    assert!(glyph_index('\t').is_none());
+    assert!(glyph_index('\u{10FFFF}').is_none());
+}
+
+#[test]
+fn text_shadow() {
+    let push = |shadow: Option<TextShadow>| {
+        let mut geometry = OverlayGeometry::new(1);
+        geometry.set_text_shadow(shadow);
+        geometry.push_text(0, "A", Point { x: 10, y: 20 }, (255, 255, 255, 255));
+        geometry
+    };
+    let text = push(None);
+    assert_eq!(text.vertices.len(), 4);
+
+    let geometry = push(Some(TextShadow::Drop((0, 0, 0, 128))));
+    assert_eq!(geometry.vertices.len(), 8);
+    // The shadow is behind the text.
+    assert_eq!(geometry.vertices[0].x, text.vertices[0].x + 1.0);
+    assert_eq!(geometry.vertices[0].y, text.vertices[0].y + 1.0);
+    assert_eq!(geometry.vertices[0].color, color_to_u32((0, 0, 0, 128)));
+    assert_eq!(&geometry.vertices[4..], &text.vertices[..]);
+
+    let geometry = push(Some(TextShadow::Outline((0, 0, 0, 255))));
+    assert_eq!(geometry.vertices.len(), 36);
+
+    // The shadow doesn't change the layout.
+    let item_rect = |shadow: Option<TextShadow>| {
+        let mut overlay = Overlay::new();
+        overlay.style.text_shadow = shadow;
+        overlay.begin_frame();
+        let id = overlay.draw_item(&"shadow");
+        overlay.finish();
+        overlay.item_rect(id)
+    };
+    assert_eq!(
+        item_rect(Some(TextShadow::Outline((0, 0, 0, 255)))),
+        item_rect(None)
+    );
+}
+
+#[test]
+fn index_batches() {
+    let mut geometry = OverlayGeometry::new(2);
+    let black = (0, 0, 0, 255);
+    let red = (255, 0, 0, 255);
+    let rect = |x: i32, y: i32| (Point { x, y }, Point { x: x + 2, y: y + 2 });
+    // Fills the first batch exactly.
+    for _ in 0..MAX_BATCH_VERTICES / 4 {
+        geometry.push_rectangle(0, &rect(0, 0), black, black);
+    }
+    assert_eq!(geometry.layers[0].batches().count(), 1);
+
+    // The next shape would wrap the indices around.
+    geometry.push_rectangle(1, &rect(5, 5), red, red);
+    geometry.push_rectangle(0, &rect(5, 0), red, red);
+    let batches: Vec<(usize, usize)> = geometry.layers[0]
+        .batches()
+        .map(|(base, indices)| (base, indices.len()))
+        .collect();
+    assert_eq!(
+        batches,
+        [(0, MAX_BATCH_VERTICES / 4 * 6), (MAX_BATCH_VERTICES, 6)]
+    );
+    assert_eq!(
+        geometry.layers[1].batches().next().unwrap(),
+        (MAX_BATCH_VERTICES, &[0, 1, 2, 0, 2, 3][..])
+    );
+    let (base, indices) = geometry.layers[0].batches().last().unwrap();
+    assert_eq!(geometry.vertices[base + indices[0] as usize].x, 5.0);
+
+    // The shapes of the second batch are drawn where they belong.
+    let image = software::render(&geometry, (10, 10), 1.0, (255, 255, 255, 255));
+    assert_eq!(image.pixel(6, 6), red);
+    assert_eq!(image.pixel(6, 1), red);
+    assert_eq!(image.pixel(1, 1), black);
+}
+
+#[test]
+fn glyph_metrics() {
+    let font = Font::DEFAULT;
+    assert_eq!(font.line_height(), FONT_HEIGHT as i32);
+
+    let a = font.glyph_metrics('a').unwrap();
+    assert!(a.advance > 0);
+    assert!(a.size.0 > 0 && a.size.1 > 0);
+    let space = font.glyph_metrics(' ').unwrap();
+    assert_eq!(space.size, (0, 0));
+    assert_eq!(font.glyph_metrics('\u{1F600}'), None);
+
+    // Measuring matches the distance push_text moves by.
+    let text = "Width, in pixels";
+    let mut geometry = OverlayGeometry::new(1);
+    let start = Point { x: 5, y: 20 };
+    let mut position = start;
+    let mut bounds = (start, start);
+    geometry.push_glyphs(0, text, font.face(), &mut position, start.x, 0, &mut bounds);
+    assert_eq!(font.text_width(text), position.x - start.x);
+    assert_eq!(font.text_width("a\nab\n"), font.text_width("ab"));
+    assert_eq!(font.text_width(""), 0);
+}
+
+#[test]
+fn font_faces() {
+    const FACES: &[FontFace] = &[
+        FontFace {
+            height: 18,
+            bold: false,
+            glyphs: &[],
+            kerning: &[],
+        },
+        FontFace {
+            height: 24,
+            bold: false,
+            glyphs: &[],
+            kerning: &[],
+        },
+        FontFace {
+            height: 18,
+            bold: true,
+            glyphs: &[],
+            kerning: &[],
+        },
+    ];
+    let select = |font| {
+        let face = select_face(FACES, font);
+        (face.height, face.bold)
+    };
+    assert_eq!(select(Font::with_height(18)), (18, false));
+    assert_eq!(select(Font::with_height(30)), (24, false));
+    assert_eq!(select(Font::with_height(30).bold()), (18, true));
+    assert_eq!(
+        select_face(&FACES[..2], Font::with_height(18).bold()).height,
+        18
+    );
+
+    // The embedded font has regular faces of 14, 18 and 24 pixels.
+    assert_eq!(Font::with_height(12).line_height(), 14);
+    assert_eq!(Font::DEFAULT.line_height(), 18);
+    assert_eq!(Font::with_height(32).line_height(), 24);
+
+    // Bold text falls back to the regular face of the closest height.
+    let mut geom = OverlayGeometry::new(1);
+    let white = (255, 255, 255, 255);
+    let regular = geom.push_text(0, "Title", Point { x: 0, y: 20 }, white);
+    let large = Font::with_height(24);
+    let large = geom.push_text_with_font(0, "Title", Point { x: 0, y: 20 }, white, large);
+    let font = Font::with_height(40).bold();
+    let bold = geom.push_text_with_font(0, "Title", Point { x: 0, y: 20 }, white, font);
+    assert_eq!(large, bold);
+    assert_ne!(regular, large);
+}
+
+#[test]
+fn style_font() {
+    let mut overlay = Overlay::new();
+    overlay.begin_frame();
+    let small = overlay.draw_item(&"Title");
+    overlay.style.font = Font::with_height(24);
+    let large = overlay.draw_item(&"Title");
+    assert_eq!(overlay.geometry.font(), Font::with_height(24));
+
+    let small = overlay.item_rect(small).unwrap();
+    let large = overlay.item_rect(large).unwrap();
+    assert!(large.1.x - large.0.x > small.1.x - small.0.x);
+    assert!(large.1.y - large.0.y > small.1.y - small.0.y);
+}
+
+#[test]
+fn kerning_pairs() {
+    let pairs = [
+        KerningPair {
+            left: 1,
+            right: 2,
+            advance: -1.2,
+        },
+        KerningPair {
+            left: 2,
+            right: 0,
+            advance: 0.6,
+        },
+    ];
+    assert_eq!(kerning(&pairs, 1, 2), -1);
+    assert_eq!(kerning(&pairs, 2, 0), 1);
+    assert_eq!(kerning(&pairs, 2, 1), 0);
+    assert_eq!(kerning(&[], 1, 2), 0);
+}
+
+#[test]
+fn distance_field() {
+    // The edge is half covered and the opaque pixel fully covered.
+    assert_eq!(distance_coverage(0.5, 4.0, 1.0), 0.5);
+    assert_eq!(distance_coverage(1.0, 4.0, 1.0), 1.0);
+    assert_eq!(distance_coverage(0.0, 4.0, 3.0), 0.0);
+    // Half a pixel inside the edge, a transition that stays one device pixel
+    // wide at any scale.
+    let half_pixel = 0.5 + 0.5 / 8.0;
+    assert_eq!(distance_coverage(half_pixel, 4.0, 1.0), 1.0);
+    assert!(distance_coverage(0.5 + 0.5 / 8.0 / 3.0, 4.0, 3.0) > 0.999);
+    assert!(distance_coverage(half_pixel, 4.0, 0.5) < 1.0);
+}
+
+#[test]
+fn themes() {
+    let mut overlay = Overlay::new();
+    assert_eq!(overlay.style, Style::with_theme(Theme::Dark));
+
+    overlay.style.margin = 4;
+    overlay.set_theme(Theme::Light);
+    assert_eq!(overlay.style.margin, 4);
+    assert_eq!(
+        overlay.style.text_color,
+        Style::with_theme(Theme::Light).text_color
+    );
+
+    overlay.begin_frame();
+    overlay.draw_item(&"text");
+    overlay.finish();
+    let text_color = color_to_u32(overlay.style.text_color[0]);
+    assert!(overlay
+        .geometry
+        .vertices
+        .iter()
+        .any(|v| v.color == text_color));
+}
+
+#[test]
+fn titled_group() {
+    let mut overlay = Overlay::new();
+    overlay.begin_frame();
+    overlay.begin_titled_group("GPU memory");
+    let item = overlay.draw_item(&"textures");
+    overlay.finish();
+
+    // The title is drawn above the item, in the title color.
+    let title_color = color_to_u32(overlay.style.title_color);
+    let title_bottom = overlay
+        .geometry
+        .vertices
+        .iter()
+        .filter(|v| v.color == title_color)
+        .map(|v| v.y as i32)
+        .max()
+        .unwrap();
+    assert!(overlay.item_rect(item).unwrap().0.y >= title_bottom);
+
+    // Group background and header.
+    assert_eq!(overlay.geometry.layers[BACKGROUND_LAYER].indices.len(), 12);
+}
+
+#[test]
+fn counter_tooltips() {
+    let mut counter = Counter::new(CounterDescriptor::float("frame", "ms", CounterId(0)));
+    counter.enable_history(4);
+    counter.set(16.0);
+    counter.update(true);
+
+    let columns = [Column::name(), Column::value()];
+    let rows = [Row::Counter(&counter)];
+    let table = Table {
+        columns: &columns,
+        rows: &rows,
+        labels: false,
+        sort: None,
+    };
+    let mut graph = Graph::new(&counter);
+    graph.width = Some(50);
+    graph.height = Some(20);
+
+    let mut overlay = Overlay::new();
+    overlay.item_flow = Orientation::Vertical;
+    let draw = |overlay: &mut Overlay, mouse: Option<Point>| {
+        overlay.set_mouse_position(mouse);
+        overlay.begin_frame();
+        let ids = (overlay.draw_item(&table), overlay.draw_item(&graph));
+        overlay.finish();
+        ids
+    };
+    let (table_id, graph_id) = draw(&mut overlay, None);
+    assert!(overlay.tooltip.is_empty());
+
+    for id in [table_id, graph_id] {
+        let rect = overlay.item_rect(id).unwrap();
+        let center = Point {
+            x: (rect.0.x + rect.1.x) / 2,
+            y: (rect.0.y + rect.1.y) / 2,
+        };
+        draw(&mut overlay, Some(center));
+        assert_eq!(overlay.hovered_item(), Some(id));
+        assert!(overlay.tooltip.starts_with("frame (ms)\nlast: 16"));
+    }
+
+    // Outside of the items.
+    let rect = overlay.item_rect(graph_id).unwrap();
+    let below = Point {
+        x: rect.0.x,
+        y: rect.1.y + 10,
+    };
+    draw(&mut overlay, Some(below));
+    assert_eq!(overlay.hovered_item(), None);
+    assert!(overlay.tooltip.is_empty());
 }
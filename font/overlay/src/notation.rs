@@ -0,0 +1,193 @@
+use crate::Format;
+use std::fmt::{self, Write};
+
+/// How large values are written, see `CounterDescriptor::notation`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// All digits, for example `1234567.5`.
+    #[default]
+    Plain,
+    /// Digits grouped by thousands, for example `1,234,567.5`.
+    Separators,
+    /// Powers of 1000 with SI prefixes, for example `1.2M`.
+    Si,
+    /// Powers of 1024, for sizes in bytes. `1.2G` is 1.2 times 2^30.
+    Binary,
+}
+
+const SI_PREFIXES: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+const BINARY_PREFIXES: [&str; 7] = ["", "K", "M", "G", "T", "P", "E"];
+
+/// A counter value formatted according to its `Format` and `Notation`.
+///
+/// Supports width and alignment, for example `{:>8}`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FormattedValue {
+    pub value: f32,
+    pub format: Format,
+    pub notation: Notation,
+}
+
+impl FormattedValue {
+    fn write_to(&self, output: &mut impl Write) -> fmt::Result {
+        let value = self.value;
+        if !value.is_finite() {
+            return write!(output, "{value}");
+        }
+
+        let (base, prefixes) = match self.notation {
+            Notation::Plain => return write_plain(value, self.format, output),
+            Notation::Separators => return write_separated(value, self.format, output),
+            Notation::Si => (1000.0, &SI_PREFIXES),
+            Notation::Binary => (1024.0, &BINARY_PREFIXES),
+        };
+
+        let mut scaled = value.abs() as f64;
+        if scaled < base {
+            return write_plain(value, self.format, output);
+        }
+
+        let mut prefix = 0;
+        // Move to the next prefix if rounding would show the base, as in `1000.0k`.
+        while scaled >= base - 0.05 && prefix + 1 < prefixes.len() {
+            scaled /= base;
+            prefix += 1;
+        }
+        let sign = if value < 0.0 { "-" } else { "" };
+
+        write!(output, "{sign}{scaled:.1}{}", prefixes[prefix])
+    }
+}
+
+fn write_plain(value: f32, format: Format, output: &mut impl Write) -> fmt::Result {
+    match format {
+        Format::Int => write!(output, "{value}"),
+        Format::Float => write!(output, "{value:.2}"),
+    }
+}
+
+fn write_separated(value: f32, format: Format, output: &mut impl Write) -> fmt::Result {
+    let mut digits = Buffer::new();
+    match format {
+        Format::Int => write!(digits, "{:.0}", value.abs())?,
+        Format::Float => write!(digits, "{:.2}", value.abs())?,
+    }
+    let digits = digits.as_str();
+    let (int, fract) = digits.split_at(digits.find('.').unwrap_or(digits.len()));
+
+    if value < 0.0 && digits.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+        output.write_char('-')?;
+    }
+    for (i, digit) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            output.write_char(',')?;
+        }
+        output.write_char(digit)?;
+    }
+
+    output.write_str(fract)
+}
+
+impl fmt::Display for FormattedValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = match f.width() {
+            Some(width) => width,
+            None => return self.write_to(f),
+        };
+
+        // Format on the stack first to know how much padding to add.
+        let mut buffer = Buffer::new();
+        self.write_to(&mut buffer)?;
+        let s = buffer.as_str();
+        let padding = width.saturating_sub(s.len());
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+
+        for _ in 0..before {
+            f.write_char(f.fill())?;
+        }
+        f.write_str(s)?;
+        for _ in 0..after {
+            f.write_char(f.fill())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Large enough for any `f32` with two decimals and separators.
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Buffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only complete strings are written.
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn notations() {
+    let f = |value: f32, format: Format, notation: Notation| {
+        FormattedValue {
+            value,
+            format,
+            notation,
+        }
+        .to_string()
+    };
+    use Format::{Float, Int};
+    use Notation::*;
+
+    assert_eq!(f(1234567.0, Int, Plain), "1234567");
+    assert_eq!(f(1234.5, Float, Plain), "1234.50");
+
+    assert_eq!(f(1234567890.0, Int, Separators), "1,234,567,936");
+    assert_eq!(f(999.0, Int, Separators), "999");
+    assert_eq!(f(-1234.5, Float, Separators), "-1,234.50");
+    assert_eq!(f(-0.001, Float, Separators), "0.00");
+
+    assert_eq!(f(12345.0, Int, Si), "12.3k");
+    assert_eq!(f(999.0, Int, Si), "999");
+    assert_eq!(f(0.5, Float, Si), "0.50");
+    assert_eq!(f(999_999.0, Int, Si), "1.0M");
+    assert_eq!(f(-2.5e9, Float, Si), "-2.5G");
+
+    assert_eq!(f(1.2 * 1024.0 * 1024.0 * 1024.0, Int, Binary), "1.2G");
+    assert_eq!(f(1000.0, Int, Binary), "1000");
+    assert_eq!(f(f32::MAX, Float, Separators).len(), 54);
+    assert_eq!(f(f32::NAN, Float, Si), "NaN");
+
+    let value = FormattedValue {
+        value: 12345.0,
+        format: Int,
+        notation: Si,
+    };
+    assert_eq!(format!("[{value:>7}]"), "[  12.3k]");
+    assert_eq!(format!("[{value:<7}]"), "[12.3k  ]");
+}
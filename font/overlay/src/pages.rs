@@ -0,0 +1,134 @@
+use crate::{Overlay, OverlayItem, Point, FRONT_LAYER};
+
+/// A set of named pages, only one of which is active at a time.
+///
+/// The application builds the content of the active page only, which lets one
+/// overlay host several views without cluttering the screen. `next_page` and
+/// `previous_page` are meant to be wired to hotkeys.
+///
+/// `Pages` can also be drawn as an overlay item, showing the list of pages with
+/// the active one highlighted.
+///
+/// # Example
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// let mut pages = Pages::new(&["gpu", "memory", "cpu"]);
+/// let mut overlay = Overlay::new();
+///
+/// pages.set_page("memory");
+///
+/// overlay.begin_frame();
+/// overlay.draw_item(&pages);
+/// if pages.is_active("gpu") {
+///     overlay.draw_item(&"GPU stuff");
+/// }
+/// if pages.is_active("memory") {
+///     overlay.draw_item(&"Memory stuff");
+/// }
+/// overlay.finish();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pages {
+    names: Vec<String>,
+    active: usize,
+}
+
+impl Pages {
+    pub fn new(names: &[&str]) -> Self {
+        Pages {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            active: 0,
+        }
+    }
+
+    /// Adds a page if there isn't already one with the same name.
+    pub fn add_page(&mut self, name: &str) {
+        if !self.names.iter().any(|n| n == name) {
+            self.names.push(name.to_string());
+        }
+    }
+
+    /// Activates the page with the provided name.
+    ///
+    /// Returns false if there is no such page.
+    pub fn set_page(&mut self, name: &str) -> bool {
+        match self.names.iter().position(|n| n == name) {
+            Some(idx) => {
+                self.active = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Activates the next page, wrapping around after the last one.
+    pub fn next_page(&mut self) {
+        if !self.names.is_empty() {
+            self.active = (self.active + 1) % self.names.len();
+        }
+    }
+
+    /// Activates the previous page, wrapping around before the first one.
+    pub fn previous_page(&mut self) {
+        if !self.names.is_empty() {
+            self.active = (self.active + self.names.len() - 1) % self.names.len();
+        }
+    }
+
+    /// The name of the active page, if any.
+    pub fn active_page(&self) -> Option<&str> {
+        self.names.get(self.active).map(|name| name.as_str())
+    }
+
+    pub fn is_active(&self, name: &str) -> bool {
+        self.active_page() == Some(name)
+    }
+
+    pub fn page_names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(|name| name.as_str())
+    }
+}
+
+impl OverlayItem for Pages {
+    fn draw(&self, position: Point, output: &mut Overlay) -> (Point, Point) {
+        let mut min = position;
+        let mut max = position;
+        let mut x = position.x;
+        let y = position.y + output.style.font.line_height();
+        for (idx, name) in self.names.iter().enumerate() {
+            let color = if idx == self.active {
+                output.style.title_color
+            } else {
+                output.style.text_color[1]
+            };
+            let r = output
+                .geometry
+                .push_text(FRONT_LAYER, name, Point { x, y }, color);
+            min.y = min.y.min(r.0.y);
+            max.x = max.x.max(r.1.x);
+            max.y = max.y.max(r.1.y);
+            x = r.1.x + output.style.column_spacing;
+        }
+
+        (min, max)
+    }
+}
+
+#[test]
+fn page_cycling() {
+    let mut pages = Pages::new(&["gpu", "memory"]);
+    pages.add_page("cpu");
+    assert!(pages.is_active("gpu"));
+    pages.next_page();
+    assert!(pages.is_active("memory"));
+    pages.next_page();
+    pages.next_page();
+    assert!(pages.is_active("gpu"));
+    pages.previous_page();
+    assert_eq!(pages.active_page(), Some("cpu"));
+    assert!(!pages.set_page("network"));
+    assert!(pages.set_page("memory"));
+    assert!(pages.is_active("memory"));
+}
@@ -0,0 +1,220 @@
+use crate::{Overlay, Point, FRONT_LAYER};
+
+/// The persistent state of a panel.
+///
+/// Applications can save the states returned by `Overlay::panel_states` and
+/// restore them in a later run using `Overlay::restore_panel_states`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PanelState {
+    pub name: String,
+    /// The position of the panel, or `None` if the panel is laid out with the
+    /// other groups.
+    pub position: Option<Point>,
+    pub collapsed: bool,
+}
+
+pub(crate) struct Panel {
+    pub state: PanelState,
+    /// The area covered by the panel in the current frame, including margins.
+    pub rect: Option<(Point, Point)>,
+}
+
+pub(crate) struct CurrentPanel {
+    pub index: usize,
+    /// The layout area of the regular flow, saved while drawing a panel that
+    /// has its own position.
+    pub flow_area: Option<(Point, Point)>,
+}
+
+pub(crate) struct Drag {
+    pub panel: usize,
+    pub grab_offset: Point,
+}
+
+impl Overlay {
+    /// Starts a named group which can be moved with the mouse and collapsed.
+    ///
+    /// The panel ends with the next call to `end_group`, `push_column` or `finish`.
+    /// Returns false if the panel is collapsed, in which case only its name is
+    /// shown and the caller can skip drawing its content.
+    pub fn begin_panel(&mut self, name: &str) -> bool {
+        if self.in_group {
+            self.end_group();
+        }
+
+        let index = match self.panels.iter().position(|p| p.state.name == name) {
+            Some(idx) => idx,
+            None => {
+                self.panels.push(Panel {
+                    state: PanelState {
+                        name: name.to_string(),
+                        position: None,
+                        collapsed: false,
+                    },
+                    rect: None,
+                });
+                self.panels.len() - 1
+            }
+        };
+
+        let flow_area = self.panels[index].state.position.map(|p| {
+            let flow_area = self.group_area;
+            self.group_area = (p, p);
+            flow_area
+        });
+
+        if flow_area.is_some() {
+            self.cursor = self.group_area.0;
+            self.in_group = true;
+        } else {
+            self.begin_group();
+        }
+
+        self.current_panel = Some(CurrentPanel { index, flow_area });
+
+        if self.panels[index].state.collapsed {
+            let p = Point {
+                x: self.cursor.x,
+                y: self.cursor.y + self.style.font.line_height(),
+            };
+            let rect = self
+                .geometry
+                .push_text(FRONT_LAYER, name, p, self.style.title_color);
+            self.group_area.1.x = self.group_area.1.x.max(rect.1.x);
+            self.group_area.1.y = self.group_area.1.y.max(rect.1.y);
+
+            return false;
+        }
+
+        true
+    }
+
+    pub(crate) fn end_panel(&mut self, panel: CurrentPanel) {
+        let margin = self.style.margin;
+        let mut rect = self.group_area;
+        rect.0.x -= margin;
+        rect.0.y -= margin;
+        rect.1.x += margin;
+        rect.1.y += margin;
+        self.panels[panel.index].rect = Some(rect);
+
+        if let Some(flow_area) = panel.flow_area {
+            self.group_area = flow_area;
+        }
+    }
+
+    /// Sets whether the mouse's primary button is pressed.
+    ///
+    /// Pressing the button over a panel and moving the mouse drags the panel.
+    pub fn set_mouse_button(&mut self, pressed: bool) {
+        self.mouse_pressed = pressed;
+    }
+
+    pub fn set_panel_collapsed(&mut self, name: &str, collapsed: bool) {
+        if let Some(panel) = self.panels.iter_mut().find(|p| p.state.name == name) {
+            panel.state.collapsed = collapsed;
+        }
+    }
+
+    pub fn toggle_panel_collapsed(&mut self, name: &str) {
+        if let Some(panel) = self.panels.iter_mut().find(|p| p.state.name == name) {
+            panel.state.collapsed = !panel.state.collapsed;
+        }
+    }
+
+    /// Moves a panel back into the regular layout flow.
+    pub fn reset_panel_position(&mut self, name: &str) {
+        if let Some(panel) = self.panels.iter_mut().find(|p| p.state.name == name) {
+            panel.state.position = None;
+        }
+    }
+
+    /// Returns the state of all panels seen so far.
+    pub fn panel_states(&self) -> impl Iterator<Item = &PanelState> {
+        self.panels.iter().map(|p| &p.state)
+    }
+
+    /// Restores panel states, typically saved in a previous run.
+    pub fn restore_panel_states(&mut self, states: impl IntoIterator<Item = PanelState>) {
+        for state in states {
+            match self.panels.iter_mut().find(|p| p.state.name == state.name) {
+                Some(panel) => panel.state = state,
+                None => self.panels.push(Panel { state, rect: None }),
+            }
+        }
+    }
+
+    /// Starts, updates or stops dragging panels depending on the mouse state.
+    pub(crate) fn update_drag(&mut self) {
+        let Some(mouse) = self.mouse else {
+            self.drag = None;
+            self.mouse_was_pressed = self.mouse_pressed;
+            return;
+        };
+
+        if !self.mouse_pressed {
+            self.drag = None;
+        } else if let Some(drag) = &self.drag {
+            // Panel positions are in layout space while the mouse position and
+            // panel rectangles are in target space.
+            let state = &mut self.panels[drag.panel].state;
+            state.position = Some(Point {
+                x: mouse.x - drag.grab_offset.x - self.anchor_offset.x,
+                y: mouse.y - drag.grab_offset.y - self.anchor_offset.y,
+            });
+        } else if !self.mouse_was_pressed {
+            let margin = self.style.margin;
+            let hit = self.panels.iter().rposition(|p| {
+                p.rect
+                    .map(|r| crate::rect_contains(&r, mouse))
+                    .unwrap_or(false)
+            });
+            if let Some(idx) = hit {
+                let rect = self.panels[idx].rect.unwrap();
+                self.drag = Some(Drag {
+                    panel: idx,
+                    grab_offset: Point {
+                        x: mouse.x - (rect.0.x + margin),
+                        y: mouse.y - (rect.0.y + margin),
+                    },
+                });
+            }
+        }
+
+        self.mouse_was_pressed = self.mouse_pressed;
+    }
+}
+
+#[test]
+fn drag_panel() {
+    let mut overlay = Overlay::new();
+    let frame = |overlay: &mut Overlay| {
+        overlay.begin_frame();
+        if overlay.begin_panel("stats") {
+            overlay.draw_item(&"hello");
+        }
+        overlay.finish();
+    };
+
+    frame(&mut overlay);
+    overlay.set_mouse_position(Some(Point { x: 20, y: 20 }));
+    overlay.set_mouse_button(true);
+    frame(&mut overlay);
+    overlay.set_mouse_position(Some(Point { x: 120, y: 70 }));
+    frame(&mut overlay);
+    overlay.set_mouse_button(false);
+    frame(&mut overlay);
+
+    let state = overlay.panel_states().next().unwrap().clone();
+    assert_eq!(state.name, "stats");
+    assert_eq!(state.position, Some(Point { x: 110, y: 60 }));
+
+    let mut other = Overlay::new();
+    other.restore_panel_states(Some(state.clone()));
+    other.toggle_panel_collapsed("stats");
+    assert!(!other.begin_panel("stats"));
+}
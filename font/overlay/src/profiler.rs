@@ -0,0 +1,187 @@
+use std::ops::Range;
+
+use crate::{
+    event_counters::KeyedCounters, Column, Counter, CounterDescriptor, CounterId, Overlay,
+    OverlayItem, Point,
+};
+
+/// Maps the timings of nested profiler scopes into counters with history.
+///
+/// Each scope gets a counter named after its path, for example
+/// `"frame/shadows"`, the first time it is recorded. Scopes that are not
+/// recorded in a frame have no value for that frame.
+///
+/// With the `wgpu_profiler` feature, the results of `wgpu_profiler` can be
+/// recorded with `record_tree`.
+pub struct ProfilerScopes {
+    scopes: KeyedCounters<()>,
+    path: String,
+    pub columns: &'static [Column],
+}
+
+const DEFAULT_COLUMNS: &[Column] = &[
+    Column::name(),
+    Column::value(),
+    Column::avg(),
+    Column::max(),
+    Column::history_graph(),
+];
+
+impl ProfilerScopes {
+    pub fn new(history_size: usize) -> Self {
+        ProfilerScopes {
+            scopes: KeyedCounters::new(history_size),
+            path: String::new(),
+            columns: DEFAULT_COLUMNS,
+        }
+    }
+
+    /// Adds the duration of a scope to the current frame, in milliseconds.
+    ///
+    /// Scopes recorded several times in a frame accumulate.
+    pub fn record(&mut self, path: &str, ms: f32) {
+        let descriptor = CounterDescriptor::float("", "ms", CounterId(0)).with_history();
+        let id = self.scopes.entry(path, &descriptor, || ()).id();
+        self.scopes.counters.add(id, ms);
+    }
+
+    /// Records the results of `wgpu_profiler` for the current frame.
+    ///
+    /// # Example
+    ///
+    /// With `results` returned by `GpuProfiler::process_finished_frame`:
+    ///
+    /// ```no_run
+    /// # use wgpu_profiler::GpuTimerQueryResult;
+    /// # fn frame(overlay: &mut debug_overlay::Overlay, scopes: &mut debug_overlay::ProfilerScopes, results: Option<Vec<GpuTimerQueryResult>>) {
+    /// if let Some(results) = results {
+    ///     scopes.record_tree(&results);
+    /// }
+    /// scopes.update();
+    /// overlay.draw_item(scopes);
+    /// # }
+    /// ```
+    #[cfg(feature = "wgpu_profiler")]
+    pub fn record_tree(&mut self, results: &[wgpu_profiler::GpuTimerQueryResult]) {
+        self.record_tree_with(
+            results,
+            |r| &r.label,
+            |r| r.time.clone(),
+            |r| &r.nested_queries,
+        );
+    }
+
+    /// Records a tree of scopes with start and end times in seconds, from any
+    /// profiler.
+    pub fn record_tree_with<S>(
+        &mut self,
+        scopes: &[S],
+        label: impl Fn(&S) -> &str + Copy,
+        time: impl Fn(&S) -> Option<Range<f64>> + Copy,
+        children: impl Fn(&S) -> &[S] + Copy,
+    ) {
+        for scope in scopes {
+            let parent_len = self.path.len();
+            if parent_len > 0 {
+                self.path.push('/');
+            }
+            self.path.push_str(label(scope));
+
+            if let Some(time) = time(scope) {
+                let ms = ((time.end - time.start) * 1000.0) as f32;
+                let path = std::mem::take(&mut self.path);
+                self.record(&path, ms);
+                self.path = path;
+            }
+            self.record_tree_with(children(scope), label, time, children);
+
+            self.path.truncate(parent_len);
+        }
+    }
+
+    /// Must be called once per frame, after recording the scopes.
+    pub fn update(&mut self) {
+        self.scopes.counters.update();
+    }
+
+    /// The counter of a scope, if it was recorded.
+    pub fn get(&self, path: &str) -> Option<&Counter> {
+        self.scopes.get(path)
+    }
+
+    /// Forgets all scopes.
+    pub fn clear(&mut self) {
+        self.scopes.clear();
+    }
+}
+
+impl OverlayItem for ProfilerScopes {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        self.scopes.draw(self.columns, origin, overlay)
+    }
+}
+
+#[test]
+fn profiler_scopes() {
+    struct Scope {
+        label: &'static str,
+        time: Option<Range<f64>>,
+        children: Vec<Scope>,
+    }
+
+    let frame = [Scope {
+        label: "frame",
+        time: Some(0.0..0.010),
+        children: vec![
+            Scope {
+                label: "shadows",
+                time: Some(0.001..0.003),
+                children: Vec::new(),
+            },
+            Scope {
+                label: "pending",
+                time: None,
+                children: Vec::new(),
+            },
+        ],
+    }];
+
+    let mut scopes = ProfilerScopes::new(4);
+    scopes.record_tree_with(&frame, |s| s.label, |s| s.time.clone(), |s| &s.children);
+    scopes.record("ui", 1.5);
+    scopes.update();
+
+    assert_eq!(scopes.get("frame").unwrap().last_value, 10.0);
+    let shadows = scopes.get("frame/shadows").unwrap();
+    assert!((shadows.last_value - 2.0).abs() < 0.001);
+    assert_eq!(shadows.history().unwrap().count(), 4);
+    assert!(scopes.get("frame/pending").is_none());
+    assert_eq!(scopes.get("ui").unwrap().name(), "ui");
+}
+
+#[cfg(feature = "wgpu_profiler")]
+#[test]
+fn wgpu_profiler_results() {
+    use wgpu_profiler::GpuTimerQueryResult;
+
+    let query = |label: &str, time, nested_queries| GpuTimerQueryResult {
+        label: label.to_string(),
+        pid: std::process::id(),
+        tid: std::thread::current().id(),
+        time,
+        nested_queries,
+    };
+    let results = [query(
+        "frame",
+        Some(0.0..0.004),
+        vec![query("shadows", Some(0.001..0.002), Vec::new())],
+    )];
+
+    let mut scopes = ProfilerScopes::new(0);
+    scopes.record_tree(&results);
+    scopes.update();
+
+    assert_eq!(scopes.get("frame").unwrap().last_value, 4.0);
+    let shadows = scopes.get("frame/shadows").unwrap();
+    assert!((shadows.last_value - 1.0).abs() < 0.001);
+}
@@ -0,0 +1,157 @@
+//! Everything needed to show the overlay in a `winit` + `wgpu` application.
+//!
+//! `Quickstart` owns the overlay, its renderer and input handling. It toggles
+//! the overlay with a key (F12 by default), cycles pages with page up and page
+//! down, and follows resizes and scale factor changes of the window.
+//!
+//! ```no_run
+//! # fn example(
+//! #     window: &winit::window::Window,
+//! #     event: &winit::event::WindowEvent,
+//! #     device: &wgpu::Device,
+//! #     queue: &wgpu::Queue,
+//! #     surface_config: &wgpu::SurfaceConfiguration,
+//! #     encoder: &mut wgpu::CommandEncoder,
+//! #     view: &wgpu::TextureView,
+//! # ) {
+//! use debug_overlay::quickstart::Quickstart;
+//!
+//! // Once, after configuring the surface:
+//! let mut hud = Quickstart::new(device, queue, surface_config, window.scale_factor());
+//!
+//! // For each window event:
+//! hud.window_event(event);
+//!
+//! // Each frame, after rendering the scene:
+//! if let Some(overlay) = hud.begin_frame() {
+//!     overlay.draw_item(&"Hello");
+//! }
+//! hud.render(device, queue, encoder, view);
+//! # }
+//! ```
+
+use winit::event::WindowEvent;
+
+use crate::wgpu::{Renderer, RendererOptions, Viewport};
+use crate::{Overlay, OverlayInput, Pages};
+
+/// Owns the overlay and the state needed to draw it into a window.
+pub struct Quickstart {
+    pub overlay: Overlay,
+    /// Handles the mouse and the keys, see `OverlayInput::toggle_key` to change
+    /// the key that shows and hides the overlay.
+    pub input: OverlayInput,
+    renderer: Renderer,
+    viewport: Viewport,
+    target_size: (u32, u32),
+}
+
+impl Quickstart {
+    /// Creates the renderer for a surface configured with `surface_config`.
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        surface_config: &wgpu::SurfaceConfiguration,
+        scale_factor: f64,
+    ) -> Self {
+        let renderer = Renderer::new(
+            device,
+            queue,
+            &RendererOptions {
+                target_format: surface_config.format,
+                scale_factor: scale_factor as f32,
+                ..RendererOptions::default()
+            },
+        );
+        let viewport = renderer.create_viewport(device, scale_factor as f32);
+        let mut input = OverlayInput::new(Pages::new(&[]));
+        input.set_scale_factor(scale_factor);
+
+        Quickstart {
+            overlay: Overlay::new(),
+            input,
+            renderer,
+            viewport,
+            target_size: (surface_config.width, surface_config.height),
+        }
+    }
+
+    /// Forwards a window event to the overlay.
+    ///
+    /// Returns true if the event was a key press handled by the overlay, which
+    /// the application should then ignore.
+    pub fn window_event(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::Resized(size) => {
+                self.target_size = (size.width, size.height);
+            }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.viewport.set_scale_factor(*scale_factor as f32);
+            }
+            _ => {}
+        }
+
+        self.input.window_event(event)
+    }
+
+    /// Starts drawing the overlay for this frame, or returns `None` if it is
+    /// hidden.
+    pub fn begin_frame(&mut self) -> Option<&mut Overlay> {
+        self.input.visibility.tick();
+        if !self.input.visibility.is_drawn() {
+            return None;
+        }
+
+        let scale_factor = self.viewport.scale_factor();
+        self.overlay.set_target_size((
+            (self.target_size.0 as f32 / scale_factor) as u32,
+            (self.target_size.1 as f32 / scale_factor) as u32,
+        ));
+        self.input.apply(&mut self.overlay);
+        self.overlay.begin_frame();
+
+        Some(&mut self.overlay)
+    }
+
+    /// Draws the overlay on top of the content of `view`, which must have the
+    /// size and format of the surface.
+    ///
+    /// Does nothing if the overlay is hidden.
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+    ) {
+        if !self.input.visibility.is_drawn() {
+            return;
+        }
+
+        self.overlay.finish();
+        self.renderer
+            .update_geometry(&self.overlay.geometry, device, queue);
+        self.renderer.update_viewport(
+            &mut self.viewport,
+            self.target_size,
+            self.input.visibility.opacity(),
+            queue,
+        );
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Debug overlay"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        self.renderer.render_viewport(&self.viewport, &mut pass);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{Orientation, Overlay, Point, FRONT_LAYER};
+
+pub(crate) struct ScrollRegion {
+    pub name: String,
+    pub offset: i32,
+}
+
+pub(crate) struct CurrentScrollRegion {
+    pub index: usize,
+    pub origin: Point,
+    pub height: i32,
+    pub content_height: i32,
+    pub max_x: i32,
+    pub prev_clip: Option<(Point, Point)>,
+}
+
+const SCROLLBAR_WIDTH: i32 = 3;
+
+impl Overlay {
+    /// Starts a vertically scrollable area of the provided height in the current group.
+    ///
+    /// Items drawn until the matching `end_scroll_region` are stacked vertically,
+    /// offset by the scroll position and clipped to the region.
+    pub fn begin_scroll_region(&mut self, name: &str, height: i32) {
+        if self.current_scroll.is_some() {
+            self.end_scroll_region();
+        }
+
+        let first = !self.in_group;
+        if !self.in_group {
+            self.begin_group();
+        }
+
+        let margin = if first { 0 } else { self.style.margin };
+        let origin = match self.item_flow {
+            Orientation::Vertical => Point {
+                x: self.group_area.0.x,
+                y: self.group_area.1.y + margin,
+            },
+            Orientation::Horizontal => Point {
+                x: self.group_area.1.x + margin,
+                y: self.group_area.0.y,
+            },
+        };
+
+        let index = match self.scroll_regions.iter().position(|r| r.name == name) {
+            Some(idx) => idx,
+            None => {
+                self.scroll_regions.push(ScrollRegion {
+                    name: name.to_string(),
+                    offset: 0,
+                });
+                self.scroll_regions.len() - 1
+            }
+        };
+
+        let prev_clip = self.geometry.clip_rect();
+        self.geometry.set_clip_rect(Some((
+            Point {
+                x: i32::MIN / 2,
+                y: origin.y,
+            },
+            Point {
+                x: i32::MAX / 2,
+                y: origin.y + height,
+            },
+        )));
+
+        self.current_scroll = Some(CurrentScrollRegion {
+            index,
+            origin,
+            height,
+            content_height: 0,
+            max_x: origin.x,
+            prev_clip,
+        });
+    }
+
+    pub fn end_scroll_region(&mut self) {
+        let Some(region) = self.current_scroll.take() else {
+            return;
+        };
+
+        self.geometry.set_clip_rect(region.prev_clip);
+
+        let visible_height = region.content_height.min(region.height);
+        let max_offset = (region.content_height - region.height).max(0);
+        let mut rect = (
+            region.origin,
+            Point {
+                x: region.max_x,
+                y: region.origin.y + visible_height,
+            },
+        );
+
+        let hovered = self.is_hovered(&rect);
+        let offset = &mut self.scroll_regions[region.index].offset;
+        if hovered {
+            *offset += self.mouse_wheel;
+        }
+        *offset = (*offset).clamp(0, max_offset);
+
+        if max_offset > 0 {
+            let x = rect.1.x + self.style.margin / 2;
+            let h = region.height;
+            let thumb_start = *offset * h / region.content_height;
+            let thumb_len = (h * h / region.content_height).max(SCROLLBAR_WIDTH);
+            let thumb = (
+                Point {
+                    x,
+                    y: region.origin.y + thumb_start,
+                },
+                Point {
+                    x: x + SCROLLBAR_WIDTH,
+                    y: region.origin.y + thumb_start + thumb_len,
+                },
+            );
+            let color = self.style.text_color[1];
+            self.geometry
+                .push_rectangle(FRONT_LAYER, &thumb, color, color);
+            rect.1.x = x + SCROLLBAR_WIDTH;
+        }
+
+        self.group_area.0.x = self.group_area.0.x.min(rect.0.x);
+        self.group_area.0.y = self.group_area.0.y.min(rect.0.y);
+        self.group_area.1.x = self.group_area.1.x.max(rect.1.x);
+        self.group_area.1.y = self.group_area.1.y.max(rect.1.y);
+    }
+
+    /// Sets the scroll offset (in pixels) of a scroll region.
+    pub fn set_scroll_offset(&mut self, name: &str, offset: i32) {
+        match self.scroll_regions.iter_mut().find(|r| r.name == name) {
+            Some(region) => region.offset = offset,
+            None => self.scroll_regions.push(ScrollRegion {
+                name: name.to_string(),
+                offset,
+            }),
+        }
+    }
+
+    pub fn scroll_offset(&self, name: &str) -> i32 {
+        self.scroll_regions
+            .iter()
+            .find(|r| r.name == name)
+            .map(|r| r.offset)
+            .unwrap_or(0)
+    }
+
+    /// Scrolls the region under the mouse by the provided amount of pixels in
+    /// the current frame.
+    ///
+    /// Positive values scroll towards the end of the content.
+    pub fn set_mouse_wheel(&mut self, delta: i32) {
+        self.mouse_wheel = delta;
+    }
+}
+
+#[test]
+fn scroll_region() {
+    let mut overlay = Overlay::new();
+    overlay.item_flow = Orientation::Vertical;
+    let frame = |overlay: &mut Overlay| -> Vec<crate::ItemId> {
+        overlay.begin_frame();
+        overlay.begin_scroll_region("list", 50);
+        let items = (0..10).map(|_| overlay.draw_item(&"item")).collect();
+        overlay.end_scroll_region();
+        overlay.finish();
+        items
+    };
+
+    overlay.set_scroll_offset("list", 1000);
+    frame(&mut overlay);
+    let max_offset = overlay.scroll_offset("list");
+    assert!(max_offset > 0 && max_offset < 1000);
+
+    overlay.set_scroll_offset("list", 0);
+    let items = frame(&mut overlay);
+    let first = overlay.item_rect(items[0]).unwrap();
+    assert_eq!(overlay.hit_test(first.0), Some(items[0]));
+    // The last item is clipped out.
+    let last = overlay.item_rect(items[9]).unwrap();
+    assert!(last.0.y >= last.1.y);
+
+    overlay.set_mouse_position(Some(first.0));
+    overlay.set_mouse_wheel(20);
+    frame(&mut overlay);
+    assert_eq!(overlay.scroll_offset("list"), 20);
+}
@@ -0,0 +1,490 @@
+use crate::{color_to_u32, Color, Layer, OverlayGeometry, Point, PointF, Vertex, OPAQUE_PIXEL};
+
+/// How consecutive segments of a polyline are connected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    /// Extends the outer edges of the segments until they meet.
+    ///
+    /// Falls back to a bevel join for very sharp angles.
+    Miter,
+    /// Connects the outer corners of the segments with a straight edge.
+    Bevel,
+}
+
+/// Sharper joins than this ratio of the miter length to the half-width are beveled.
+const MITER_LIMIT: f32 = 4.0;
+
+impl OverlayGeometry {
+    /// Pushes a line segment of the provided width, clipped by the clip
+    /// rectangle.
+    pub fn push_line(&mut self, layer: Layer, p0: PointF, p1: PointF, width: f32, color: Color) {
+        let Some(n) = segment_normal(p0, p1, width * 0.5) else {
+            return;
+        };
+
+        self.push_segment(layer, p0, p1, n, color_to_u32(color));
+    }
+
+    /// Pushes connected line segments of the provided width, clipped by the
+    /// clip rectangle.
+    pub fn push_polyline(
+        &mut self,
+        layer: Layer,
+        points: &[PointF],
+        width: f32,
+        join: LineJoin,
+        color: Color,
+    ) {
+        let hw = width * 0.5;
+        let color = color_to_u32(color);
+
+        for segment in points.windows(2) {
+            let (p0, p1) = (segment[0], segment[1]);
+            let Some(n) = segment_normal(p0, p1, hw) else {
+                continue;
+            };
+            self.push_segment(layer, p0, p1, n, color);
+        }
+
+        for joint in points.windows(3) {
+            let (p0, p, p1) = (joint[0], joint[1], joint[2]);
+            let (Some(n0), Some(n1)) = (segment_normal(p0, p, hw), segment_normal(p, p1, hw))
+            else {
+                continue;
+            };
+            let cross = (p.x - p0.x) * (p1.y - p.y) - (p.y - p0.y) * (p1.x - p.x);
+            if cross.abs() < f32::EPSILON {
+                continue;
+            }
+
+            // The join is only needed on the outer side of the turn.
+            let side = if cross > 0.0 { -1.0 } else { 1.0 };
+            let a = PointF {
+                x: p.x + n0.x * side,
+                y: p.y + n0.y * side,
+            };
+            let b = PointF {
+                x: p.x + n1.x * side,
+                y: p.y + n1.y * side,
+            };
+
+            let miter = match join {
+                LineJoin::Miter => miter_point(p, a, b, hw),
+                LineJoin::Bevel => None,
+            };
+            match miter {
+                Some(m) => self.push_convex_polygon(layer, &[p, a, m, b], color),
+                None => self.push_convex_polygon(layer, &[p, a, b], color),
+            }
+        }
+    }
+
+    /// Pushes a filled circle.
+    ///
+    /// Like meshes, circles are not affected by the clip rectangle.
+    pub fn push_circle(&mut self, layer: Layer, center: PointF, radius: f32, color: Color) {
+        if radius <= 0.0 {
+            return;
+        }
+
+        let color = color_to_u32(color);
+        let n = circle_segments(radius);
+        self.next_index(layer, n as usize + 1);
+        let c = self.push_solid_vertex(center.x, center.y, color);
+        let first = c + 1;
+        for i in 0..n {
+            let (sin, cos) = (i as f32 * std::f32::consts::TAU / n as f32).sin_cos();
+            self.push_solid_vertex(center.x + cos * radius, center.y + sin * radius, color);
+        }
+        for i in 0..n {
+            let next = (i + 1) % n;
+            self.push_triangle(layer, c, first + i, first + next);
+        }
+    }
+
+    /// Pushes the outline of a circle.
+    ///
+    /// The outline is centered on the circle's radius. Like meshes, circle
+    /// outlines are not affected by the clip rectangle.
+    pub fn push_circle_outline(
+        &mut self,
+        layer: Layer,
+        center: PointF,
+        radius: f32,
+        thickness: f32,
+        color: Color,
+    ) {
+        let inner = (radius - thickness * 0.5).max(0.0);
+        let outer = radius + thickness * 0.5;
+        if outer <= 0.0 {
+            return;
+        }
+
+        let color = color_to_u32(color);
+        let n = circle_segments(outer);
+        let first = self.next_index(layer, n as usize * 2);
+        for i in 0..n {
+            let (sin, cos) = (i as f32 * std::f32::consts::TAU / n as f32).sin_cos();
+            self.push_solid_vertex(center.x + cos * inner, center.y + sin * inner, color);
+            self.push_solid_vertex(center.x + cos * outer, center.y + sin * outer, color);
+        }
+        for i in 0..n {
+            let a = first + i * 2;
+            let b = first + ((i + 1) % n) * 2;
+            self.push_triangle(layer, a, a + 1, b + 1);
+            self.push_triangle(layer, a, b + 1, b);
+        }
+    }
+
+    /// Pushes a rectangle with rounded corners.
+    ///
+    /// The radius is clamped to half of the smallest side of the rectangle.
+    /// Like meshes, rounded rectangles are not affected by the clip rectangle.
+    pub fn push_rounded_rectangle(
+        &mut self,
+        layer: Layer,
+        rect: &(Point, Point),
+        radius: f32,
+        color: Color,
+    ) {
+        let x0 = rect.0.x as f32;
+        let y0 = rect.0.y as f32;
+        let x1 = rect.1.x as f32;
+        let y1 = rect.1.y as f32;
+        if x1 <= x0 || y1 <= y0 {
+            return;
+        }
+
+        let radius = radius.min((x1 - x0) * 0.5).min((y1 - y0) * 0.5).max(0.0);
+        let color = color_to_u32(color);
+        let n = circle_segments(radius) / 4;
+
+        // The shape is convex so it can be drawn as a fan around its center.
+        let count = (n + 1) * 4;
+        self.next_index(layer, count as usize + 1);
+        let c = self.push_solid_vertex((x0 + x1) * 0.5, (y0 + y1) * 0.5, color);
+        let first = c + 1;
+        let corners = [
+            (x1 - radius, y1 - radius),
+            (x0 + radius, y1 - radius),
+            (x0 + radius, y0 + radius),
+            (x1 - radius, y0 + radius),
+        ];
+        for (corner, (cx, cy)) in corners.iter().enumerate() {
+            for i in 0..=n {
+                let angle = (corner as f32 + i as f32 / n as f32) * std::f32::consts::FRAC_PI_2;
+                let (sin, cos) = angle.sin_cos();
+                self.push_solid_vertex(cx + cos * radius, cy + sin * radius, color);
+            }
+        }
+
+        for i in 0..count {
+            self.push_triangle(layer, c, first + i, first + (i + 1) % count);
+        }
+    }
+
+    /// Pushes the outline of a rectangle, drawn inside of the rectangle.
+    pub fn push_rect_outline(
+        &mut self,
+        layer: Layer,
+        rect: &(Point, Point),
+        thickness: i32,
+        color: Color,
+    ) {
+        let (p0, p1) = *rect;
+        let t = thickness.min((p1.x - p0.x) / 2).min((p1.y - p0.y) / 2);
+        if t <= 0 {
+            return;
+        }
+
+        let inner_y0 = p0.y + t;
+        let inner_y1 = p1.y - t;
+        let sides = [
+            (
+                p0,
+                Point {
+                    x: p1.x,
+                    y: inner_y0,
+                },
+            ),
+            (
+                Point {
+                    x: p0.x,
+                    y: inner_y1,
+                },
+                p1,
+            ),
+            (
+                Point {
+                    x: p0.x,
+                    y: inner_y0,
+                },
+                Point {
+                    x: p0.x + t,
+                    y: inner_y1,
+                },
+            ),
+            (
+                Point {
+                    x: p1.x - t,
+                    y: inner_y0,
+                },
+                Point {
+                    x: p1.x,
+                    y: inner_y1,
+                },
+            ),
+        ];
+        for side in &sides {
+            self.push_rectangle(layer, side, color, color);
+        }
+    }
+
+    /// Pushes the quad of a segment, `n` being its normal scaled to half of its
+    /// width.
+    fn push_segment(&mut self, layer: Layer, p0: PointF, p1: PointF, n: PointF, color: u32) {
+        let quad = [
+            PointF {
+                x: p0.x + n.x,
+                y: p0.y + n.y,
+            },
+            PointF {
+                x: p1.x + n.x,
+                y: p1.y + n.y,
+            },
+            PointF {
+                x: p1.x - n.x,
+                y: p1.y - n.y,
+            },
+            PointF {
+                x: p0.x - n.x,
+                y: p0.y - n.y,
+            },
+        ];
+        self.push_convex_polygon(layer, &quad, color);
+    }
+
+    /// Pushes a convex polygon of at most 4 points as a triangle fan, clipped
+    /// by the clip rectangle.
+    fn push_convex_polygon(&mut self, layer: Layer, points: &[PointF], color: u32) {
+        // Clipping by each side of the rectangle adds at most one point.
+        let mut buffers = [[PointF { x: 0.0, y: 0.0 }; 8]; 2];
+        let mut len = points.len();
+        buffers[0][..len].copy_from_slice(points);
+
+        if let Some((min, max)) = self.clip {
+            // The distance to each side of the rectangle, positive inside.
+            let distance = |side: usize, p: PointF| match side {
+                0 => p.x - min.x as f32,
+                1 => max.x as f32 - p.x,
+                2 => p.y - min.y as f32,
+                _ => max.y as f32 - p.y,
+            };
+            for side in 0..4 {
+                let [a, b] = &mut buffers;
+                let (src, dst) = if side % 2 == 0 { (a, b) } else { (b, a) };
+                let mut clipped_len = 0;
+                for i in 0..len {
+                    let prev = src[(i + len - 1) % len];
+                    let curr = src[i];
+                    let (d_prev, d_curr) = (distance(side, prev), distance(side, curr));
+                    if (d_prev >= 0.0) != (d_curr >= 0.0) {
+                        let t = d_prev / (d_prev - d_curr);
+                        dst[clipped_len] = PointF {
+                            x: prev.x + (curr.x - prev.x) * t,
+                            y: prev.y + (curr.y - prev.y) * t,
+                        };
+                        clipped_len += 1;
+                    }
+                    if d_curr >= 0.0 {
+                        dst[clipped_len] = curr;
+                        clipped_len += 1;
+                    }
+                }
+                len = clipped_len;
+            }
+        }
+
+        if len < 3 {
+            return;
+        }
+
+        // After an even number of sides, the result is back in the first buffer.
+        let first = self.next_index(layer, len);
+        for p in &buffers[0][..len] {
+            self.push_solid_vertex(p.x, p.y, color);
+        }
+        for i in 1..len as u16 - 1 {
+            self.push_triangle(layer, first, first + i, first + i + 1);
+        }
+    }
+
+    /// Pushes a vertex and returns its index in the current batch, see
+    /// `next_index`.
+    fn push_solid_vertex(&mut self, x: f32, y: f32, color: u32) -> u16 {
+        let idx = (self.vertices.len() - self.base_vertex) as u16;
+        self.vertices.push(Vertex {
+            x,
+            y,
+            uv: (OPAQUE_PIXEL.0 as u32) << 16 | OPAQUE_PIXEL.1 as u32,
+            color,
+        });
+
+        idx
+    }
+
+    fn push_triangle(&mut self, layer: Layer, a: u16, b: u16, c: u16) {
+        self.layers[layer].indices.extend_from_slice(&[a, b, c]);
+    }
+}
+
+/// Computes the point where the outer edges of two segments meet, given the
+/// joint and the outer corners of the segments at the joint.
+fn miter_point(joint: PointF, a: PointF, b: PointF, half_width: f32) -> Option<PointF> {
+    // The miter point is along the bisector of the two outer normals.
+    let mx = (a.x - joint.x) + (b.x - joint.x);
+    let my = (a.y - joint.y) + (b.y - joint.y);
+    let len = (mx * mx + my * my).sqrt();
+    if len < f32::EPSILON {
+        return None;
+    }
+    let (mx, my) = (mx / len, my / len);
+
+    let cos_half_angle = ((a.x - joint.x) * mx + (a.y - joint.y) * my) / half_width;
+    if cos_half_angle * MITER_LIMIT < 1.0 {
+        return None;
+    }
+
+    let miter_length = half_width / cos_half_angle;
+    Some(PointF {
+        x: joint.x + mx * miter_length,
+        y: joint.y + my * miter_length,
+    })
+}
+
+/// The number of segments used to approximate a circle of the provided radius.
+fn circle_segments(radius: f32) -> u16 {
+    // Roughly one segment every 3 pixels along the circumference.
+    ((radius * std::f32::consts::TAU / 3.0) as u16).clamp(8, 64)
+}
+
+/// Returns the normal of a segment scaled to the provided length, or `None`
+/// if the segment is degenerate.
+fn segment_normal(p0: PointF, p1: PointF, length: f32) -> Option<PointF> {
+    let dx = p1.x - p0.x;
+    let dy = p1.y - p0.y;
+    let len = (dx * dx + dy * dy).sqrt();
+    if len < f32::EPSILON {
+        return None;
+    }
+
+    Some(PointF {
+        x: -dy / len * length,
+        y: dx / len * length,
+    })
+}
+
+#[test]
+fn polyline_joins() {
+    let mut geom = OverlayGeometry::new(1);
+    let points = [
+        PointF { x: 0.0, y: 0.0 },
+        PointF { x: 10.0, y: 0.0 },
+        PointF { x: 10.0, y: 10.0 },
+    ];
+
+    geom.push_polyline(0, &points, 2.0, LineJoin::Miter, (255, 255, 255, 255));
+    // Two segments and a miter join made of two triangles.
+    assert_eq!(geom.layers[0].indices.len(), 6 * 2 + 6);
+    // The miter point, between the two outer corners of the join.
+    let corner = geom.vertices[geom.vertices.len() - 2];
+    assert!((corner.x - 11.0).abs() < 0.001);
+    assert!((corner.y + 1.0).abs() < 0.001);
+
+    geom.begin_frame();
+    geom.push_polyline(0, &points, 2.0, LineJoin::Bevel, (255, 255, 255, 255));
+    assert_eq!(geom.layers[0].indices.len(), 6 * 2 + 3);
+}
+
+#[test]
+fn clipped_lines() {
+    let mut geom = OverlayGeometry::new(1);
+    let white = (255, 255, 255, 255);
+    geom.set_clip_rect(Some((Point { x: 0, y: 0 }, Point { x: 10, y: 10 })));
+
+    // Fully outside of the clip rectangle.
+    geom.push_line(
+        0,
+        PointF { x: 20.0, y: 0.0 },
+        PointF { x: 30.0, y: 10.0 },
+        2.0,
+        white,
+    );
+    assert!(geom.vertices.is_empty());
+
+    // Crossing the right side, the quad loses its right part.
+    let points = [PointF { x: 5.0, y: 5.0 }, PointF { x: 15.0, y: 5.0 }];
+    geom.push_polyline(0, &points, 2.0, LineJoin::Miter, white);
+    assert_eq!(geom.vertices.len(), 4);
+    assert_eq!(geom.layers[0].indices.len(), 6);
+    let max_x = geom.vertices.iter().map(|v| v.x).fold(0.0, f32::max);
+    assert_eq!(max_x, 10.0);
+
+    // Crossing a corner, only a triangle in the corner remains.
+    geom.begin_frame();
+    geom.set_clip_rect(Some((Point { x: 0, y: 0 }, Point { x: 10, y: 10 })));
+    geom.push_line(
+        0,
+        PointF { x: 5.0, y: 15.0 },
+        PointF { x: 15.0, y: 5.0 },
+        4.0,
+        white,
+    );
+    assert_eq!(geom.vertices.len(), 3);
+    assert!(geom.vertices.iter().any(|v| v.x == 10.0 && v.y == 10.0));
+    assert!(geom
+        .vertices
+        .iter()
+        .all(|v| v.x >= 0.0 && v.x <= 10.0 && v.y >= 0.0 && v.y <= 10.0));
+}
+
+#[test]
+fn circles() {
+    let mut geom = OverlayGeometry::new(1);
+    let center = PointF { x: 10.0, y: 10.0 };
+
+    geom.push_circle(0, center, 2.0, (255, 255, 255, 255));
+    let n = circle_segments(2.0) as usize;
+    assert_eq!(geom.vertices.len(), n + 1);
+    assert_eq!(geom.layers[0].indices.len(), n * 3);
+
+    geom.begin_frame();
+    geom.push_circle_outline(0, center, 20.0, 2.0, (255, 255, 255, 255));
+    let n = circle_segments(21.0) as usize;
+    assert_eq!(geom.vertices.len(), n * 2);
+    assert_eq!(geom.layers[0].indices.len(), n * 6);
+    for v in &geom.vertices {
+        let d = ((v.x - center.x).powi(2) + (v.y - center.y).powi(2)).sqrt();
+        assert!((d - 19.0).abs() < 0.01 || (d - 21.0).abs() < 0.01);
+    }
+}
+
+#[test]
+fn rect_outline() {
+    let mut geom = OverlayGeometry::new(1);
+    let rect = (Point { x: 0, y: 0 }, Point { x: 10, y: 20 });
+    geom.push_rect_outline(0, &rect, 2, (255, 255, 255, 255));
+    assert_eq!(geom.vertices.len(), 16);
+    assert!(geom
+        .vertices
+        .iter()
+        .all(|v| v.x >= 0.0 && v.x <= 10.0 && v.y >= 0.0 && v.y <= 20.0));
+
+    geom.begin_frame();
+    geom.push_rounded_rectangle(0, &rect, 100.0, (255, 255, 255, 255));
+    // The radius is clamped to 5 so the rounded rectangle touches all sides.
+    let max_x = geom.vertices.iter().map(|v| v.x).fold(0.0, f32::max);
+    let max_y = geom.vertices.iter().map(|v| v.y).fold(0.0, f32::max);
+    assert!((max_x - 10.0).abs() < 0.01);
+    assert!((max_y - 20.0).abs() < 0.01);
+}
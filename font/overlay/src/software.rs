@@ -0,0 +1,454 @@
+//! Renders the overlay on the CPU.
+//!
+//! The rasterizer follows the `wgpu` shader closely enough to validate layout
+//! and geometry changes without a GPU, by comparing the output against
+//! reference images with `assert_reference`.
+//!
+//! ```no_run
+//! use debug_overlay::*;
+//!
+//! let mut overlay = Overlay::new();
+//! overlay.begin_frame();
+//! overlay.draw_item(&"Hello");
+//! overlay.finish();
+//!
+//! let image = software::render(&overlay.geometry, (200, 100), 1.0, (0, 0, 0, 255));
+//! software::assert_reference(&image, "tests/reference/hello.ppm", 2);
+//! ```
+
+use std::{fs, io, path::Path};
+
+use crate::{
+    distance_coverage,
+    embedded_font::{ATLAS_HEIGHT, ATLAS_WIDTH, DISTANCE_FIELD, DISTANCE_SPREAD, GLYPH_ATLAS},
+    Color, OverlayGeometry, Vertex,
+};
+
+/// When this environment variable is set, `assert_reference` overwrites the
+/// reference images instead of comparing against them.
+pub const UPDATE_REFERENCES_VAR: &str = "OVERLAY_UPDATE_REFERENCES";
+
+/// An RGBA image with 8 bits per channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+/// How much two images differ, see `Image::diff`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImageDiff {
+    /// The largest difference of a color channel.
+    pub max_difference: u8,
+    /// The number of pixels with a channel differing by more than the tolerance.
+    pub differing_pixels: usize,
+}
+
+impl Image {
+    pub fn new(width: u32, height: u32, color: Color) -> Self {
+        Image {
+            width,
+            height,
+            pixels: vec![[color.0, color.1, color.2, color.3]; (width * height) as usize],
+        }
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Color {
+        let [r, g, b, a] = self.pixels[(y * self.width + x) as usize];
+        (r, g, b, a)
+    }
+
+    /// Writes the image in the binary PPM format. The alpha channel is dropped.
+    pub fn write_ppm(&self, output: &mut dyn io::Write) -> io::Result<()> {
+        write!(output, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for [r, g, b, _] in &self.pixels {
+            output.write_all(&[*r, *g, *b])?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses a binary PPM image, as written by `write_ppm`.
+    pub fn read_ppm(src: &[u8]) -> Option<Image> {
+        // The header is made of four whitespace separated fields.
+        let mut fields = Vec::new();
+        let mut pos = 0;
+        while fields.len() < 4 {
+            while src.get(pos)?.is_ascii_whitespace() {
+                pos += 1;
+            }
+            let start = pos;
+            while !src.get(pos)?.is_ascii_whitespace() {
+                pos += 1;
+            }
+            fields.push(std::str::from_utf8(&src[start..pos]).ok()?);
+        }
+        // A single whitespace separates the header from the data.
+        pos += 1;
+
+        if fields[0] != "P6" || fields[3] != "255" {
+            return None;
+        }
+        let width: u32 = fields[1].parse().ok()?;
+        let height: u32 = fields[2].parse().ok()?;
+        let data = src.get(pos..pos + (width * height * 3) as usize)?;
+
+        Some(Image {
+            width,
+            height,
+            pixels: data
+                .chunks_exact(3)
+                .map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+                .collect(),
+        })
+    }
+
+    /// Compares the color channels of two images, or returns `None` if their
+    /// sizes differ.
+    pub fn diff(&self, other: &Image, tolerance: u8) -> Option<ImageDiff> {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+
+        let mut diff = ImageDiff::default();
+        for (a, b) in self.pixels.iter().zip(&other.pixels) {
+            let max = (0..3).map(|c| a[c].abs_diff(b[c])).max().unwrap();
+            diff.max_difference = diff.max_difference.max(max);
+            if max > tolerance {
+                diff.differing_pixels += 1;
+            }
+        }
+
+        Some(diff)
+    }
+}
+
+/// Renders the geometry over a background color, the same way the `wgpu`
+/// renderer draws it into an `Rgba8Unorm` target.
+pub fn render(
+    geometry: &OverlayGeometry,
+    size: (u32, u32),
+    scale: f32,
+    background: Color,
+) -> Image {
+    render_with_sampling(geometry, size, scale, background, false)
+}
+
+/// Like `render`, sampling the glyph atlas with bilinear filtering like the
+/// `wgpu` renderer does with `RendererOptions::filtered_sampling`.
+pub fn render_filtered(
+    geometry: &OverlayGeometry,
+    size: (u32, u32),
+    scale: f32,
+    background: Color,
+) -> Image {
+    render_with_sampling(geometry, size, scale, background, true)
+}
+
+fn render_with_sampling(
+    geometry: &OverlayGeometry,
+    size: (u32, u32),
+    scale: f32,
+    background: Color,
+    filtered: bool,
+) -> Image {
+    let mut image = Image::new(size.0, size.1, background);
+    for layer in &geometry.layers {
+        for (base_vertex, indices) in layer.batches() {
+            for triangle in indices.chunks_exact(3) {
+                let v = |i: usize| &geometry.vertices[base_vertex + triangle[i] as usize];
+                rasterize_triangle(&mut image, [v(0), v(1), v(2)], scale, filtered);
+            }
+        }
+    }
+
+    image
+}
+
+/// Compares an image against a reference image stored at `path`, allowing
+/// color channels to differ by up to `tolerance`.
+///
+/// The reference is only written when the environment variable
+/// `OVERLAY_UPDATE_REFERENCES` is set, a missing reference is an error
+/// otherwise. On mismatch the image is saved next to the reference with the
+/// `.actual.ppm` extension, and this function panics.
+pub fn assert_reference(image: &Image, path: impl AsRef<Path>, tolerance: u8) {
+    let path = path.as_ref();
+    let save = |path: &Path| {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).unwrap();
+        }
+        let mut bytes = Vec::new();
+        image.write_ppm(&mut bytes).unwrap();
+        fs::write(path, bytes).unwrap();
+    };
+
+    if std::env::var_os(UPDATE_REFERENCES_VAR).is_some() {
+        save(path);
+        return;
+    }
+
+    let reference = fs::read(path).unwrap_or_else(|_| {
+        panic!(
+            "Missing reference image {}, run with {UPDATE_REFERENCES_VAR}=1 to create it",
+            path.display(),
+        )
+    });
+    let reference = Image::read_ppm(&reference)
+        .unwrap_or_else(|| panic!("Invalid reference image {}", path.display()));
+    let diff = image.diff(&reference, tolerance);
+    if diff.map(|d| d.differing_pixels) != Some(0) {
+        let actual = path.with_extension("actual.ppm");
+        save(&actual);
+        match diff {
+            Some(diff) => panic!(
+                "{} pixels differ from {} (max difference {}), see {}",
+                diff.differing_pixels,
+                path.display(),
+                diff.max_difference,
+                actual.display(),
+            ),
+            None => panic!(
+                "Size {}x{} doesn't match {}, see {}",
+                image.width,
+                image.height,
+                path.display(),
+                actual.display(),
+            ),
+        }
+    }
+}
+
+/// The interpolated attributes of a vertex.
+#[derive(Copy, Clone)]
+struct Attributes {
+    u: f32,
+    v: f32,
+    color: [f32; 4],
+}
+
+fn attributes(vertex: &Vertex) -> Attributes {
+    let [r, g, b, a] = vertex.color.to_be_bytes();
+    Attributes {
+        u: (vertex.uv >> 16) as f32,
+        v: (vertex.uv & 0xFFFF) as f32,
+        color: [r, g, b, a].map(|c| c as f32 / 255.0),
+    }
+}
+
+fn edge(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+/// Pixels exactly on an edge shared by two triangles must be drawn once. This
+/// picks one of the two directions the edge is traversed in.
+fn owns_edge(a: (f32, f32), b: (f32, f32)) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    dy > 0.0 || (dy == 0.0 && dx > 0.0)
+}
+
+fn rasterize_triangle(image: &mut Image, vertices: [&Vertex; 3], scale: f32, filtered: bool) {
+    let mut p = vertices.map(|v| (v.x * scale, v.y * scale));
+    let mut attr = vertices.map(attributes);
+    let mut area = edge(p[0], p[1], p[2]);
+    if area == 0.0 {
+        return;
+    }
+    if area < 0.0 {
+        p.swap(1, 2);
+        attr.swap(1, 2);
+        area = -area;
+    }
+
+    let min_x = p
+        .iter()
+        .map(|p| p.0)
+        .fold(f32::MAX, f32::min)
+        .floor()
+        .max(0.0) as u32;
+    let min_y = p
+        .iter()
+        .map(|p| p.1)
+        .fold(f32::MAX, f32::min)
+        .floor()
+        .max(0.0) as u32;
+    let max_x = (p
+        .iter()
+        .map(|p| p.0)
+        .fold(f32::MIN, f32::max)
+        .ceil()
+        .max(0.0) as u32)
+        .min(image.width);
+    let max_y = (p
+        .iter()
+        .map(|p| p.1)
+        .fold(f32::MIN, f32::max)
+        .ceil()
+        .max(0.0) as u32)
+        .min(image.height);
+
+    let edges = [(1, 2), (2, 0), (0, 1)];
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let center = (x as f32 + 0.5, y as f32 + 0.5);
+            let mut weights = [0.0; 3];
+            let mut inside = true;
+            for (i, &(a, b)) in edges.iter().enumerate() {
+                let w = edge(p[a], p[b], center);
+                if w < 0.0 || (w == 0.0 && !owns_edge(p[a], p[b])) {
+                    inside = false;
+                    break;
+                }
+                weights[i] = w / area;
+            }
+            if !inside {
+                continue;
+            }
+
+            let lerp = |f: &dyn Fn(&Attributes) -> f32| {
+                weights[0] * f(&attr[0]) + weights[1] * f(&attr[1]) + weights[2] * f(&attr[2])
+            };
+            let u = lerp(&|a| a.u);
+            let v = lerp(&|a| a.v);
+            let color = [0, 1, 2, 3].map(|c| lerp(&|a| a.color[c]));
+
+            let texel = glyph_coverage(u, v, scale, filtered);
+            let alpha = color[3];
+            let src = color.map(|c| c * alpha * texel);
+
+            let dst = &mut image.pixels[(y * image.width + x) as usize];
+            for c in 0..4 {
+                let blended = src[c] + dst[c] as f32 / 255.0 * (1.0 - src[3]);
+                dst[c] = (blended.clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        }
+    }
+}
+
+fn glyph_coverage(u: f32, v: f32, scale: f32, filtered: bool) -> f32 {
+    if !DISTANCE_FIELD && !filtered {
+        // Interpolation errors must not move texel coordinates such as the
+        // opaque pixel's to the previous texel.
+        const EPSILON: f32 = 0.001;
+        return texel((u + EPSILON) as i32, (v + EPSILON) as i32);
+    }
+
+    // Bilinear filtering with clamped coordinates, like the shader.
+    let (u, v) = (u - 0.5, v - 0.5);
+    let (x, y) = (u.floor(), v.floor());
+    let (fx, fy) = (u - x, v - y);
+    let clamped = |x: f32, y: f32| {
+        texel(
+            (x as i32).clamp(0, ATLAS_WIDTH as i32 - 1),
+            (y as i32).clamp(0, ATLAS_HEIGHT as i32 - 1),
+        )
+    };
+    let top = clamped(x, y) * (1.0 - fx) + clamped(x + 1.0, y) * fx;
+    let bottom = clamped(x, y + 1.0) * (1.0 - fx) + clamped(x + 1.0, y + 1.0) * fx;
+    let value = top * (1.0 - fy) + bottom * fy;
+    if !DISTANCE_FIELD {
+        return value;
+    }
+
+    distance_coverage(value, DISTANCE_SPREAD, scale)
+}
+
+fn texel(x: i32, y: i32) -> f32 {
+    if x < 0 || y < 0 || x >= ATLAS_WIDTH as i32 || y >= ATLAS_HEIGHT as i32 {
+        return 0.0;
+    }
+
+    GLYPH_ATLAS[(y as u32 * ATLAS_WIDTH + x as u32) as usize] as f32 / 255.0
+}
+
+#[test]
+fn rasterize_rectangles() {
+    use crate::Point;
+
+    let mut geometry = OverlayGeometry::new(1);
+    let rect = (Point { x: 1, y: 2 }, Point { x: 7, y: 5 });
+    geometry.push_rectangle(0, &rect, (255, 0, 0, 255), (255, 0, 0, 255));
+
+    let image = render(&geometry, (8, 8), 1.0, (0, 0, 0, 255));
+    assert_eq!(image.pixel(0, 0), (0, 0, 0, 255));
+    assert_eq!(image.pixel(1, 2), (255, 0, 0, 255));
+    assert_eq!(image.pixel(6, 4), (255, 0, 0, 255));
+    assert_eq!(image.pixel(7, 4), (0, 0, 0, 255));
+    assert_eq!(image.pixel(6, 5), (0, 0, 0, 255));
+
+    // Pixels on the diagonal shared by the two triangles are blended once.
+    let mut geometry = OverlayGeometry::new(1);
+    let rect = (Point { x: 0, y: 0 }, Point { x: 8, y: 8 });
+    geometry.push_rectangle(0, &rect, (255, 255, 255, 128), (255, 255, 255, 128));
+    let image = render(&geometry, (8, 8), 1.0, (0, 0, 0, 255));
+    assert!(image.pixels.iter().all(|p| *p == image.pixels[0]));
+
+    let mut ppm = Vec::new();
+    image.write_ppm(&mut ppm).unwrap();
+    assert_eq!(Image::read_ppm(&ppm).as_ref(), Some(&image));
+}
+
+#[test]
+fn filtered_sampling() {
+    use crate::{Overlay, Point};
+
+    let mut overlay = Overlay::new();
+    overlay.begin_frame();
+    overlay.draw_item(&"Filtered");
+    overlay.finish();
+    overlay.geometry.push_rectangle(
+        crate::FRONT_LAYER,
+        &(Point { x: 100, y: 10 }, Point { x: 120, y: 30 }),
+        (255, 0, 0, 255),
+        (255, 0, 0, 255),
+    );
+
+    // Texels are sampled at their center without scaling, so filtering
+    // doesn't change the output.
+    let nearest = render(&overlay.geometry, (128, 40), 1.0, (0, 0, 0, 255));
+    let filtered = render_filtered(&overlay.geometry, (128, 40), 1.0, (0, 0, 0, 255));
+    assert_eq!(nearest.diff(&filtered, 1).unwrap().differing_pixels, 0);
+
+    // Shapes stay opaque at fractional scale factors.
+    let scaled = render_filtered(&overlay.geometry, (192, 60), 1.5, (0, 0, 0, 255));
+    for (x, y) in [(151, 16), (165, 30), (179, 44)] {
+        assert_eq!(scaled.pixel(x, y), (255, 0, 0, 255));
+    }
+    let nearest = render(&overlay.geometry, (192, 60), 1.5, (0, 0, 0, 255));
+    assert!(scaled.diff(&nearest, 0).unwrap().differing_pixels > 0);
+}
+
+#[test]
+fn golden_overlay() {
+    use crate::{Overlay, Point};
+
+    let mut overlay = Overlay::new();
+    overlay.begin_frame();
+    overlay.begin_titled_group("Golden");
+    overlay.draw_item(&"Hello, overlay!");
+    overlay.end_group();
+    overlay.finish();
+    overlay.geometry.push_rectangle(
+        crate::FRONT_LAYER,
+        &(Point { x: 150, y: 10 }, Point { x: 190, y: 50 }),
+        (80, 160, 240, 255),
+        (240, 80, 160, 255),
+    );
+
+    let image = render(&overlay.geometry, (200, 80), 1.0, (40, 40, 40, 255));
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/reference/overlay.ppm");
+    assert_reference(&image, path, 2);
+
+    let mut other = image.clone();
+    other.pixels[0][0] ^= 0xFF;
+    assert_eq!(image.diff(&other, 2).unwrap().differing_pixels, 1);
+}
+
+#[test]
+#[should_panic(expected = "Missing reference image")]
+fn missing_reference() {
+    let image = render(&OverlayGeometry::new(1), (4, 4), 1.0, (0, 0, 0, 255));
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/reference/missing.ppm");
+    assert_reference(&image, path, 0);
+}
@@ -0,0 +1,280 @@
+//! Writes the history of the counters as a self-contained SVG report.
+//!
+//! Each group with counters recording their history (see
+//! `CounterDescriptor::with_history`) is drawn as a line chart, with a legend
+//! and a line at the upper bound of each counter's safe range, so that a
+//! profiling session can be archived as a single file.
+//!
+//! ```
+//! use debug_overlay::*;
+//!
+//! let mut counters = Counters::new(120);
+//! let group = counters.register_group(
+//!     "Frame",
+//!     &[CounterDescriptor::float("CPU", "ms", CounterId(0))
+//!         .safe_range(0.0..16.0)
+//!         .with_history()],
+//! );
+//! let cpu = group.counter(CounterIndex(0));
+//!
+//! for frame in 0..120 {
+//!     counters.set(cpu, 10.0 + (frame % 10) as f32);
+//!     counters.update();
+//! }
+//!
+//! let mut report = String::new();
+//! debug_overlay::svg::write_report(&counters, &mut report);
+//! assert!(report.starts_with("<svg"));
+//! ```
+
+use std::fmt::Write;
+
+use ::svg_fmt::{
+    line_segment, path, rectangle, rgb, text, Align, BeginSvg, EndSvg, Fill, Rectangle, Stroke,
+    VerticalLayout,
+};
+
+use crate::{Color, Counter, Counters, Format};
+
+const WIDTH: f32 = 800.0;
+const MARGIN: f32 = 10.0;
+const TITLE_HEIGHT: f32 = 24.0;
+const CHART_HEIGHT: f32 = 150.0;
+const LEGEND_ROW_HEIGHT: f32 = 16.0;
+/// Room on the left of the charts for the labels of the vertical axis.
+const AXIS_WIDTH: f32 = 60.0;
+
+/// Writes an SVG document with one chart per group of counters.
+///
+/// Groups without any counter recording its history are skipped.
+pub fn write_report(counters: &Counters, output: &mut String) {
+    let mut body = String::new();
+    let mut layout = VerticalLayout::new(MARGIN, MARGIN, WIDTH - 2.0 * MARGIN);
+    for (group, group_counters) in counters.groups() {
+        let charted: Vec<&Counter> = group_counters
+            .iter()
+            .filter(|counter| counter.history().is_some())
+            .collect();
+        if charted.is_empty() {
+            continue;
+        }
+
+        write_chart(&mut body, &mut layout, group, &charted);
+        layout.advance(MARGIN);
+    }
+
+    let height = layout.y + MARGIN;
+    let _ = writeln!(
+        output,
+        "{}",
+        BeginSvg {
+            w: WIDTH,
+            h: height
+        }
+    );
+    let _ = writeln!(
+        output,
+        "    {}",
+        rectangle(0.0, 0.0, WIDTH, height).fill(rgb(20, 20, 20))
+    );
+    output.push_str(&body);
+    let _ = writeln!(output, "{}", EndSvg);
+}
+
+fn write_chart(
+    output: &mut String,
+    layout: &mut VerticalLayout,
+    group: &str,
+    counters: &[&Counter],
+) {
+    let title = layout.push_rectangle(TITLE_HEIGHT);
+    let _ = writeln!(
+        output,
+        "    {}",
+        text(title.x, title.y + 16.0, escape(group))
+            .size(14.0)
+            .color(rgb(255, 255, 255))
+    );
+
+    let area = layout.push_rectangle(CHART_HEIGHT);
+    let chart = rectangle(area.x + AXIS_WIDTH, area.y, area.w - AXIS_WIDTH, area.h);
+    let _ = writeln!(
+        output,
+        "    {}",
+        chart
+            .clone()
+            .fill(rgb(40, 40, 40))
+            .stroke(Stroke::Color(rgb(90, 90, 90), 1.0))
+    );
+
+    // The vertical axis starts at zero and covers the samples and the bounds
+    // of the safe ranges.
+    let max = counters
+        .iter()
+        .flat_map(|counter| counter.history().unwrap().flatten().chain(budget(counter)))
+        .filter(|value| value.is_finite())
+        .fold(0.0, f32::max);
+    let max = if max > 0.0 { max } else { 1.0 };
+    let y = |value: f32| chart.y + chart.h * (1.0 - value / max);
+
+    for (label, value) in [(format_value(counters[0], max), max), ("0".into(), 0.0)] {
+        let _ = writeln!(
+            output,
+            "    {}",
+            text(chart.x - 4.0, y(value) + 4.0, label)
+                .align(Align::Right)
+                .color(rgb(200, 200, 200))
+        );
+    }
+
+    for counter in counters {
+        let color = svg_color(counter.descriptor.color);
+        if let Some(budget) = budget(counter) {
+            let _ = writeln!(
+                output,
+                "    {}",
+                line_segment(chart.x, y(budget), chart.x + chart.w, y(budget))
+                    .color(color)
+                    .width(0.5)
+            );
+        }
+
+        let _ = writeln!(output, "    {}", history_path(counter, &chart, &y));
+    }
+
+    for counter in counters {
+        let row = layout.push_rectangle(LEGEND_ROW_HEIGHT);
+        let x = row.x + AXIS_WIDTH;
+        let _ = writeln!(
+            output,
+            "    {}",
+            rectangle(x, row.y + 3.0, 10.0, 10.0).fill(svg_color(counter.descriptor.color))
+        );
+        let _ = writeln!(
+            output,
+            "    {}",
+            text(x + 16.0, row.y + 12.0, legend(counter)).color(rgb(255, 255, 255))
+        );
+    }
+}
+
+/// A line through the samples of the history, interrupted where the counter
+/// had no value.
+fn history_path(counter: &Counter, chart: &Rectangle, y: &dyn Fn(f32) -> f32) -> ::svg_fmt::Path {
+    let samples: Vec<Option<f32>> = counter.history().unwrap().collect();
+    let step = chart.w / (samples.len() - 1).max(1) as f32;
+    let mut line = path()
+        .fill(Fill::None)
+        .stroke(Stroke::Color(svg_color(counter.descriptor.color), 1.5));
+    let mut pen_down = false;
+    for (idx, sample) in samples.iter().enumerate() {
+        let Some(value) = sample.filter(|value| value.is_finite()) else {
+            pen_down = false;
+            continue;
+        };
+        let x = chart.x + idx as f32 * step;
+        line = if pen_down {
+            line.line_to(x, y(value))
+        } else {
+            line.move_to(x, y(value))
+        };
+        pen_down = true;
+    }
+
+    line
+}
+
+/// The name and unit of the counter, followed by statistics over its history.
+fn legend(counter: &Counter) -> String {
+    let mut legend = escape(counter.name());
+    if !counter.descriptor.unit.is_empty() {
+        let _ = write!(legend, " ({})", escape(counter.descriptor.unit));
+    }
+
+    let samples: Vec<f32> = counter.history().unwrap().flatten().collect();
+    if !samples.is_empty() {
+        let avg = samples.iter().sum::<f32>() / samples.len() as f32;
+        let min = samples.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = samples.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let _ = write!(
+            legend,
+            "  avg: {}  min: {}  max: {}",
+            format_value(counter, avg),
+            format_value(counter, min),
+            format_value(counter, max),
+        );
+    }
+    if let Some(budget) = budget(counter) {
+        let _ = write!(legend, "  budget: {}", format_value(counter, budget));
+    }
+
+    legend
+}
+
+/// The upper bound of the counter's safe range, if any.
+fn budget(counter: &Counter) -> Option<f32> {
+    let range = counter.descriptor.safe_range.as_ref()?;
+    Some(range.end).filter(|end| end.is_finite())
+}
+
+fn format_value(counter: &Counter, value: f32) -> String {
+    let value = match counter.descriptor.format {
+        Format::Int => value.round(),
+        Format::Float => value,
+    };
+    counter.descriptor.format_value(value).to_string()
+}
+
+fn svg_color(color: Color) -> ::svg_fmt::Color {
+    rgb(color.0, color.1, color.2)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[test]
+fn report() {
+    use crate::{CounterDescriptor, CounterId, CounterIndex};
+
+    let mut counters = Counters::new(4);
+    let frame = counters.register_group(
+        "Frame <main>",
+        &[
+            CounterDescriptor::float("CPU", "ms", CounterId(0))
+                .safe_range(0.0..16.0)
+                .with_history(),
+            CounterDescriptor::int("Draw calls", "", CounterId(1)),
+        ],
+    );
+    counters.register_group(
+        "Memory",
+        &[CounterDescriptor::int("Textures", "MB", CounterId(0))],
+    );
+
+    let cpu = frame.counter(CounterIndex(0));
+    for value in [Some(8.0), None, Some(12.0), Some(20.0)] {
+        counters.set(cpu, value);
+        counters.update();
+    }
+
+    let mut report = String::new();
+    write_report(&counters, &mut report);
+
+    assert!(report.starts_with("<svg"));
+    assert!(report.trim_end().ends_with("</svg>"));
+    assert!(report.contains("Frame &lt;main&gt;"));
+    assert!(report.contains("CPU (ms)  avg: 13.33  min: 8.00  max: 20.00  budget: 16.00"));
+    // Counters and groups without history are not part of the report.
+    assert!(!report.contains("Draw calls"));
+    assert!(!report.contains("Memory"));
+    // The missing sample splits the line in two.
+    let line = report
+        .lines()
+        .find(|line| line.contains("stroke-width:1.5"))
+        .unwrap();
+    assert_eq!(line.matches("M ").count(), 2);
+    assert_eq!(line.matches("L ").count(), 1);
+}
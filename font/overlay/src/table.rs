@@ -1,6 +1,6 @@
 use crate::{
-    graph::draw_graph, Color, Counter, Format, Orientation, Overlay, OverlayItem, Point,
-    FONT_HEIGHT, FRONT_LAYER,
+    graph::draw_graph, BarLayout, Color, Counter, Graph, GraphStyle, Overlay, OverlayItem, Point,
+    FRONT_LAYER,
 };
 use std::fmt::Write;
 
@@ -8,6 +8,25 @@ pub struct Column {
     kind: ColumnKind,
     unit: bool,
     label: Option<&'static str>,
+    bars: BarLayout,
+    align: Align,
+    width: Option<i32>,
+    tinted: bool,
+    summary: Option<Summary>,
+}
+
+/// How the values of a column are combined in the summary row of a table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Summary {
+    Sum,
+    Average,
+}
+
+/// The horizontal alignment of the cells of a column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
 }
 
 impl Column {
@@ -16,6 +35,11 @@ impl Column {
             kind: ColumnKind::Empty,
             label: None,
             unit: false,
+            bars: BarLayout::AUTO,
+            align: Align::Left,
+            width: None,
+            tinted: false,
+            summary: None,
         }
     }
     pub const fn color() -> Self {
@@ -60,6 +84,38 @@ impl Column {
             ..Self::default()
         }
     }
+    /// The change per second of the counter's value.
+    pub const fn rate() -> Self {
+        Column {
+            kind: ColumnKind::Rate,
+            ..Self::default()
+        }
+    }
+    /// The exponential moving average of the counter, see
+    /// `CounterDescriptor::ema_factor`.
+    pub const fn ema() -> Self {
+        Column {
+            kind: ColumnKind::Ema,
+            ..Self::default()
+        }
+    }
+    /// The value below which `p` percent of the samples in the counter's
+    /// history fall.
+    ///
+    /// Requires the counter's history to be enabled.
+    pub const fn percentile(p: f32) -> Self {
+        Column {
+            kind: ColumnKind::Percentile(p),
+            ..Self::default()
+        }
+    }
+    /// A compact line graph of the counter's history.
+    pub const fn sparkline() -> Self {
+        Column {
+            kind: ColumnKind::Sparkline,
+            ..Self::default()
+        }
+    }
     pub const fn with_unit(mut self) -> Self {
         self.unit = true;
         self
@@ -68,6 +124,39 @@ impl Column {
         self.label = Some(label);
         self
     }
+    /// Draws the cells with the color of the row's counter, matching the colors
+    /// used by graphs.
+    pub const fn tinted(mut self) -> Self {
+        self.tinted = true;
+        self
+    }
+    /// Shows the sum or average of the column's values in a row at the bottom
+    /// of the table.
+    ///
+    /// Only applies to numeric columns. The name column shows the label of
+    /// the summary row.
+    pub const fn summary(mut self, summary: Summary) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+    /// Right-aligned numeric columns keep their digits lined up as values change.
+    pub const fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+    /// Sets a fixed width in pixels, so that the table doesn't change size as
+    /// values change.
+    pub const fn width(mut self, width: i32) -> Self {
+        self.width = Some(width);
+        self
+    }
+    /// Sets the size and spacing of the bars in `history_graph` columns.
+    ///
+    /// By default each sample is one pixel wide.
+    pub const fn bars(mut self, bars: BarLayout) -> Self {
+        self.bars = bars;
+        self
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -79,14 +168,89 @@ pub enum ColumnKind {
     Min,
     Max,
     Value,
+    Rate,
+    Ema,
+    Percentile(f32),
     HistoryGraph,
+    Sparkline,
     Changed,
 }
 
+/// The order of the rows of a table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    /// Alphabetical order.
+    Name,
+    /// Highest last value first.
+    Value,
+    /// Highest average first.
+    Avg,
+    /// Highest maximum first.
+    Max,
+}
+
+/// A row of a `Table`.
+#[derive(Copy, Clone)]
+pub enum Row<'a> {
+    Counter(&'a Counter),
+    /// A title starting a new section of the table.
+    Header(&'a str),
+    /// A horizontal line starting a new section of the table.
+    Separator,
+}
+
+impl<'a> From<&'a Counter> for Row<'a> {
+    fn from(counter: &'a Counter) -> Self {
+        Row::Counter(counter)
+    }
+}
+
 pub struct Table<'a> {
     pub columns: &'a [Column],
-    pub rows: &'a [&'a Counter],
+    pub rows: &'a [Row<'a>],
     pub labels: bool,
+    /// Sorts the rows of each section when drawing the table, or `None` to
+    /// keep the order of `rows`.
+    pub sort: Option<SortBy>,
+}
+
+/// Sorts the counters of each section, keeping headers and separators in place.
+fn sort_rows(rows: &mut [Row], sort: SortBy) {
+    for section in rows.split_mut(|row| !matches!(row, Row::Counter(_))) {
+        sort_counters(section, sort);
+    }
+}
+
+/// Sorts rows that are all counters.
+fn sort_counters(rows: &mut [Row], sort: SortBy) {
+    fn name<'a>(row: &Row<'a>) -> &'a str {
+        match *row {
+            Row::Counter(counter) => counter.name(),
+            _ => "",
+        }
+    }
+    let value = |row: &Row<'_>| {
+        let Row::Counter(counter) = *row else {
+            return f32::NAN;
+        };
+        match sort {
+            SortBy::Name => f32::NAN,
+            SortBy::Value => counter.last_value,
+            SortBy::Avg => counter.displayed_avg,
+            SortBy::Max => counter.displayed_max,
+        }
+    };
+    match sort {
+        SortBy::Name => rows.sort_by(|a, b| name(a).cmp(name(b))),
+        _ => rows.sort_by(|a, b| {
+            // Highest first, counters without a value last.
+            let (a, b) = (value(a), value(b));
+            match (a.is_finite(), b.is_finite()) {
+                (true, true) => b.partial_cmp(&a).unwrap(),
+                (a, b) => b.cmp(&a),
+            }
+        }),
+    }
 }
 
 impl<'a> OverlayItem for Table<'a> {
@@ -94,55 +258,187 @@ impl<'a> OverlayItem for Table<'a> {
         let mut min = origin;
         let mut max = origin;
 
+        let sorted_rows;
+        let rows = match self.sort {
+            Some(sort) => {
+                let mut rows = self.rows.to_vec();
+                sort_rows(&mut rows, sort);
+                sorted_rows = rows;
+                &sorted_rows[..]
+            }
+            None => self.rows,
+        };
+
         let margin = overlay.style.margin;
-        let row_height = overlay.style.line_spacing + FONT_HEIGHT as i32;
+        let line_height = overlay.style.font.line_height();
+        let row_height = overlay.style.line_spacing + line_height;
 
-        let y0 = origin.y + FONT_HEIGHT as i32;
+        let y0 = origin.y + line_height;
+        let rows_y0 = if self.labels {
+            y0 + row_height + margin
+        } else {
+            y0
+        };
         let mut x = origin.x;
 
+        let summary = self.columns.iter().find_map(|c| c.summary);
+
+        // The first vertex and the right edge of each cell of the current column.
+        let mut cells = Vec::new();
+        let mut header_max = x;
         for column in self.columns {
             let mut y = y0;
             let mut color_idx = 0;
+            let mut column_max = x;
+            cells.clear();
 
             if self.labels {
                 if let Some(label) = column.label {
+                    let first_vertex = overlay.geometry.vertex_count();
                     let r = overlay.geometry.push_text(
                         FRONT_LAYER,
                         label,
                         Point { x, y },
                         overlay.style.title_color,
                     );
-                    add_point_to_rect(r.1, &mut min, &mut max);
+                    add_point_to_rect(Point { x, y: r.1.y }, &mut min, &mut max);
+                    column_max = column_max.max(r.1.x);
+                    cells.push((first_vertex, r.1.x));
                 }
                 y += row_height + margin;
             }
 
-            for row in self.rows {
+            for row in rows {
+                let row = match *row {
+                    Row::Counter(counter) => counter,
+                    Row::Header(title) => {
+                        if x == origin.x {
+                            let p = Point { x, y };
+                            let color = overlay.style.title_color;
+                            let r = overlay.geometry.push_text(FRONT_LAYER, title, p, color);
+                            header_max = header_max.max(r.1.x);
+                        }
+                        y += row_height;
+                        color_idx = 0;
+                        continue;
+                    }
+                    Row::Separator => {
+                        y += row_height;
+                        color_idx = 0;
+                        continue;
+                    }
+                };
                 overlay.string_buffer.clear();
 
-                let highlight = row
-                    .descriptor
-                    .safe_range
-                    .as_ref()
-                    .map(|range| row.displayed_max > range.end || row.displayed_min < range.start)
-                    .unwrap_or(false);
+                // Active alerts flash instead of staying highlighted.
+                let highlight = if row.is_alerting() {
+                    row.alert_flash()
+                } else {
+                    row.descriptor
+                        .safe_range
+                        .as_ref()
+                        .map(|range| {
+                            row.displayed_max > range.end || row.displayed_min < range.start
+                        })
+                        .unwrap_or(false)
+                };
                 let color = if highlight {
                     overlay.style.highlight_color
+                } else if column.tinted {
+                    row.descriptor.color
                 } else {
                     overlay.style.text_color[color_idx]
                 };
 
+                let first_vertex = overlay.geometry.vertex_count();
                 let r = draw_cell(x, y, column, row, color, overlay);
-                add_point_to_rect(r.1, &mut min, &mut max);
+                add_point_to_rect(Point { x, y: r.1.y }, &mut min, &mut max);
+                column_max = column_max.max(r.1.x);
+                cells.push((first_vertex, r.1.x));
 
                 y += row_height;
                 color_idx = (color_idx + 1) % 2;
             }
-            let min_column_width = 0;
-            let dx = (max.x - x).max(min_column_width) + overlay.style.column_spacing;
+
+            if let Some(default_summary) = summary {
+                let first_vertex = overlay.geometry.vertex_count();
+                let color = overlay.style.title_color;
+                let r = match column.kind {
+                    ColumnKind::Name => {
+                        let label = match default_summary {
+                            Summary::Sum => "total",
+                            Summary::Average => "average",
+                        };
+                        draw_cell_text(x, y, label, "", color, overlay)
+                    }
+                    _ => match (column.summary, first_counter(rows)) {
+                        (Some(op), Some(first)) => {
+                            let val = summarize(rows, &column.kind, op);
+                            draw_cell_value(x, y, val, first, column.unit, color, overlay)
+                        }
+                        _ => (Point { x, y }, Point { x, y }),
+                    },
+                };
+                add_point_to_rect(Point { x, y: r.1.y }, &mut min, &mut max);
+                column_max = column_max.max(r.1.x);
+                cells.push((first_vertex, r.1.x));
+            }
+
+            let column_width = match column.width {
+                Some(w) => w,
+                None => column_max - x,
+            };
+
+            if column.align == Align::Right {
+                let right = x + column_width;
+                let mut cells = cells.iter().peekable();
+                while let Some(&(first_vertex, cell_max)) = cells.next() {
+                    let end = cells
+                        .peek()
+                        .map(|cell| cell.0)
+                        .unwrap_or(overlay.geometry.vertex_count());
+                    overlay
+                        .geometry
+                        .translate_vertices(first_vertex..end, right - cell_max, 0);
+                }
+                max.x = max.x.max(right);
+            } else {
+                max.x = max.x.max(column_max);
+            }
+
+            let dx = column_width + overlay.style.column_spacing;
             x += dx;
         }
 
+        max.x = max.x.max(header_max);
+        if let Some(Row::Header(_) | Row::Separator) = rows.last() {
+            max.y = max.y.max(rows_y0 + (rows.len() as i32 - 1) * row_height);
+        }
+
+        let (r, g, b, _) = overlay.style.text_color[1];
+        let separator_color = (r, g, b, 100);
+        for (idx, row) in rows.iter().enumerate() {
+            if let Row::Separator = row {
+                let y = rows_y0 + idx as i32 * row_height - line_height / 2;
+                let line = (Point { x: min.x, y }, Point { x: max.x, y: y + 1 });
+                overlay.geometry.push_rectangle(
+                    FRONT_LAYER,
+                    &line,
+                    separator_color,
+                    separator_color,
+                );
+            }
+        }
+
+        if let Some(mouse) = overlay.mouse_position() {
+            let dy = mouse.y - (rows_y0 - line_height);
+            if overlay.is_hovered(&(min, max)) && dy >= 0 {
+                if let Some(Row::Counter(counter)) = rows.get((dy / row_height) as usize) {
+                    overlay.show_counter_tooltip(counter);
+                }
+            }
+        }
+
         (min, max)
     }
 }
@@ -160,7 +456,7 @@ fn draw_cell(
         ColumnKind::Name => draw_cell_text(
             x,
             y,
-            counter.descriptor.name,
+            counter.name(),
             if column.unit {
                 counter.descriptor.unit
             } else {
@@ -169,6 +465,9 @@ fn draw_cell(
             color,
             overlay,
         ),
+        ColumnKind::Value if counter.descriptor.show_rate => {
+            draw_cell_rate(x, y, counter, column.unit, color, overlay)
+        }
         ColumnKind::Value => draw_cell_value(
             x,
             y,
@@ -178,6 +477,7 @@ fn draw_cell(
             color,
             overlay,
         ),
+        ColumnKind::Rate => draw_cell_rate(x, y, counter, column.unit, color, overlay),
         ColumnKind::Avg => draw_cell_value(
             x,
             y,
@@ -205,13 +505,23 @@ fn draw_cell(
             color,
             overlay,
         ),
+        ColumnKind::Ema => draw_cell_value(x, y, counter.ema, counter, column.unit, color, overlay),
+        ColumnKind::Percentile(p) => draw_cell_value(
+            x,
+            y,
+            counter.percentile(p).unwrap_or(f32::NAN),
+            counter,
+            column.unit,
+            color,
+            overlay,
+        ),
         ColumnKind::HistoryGraph => {
             if !counter.history.is_empty() {
-                let w = counter.history.len() as i32;
+                let w = column.bars.width_for(counter.history.len());
                 let rect = (
                     Point {
                         x,
-                        y: y - FONT_HEIGHT as i32,
+                        y: y - overlay.style.font.line_height(),
                     },
                     Point { x: x + w, y },
                 );
@@ -220,15 +530,27 @@ fn draw_cell(
                 } else {
                     0.0
                 };
-                draw_graph(
-                    FRONT_LAYER,
-                    rect,
-                    counter,
-                    ref_value,
-                    color,
-                    Orientation::Vertical,
-                    overlay,
-                );
+                let mut graph = Graph::new(counter);
+                graph.color = color;
+                graph.reference_value = ref_value;
+                graph.bars = column.bars;
+                draw_graph(FRONT_LAYER, rect, &graph, overlay);
+
+                rect
+            } else {
+                (Point { x, y }, Point { x, y })
+            }
+        }
+        ColumnKind::Sparkline => {
+            if !counter.history.is_empty() {
+                let rect = rect((
+                    (x, y - overlay.style.font.line_height() + 2),
+                    (x + SPARKLINE_WIDTH, y - 1),
+                ));
+                let mut graph = Graph::new(counter);
+                graph.color = color;
+                graph.style = GraphStyle::Line;
+                draw_graph(FRONT_LAYER, rect, &graph, overlay);
 
                 rect
             } else {
@@ -248,6 +570,48 @@ fn draw_cell(
     }
 }
 
+const SPARKLINE_WIDTH: i32 = 40;
+
+/// The numeric value shown by a column for a counter, if any.
+fn cell_value(kind: &ColumnKind, counter: &Counter) -> Option<f32> {
+    let val = match *kind {
+        ColumnKind::Value => counter.last_value,
+        ColumnKind::Avg => counter.displayed_avg,
+        ColumnKind::Min => counter.displayed_min,
+        ColumnKind::Max => counter.displayed_max,
+        ColumnKind::Ema => counter.ema,
+        ColumnKind::Rate => counter.rate,
+        ColumnKind::Percentile(p) => counter.percentile(p)?,
+        _ => return None,
+    };
+
+    Some(val).filter(|v| v.is_finite())
+}
+
+fn first_counter<'a>(rows: &[Row<'a>]) -> Option<&'a Counter> {
+    rows.iter().find_map(|row| match *row {
+        Row::Counter(counter) => Some(counter),
+        _ => None,
+    })
+}
+
+/// Combines the values of a column over all counters of a table.
+fn summarize(rows: &[Row], kind: &ColumnKind, summary: Summary) -> f32 {
+    let (sum, count) = rows
+        .iter()
+        .filter_map(|row| match *row {
+            Row::Counter(counter) => cell_value(kind, counter),
+            _ => None,
+        })
+        .fold((0.0, 0), |(sum, count), val| (sum + val, count + 1));
+
+    match summary {
+        Summary::Sum if count > 0 => sum,
+        Summary::Average if count > 0 => sum / count as f32,
+        _ => f32::NAN,
+    }
+}
+
 fn draw_cell_text(
     x: i32,
     y: i32,
@@ -283,11 +647,30 @@ fn draw_cell_value(
 
     let unit_str = if unit { counter.descriptor.unit } else { "" };
     overlay.string_buffer.clear();
-    let _ = match counter.descriptor.format {
-        Format::Int => write!(overlay.string_buffer, "{val:>5}{unit_str}"),
-        Format::Float => write!(overlay.string_buffer, "{val:>5.2}{unit_str}"),
+    let val = counter.descriptor.format_value(val);
+    let _ = write!(overlay.string_buffer, "{val:>5}{unit_str}");
+
+    overlay
+        .geometry
+        .push_text(FRONT_LAYER, &overlay.string_buffer, Point { x, y }, color)
+}
+
+fn draw_cell_rate(
+    x: i32,
+    y: i32,
+    counter: &Counter,
+    unit: bool,
+    color: Color,
+    overlay: &mut Overlay,
+) -> (Point, Point) {
+    let Some(rate) = counter.rate() else {
+        return (Point { x, y }, Point { x, y });
     };
 
+    let unit_str = if unit { counter.descriptor.unit } else { "" };
+    overlay.string_buffer.clear();
+    let _ = write!(overlay.string_buffer, "{rate:>5.2}{unit_str}/s");
+
     overlay
         .geometry
         .push_text(FRONT_LAYER, &overlay.string_buffer, Point { x, y }, color)
@@ -312,3 +695,170 @@ fn rect(r: ((i32, i32), (i32, i32))) -> (Point, Point) {
         },
     )
 }
+
+#[test]
+fn sparkline_column() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut counter = Counter::new(CounterDescriptor::float("frame", "ms", CounterId(0)));
+    counter.enable_history(120);
+    counter.set(1.0);
+    counter.update(false);
+
+    let mut overlay = Overlay::new();
+    let r = draw_cell(
+        0,
+        20,
+        &Column::sparkline(),
+        &counter,
+        (255, 255, 255, 255),
+        &mut overlay,
+    );
+    // The sparkline has a fixed width regardless of the history size.
+    assert_eq!(r.1.x - r.0.x, SPARKLINE_WIDTH);
+    assert!(r.1.y <= 20);
+}
+
+#[test]
+fn sorted_rows() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut a = Counter::new(CounterDescriptor::float("a", "", CounterId(0)));
+    let mut b = Counter::new(CounterDescriptor::float("b", "", CounterId(1)));
+    let c = Counter::new(CounterDescriptor::float("c", "", CounterId(2)));
+    a.set(1.0);
+    b.set(2.0);
+
+    let names = |rows: &[Row]| -> Vec<String> {
+        rows.iter()
+            .map(|row| match row {
+                Row::Counter(c) => c.name().to_string(),
+                Row::Header(title) => title.to_string(),
+                Row::Separator => "-".to_string(),
+            })
+            .collect()
+    };
+
+    let mut rows = [
+        Row::Counter(&c),
+        Row::Counter(&a),
+        Row::Counter(&b),
+        Row::Separator,
+        Row::Counter(&b),
+        Row::Counter(&a),
+    ];
+    sort_rows(&mut rows, SortBy::Value);
+    assert_eq!(names(&rows), ["b", "a", "c", "-", "b", "a"]);
+
+    sort_rows(&mut rows, SortBy::Name);
+    assert_eq!(names(&rows), ["a", "b", "c", "-", "a", "b"]);
+}
+
+#[test]
+fn right_aligned_column() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let a = Counter::new(CounterDescriptor::int("a", "", CounterId(0)));
+    let b = Counter::new(CounterDescriptor::int("long name", "", CounterId(1)));
+
+    let columns = [Column::name().align(Align::Right).width(100)];
+    let rows = [Row::Counter(&a), Row::Counter(&b)];
+    let table = Table {
+        columns: &columns,
+        rows: &rows,
+        labels: false,
+        sort: None,
+    };
+    let mut overlay = Overlay::new();
+    let rect = table.draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert_eq!(rect.1.x, 100);
+
+    // Both cells end at the right edge of the column.
+    let vertices = &overlay.geometry.vertices;
+    let right_edge = |v: &[crate::Vertex]| v.iter().map(|v| v.x).fold(0.0, f32::max);
+    assert_eq!(right_edge(&vertices[..4]), 100.0);
+    assert_eq!(right_edge(&vertices[4..]), 100.0);
+}
+
+#[test]
+fn sections() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let a = Counter::new(CounterDescriptor::int("a", "", CounterId(0)));
+    let b = Counter::new(CounterDescriptor::int("b", "", CounterId(1)));
+
+    let columns = [Column::name()];
+    let rows = [
+        Row::Header("first section"),
+        Row::Counter(&a),
+        Row::Separator,
+        Row::Counter(&b),
+    ];
+    let table = Table {
+        columns: &columns,
+        rows: &rows,
+        labels: false,
+        sort: None,
+    };
+    let mut overlay = Overlay::new();
+    let rect = table.draw(Point { x: 0, y: 0 }, &mut overlay);
+
+    let row_height = overlay.style.line_spacing + overlay.style.font.line_height();
+    assert!(rect.1.y > 3 * row_height);
+    // The header is wider than the name column.
+    assert!(rect.1.x > 40);
+
+    // Hovering the last row shows the tooltip of the second counter.
+    overlay.set_mouse_position(Some(Point {
+        x: 2,
+        y: 3 * row_height + 2,
+    }));
+    table.draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert!(overlay.tooltip.starts_with("b"));
+}
+
+#[test]
+fn tinted_column() {
+    use crate::{color_to_u32, CounterDescriptor, CounterId};
+
+    let red = (255, 0, 0, 255);
+    let a = Counter::new(CounterDescriptor::int("a", "", CounterId(0)).color(red));
+    let columns = [Column::name().tinted()];
+    let rows = [Row::Counter(&a)];
+    let table = Table {
+        columns: &columns,
+        rows: &rows,
+        labels: false,
+        sort: None,
+    };
+    let mut overlay = Overlay::new();
+    table.draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert_eq!(overlay.geometry.vertices[0].color, color_to_u32(red));
+}
+
+#[test]
+fn summary_row() {
+    use crate::{CounterDescriptor, CounterId};
+
+    let mut a = Counter::new(CounterDescriptor::float("a", "MB", CounterId(0)));
+    let mut b = Counter::new(CounterDescriptor::float("b", "MB", CounterId(1)));
+    a.set(1.5);
+    b.set(2.0);
+    let rows = [Row::Counter(&a), Row::Separator, Row::Counter(&b)];
+
+    assert_eq!(summarize(&rows, &ColumnKind::Value, Summary::Sum), 3.5);
+    assert_eq!(summarize(&rows, &ColumnKind::Value, Summary::Average), 1.75);
+    assert!(summarize(&rows, &ColumnKind::Max, Summary::Average).is_nan());
+
+    let columns = [Column::name(), Column::value().summary(Summary::Sum)];
+    let table = Table {
+        columns: &columns,
+        rows: &rows,
+        labels: false,
+        sort: None,
+    };
+    let mut overlay = Overlay::new();
+    let rect = table.draw(Point { x: 0, y: 0 }, &mut overlay);
+    let row_height = overlay.style.line_spacing + overlay.style.font.line_height();
+    assert!(rect.1.y > 3 * row_height);
+}
@@ -0,0 +1,230 @@
+use crate::{
+    color_to_u32, Color, Layer, Overlay, OverlayGeometry, OverlayItem, Point, FRONT_LAYER,
+};
+
+/// Text with inline color markup.
+///
+/// `#[rrggbb]` or `#[rrggbbaa]` (hexadecimal) sets the color of the text that
+/// follows, `#[]` goes back to the default color.
+///
+/// For example `"frame: #[ff6464]23.5#[] ms"` only highlights the value.
+pub struct Markup<'a>(pub &'a str);
+
+impl<'a> OverlayItem for Markup<'a> {
+    fn draw(&self, position: Point, output: &mut Overlay) -> (Point, Point) {
+        let p = Point {
+            x: position.x,
+            y: position.y + output.style.font.line_height(),
+        };
+
+        output
+            .geometry
+            .push_markup(FRONT_LAYER, self.0, p, output.style.text_color[0])
+    }
+}
+
+/// Labeled values displayed in two aligned columns.
+///
+/// Rows alternate between the two text colors of the style, like tables.
+///
+/// ```
+/// use debug_overlay::*;
+///
+/// let mut overlay = Overlay::new();
+/// overlay.draw_item(&KeyValues(&[("adapter", "llvmpipe"), ("backend", "vulkan")]));
+/// ```
+pub struct KeyValues<'a, V: AsRef<str> = &'a str>(pub &'a [(&'a str, V)]);
+
+impl<'a, V: AsRef<str>> OverlayItem for KeyValues<'a, V> {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let row_height = overlay.style.line_spacing + overlay.style.font.line_height();
+        let y0 = origin.y + overlay.style.font.line_height();
+        let mut max = origin;
+
+        let mut y = y0;
+        for (idx, (key, _)) in self.0.iter().enumerate() {
+            let color = overlay.style.text_color[idx % 2];
+            let r = overlay
+                .geometry
+                .push_text(FRONT_LAYER, key, Point { x: origin.x, y }, color);
+            max.x = max.x.max(r.1.x);
+            max.y = max.y.max(r.1.y);
+            y += row_height;
+        }
+
+        let x = if self.0.is_empty() {
+            origin.x
+        } else {
+            max.x + overlay.style.column_spacing
+        };
+        let mut y = y0;
+        for (idx, (_, value)) in self.0.iter().enumerate() {
+            let color = overlay.style.text_color[idx % 2];
+            let r = overlay
+                .geometry
+                .push_text(FRONT_LAYER, value.as_ref(), Point { x, y }, color);
+            max.x = max.x.max(r.1.x);
+            max.y = max.y.max(r.1.y);
+            y += row_height;
+        }
+
+        (origin, max)
+    }
+}
+
+impl OverlayGeometry {
+    /// Similar to `push_text`, interpreting the color markup described in `Markup`.
+    pub fn push_markup(
+        &mut self,
+        layer: Layer,
+        text: &str,
+        mut position: Point,
+        color: Color,
+    ) -> (Point, Point) {
+        let mut bounds = (position, position);
+        let line_start = position.x;
+        for segment in MarkupSegments::new(text) {
+            let color = color_to_u32(segment.color.unwrap_or(color));
+            self.push_glyphs(
+                layer,
+                segment.text,
+                self.font.face(),
+                &mut position,
+                line_start,
+                color,
+                &mut bounds,
+            );
+        }
+
+        bounds
+    }
+}
+
+/// A run of text sharing the same color.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextSegment<'a> {
+    pub text: &'a str,
+    /// The color set by the markup, or `None` for the default color.
+    pub color: Option<Color>,
+}
+
+/// Splits a string containing color markup into segments.
+///
+/// Tags that can't be parsed are kept as text.
+pub struct MarkupSegments<'a> {
+    src: &'a str,
+    color: Option<Color>,
+}
+
+impl<'a> MarkupSegments<'a> {
+    pub fn new(src: &'a str) -> Self {
+        MarkupSegments { src, color: None }
+    }
+}
+
+impl<'a> Iterator for MarkupSegments<'a> {
+    type Item = TextSegment<'a>;
+    fn next(&mut self) -> Option<TextSegment<'a>> {
+        loop {
+            if self.src.is_empty() {
+                return None;
+            }
+
+            let mut search_from = 0;
+            let split = loop {
+                let Some(idx) = self.src[search_from..].find("#[") else {
+                    break None;
+                };
+                let tag_start = search_from + idx;
+                let rest = &self.src[tag_start + 2..];
+                if let Some(end) = rest.find(']') {
+                    if let Some(color) = parse_tag(&rest[..end]) {
+                        break Some((tag_start, tag_start + 2 + end + 1, color));
+                    }
+                }
+                search_from = tag_start + 2;
+            };
+
+            let (text, next_color) = match split {
+                Some((tag_start, tag_end, color)) => {
+                    let text = &self.src[..tag_start];
+                    self.src = &self.src[tag_end..];
+                    (text, color)
+                }
+                None => {
+                    let text = self.src;
+                    self.src = "";
+                    (text, self.color)
+                }
+            };
+
+            let color = self.color;
+            self.color = next_color;
+
+            if !text.is_empty() {
+                return Some(TextSegment { text, color });
+            }
+        }
+    }
+}
+
+/// Parses the content of a markup tag.
+///
+/// Returns `Some(None)` for the reset tag.
+fn parse_tag(tag: &str) -> Option<Option<Color>> {
+    if tag.is_empty() {
+        return Some(None);
+    }
+
+    if tag.len() != 6 && tag.len() != 8 {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(tag.get(i..i + 2)?, 16).ok();
+    let alpha = if tag.len() == 8 { channel(6)? } else { 255 };
+
+    Some(Some((channel(0)?, channel(2)?, channel(4)?, alpha)))
+}
+
+#[test]
+fn markup_segments() {
+    let segments: Vec<TextSegment> =
+        MarkupSegments::new("a#[ff0000]bc#[]d#[00ff0080]#[zz]e").collect();
+    assert_eq!(
+        &segments[..],
+        &[
+            TextSegment {
+                text: "a",
+                color: None
+            },
+            TextSegment {
+                text: "bc",
+                color: Some((255, 0, 0, 255))
+            },
+            TextSegment {
+                text: "d",
+                color: None
+            },
+            TextSegment {
+                text: "#[zz]e",
+                color: Some((0, 255, 0, 128))
+            },
+        ]
+    );
+}
+
+#[test]
+fn key_values() {
+    let mut overlay = Overlay::new();
+    let values = [("a", "1".to_string()), ("long", "1".to_string())];
+    let rect = KeyValues(&values).draw(Point { x: 0, y: 0 }, &mut overlay);
+
+    let vertices = &overlay.geometry.vertices;
+    // Both values start at the same position, after the longest key.
+    let value_x = vertices[4 * 5].x;
+    assert_eq!(vertices[4 * 6].x, value_x);
+    assert!(value_x > vertices[4 * 4 + 1].x);
+    assert!(rect.1.x as f32 > value_x);
+    assert_eq!(vertices[0].color, color_to_u32(overlay.style.text_color[0]));
+    assert_eq!(vertices[4].color, color_to_u32(overlay.style.text_color[1]));
+}
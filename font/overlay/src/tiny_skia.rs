@@ -0,0 +1,368 @@
+//! Renders the overlay into a `tiny-skia` pixmap, for tools without a GPU.
+//!
+//! The geometry is drawn with the same sampling and blending rules as the
+//! `wgpu` renderer, except that translucent colors are composited with regular
+//! source-over blending, which looks slightly lighter than the GPU output.
+//!
+//! ```
+//! use debug_overlay::*;
+//!
+//! let mut overlay = Overlay::new();
+//! overlay.begin_frame();
+//! overlay.draw_item(&"Hello");
+//! overlay.finish();
+//!
+//! let mut renderer = debug_overlay::tiny_skia::Renderer::new();
+//! let mut pixmap = ::tiny_skia::Pixmap::new(200, 100).unwrap();
+//! renderer.render(&overlay.geometry, &mut pixmap.as_mut(), 1.0);
+//! ```
+
+use std::collections::HashMap;
+
+use ::tiny_skia::{
+    Color as SkColor, FillRule, FilterQuality, GradientStop, LinearGradient, Paint, PathBuilder,
+    Pattern, Pixmap, PixmapMut, PremultipliedColorU8, Shader, SpreadMode, Transform,
+};
+
+use crate::{
+    distance_coverage,
+    embedded_font::{
+        ATLAS_HEIGHT, ATLAS_WIDTH, DISTANCE_FIELD, DISTANCE_SPREAD, GLYPH_ATLAS, OPAQUE_PIXEL,
+    },
+    Color, OverlayGeometry, Vertex,
+};
+
+/// The number of text colors for which a tinted copy of the atlas is kept.
+const MAX_TINTED_ATLASES: usize = 32;
+
+/// Draws overlay geometry with `tiny-skia`.
+pub struct Renderer {
+    /// The coverage of each texel of the glyph atlas.
+    coverage: Vec<u8>,
+    /// Copies of the atlas in the colors text was drawn with, since patterns
+    /// can't be tinted.
+    tinted: HashMap<u32, Pixmap>,
+}
+
+impl Renderer {
+    /// A distance field atlas is converted to coverage at its native size.
+    pub fn new() -> Self {
+        let len = (ATLAS_WIDTH * ATLAS_HEIGHT) as usize;
+        let coverage = GLYPH_ATLAS[..len]
+            .iter()
+            .map(|&a| {
+                if DISTANCE_FIELD {
+                    (distance_coverage(a as f32 / 255.0, DISTANCE_SPREAD, 1.0) * 255.0).round()
+                        as u8
+                } else {
+                    a
+                }
+            })
+            .collect();
+
+        Renderer {
+            coverage,
+            tinted: HashMap::new(),
+        }
+    }
+
+    /// Draws the geometry over the content of the pixmap, with `scale` pixels
+    /// per overlay pixel.
+    pub fn render(&mut self, geometry: &OverlayGeometry, pixmap: &mut PixmapMut, scale: f32) {
+        let transform = Transform::from_scale(scale, scale);
+        for (base_vertex, indices) in geometry.layers.iter().flat_map(|layer| layer.batches()) {
+            let mut triangles = indices.chunks_exact(3).peekable();
+            while let Some(triangle) = triangles.next() {
+                let v = |i: u16| &geometry.vertices[base_vertex + i as usize];
+                let mut corners = vec![v(triangle[0]), v(triangle[1]), v(triangle[2])];
+                let Some(fill) = TriangleFill::new([corners[0], corners[1], corners[2]]) else {
+                    continue;
+                };
+
+                // Quads are drawn as a single path, so that pixels on their
+                // diagonal are blended once.
+                if let Some(next) = triangles.peek() {
+                    let fourth = v(next[2]);
+                    if next[0] == triangle[0] && next[1] == triangle[2] && fill.extends_to(fourth) {
+                        corners.push(fourth);
+                        triangles.next();
+                    }
+                }
+
+                self.fill(pixmap, &corners, &fill, transform);
+            }
+        }
+    }
+
+    fn fill(
+        &mut self,
+        pixmap: &mut PixmapMut,
+        corners: &[&Vertex],
+        fill: &TriangleFill,
+        transform: Transform,
+    ) {
+        let mut path = PathBuilder::new();
+        path.move_to(corners[0].x, corners[0].y);
+        for corner in &corners[1..] {
+            path.line_to(corner.x, corner.y);
+        }
+        path.close();
+        let Some(path) = path.finish() else {
+            return;
+        };
+
+        let shader = match fill.uv_transform {
+            Some(uv_transform) => {
+                let color = corners[0].color;
+                let atlas = self.tinted_atlas(color);
+                Pattern::new(
+                    atlas.as_ref(),
+                    SpreadMode::Pad,
+                    FilterQuality::Nearest,
+                    1.0,
+                    uv_transform,
+                )
+            }
+            None => fill.gradient(corners),
+        };
+
+        let paint = Paint {
+            shader,
+            anti_alias: false,
+            ..Paint::default()
+        };
+        pixmap.fill_path(&path, &paint, FillRule::Winding, transform, None);
+    }
+
+    /// The glyph atlas in a color, with the coverage of the glyphs in alpha.
+    fn tinted_atlas(&mut self, color: u32) -> &Pixmap {
+        if self.tinted.len() >= MAX_TINTED_ATLASES && !self.tinted.contains_key(&color) {
+            self.tinted.clear();
+        }
+
+        let coverage = &self.coverage;
+        self.tinted.entry(color).or_insert_with(|| {
+            let [r, g, b, a] = color.to_be_bytes();
+            let mut atlas = Pixmap::new(ATLAS_WIDTH, ATLAS_HEIGHT).unwrap();
+            for (pixel, &coverage) in atlas.pixels_mut().iter_mut().zip(coverage) {
+                let alpha = (a as u32 * coverage as u32 + 127) / 255;
+                let premultiply = |c: u8| ((c as u32 * alpha + 127) / 255) as u8;
+                *pixel = PremultipliedColorU8::from_rgba(
+                    premultiply(r),
+                    premultiply(g),
+                    premultiply(b),
+                    alpha as u8,
+                )
+                .unwrap();
+            }
+            atlas
+        })
+    }
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Renderer::new()
+    }
+}
+
+/// Renders the geometry over a background color into a new pixmap, or returns
+/// `None` if the size is empty.
+pub fn render(
+    geometry: &OverlayGeometry,
+    size: (u32, u32),
+    scale: f32,
+    background: Color,
+) -> Option<Pixmap> {
+    let mut pixmap = Pixmap::new(size.0, size.1)?;
+    let (r, g, b, a) = background;
+    pixmap.fill(SkColor::from_rgba8(r, g, b, a));
+    Renderer::new().render(geometry, &mut pixmap.as_mut(), scale);
+
+    Some(pixmap)
+}
+
+/// How the vertex attributes of a triangle vary over its surface.
+///
+/// Attributes are interpolated linearly, so they are affine functions of the
+/// position, which can be extended to the neighboring triangle of a quad.
+struct TriangleFill {
+    corners: [(f32, f32); 3],
+    colors: [[f32; 4]; 3],
+    /// The inverse of the matrix of the edges from the first corner, mapping
+    /// positions to barycentric coordinates.
+    inverse: [f32; 4],
+    /// Maps atlas texels to positions, for triangles that sample glyphs.
+    uv_transform: Option<Transform>,
+}
+
+impl TriangleFill {
+    fn new(vertices: [&Vertex; 3]) -> Option<Self> {
+        let corners = vertices.map(|v| (v.x, v.y));
+        let (e1, e2) = (sub(corners[1], corners[0]), sub(corners[2], corners[0]));
+        let det = e1.0 * e2.1 - e2.0 * e1.1;
+        if det == 0.0 {
+            return None;
+        }
+        let inverse = [e2.1 / det, -e2.0 / det, -e1.1 / det, e1.0 / det];
+
+        let opaque_uv = (OPAQUE_PIXEL.0 as u32) << 16 | OPAQUE_PIXEL.1 as u32;
+        let uv_transform = if vertices.iter().all(|v| v.uv == opaque_uv) {
+            None
+        } else {
+            // Solves positions = M * uv + t from the three corners.
+            let uvs = vertices.map(|v| ((v.uv >> 16) as f32, (v.uv & 0xFFFF) as f32));
+            let (d1, d2) = (sub(uvs[1], uvs[0]), sub(uvs[2], uvs[0]));
+            let uv_det = d1.0 * d2.1 - d2.0 * d1.1;
+            if uv_det == 0.0 {
+                return None;
+            }
+            let inv = [d2.1 / uv_det, -d2.0 / uv_det, -d1.1 / uv_det, d1.0 / uv_det];
+            let sx = e1.0 * inv[0] + e2.0 * inv[2];
+            let kx = e1.0 * inv[1] + e2.0 * inv[3];
+            let ky = e1.1 * inv[0] + e2.1 * inv[2];
+            let sy = e1.1 * inv[1] + e2.1 * inv[3];
+            let tx = corners[0].0 - sx * uvs[0].0 - kx * uvs[0].1;
+            let ty = corners[0].1 - ky * uvs[0].0 - sy * uvs[0].1;
+            Some(Transform::from_row(sx, ky, kx, sy, tx, ty))
+        };
+
+        Some(TriangleFill {
+            corners,
+            colors: vertices.map(|v| v.color.to_be_bytes().map(|c| c as f32 / 255.0)),
+            inverse,
+            uv_transform,
+        })
+    }
+
+    /// The interpolated color at a position, possibly outside of the triangle.
+    fn color_at(&self, p: (f32, f32)) -> [f32; 4] {
+        let d = sub(p, self.corners[0]);
+        let w1 = self.inverse[0] * d.0 + self.inverse[1] * d.1;
+        let w2 = self.inverse[2] * d.0 + self.inverse[3] * d.1;
+        let w0 = 1.0 - w1 - w2;
+        [0, 1, 2, 3]
+            .map(|c| w0 * self.colors[0][c] + w1 * self.colors[1][c] + w2 * self.colors[2][c])
+    }
+
+    /// Whether the attributes of a vertex follow the same functions as the
+    /// triangle's.
+    fn extends_to(&self, vertex: &Vertex) -> bool {
+        let p = (vertex.x, vertex.y);
+        let color = self.color_at(p);
+        let expected = vertex.color.to_be_bytes();
+        let same_color = (0..4).all(|c| (color[c] * 255.0 - expected[c] as f32).abs() < 0.5);
+
+        let same_uv = match self.uv_transform {
+            Some(transform) => {
+                let uv = ((vertex.uv >> 16) as f32, (vertex.uv & 0xFFFF) as f32);
+                let x = transform.sx * uv.0 + transform.kx * uv.1 + transform.tx;
+                let y = transform.ky * uv.0 + transform.sy * uv.1 + transform.ty;
+                (x - p.0).abs() < 0.01 && (y - p.1).abs() < 0.01
+            }
+            None => true,
+        };
+
+        same_color && same_uv
+    }
+
+    /// A solid color or a linear gradient along the direction in which the
+    /// color varies the most.
+    ///
+    /// This is exact when all channels vary in the same direction, which is the
+    /// case of the gradients of the overlay.
+    fn gradient(&self, corners: &[&Vertex]) -> Shader<'static> {
+        let color = |c: [f32; 4]| {
+            let [r, g, b, a] = c.map(|c| c.clamp(0.0, 1.0));
+            SkColor::from_rgba(r, g, b, a).unwrap()
+        };
+
+        // The gradient of each channel over the triangle.
+        let origin = self.color_at(self.corners[0]);
+        let dx = self.color_at((self.corners[0].0 + 1.0, self.corners[0].1));
+        let dy = self.color_at((self.corners[0].0, self.corners[0].1 + 1.0));
+        let direction = (0..4)
+            .map(|c| (dx[c] - origin[c], dy[c] - origin[c]))
+            .max_by(|a, b| length(*a).total_cmp(&length(*b)))
+            .unwrap();
+        if length(direction) < 1e-6 {
+            return Shader::SolidColor(color(self.colors[0]));
+        }
+
+        let n = (
+            direction.0 / length(direction),
+            direction.1 / length(direction),
+        );
+        let project = |v: &&Vertex| v.x * n.0 + v.y * n.1;
+        let start = corners
+            .iter()
+            .min_by(|a, b| project(a).total_cmp(&project(b)))
+            .unwrap();
+        let end = corners
+            .iter()
+            .max_by(|a, b| project(a).total_cmp(&project(b)))
+            .unwrap();
+        let distance = project(end) - project(start);
+        let start = (start.x, start.y);
+        let end = (start.0 + n.0 * distance, start.1 + n.1 * distance);
+
+        LinearGradient::new(
+            start.into(),
+            end.into(),
+            vec![
+                GradientStop::new(0.0, color(self.color_at(start))),
+                GradientStop::new(1.0, color(self.color_at(end))),
+            ],
+            SpreadMode::Pad,
+            Transform::identity(),
+        )
+        .unwrap_or(Shader::SolidColor(color(self.colors[0])))
+    }
+}
+
+fn sub(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn length(v: (f32, f32)) -> f32 {
+    (v.0 * v.0 + v.1 * v.1).sqrt()
+}
+
+#[test]
+fn matches_software_renderer() {
+    use crate::{software, Point};
+
+    let mut geometry = OverlayGeometry::new(2);
+    geometry.push_rectangle(
+        0,
+        &(Point { x: 4, y: 4 }, Point { x: 120, y: 40 }),
+        (40, 60, 200, 255),
+        (200, 60, 40, 255),
+    );
+    geometry.push_horizontal_gradient(
+        0,
+        &(Point { x: 4, y: 44 }, Point { x: 120, y: 56 }),
+        (0, 0, 0, 255),
+        (255, 255, 255, 255),
+    );
+    geometry.push_text(1, "Skia 0.5", Point { x: 8, y: 30 }, (255, 255, 255, 255));
+
+    for scale in [1.0, 2.0] {
+        let size = (128 * scale as u32, 64 * scale as u32);
+        let expected = software::render(&geometry, size, scale, (0, 0, 0, 255));
+        let pixmap = render(&geometry, size, scale, (0, 0, 0, 255)).unwrap();
+        let image = software::Image {
+            width: pixmap.width(),
+            height: pixmap.height(),
+            pixels: pixmap
+                .pixels()
+                .iter()
+                .map(|p| [p.red(), p.green(), p.blue(), p.alpha()])
+                .collect(),
+        };
+        let diff = image.diff(&expected, 2).unwrap();
+        assert_eq!(diff.differing_pixels, 0, "{diff:?} at scale {scale}");
+    }
+
+    assert!(render(&geometry, (0, 10), 1.0, (0, 0, 0, 255)).is_none());
+}
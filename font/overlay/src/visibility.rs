@@ -0,0 +1,137 @@
+use std::time::{Duration, Instant};
+
+/// Fades the overlay in and out when it is shown or hidden.
+///
+/// Call `tick` once per frame and pass the returned opacity to the renderer.
+///
+/// # Example
+///
+/// ```
+/// use debug_overlay::*;
+/// use std::time::Duration;
+///
+/// let mut visibility = OverlayVisibility::new(false, Duration::from_millis(150));
+///
+/// // When the hotkey is pressed:
+/// visibility.toggle();
+///
+/// // In the render loop:
+/// let opacity = visibility.tick();
+/// if visibility.is_drawn() {
+///     // Draw the overlay and pass `opacity` to `Renderer::update`.
+/// }
+/// ```
+pub struct OverlayVisibility {
+    visible: bool,
+    opacity: f32,
+    fade_duration: Duration,
+    last_tick: Option<Instant>,
+}
+
+impl OverlayVisibility {
+    pub fn new(visible: bool, fade_duration: Duration) -> Self {
+        OverlayVisibility {
+            visible,
+            opacity: if visible { 1.0 } else { 0.0 },
+            fade_duration,
+            last_tick: None,
+        }
+    }
+
+    pub fn set_fade_duration(&mut self, duration: Duration) {
+        self.fade_duration = duration;
+    }
+
+    /// Starts fading in or out. Reversing a fade midway continues from the
+    /// current opacity.
+    pub fn set_visible(&mut self, visible: bool) {
+        if !self.is_fading() {
+            // The previous tick may be arbitrarily old if the overlay was not
+            // ticked while hidden.
+            self.last_tick = None;
+        }
+        self.visible = visible;
+    }
+
+    pub fn toggle(&mut self) {
+        self.set_visible(!self.visible);
+    }
+
+    /// Whether the overlay is shown or fading in.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Whether the overlay needs to be drawn, in other words whether it is
+    /// visible or still fading out.
+    pub fn is_drawn(&self) -> bool {
+        self.opacity > 0.0
+    }
+
+    pub fn is_fading(&self) -> bool {
+        let target = if self.visible { 1.0 } else { 0.0 };
+        self.opacity != target
+    }
+
+    /// The current opacity, between 0 and 1.
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// Advances the animation by the time elapsed since the previous call and
+    /// returns the opacity.
+    pub fn tick(&mut self) -> f32 {
+        let now = Instant::now();
+        let elapsed = self
+            .last_tick
+            .map(|last| now - last)
+            .unwrap_or(Duration::ZERO);
+        self.last_tick = Some(now);
+
+        self.advance(elapsed)
+    }
+
+    /// Advances the animation by a given amount of time and returns the opacity.
+    pub fn advance(&mut self, elapsed: Duration) -> f32 {
+        let step = if self.fade_duration.is_zero() {
+            1.0
+        } else {
+            elapsed.as_secs_f32() / self.fade_duration.as_secs_f32()
+        };
+        self.opacity = if self.visible {
+            (self.opacity + step).min(1.0)
+        } else {
+            (self.opacity - step).max(0.0)
+        };
+
+        self.opacity
+    }
+}
+
+#[test]
+fn fade() {
+    let ms = Duration::from_millis;
+    let mut visibility = OverlayVisibility::new(false, ms(100));
+    assert!(!visibility.is_drawn());
+
+    visibility.toggle();
+    assert!(visibility.is_visible());
+    assert_eq!(visibility.advance(ms(50)), 0.5);
+    assert!(visibility.is_drawn());
+    assert_eq!(visibility.advance(ms(100)), 1.0);
+    assert!(!visibility.is_fading());
+
+    visibility.toggle();
+    assert_eq!(visibility.advance(ms(25)), 0.75);
+    // Reversing midway starts from the current opacity.
+    visibility.toggle();
+    assert_eq!(visibility.advance(ms(25)), 1.0);
+
+    visibility.set_visible(false);
+    visibility.advance(ms(200));
+    assert!(!visibility.is_drawn());
+
+    visibility.set_fade_duration(Duration::ZERO);
+    visibility.toggle();
+    assert_eq!(visibility.advance(Duration::ZERO), 1.0);
+}
@@ -1,31 +1,215 @@
+use std::collections::HashMap;
 use std::mem::size_of;
+use std::ops::Range;
 use wgpu::{self, util::DeviceExt};
 use crate::wgpu_common::*;
 
 use crate::{
-    embedded_font::{ATLAS_HEIGHT, ATLAS_WIDTH},
-    Vertex,
+    color_to_u32,
+    embedded_font::{ATLAS_HEIGHT, ATLAS_WIDTH, OPAQUE_PIXEL},
+    Color, Vertex,
 };
 
-pub use crate::wgpu_common::RendererOptions;
+pub use crate::wgpu_common::{ColorSpace, RendererOptions, ShaderError};
+
+/// Persistent vertex and index buffers, along with the content last uploaded
+/// to them.
+struct GeometryBuffers {
+    vbo: Option<(wgpu::Buffer, usize)>,
+    ibo: Option<(wgpu::Buffer, usize)>,
+    vertices: Vec<Vertex>,
+    indices: Vec<u16>,
+}
+
+impl GeometryBuffers {
+    fn new() -> Self {
+        GeometryBuffers {
+            vbo: None,
+            ibo: None,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+}
+
+/// Makes sure the buffer can hold `len` elements, reallocating with some
+/// headroom if needed. Returns true if the buffer was reallocated.
+fn ensure_capacity(
+    buffer: &mut Option<(wgpu::Buffer, usize)>,
+    len: usize,
+    element_size: usize,
+    label: &'static str,
+    usage: wgpu::BufferUsages,
+    device: &wgpu::Device,
+) -> bool {
+    let capacity = buffer.as_ref().map(|(_, cap)| *cap).unwrap_or(0);
+    let new_capacity = grow_capacity(capacity, len);
+    if buffer.is_some() && new_capacity == capacity {
+        return false;
+    }
+
+    if let Some((old, _)) = buffer.take() {
+        old.destroy();
+    }
+    *buffer = Some((
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: (new_capacity * element_size) as u64,
+            usage: usage | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }),
+        new_capacity,
+    ));
+
+    true
+}
+
+/// A render target with its own size and scale factor.
+///
+/// The geometry uploaded with `Renderer::update_geometry` is shared by all
+/// viewports, which only differ by their globals. This allows rendering the
+/// same overlay into several windows or split-screen views.
+pub struct Viewport {
+    ubo: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    scale_factor: f32,
+    globals: ShaderGlobals,
+}
+
+impl Viewport {
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Takes effect at the next `Renderer::update_viewport`.
+    pub fn set_scale_factor(&mut self, scale_factor: f32) {
+        self.scale_factor = scale_factor;
+    }
+
+    fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        atlas: &wgpu::Texture,
+        scale_factor: f32,
+    ) -> Self {
+        let ubo = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Debug overlay globals"),
+            contents: bytemuck::cast_slice(&[0.0f32; 8]),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+        });
+
+        let atlas_view = atlas.create_view(&Default::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Debug overlay"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                        buffer: &ubo,
+                        offset: 0,
+                        size: wgpu::BufferSize::new(32),
+                    }),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&atlas_view),
+                },
+            ],
+        });
+
+        Viewport {
+            ubo,
+            bind_group,
+            scale_factor,
+            globals: ShaderGlobals {
+                target_size: (0.0, 0.0),
+                scale: 0.0,
+                opacity: 0.0,
+                y_flip: 1.0,
+            },
+        }
+    }
+
+    fn update(&mut self, globals: ShaderGlobals, queue: &wgpu::Queue) {
+        if self.globals == globals {
+            return;
+        }
+
+        queue.write_buffer(
+            &self.ubo,
+            0,
+            bytemuck::cast_slice(&[
+                globals.target_size.0,
+                globals.target_size.1,
+                globals.scale,
+                globals.opacity,
+                globals.y_flip,
+            ]),
+        );
+        self.globals = globals;
+    }
+}
+
+/// Parameters of the offscreen mode, see `Renderer::set_offscreen`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OffscreenOptions {
+    /// Size of the offscreen texture in pixels.
+    pub size: (u32, u32),
+    /// The overlay is drawn into the texture once every `refresh_interval` frames.
+    pub refresh_interval: u32,
+}
+
+struct Offscreen {
+    options: OffscreenOptions,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    pipeline: wgpu::RenderPipeline,
+    viewport: Viewport,
+    composite_pipeline: wgpu::RenderPipeline,
+    composite_ubo: wgpu::Buffer,
+    composite_bind_group: wgpu::BindGroup,
+    frame: u32,
+}
 
 /// Renders an overlay using `wgpu`.
 pub struct Renderer {
     glyph_atlas_texture: wgpu::Texture,
-    bind_group: wgpu::BindGroup,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: wgpu::PipelineLayout,
+    module: wgpu::ShaderModule,
+    options: RendererOptions,
+    viewport: Viewport,
     pipeline: wgpu::RenderPipeline,
-    vbo: Option<(wgpu::Buffer, usize)>,
-    ibo: Option<(wgpu::Buffer, usize)>,
-    ubo: wgpu::Buffer,
-    index_count: u32,
+    offscreen: Option<Offscreen>,
+    buffers: Vec<GeometryBuffers>,
+    current_buffers: usize,
+    indices: Vec<u16>,
+    /// The range of indices and the first vertex of each draw call.
+    draws: Vec<(Range<u32>, usize)>,
     y_flip: bool,
-    scale: f32,
-    globals: ShaderGlobals,
 }
 
 impl Renderer {
     /// Constructor.
+    ///
+    /// Panics if `options.fragment_override` is not a valid shader, see `try_new`.
     pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, options: &RendererOptions) -> Self {
+        match Self::try_new(device, queue, options) {
+            Ok(renderer) => renderer,
+            Err(e) => panic!("Invalid debug overlay shader: {e}"),
+        }
+    }
+
+    /// Constructor, returning an error if `options.fragment_override` is not a
+    /// valid shader.
+    pub fn try_new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        options: &RendererOptions,
+    ) -> Result<Self, ShaderError> {
+        let module = create_shader_module(device, options)?;
+
         let width = ATLAS_WIDTH;
         let height = width;
 
@@ -90,246 +274,673 @@ impl Renderer {
             ],
         });
 
-        let glyph_atlas_view = glyph_atlas_texture.create_view(&Default::default());
+        let viewport = Viewport::new(device, &bgl, &glyph_atlas_texture, options.scale_factor);
 
-        let ubo = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("Debug overlay globals"),
-            contents: bytemuck::cast_slice(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]),
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug overlay"),
+            bind_group_layouts: &[&bgl],
+            push_constant_ranges: &[],
         });
 
-        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("Debug overlay"),
-            layout: &bgl,
+        let pipeline = create_mesh_pipeline(
+            device,
+            &pipeline_layout,
+            &module,
+            options.target_format,
+            options.depth_stencil_format,
+            options.sample_count,
+            &shader_constants(options, options.target_format),
+        );
+
+        let buffer_count = if options.double_buffering { 2 } else { 1 };
+
+        Ok(Renderer {
+            glyph_atlas_texture,
+            bind_group_layout: bgl,
+            pipeline_layout,
+            module,
+            options: options.clone(),
+            viewport,
+            pipeline,
+            offscreen: None,
+
+            buffers: (0..buffer_count).map(|_| GeometryBuffers::new()).collect(),
+            current_buffers: 0,
+            indices: Vec::new(),
+            draws: Vec::new(),
+            y_flip: options.y_flip,
+        })
+    }
+
+    /// Applies new options without recreating the glyph atlas and buffers.
+    ///
+    /// Pipelines are rebuilt, for example when a window moves to a monitor with
+    /// a different format or sample count. The offscreen texture, if any, is
+    /// recreated and redrawn at the next `render_offscreen`.
+    ///
+    /// If the fragment override is not a valid shader, the renderer is left
+    /// unchanged.
+    pub fn reconfigure(
+        &mut self,
+        device: &wgpu::Device,
+        options: &RendererOptions,
+    ) -> Result<(), ShaderError> {
+        if options.fragment_override != self.options.fragment_override {
+            self.module = create_shader_module(device, options)?;
+        }
+
+        self.pipeline = create_mesh_pipeline(
+            device,
+            &self.pipeline_layout,
+            &self.module,
+            options.target_format,
+            options.depth_stencil_format,
+            options.sample_count,
+            &shader_constants(options, options.target_format),
+        );
+
+        let buffer_count = if options.double_buffering { 2 } else { 1 };
+        if buffer_count < self.buffers.len() {
+            // Keep the buffers holding the current geometry.
+            self.buffers.swap(0, self.current_buffers);
+            self.current_buffers = 0;
+        }
+        self.buffers.resize_with(buffer_count, GeometryBuffers::new);
+
+        self.y_flip = options.y_flip;
+        self.viewport.scale_factor = options.scale_factor;
+        self.options = options.clone();
+
+        let offscreen = self.offscreen.as_ref().map(|offscreen| offscreen.options);
+        if offscreen.is_some() {
+            self.set_offscreen(device, offscreen);
+        }
+
+        Ok(())
+    }
+
+    /// Creates an additional render target for the overlay.
+    ///
+    /// The renderer's own viewport, used by `update` and `render`, is configured
+    /// with `RendererOptions::scale_factor`.
+    pub fn create_viewport(&self, device: &wgpu::Device, scale_factor: f32) -> Viewport {
+        Viewport::new(
+            device,
+            &self.bind_group_layout,
+            &self.glyph_atlas_texture,
+            scale_factor,
+        )
+    }
+
+    /// Transfers the overlay information to the GPU.
+    ///
+    /// Must be called once per frame where the overlay is shown, before calling `render`.
+    pub fn update(
+        &mut self,
+        overlay: &crate::OverlayGeometry,
+        taregt_size: (u32, u32),
+        opacity: f32,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) {
+        self.update_geometry(overlay, device, queue);
+
+        let globals = self.globals(self.viewport.scale_factor, taregt_size, opacity);
+        self.viewport.update(globals, queue);
+    }
+
+    /// Transfers the overlay geometry to the GPU, without touching any viewport.
+    ///
+    /// When rendering into several viewports, call this once per frame followed
+    /// by `update_viewport` for each of them.
+    pub fn update_geometry(
+        &mut self,
+        overlay: &crate::OverlayGeometry,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) {
+        const VTX_SIZE: usize = size_of::<Vertex>();
+        const IDX_SIZE: usize = size_of::<u16>();
+
+        let quad;
+        let backdrop: &[Vertex] = match self.options.backdrop {
+            Some(color) if overlay.layers.iter().any(|l| !l.indices.is_empty()) => {
+                quad = backdrop_quad(color);
+                &quad
+            }
+            _ => &[],
+        };
+
+        self.indices.clear();
+        self.draws.clear();
+        if !backdrop.is_empty() {
+            // Drawn first, after the vertices of the overlay.
+            self.indices.extend_from_slice(&[0, 1, 2, 0, 2, 3]);
+            self.draws.push((0..6, overlay.vertices.len()));
+        }
+        // Batches of the same vertices in consecutive layers are drawn at once.
+        for (base_vertex, indices) in overlay.layers.iter().flat_map(|layer| layer.batches()) {
+            let start = self.indices.len() as u32;
+            self.indices.extend_from_slice(indices);
+            let end = self.indices.len() as u32;
+            match self.draws.last_mut() {
+                Some((range, base)) if *base == base_vertex && range.end == start => {
+                    range.end = end;
+                }
+                _ => self.draws.push((start..end, base_vertex)),
+            }
+        }
+        // Copies must have a size that is a multiple of 4 bytes.
+        if self.indices.len() % 2 == 1 {
+            self.indices.push(0);
+        }
+
+        self.current_buffers = (self.current_buffers + 1) % self.buffers.len();
+        let buffers = &mut self.buffers[self.current_buffers];
+
+        let new_vbo = ensure_capacity(
+            &mut buffers.vbo,
+            overlay.vertices.len() + backdrop.len(),
+            VTX_SIZE,
+            "Debug overlay vertices",
+            wgpu::BufferUsages::VERTEX,
+            device,
+        );
+        let new_ibo = ensure_capacity(
+            &mut buffers.ibo,
+            self.indices.len(),
+            IDX_SIZE,
+            "Debug overlay indices",
+            wgpu::BufferUsages::INDEX,
+            device,
+        );
+
+        // Only upload what changed since the last time these buffers were used.
+        let vertex_count = overlay.vertices.len();
+        let up_to_date = !new_vbo
+            && buffers.vertices.len() == vertex_count + backdrop.len()
+            && buffers.vertices[..vertex_count] == overlay.vertices[..]
+            && buffers.vertices[vertex_count..] == backdrop[..];
+        if !up_to_date {
+            let vbo = &buffers.vbo.as_ref().unwrap().0;
+            if !overlay.vertices.is_empty() {
+                queue.write_buffer(vbo, 0, bytemuck::cast_slice(&overlay.vertices[..]));
+            }
+            if !backdrop.is_empty() {
+                queue.write_buffer(
+                    vbo,
+                    (vertex_count * VTX_SIZE) as u64,
+                    bytemuck::cast_slice(backdrop),
+                );
+            }
+            buffers.vertices.clear();
+            buffers.vertices.extend_from_slice(&overlay.vertices);
+            buffers.vertices.extend_from_slice(backdrop);
+        }
+
+        if new_ibo || buffers.indices != self.indices {
+            if !self.indices.is_empty() {
+                queue.write_buffer(
+                    &buffers.ibo.as_ref().unwrap().0,
+                    0,
+                    bytemuck::cast_slice(&self.indices[..]),
+                );
+            }
+            std::mem::swap(&mut buffers.indices, &mut self.indices);
+        }
+    }
+
+    /// Sets the size and opacity of a viewport created with `create_viewport`.
+    ///
+    /// Must be called before `render_viewport`, whenever the target size or the
+    /// opacity changes.
+    pub fn update_viewport(
+        &self,
+        viewport: &mut Viewport,
+        target_size: (u32, u32),
+        opacity: f32,
+        queue: &wgpu::Queue,
+    ) {
+        let globals = self.globals(viewport.scale_factor, target_size, opacity);
+        viewport.update(globals, queue);
+    }
+
+    fn globals(&self, scale: f32, target_size: (u32, u32), opacity: f32) -> ShaderGlobals {
+        ShaderGlobals {
+            target_size: (target_size.0 as f32, target_size.1 as f32),
+            scale,
+            opacity,
+            y_flip: if self.y_flip { -1.0 } else { 1.0 },
+        }
+    }
+
+    /// Display the overlay in a render pass.
+    ///
+    /// Must be called once per frame where the overlay is shown, after calling `update`.
+    pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        self.render_viewport(&self.viewport, pass);
+    }
+
+    /// Display the overlay in a render pass targeting one of the viewports
+    /// created with `create_viewport`.
+    ///
+    /// Must be called after `update_geometry` and `update_viewport`.
+    pub fn render_viewport<'a>(&'a self, viewport: &'a Viewport, pass: &mut wgpu::RenderPass<'a>) {
+        if self.draws.is_empty() {
+            return;
+        }
+
+        self.draw_geometry(pass, &viewport.bind_group, &self.pipeline);
+    }
+
+    fn draw_geometry<'a>(
+        &'a self,
+        pass: &mut wgpu::RenderPass<'a>,
+        bind_group: &'a wgpu::BindGroup,
+        pipeline: &'a wgpu::RenderPipeline,
+    ) {
+        let buffers = &self.buffers[self.current_buffers];
+        let vbo = &buffers.vbo.as_ref().unwrap().0;
+        let ibo = &buffers.ibo.as_ref().unwrap().0;
+
+        pass.set_index_buffer(ibo.slice(..), wgpu::IndexFormat::Uint16);
+        pass.set_bind_group(0, bind_group, &[]);
+        pass.set_pipeline(pipeline);
+
+        // Offsetting the vertex buffer rather than using a base vertex works
+        // on all backends.
+        for (indices, base_vertex) in &self.draws {
+            let offset = (base_vertex * size_of::<Vertex>()) as u64;
+            pass.set_vertex_buffer(0, vbo.slice(offset..));
+            pass.draw_indexed(indices.clone(), 0, 0..1);
+        }
+    }
+
+    /// Renders the overlay into its own texture, redrawn once every few frames,
+    /// and composited into the target with `composite`.
+    ///
+    /// This reduces the per-frame cost of heavy overlays, and allows placing
+    /// the overlay anywhere in the target. Pass `None` to go back to rendering
+    /// the overlay directly with `render`.
+    pub fn set_offscreen(&mut self, device: &wgpu::Device, options: Option<OffscreenOptions>) {
+        if let Some(offscreen) = self.offscreen.take() {
+            offscreen.texture.destroy();
+        }
+        let Some(options) = options else {
+            return;
+        };
+        let options = OffscreenOptions {
+            size: (options.size.0.max(1), options.size.1.max(1)),
+            refresh_interval: options.refresh_interval.max(1),
+        };
+        let format = self.options.target_format;
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Debug overlay offscreen target"),
+            dimension: wgpu::TextureDimension::D2,
+            size: wgpu::Extent3d {
+                width: options.size.0,
+                height: options.size.1,
+                depth_or_array_layers: 1,
+            },
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            mip_level_count: 1,
+            sample_count: 1,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&Default::default());
+
+        let pipeline = create_mesh_pipeline(
+            device,
+            &self.pipeline_layout,
+            &self.module,
+            format,
+            None,
+            1,
+            &shader_constants(&self.options, format),
+        );
+
+        // The offscreen texture is rendered with the scale factor of the
+        // renderer's own viewport.
+        let viewport = self.create_viewport(device, self.viewport.scale_factor);
+
+        let composite_bgl = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Debug overlay composite"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(16),
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let composite_ubo = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug overlay composite"),
+            size: 16,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+            mapped_at_creation: false,
+        });
+        let composite_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Debug overlay composite"),
+            layout: &composite_bgl,
             entries: &[
                 wgpu::BindGroupEntry {
                     binding: 0,
-                    resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
-                        buffer: &ubo,
-                        offset: 0,
-                        size: wgpu::BufferSize::new(32),
-                    }),
+                    resource: composite_ubo.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
                     binding: 1,
-                    resource: wgpu::BindingResource::TextureView(&glyph_atlas_view),
+                    resource: wgpu::BindingResource::TextureView(&view),
                 },
             ],
         });
-
-        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-            label: Some("Debug overlay"),
-            bind_group_layouts: &[&bgl],
+        let composite_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug overlay composite"),
+            bind_group_layouts: &[&composite_bgl],
             push_constant_ranges: &[],
         });
-
-        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Debug overlay"),
-            source: wgpu::ShaderSource::Wgsl(shader_src().into()),
+        let composite_module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Debug overlay composite"),
+            source: wgpu::ShaderSource::Wgsl(composite_shader_src().into()),
         });
-
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Debug overlay mesh"),
-            layout: Some(&pipeline_layout),
+        let composite_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Debug overlay composite"),
+            layout: Some(&composite_layout),
             vertex: wgpu::VertexState {
-                module: &module,
+                module: &composite_module,
                 entry_point: Some("vs_main"),
-                buffers: &[wgpu::VertexBufferLayout {
-                    array_stride: 16,
-                    step_mode: wgpu::VertexStepMode::Vertex,
-                    attributes: &[
-                        wgpu::VertexAttribute {
-                            format: wgpu::VertexFormat::Float32x2,
-                            offset: 0,
-                            shader_location: 0,
-                        },
-                        wgpu::VertexAttribute {
-                            format: wgpu::VertexFormat::Uint32x2,
-                            offset: 8,
-                            shader_location: 1,
-                        },
-                    ],
-                }],
+                buffers: &[],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             },
             fragment: Some(wgpu::FragmentState {
-                module: &module,
+                module: &composite_module,
                 entry_point: Some("fs_main"),
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: options.target_format,
+                    format,
                     blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                polygon_mode: wgpu::PolygonMode::Fill,
-                front_face: wgpu::FrontFace::Ccw,
-                strip_index_format: None,
-                cull_mode: None,
-                unclipped_depth: false,
-                conservative: false,
+                topology: wgpu::PrimitiveTopology::TriangleStrip,
+                ..wgpu::PrimitiveState::default()
             },
-            depth_stencil: options
-                .depth_stencil_format
-                .map(|format| wgpu::DepthStencilState {
+            depth_stencil: self.options.depth_stencil_format.map(|format| {
+                wgpu::DepthStencilState {
                     format,
                     depth_write_enabled: false,
                     depth_compare: wgpu::CompareFunction::Always,
                     stencil: wgpu::StencilState::default(),
                     bias: wgpu::DepthBiasState::default(),
-                }),
+                }
+            }),
             multiview: None,
             multisample: wgpu::MultisampleState {
-                count: options.sample_count,
+                count: self.options.sample_count,
                 ..wgpu::MultisampleState::default()
             },
             cache: None,
         });
 
-        Renderer {
-            glyph_atlas_texture,
-            bind_group,
+        self.offscreen = Some(Offscreen {
+            options,
+            texture,
+            view,
             pipeline,
+            viewport,
+            composite_pipeline,
+            composite_ubo,
+            composite_bind_group,
+            frame: 0,
+        });
+    }
 
-            vbo: None,
-            ibo: None,
-            ubo,
-            index_count: 0,
-            y_flip: options.y_flip,
-            scale: options.scale_factor,
-            globals: ShaderGlobals {
-                target_size: (0.0, 0.0),
-                scale: 0.0,
-                opacity: 0.0,
-                y_flip: 1.0,
-            },
+    /// Whether the next call to `render_offscreen` redraws the offscreen texture.
+    ///
+    /// When it doesn't, building the overlay and calling `update` can be skipped
+    /// for this frame.
+    pub fn offscreen_refresh_due(&self) -> bool {
+        match &self.offscreen {
+            Some(offscreen) => offscreen.frame % offscreen.options.refresh_interval == 0,
+            None => true,
         }
     }
 
-    /// Transfers the overlay information to the GPU.
+    /// The texture the overlay is drawn into in offscreen mode.
+    pub fn offscreen_texture(&self) -> Option<&wgpu::TextureView> {
+        self.offscreen.as_ref().map(|offscreen| &offscreen.view)
+    }
+
+    /// Redraws the offscreen texture if a refresh is due, and places its top-left
+    /// corner at `position` (in pixels) in a target of size `target_size`.
     ///
-    /// Must be called once per frame where the overlay is shown, before calling `render`.
-    pub fn update(
+    /// Must be called once per frame in offscreen mode, after `update` and before
+    /// `composite`.
+    pub fn render_offscreen(
         &mut self,
-        overlay: &crate::OverlayGeometry,
-        taregt_size: (u32, u32),
-        opacity: f32,
-        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
         queue: &wgpu::Queue,
+        target_size: (u32, u32),
+        position: (i32, i32),
     ) {
-        const VTX_SIZE: usize = size_of::<Vertex>();
-        const IDX_SIZE: usize = size_of::<u16>();
-
-        let vbo_len = overlay.vertices.len();
-        let ibo_len = overlay.layers.iter().map(|l| l.indices.len()).sum();
-
-        let alloc_vbo = self
-            .vbo
-            .as_ref()
-            .map(|(_, len)| *len <= vbo_len)
-            .unwrap_or(true);
-        let alloc_ibo = self
-            .ibo
-            .as_ref()
-            .map(|(_, len)| *len <= ibo_len)
-            .unwrap_or(true);
-
-        if alloc_vbo {
-            self.vbo = Some((
-                device.create_buffer(&wgpu::BufferDescriptor {
-                    label: Some("Debug overlay vertices"),
-                    size: (vbo_len * VTX_SIZE) as u64,
-                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
-                    mapped_at_creation: false,
-                }),
-                vbo_len,
-            ));
-        }
-
-        if alloc_ibo {
-            self.ibo = Some((
-                device.create_buffer(&wgpu::BufferDescriptor {
-                    label: Some("Debug overlay indices"),
-                    size: (ibo_len * IDX_SIZE) as u64,
-                    usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
-                    mapped_at_creation: false,
-                }),
-                ibo_len,
-            ));
-        }
+        let refresh = self.offscreen_refresh_due();
+        let Some(offscreen) = &mut self.offscreen else {
+            return;
+        };
+        offscreen.frame = offscreen.frame.wrapping_add(1);
 
-        if !overlay.vertices.is_empty() {
-            queue.write_buffer(
-                &self.vbo.as_ref().unwrap().0,
-                0,
-                bytemuck::cast_slice(&overlay.vertices[..]),
-            );
+        let (w, h) = offscreen.options.size;
+        let to_ndc = |x: i32, size: u32| x as f32 / size as f32 * 2.0 - 1.0;
+        let x0 = to_ndc(position.0, target_size.0);
+        let x1 = to_ndc(position.0 + w as i32, target_size.0);
+        let mut y0 = to_ndc(position.1, target_size.1);
+        let mut y1 = to_ndc(position.1 + h as i32, target_size.1);
+        if self.y_flip {
+            y0 = -y0;
+            y1 = -y1;
         }
+        queue.write_buffer(
+            &offscreen.composite_ubo,
+            0,
+            bytemuck::cast_slice(&[x0, y0, x1, y1]),
+        );
 
-        let mut ibo_byte_offset = 0;
-        self.index_count = 0;
-        for layer in &overlay.layers {
-            if layer.indices.is_empty() {
-                continue;
-            }
-            queue.write_buffer(
-                &self.ibo.as_ref().unwrap().0,
-                ibo_byte_offset,
-                bytemuck::cast_slice(&layer.indices[..]),
-            );
-            ibo_byte_offset += (layer.indices.len() * IDX_SIZE) as u64;
-            self.index_count += layer.indices.len() as u32;
+        if !refresh {
+            return;
         }
 
-        let w = taregt_size.0 as f32;
-        let h = taregt_size.1 as f32;
         let globals = ShaderGlobals {
-            target_size: (w, h),
-            scale: self.scale,
-            opacity,
-            y_flip: if self.y_flip { -1.0 } else { 1.0 },
+            target_size: (w as f32, h as f32),
+            scale: offscreen.viewport.scale_factor,
+            opacity: self.viewport.globals.opacity,
+            y_flip: -1.0,
         };
+        offscreen.viewport.update(globals, queue);
 
-        if self.globals != globals {
-            queue.write_buffer(
-                &self.ubo,
-                0,
-                bytemuck::cast_slice(&[
-                    globals.target_size.0,
-                    globals.target_size.1,
-                    globals.scale,
-                    globals.opacity,
-                    globals.y_flip,
-                ]),
+        let offscreen = self.offscreen.as_ref().unwrap();
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Debug overlay offscreen"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &offscreen.view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        if !self.draws.is_empty() {
+            self.draw_geometry(
+                &mut pass,
+                &offscreen.viewport.bind_group,
+                &offscreen.pipeline,
             );
-            self.globals = globals;
         }
     }
 
-    /// Display the overlay in a render pass.
+    /// Draws the offscreen texture in a render pass.
     ///
-    /// Must be called once per frame where the overlay is shown, after calling `update`.
-    pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
-        if self.index_count == 0 {
+    /// Does nothing if the offscreen mode is not enabled.
+    pub fn composite<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        let Some(offscreen) = &self.offscreen else {
             return;
-        }
-
-        let vbo = &self.vbo.as_ref().unwrap().0;
-        let ibo = &self.ibo.as_ref().unwrap().0;
-
-        pass.set_vertex_buffer(0, vbo.slice(..));
-        pass.set_index_buffer(ibo.slice(..), wgpu::IndexFormat::Uint16);
-        pass.set_bind_group(0, &self.bind_group, &[]);
-        pass.set_pipeline(&self.pipeline);
+        };
 
-        pass.draw_indexed(0..self.index_count, 0, 0..1);
+        pass.set_bind_group(0, &offscreen.composite_bind_group, &[]);
+        pass.set_pipeline(&offscreen.composite_pipeline);
+        pass.draw(0..4, 0..1);
     }
 }
 
 impl Drop for Renderer {
     fn drop(&mut self) {
         self.glyph_atlas_texture.destroy();
+        if let Some(offscreen) = &self.offscreen {
+            offscreen.texture.destroy();
+        }
+    }
+}
+
+fn create_shader_module(
+    device: &wgpu::Device,
+    options: &RendererOptions,
+) -> Result<wgpu::ShaderModule, ShaderError> {
+    let src = shader_src(options.fragment_override.as_deref());
+    if options.fragment_override.is_some() {
+        validate_shader(&src)?;
     }
+
+    Ok(device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Debug overlay"),
+        source: wgpu::ShaderSource::Wgsl(src.into()),
+    }))
+}
+
+/// A quad covering any target, in overlay coordinates.
+fn backdrop_quad(color: Color) -> [Vertex; 4] {
+    // Large enough to cover the target at any scale factor, and small enough
+    // to stay precise after the transformation to normalized coordinates.
+    const EXTENT: f32 = 16384.0;
+
+    let uv = (OPAQUE_PIXEL.0 as u32) << 16 | OPAQUE_PIXEL.1 as u32;
+    let color = color_to_u32(color);
+    [
+        (-EXTENT, -EXTENT),
+        (EXTENT, -EXTENT),
+        (EXTENT, EXTENT),
+        (-EXTENT, EXTENT),
+    ]
+    .map(|(x, y)| Vertex { x, y, uv, color })
+}
+
+/// Values of the overridable constants of the mesh shader.
+fn shader_constants(
+    options: &RendererOptions,
+    target_format: wgpu::TextureFormat,
+) -> HashMap<String, f64> {
+    let flag = |value: bool| if value { 1.0 } else { 0.0 };
+    HashMap::from([
+        (
+            "LINEAR_OUTPUT".to_string(),
+            flag(options.color_space.is_linear(target_format)),
+        ),
+        (
+            "FILTERED_SAMPLING".to_string(),
+            flag(options.filtered_sampling),
+        ),
+    ])
+}
+
+fn create_mesh_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    module: &wgpu::ShaderModule,
+    target_format: wgpu::TextureFormat,
+    depth_stencil_format: Option<wgpu::TextureFormat>,
+    sample_count: u32,
+    constants: &HashMap<String, f64>,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Debug overlay mesh"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module,
+            entry_point: Some("vs_main"),
+            buffers: &[wgpu::VertexBufferLayout {
+                array_stride: 16,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &[
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Float32x2,
+                        offset: 0,
+                        shader_location: 0,
+                    },
+                    wgpu::VertexAttribute {
+                        format: wgpu::VertexFormat::Uint32x2,
+                        offset: 8,
+                        shader_location: 1,
+                    },
+                ],
+            }],
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants,
+                ..Default::default()
+            },
+        },
+        fragment: Some(wgpu::FragmentState {
+            module,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format: target_format,
+                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: wgpu::PipelineCompilationOptions {
+                constants,
+                ..Default::default()
+            },
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            polygon_mode: wgpu::PolygonMode::Fill,
+            front_face: wgpu::FrontFace::Ccw,
+            strip_index_format: None,
+            cull_mode: None,
+            unclipped_depth: false,
+            conservative: false,
+        },
+        depth_stencil: depth_stencil_format.map(|format| wgpu::DepthStencilState {
+            format,
+            depth_write_enabled: false,
+            depth_compare: wgpu::CompareFunction::Always,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }),
+        multiview: None,
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            ..wgpu::MultisampleState::default()
+        },
+        cache: None,
+    })
 }
@@ -1,4 +1,8 @@
-use crate::embedded_font::ATLAS_WIDTH;
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::embedded_font::{ATLAS_WIDTH, DISTANCE_FIELD, DISTANCE_SPREAD};
+use crate::Color;
 
 /// Initial parameters for the overlay renderer.
 #[derive(Clone, Debug)]
@@ -13,6 +17,29 @@ pub struct RendererOptions {
     pub y_flip: bool,
     /// Global scaling factor.
     pub scale_factor: f32,
+    /// Sample the glyph atlas with bilinear filtering instead of picking the
+    /// nearest texel.
+    ///
+    /// Text looks sharper without filtering at integer scale factors, but
+    /// shimmers at fractional ones, for example with a scale factor of 1.5.
+    pub filtered_sampling: bool,
+    /// How the sRGB colors of the overlay are written to the target.
+    pub color_space: ColorSpace,
+    /// Alternate between two sets of vertex and index buffers so that the
+    /// upload of a frame doesn't write into buffers used by the previous one.
+    pub double_buffering: bool,
+    /// A color blended over the whole target before the overlay, for example
+    /// `(0, 0, 0, 100)` to dim the scene behind it and improve contrast.
+    ///
+    /// Nothing is drawn when the overlay is empty. The scene can't be blurred
+    /// since the renderer doesn't have access to it.
+    pub backdrop: Option<Color>,
+    /// WGSL source replacing the default fragment stage of the overlay shader.
+    ///
+    /// It must define `fs_main` with the same inputs and output as the default
+    /// one, and can use the `globals`, `glyph_atlas` and `glyph_coverage`
+    /// declarations of the default shader.
+    pub fragment_override: Option<Cow<'static, str>>,
 }
 
 impl Default for RendererOptions {
@@ -23,10 +50,62 @@ impl Default for RendererOptions {
             sample_count: 1,
             y_flip: true,
             scale_factor: 1.0,
+            filtered_sampling: false,
+            double_buffering: false,
+            backdrop: None,
+            color_space: ColorSpace::Auto,
+            fragment_override: None,
         }
     }
 }
 
+/// The color space the overlay is rendered in.
+///
+/// The colors of the overlay are sRGB-encoded. They must be converted to
+/// linear when the GPU converts the output back to sRGB, which is the case
+/// with `*Srgb` target formats.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Linear if the target format is an sRGB format, sRGB otherwise.
+    #[default]
+    Auto,
+    /// Colors are written as is.
+    Srgb,
+    /// Colors are converted to linear in the shader.
+    Linear,
+}
+
+impl ColorSpace {
+    /// Whether the shader should output linear colors when rendering to a
+    /// target of the provided format.
+    pub fn is_linear(self, target_format: wgpu::TextureFormat) -> bool {
+        match self {
+            ColorSpace::Auto => target_format.is_srgb(),
+            ColorSpace::Srgb => false,
+            ColorSpace::Linear => true,
+        }
+    }
+}
+
+/// Minimum number of elements allocated for vertex and index buffers.
+const MIN_BUFFER_CAPACITY: usize = 1024;
+
+/// Returns the number of elements to allocate for a buffer that must hold
+/// `required` elements, or `capacity` if the current allocation is large enough.
+///
+/// Buffers grow with some headroom to avoid reallocating on each frame that is
+/// slightly busier than the previous ones. Capacities are multiples of 4 so that
+/// buffers of `u16` indices have a valid size for copies.
+pub fn grow_capacity(capacity: usize, required: usize) -> usize {
+    if required <= capacity {
+        return capacity;
+    }
+
+    (required + required / 2)
+        .max(MIN_BUFFER_CAPACITY)
+        .next_multiple_of(4)
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ShaderGlobals {
@@ -36,10 +115,62 @@ pub struct ShaderGlobals {
     pub y_flip: f32,
 }
 
-pub fn shader_src() -> String {
+/// The error returned when the overlay shader fails to compile.
+#[derive(Clone, Debug)]
+pub struct ShaderError {
+    message: String,
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+/// Parses and validates the shader, so that mistakes in a fragment override
+/// are reported as an error rather than a device error.
+#[cfg(feature = "wgpu")]
+pub fn validate_shader(src: &str) -> Result<(), ShaderError> {
+    let module = naga::front::wgsl::parse_str(src).map_err(|e| ShaderError {
+        message: e.emit_to_string(src),
+    })?;
+
+    naga::valid::Validator::new(
+        naga::valid::ValidationFlags::all(),
+        naga::valid::Capabilities::default(),
+    )
+    .validate(&module)
+    .map_err(|e| ShaderError {
+        message: e.emit_to_string(src),
+    })?;
+
+    let has_fragment_stage = module
+        .entry_points
+        .iter()
+        .any(|ep| ep.name == "fs_main" && ep.stage == naga::ShaderStage::Fragment);
+    if !has_fragment_stage {
+        return Err(ShaderError {
+            message: "missing fragment entry point fs_main".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// The source of the mesh shader, with either the default fragment stage or
+/// `fragment_override`.
+pub fn shader_src(fragment_override: Option<&str>) -> String {
+    let fragment = fragment_override.unwrap_or(DEFAULT_FRAGMENT_SRC);
     format!(
         "
 const ATLAS_SIZE: f32 = {ATLAS_WIDTH}.0;
+const DISTANCE_FIELD: bool = {DISTANCE_FIELD};
+const DISTANCE_SPREAD: f32 = {DISTANCE_SPREAD:?};
+
+override LINEAR_OUTPUT: bool = false;
+override FILTERED_SAMPLING: bool = false;
 
 struct Globals {{
     target_size: vec2f,
@@ -66,13 +197,17 @@ struct VertexOutput {{
         f32(uv_color.x & 0xFFFFu)
     );
 
-    let color = vec4f(
+    var color = vec4f(
         f32((uv_color.y >> 24u) & 0xFFu),
         f32((uv_color.y >> 16u) & 0xFFu),
         f32((uv_color.y >>  8u) & 0xFFu),
         f32(uv_color.y & 0xFFu) * globals.opacity,
     ) / 255.0;
 
+    if LINEAR_OUTPUT {{
+        color = vec4f(srgb_to_linear(color.rgb), color.a);
+    }}
+
     var screen_pos = ((position * globals.scale) / globals.target_size) * 2.0 - 1.0;
     screen_pos.y *= globals.y_flip;
 
@@ -83,13 +218,123 @@ struct VertexOutput {{
     );
 }}
 
+fn srgb_to_linear(srgb: vec3f) -> vec3f {{
+    let low = srgb / 12.92;
+    let high = pow((srgb + 0.055) / 1.055, vec3f(2.4));
+    return select(high, low, srgb <= vec3f(0.04045));
+}}
+
+fn glyph_coverage(uv: vec2f) -> f32 {{
+    if !DISTANCE_FIELD && !FILTERED_SAMPLING {{
+        return textureLoad(glyph_atlas, vec2u(uv), 0).r;
+    }}
+
+    // Bilinear filtering, so that glyphs stay smooth when scaled. Glyphs are
+    // separated by at least one transparent texel in the atlas, so filtering
+    // doesn't bleed their neighbors in.
+    let p = uv - 0.5;
+    let f = fract(p);
+    let last = vec2i(textureDimensions(glyph_atlas)) - 1;
+    let p0 = clamp(vec2i(floor(p)), vec2i(0), last);
+    let p1 = clamp(vec2i(floor(p)) + 1, vec2i(0), last);
+    let top = mix(
+        textureLoad(glyph_atlas, p0, 0).r,
+        textureLoad(glyph_atlas, vec2i(p1.x, p0.y), 0).r,
+        f.x
+    );
+    let bottom = mix(
+        textureLoad(glyph_atlas, vec2i(p0.x, p1.y), 0).r,
+        textureLoad(glyph_atlas, p1, 0).r,
+        f.x
+    );
+    let value = mix(top, bottom, f.y);
+    if !DISTANCE_FIELD {{
+        return value;
+    }}
+
+    let distance = (value - 0.5) * 2.0 * DISTANCE_SPREAD * globals.scale;
+
+    return clamp(distance + 0.5, 0.0, 1.0);
+}}
+
+{fragment}
+"
+    )
+}
+
+const DEFAULT_FRAGMENT_SRC: &str = "
 @fragment fn fs_main(
     @location(0) color: vec4f,
     @location(1) uv: vec2f,
-) -> @location(0) vec4f {{
-    let texel = textureLoad(glyph_atlas, vec2u(uv), 0).r;
-    return color * color.a * texel;
-}}
+) -> @location(0) vec4f {
+    return color * color.a * glyph_coverage(uv);
+}
+";
+
+/// Draws a texture in a rectangle of the target, see `Renderer::composite`.
+pub fn composite_shader_src() -> &'static str {
+    "
+struct Composite {
+    // Left, top, right and bottom edges in normalized device coordinates.
+    rect: vec4f,
+};
+
+@group(0) @binding(0) var<uniform> params: Composite;
+@group(0) @binding(1) var overlay: texture_2d<f32>;
+
+struct VertexOutput {
+    @builtin(position) position: vec4f,
+    @location(0) uv: vec2f,
+};
+
+@vertex fn vs_main(@builtin(vertex_index) idx: u32) -> VertexOutput {
+    let corner = vec2f(f32(idx & 1u), f32(idx >> 1u));
+    let position = mix(params.rect.xy, params.rect.zw, corner);
+    let uv = corner * vec2f(textureDimensions(overlay));
+
+    return VertexOutput(vec4f(position, 0.0, 1.0), uv);
+}
+
+@fragment fn fs_main(@location(0) uv: vec2f) -> @location(0) vec4f {
+    return textureLoad(overlay, vec2u(uv), 0);
+}
 "
-    )
+}
+
+#[test]
+fn buffer_growth() {
+    assert_eq!(grow_capacity(0, 0), 0);
+    assert_eq!(grow_capacity(0, 10), MIN_BUFFER_CAPACITY);
+    assert_eq!(grow_capacity(2048, 2048), 2048);
+    assert_eq!(grow_capacity(2048, 2049), 3076);
+    assert_eq!(grow_capacity(2048, 1000), 2048);
+}
+
+#[test]
+fn color_space() {
+    use wgpu::TextureFormat::*;
+    assert!(ColorSpace::Auto.is_linear(Bgra8UnormSrgb));
+    assert!(!ColorSpace::Auto.is_linear(Bgra8Unorm));
+    assert!(!ColorSpace::Srgb.is_linear(Rgba8UnormSrgb));
+    assert!(ColorSpace::Linear.is_linear(Rgba16Float));
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn fragment_override() {
+    validate_shader(&shader_src(None)).unwrap();
+
+    let grayscale = "
+@fragment fn fs_main(
+    @location(0) color: vec4f,
+    @location(1) uv: vec2f,
+) -> @location(0) vec4f {
+    let luma = dot(color.rgb, vec3f(0.2126, 0.7152, 0.0722));
+    return vec4f(vec3f(luma), color.a) * color.a * glyph_coverage(uv);
+}
+";
+    validate_shader(&shader_src(Some(grayscale))).unwrap();
+
+    assert!(validate_shader(&shader_src(Some("fn fs_main() {}"))).is_err());
+    assert!(validate_shader(&shader_src(Some("@fragment fn fs_main("))).is_err());
 }
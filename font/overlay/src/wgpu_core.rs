@@ -144,7 +144,7 @@ impl Renderer {
 
         let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Debug overlay"),
-            source: wgpu::ShaderSource::Wgsl(shader_src().into()),
+            source: wgpu::ShaderSource::Wgsl(shader_src(None).into()),
         });
 
         let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
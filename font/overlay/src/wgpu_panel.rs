@@ -0,0 +1,124 @@
+use crate::{wgpu_counters, Column, Counters, Overlay, OverlayItem, Point, Row, Table};
+
+/// Registers the counters updated by `update_wgpu_internal_counters` in a
+/// group named "wgpu", with history enabled for the memory counters.
+pub fn register_wgpu_counters(counters: &mut Counters) -> wgpu_counters::Ids {
+    let ids = wgpu_counters::register("wgpu", counters);
+    for id in [
+        ids.buffer_memory(),
+        ids.texture_memory(),
+        ids.memory_allocations(),
+    ] {
+        counters.enable_history(id);
+    }
+
+    ids
+}
+
+/// A prebuilt view of wgpu's internal counters: resource counts followed by
+/// memory usage with history graphs.
+///
+/// # Example
+///
+/// ```no_run
+/// use debug_overlay::*;
+///
+/// # fn frame(device: &::wgpu::Device, overlay: &mut Overlay, counters: &mut Counters, ids: wgpu_counters::Ids) {
+/// // Once, at initialization:
+/// // let ids = register_wgpu_counters(&mut counters);
+///
+/// // Each frame:
+/// update_wgpu_internal_counters(counters, ids, &device.get_internal_counters());
+/// counters.update();
+///
+/// overlay.draw_item(&WgpuPanel::new(counters, ids));
+/// # }
+/// ```
+pub struct WgpuPanel<'a> {
+    pub counters: &'a Counters,
+    pub ids: wgpu_counters::Ids,
+}
+
+impl<'a> WgpuPanel<'a> {
+    pub fn new(counters: &'a Counters, ids: wgpu_counters::Ids) -> Self {
+        WgpuPanel { counters, ids }
+    }
+}
+
+const RESOURCE_COLUMNS: &[Column] = &[Column::name(), Column::value()];
+
+const MEMORY_COLUMNS: &[Column] = &[
+    Column::name(),
+    Column::value().with_unit(),
+    Column::max().with_unit(),
+    Column::history_graph(),
+];
+
+impl<'a> OverlayItem for WgpuPanel<'a> {
+    fn draw(&self, origin: Point, overlay: &mut Overlay) -> (Point, Point) {
+        let ids = self.ids;
+        let counter = |id| Row::Counter(self.counters.get_counter(id));
+        let resources: Vec<Row> = [
+            ids.buffers(),
+            ids.textures(),
+            ids.texture_views(),
+            ids.bind_groups(),
+            ids.render_pipelines(),
+            ids.compute_pipelines(),
+            ids.samplers(),
+            ids.shader_modules(),
+            ids.query_sets(),
+        ]
+        .into_iter()
+        .map(counter)
+        .collect();
+        let memory: Vec<Row> = [
+            ids.buffer_memory(),
+            ids.texture_memory(),
+            ids.memory_allocations(),
+        ]
+        .into_iter()
+        .map(counter)
+        .collect();
+
+        let resource_table = Table {
+            columns: RESOURCE_COLUMNS,
+            rows: &resources,
+            labels: false,
+            sort: None,
+        };
+        let mut rect = resource_table.draw(origin, overlay);
+
+        let memory_origin = Point {
+            x: origin.x,
+            y: rect.1.y + overlay.style.margin,
+        };
+        let memory_table = Table {
+            columns: MEMORY_COLUMNS,
+            rows: &memory,
+            labels: false,
+            sort: None,
+        };
+        let memory_rect = memory_table.draw(memory_origin, overlay);
+
+        rect.1.x = rect.1.x.max(memory_rect.1.x);
+        rect.1.y = rect.1.y.max(memory_rect.1.y);
+
+        rect
+    }
+}
+
+#[test]
+fn wgpu_panel() {
+    let mut counters = Counters::new(60);
+    let ids = register_wgpu_counters(&mut counters);
+    assert!(counters
+        .get_counter(ids.texture_memory())
+        .history()
+        .is_some());
+    assert!(counters.get_counter(ids.textures()).history().is_none());
+
+    let mut overlay = Overlay::new();
+    let rect = WgpuPanel::new(&counters, ids).draw(Point { x: 0, y: 0 }, &mut overlay);
+    assert!(rect.1.y > rect.0.y);
+}
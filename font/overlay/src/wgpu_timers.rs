@@ -0,0 +1,230 @@
+//! GPU pass timings using `wgpu` timestamp queries.
+//!
+//! Requires the device to be created with `wgpu::Features::TIMESTAMP_QUERY`.
+//!
+//! Each frame:
+//! - pass `GpuTimers::render_pass_timestamp_writes` (or the compute variant) to
+//!   the passes to measure,
+//! - call `GpuTimers::resolve` on the last command encoder of the frame,
+//! - submit the command buffers and call `GpuTimers::map_results`,
+//! - call `GpuTimers::update_counters` before `Counters::update`.
+//!
+//! Results are read back asynchronously so the counters lag a few frames
+//! behind. The application is responsible for polling the device.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::{CounterId, Counters};
+
+/// Identifies a timer registered with `GpuTimers::register`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GpuTimerId(pub u32);
+
+/// The number of frames that can be in flight before results are dropped.
+const READBACK_BUFFERS: usize = 3;
+
+enum ReadbackState {
+    Free,
+    /// The copy was recorded, the buffer can be mapped after submission.
+    Resolved,
+    Mapping(Arc<AtomicBool>),
+}
+
+struct Readback {
+    buffer: wgpu::Buffer,
+    state: ReadbackState,
+    /// The timers written in the frame this buffer holds the results of.
+    timers: Vec<GpuTimerId>,
+}
+
+/// Measures the duration of render and compute passes and writes them in
+/// milliseconds into counters.
+pub struct GpuTimers {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readbacks: Vec<Readback>,
+    counters: Vec<CounterId>,
+    written: Vec<GpuTimerId>,
+    /// Nanoseconds per timestamp tick.
+    period: f32,
+}
+
+impl GpuTimers {
+    /// Creates timers for up to `capacity` passes per frame.
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, capacity: u32) -> Self {
+        let query_count = capacity * 2;
+        let size = query_count as u64 * wgpu::QUERY_SIZE as u64;
+
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("Debug overlay timestamps"),
+            ty: wgpu::QueryType::Timestamp,
+            count: query_count,
+        });
+        let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug overlay timestamps resolve"),
+            size,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readbacks = (0..READBACK_BUFFERS)
+            .map(|_| Readback {
+                buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Debug overlay timestamps readback"),
+                    size,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                state: ReadbackState::Free,
+                timers: Vec::new(),
+            })
+            .collect();
+
+        GpuTimers {
+            query_set,
+            resolve_buffer,
+            readbacks,
+            counters: Vec::new(),
+            written: Vec::new(),
+            period: queue.get_timestamp_period(),
+        }
+    }
+
+    /// Registers a timer which writes its measurements into the provided counter.
+    pub fn register(&mut self, counter: CounterId) -> GpuTimerId {
+        assert!(
+            (self.counters.len() as u32) < self.query_set_capacity(),
+            "Registered too many GPU timers"
+        );
+        self.counters.push(counter);
+
+        GpuTimerId(self.counters.len() as u32 - 1)
+    }
+
+    fn query_set_capacity(&self) -> u32 {
+        (self.resolve_buffer.size() / wgpu::QUERY_SIZE as u64 / 2) as u32
+    }
+
+    /// Timestamp writes measuring a render pass with the provided timer.
+    pub fn render_pass_timestamp_writes(
+        &mut self,
+        timer: GpuTimerId,
+    ) -> wgpu::RenderPassTimestampWrites<'_> {
+        self.written.push(timer);
+        wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(timer.0 * 2),
+            end_of_pass_write_index: Some(timer.0 * 2 + 1),
+        }
+    }
+
+    /// Timestamp writes measuring a compute pass with the provided timer.
+    pub fn compute_pass_timestamp_writes(
+        &mut self,
+        timer: GpuTimerId,
+    ) -> wgpu::ComputePassTimestampWrites<'_> {
+        self.written.push(timer);
+        wgpu::ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(timer.0 * 2),
+            end_of_pass_write_index: Some(timer.0 * 2 + 1),
+        }
+    }
+
+    /// Records the commands copying this frame's timestamps for readback.
+    ///
+    /// Must be called after the measured passes, typically at the end of the
+    /// frame's last command encoder. If all readback buffers are still in use
+    /// the frame's measurements are dropped.
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if self.written.is_empty() {
+            return;
+        }
+
+        let free = self
+            .readbacks
+            .iter_mut()
+            .find(|r| matches!(r.state, ReadbackState::Free));
+        if let Some(readback) = free {
+            let query_count = self.counters.len() as u32 * 2;
+            let size = query_count as u64 * wgpu::QUERY_SIZE as u64;
+            encoder.resolve_query_set(&self.query_set, 0..query_count, &self.resolve_buffer, 0);
+            encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &readback.buffer, 0, size);
+
+            readback.state = ReadbackState::Resolved;
+            readback.timers.clear();
+            readback.timers.extend_from_slice(&self.written);
+        }
+
+        self.written.clear();
+    }
+
+    /// Starts reading back the timestamps resolved in this frame.
+    ///
+    /// Must be called after submitting the command encoder passed to `resolve`.
+    pub fn map_results(&mut self) {
+        for readback in &mut self.readbacks {
+            if !matches!(readback.state, ReadbackState::Resolved) {
+                continue;
+            }
+            let ready = Arc::new(AtomicBool::new(false));
+            let ready2 = ready.clone();
+            readback
+                .buffer
+                .slice(..)
+                .map_async(wgpu::MapMode::Read, move |result| {
+                    if result.is_ok() {
+                        ready2.store(true, Ordering::Release);
+                    }
+                });
+            readback.state = ReadbackState::Mapping(ready);
+        }
+    }
+
+    /// Writes the measurements that are available into the counters.
+    pub fn update_counters(&mut self, counters: &mut Counters) {
+        for readback in &mut self.readbacks {
+            let ReadbackState::Mapping(ready) = &readback.state else {
+                continue;
+            };
+            if !ready.load(Ordering::Acquire) {
+                continue;
+            }
+
+            {
+                let data = readback.buffer.slice(..).get_mapped_range();
+                let timestamps: &[u64] = bytemuck::cast_slice(&data);
+                for timer in &readback.timers {
+                    let idx = timer.0 as usize * 2;
+                    let (Some(&start), Some(&end)) = (timestamps.get(idx), timestamps.get(idx + 1))
+                    else {
+                        continue;
+                    };
+                    let ms = timestamp_delta_ms(start, end, self.period);
+                    counters.set(self.counters[timer.0 as usize], ms);
+                }
+            }
+
+            readback.buffer.unmap();
+            readback.state = ReadbackState::Free;
+        }
+    }
+}
+
+/// Converts two timestamps into a duration in milliseconds.
+fn timestamp_delta_ms(start: u64, end: u64, period: f32) -> Option<f32> {
+    if end < start {
+        return None;
+    }
+
+    Some(((end - start) as f64 * period as f64 / 1_000_000.0) as f32)
+}
+
+#[test]
+fn timestamp_deltas() {
+    assert_eq!(timestamp_delta_ms(1_000, 3_001_000, 1.0), Some(3.0));
+    assert_eq!(timestamp_delta_ms(0, 1_000_000, 2.5), Some(2.5));
+    assert_eq!(timestamp_delta_ms(10, 5, 1.0), None);
+}
@@ -0,0 +1,248 @@
+use crate::svg::{rectangle, Circle, LineSegment, Path, PathOp, Polygon, Rectangle, Text};
+
+/// Maps coordinates from the space of the data being dumped to SVG
+/// coordinates, where the Y axis points down.
+///
+/// A point `p` is mapped to `origin + p * scale`, after negating `p.y` if
+/// `flip_y` is set. Sizes such as widths and radii are scaled, while font
+/// sizes and stroke widths are left as they are.
+///
+/// ```
+/// use svg_fmt::*;
+///
+/// // Show the [-1, 1] square in the middle of a 200x200 document, Y up.
+/// let coords = CoordinateSystem::new(100.0, 100.0, 100.0).y_up();
+/// assert_eq!(coords.point(0.5, 0.5), [150.0, 50.0]);
+///
+/// let square = coords.apply(rectangle(-1.0, -1.0, 2.0, 2.0));
+/// assert_eq!((square.x, square.y, square.w, square.h), (0.0, 0.0, 200.0, 200.0));
+///
+/// let label = coords.apply(text(0.0, 1.0, "top"));
+/// # let _ = label;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CoordinateSystem {
+    /// Where the origin is in the SVG document.
+    pub origin: [f32; 2],
+    /// The size of a unit in the SVG document.
+    pub scale: f32,
+    /// Whether the Y axis points up.
+    pub flip_y: bool,
+}
+
+impl CoordinateSystem {
+    pub fn new(origin_x: f32, origin_y: f32, scale: f32) -> Self {
+        CoordinateSystem {
+            origin: [origin_x, origin_y],
+            scale,
+            flip_y: false,
+        }
+    }
+
+    /// Makes the Y axis point up, as is usual in math and geometry.
+    pub fn y_up(mut self) -> Self {
+        self.flip_y = true;
+        self
+    }
+
+    pub fn point(&self, x: f32, y: f32) -> [f32; 2] {
+        let y = if self.flip_y { -y } else { y };
+        [
+            self.origin[0] + x * self.scale,
+            self.origin[1] + y * self.scale,
+        ]
+    }
+
+    pub fn points<T: Copy + Into<[f32; 2]>>(&self, points: &[T]) -> Vec<[f32; 2]> {
+        points
+            .iter()
+            .map(|p| {
+                let [x, y] = (*p).into();
+                self.point(x, y)
+            })
+            .collect()
+    }
+
+    pub fn length(&self, l: f32) -> f32 {
+        l * self.scale.abs()
+    }
+
+    /// The rectangle from `(x, y)` to `(x + w, y + h)`.
+    ///
+    /// With `flip_y`, `(x, y)` is the bottom-left corner of the rectangle.
+    pub fn rect(&self, x: f32, y: f32, w: f32, h: f32) -> Rectangle {
+        let [x0, y0] = self.point(x, y);
+        let [x1, y1] = self.point(x + w, y + h);
+        rectangle(x0.min(x1), y0.min(y1), (x1 - x0).abs(), (y1 - y0).abs())
+    }
+
+    /// Maps the coordinates of a shape, keeping its style.
+    pub fn apply<T: Transform>(&self, shape: T) -> T {
+        shape.transform(self)
+    }
+}
+
+/// Shapes which coordinates can be mapped by a `CoordinateSystem`.
+pub trait Transform {
+    fn transform(self, coords: &CoordinateSystem) -> Self;
+}
+
+impl Transform for Rectangle {
+    fn transform(self, coords: &CoordinateSystem) -> Self {
+        let rect = coords.rect(self.x, self.y, self.w, self.h);
+        Rectangle {
+            x: rect.x,
+            y: rect.y,
+            w: rect.w,
+            h: rect.h,
+            border_radius: coords.length(self.border_radius),
+            ..self
+        }
+    }
+}
+
+impl Transform for Circle {
+    fn transform(self, coords: &CoordinateSystem) -> Self {
+        let [x, y] = coords.point(self.x, self.y);
+        Circle {
+            x,
+            y,
+            radius: coords.length(self.radius),
+            ..self
+        }
+    }
+}
+
+impl Transform for Polygon {
+    fn transform(mut self, coords: &CoordinateSystem) -> Self {
+        self.points = coords.points(&self.points);
+        self
+    }
+}
+
+impl Transform for LineSegment {
+    fn transform(self, coords: &CoordinateSystem) -> Self {
+        let [x1, y1] = coords.point(self.x1, self.y1);
+        let [x2, y2] = coords.point(self.x2, self.y2);
+        LineSegment {
+            x1,
+            y1,
+            x2,
+            y2,
+            ..self
+        }
+    }
+}
+
+impl Transform for Path {
+    fn transform(mut self, coords: &CoordinateSystem) -> Self {
+        let p = |x, y| coords.point(x, y);
+        for op in &mut self.ops {
+            *op = match *op {
+                PathOp::MoveTo { x, y } => {
+                    let [x, y] = p(x, y);
+                    PathOp::MoveTo { x, y }
+                }
+                PathOp::LineTo { x, y } => {
+                    let [x, y] = p(x, y);
+                    PathOp::LineTo { x, y }
+                }
+                PathOp::QuadraticTo {
+                    ctrl_x,
+                    ctrl_y,
+                    x,
+                    y,
+                } => {
+                    let [ctrl_x, ctrl_y] = p(ctrl_x, ctrl_y);
+                    let [x, y] = p(x, y);
+                    PathOp::QuadraticTo {
+                        ctrl_x,
+                        ctrl_y,
+                        x,
+                        y,
+                    }
+                }
+                PathOp::CubicTo {
+                    ctrl1_x,
+                    ctrl1_y,
+                    ctrl2_x,
+                    ctrl2_y,
+                    x,
+                    y,
+                } => {
+                    let [ctrl1_x, ctrl1_y] = p(ctrl1_x, ctrl1_y);
+                    let [ctrl2_x, ctrl2_y] = p(ctrl2_x, ctrl2_y);
+                    let [x, y] = p(x, y);
+                    PathOp::CubicTo {
+                        ctrl1_x,
+                        ctrl1_y,
+                        ctrl2_x,
+                        ctrl2_y,
+                        x,
+                        y,
+                    }
+                }
+                PathOp::Close => PathOp::Close,
+            };
+        }
+        self
+    }
+}
+
+impl Transform for Text {
+    fn transform(self, coords: &CoordinateSystem) -> Self {
+        let [x, y] = coords.point(self.x, self.y);
+        Text { x, y, ..self }
+    }
+}
+
+#[test]
+fn coordinate_system() {
+    use crate::svg::{line_segment, path, polygon, text, Style};
+
+    let coords = CoordinateSystem::new(10.0, 100.0, 2.0).y_up();
+    assert_eq!(coords.point(0.0, 0.0), [10.0, 100.0]);
+    assert_eq!(coords.point(5.0, 20.0), [20.0, 60.0]);
+    assert_eq!(coords.length(3.0), 6.0);
+
+    // The rectangle stays positive-sized, its top edge comes from y + h.
+    let rect = coords.apply(rectangle(0.0, 0.0, 5.0, 10.0).border_radius(1.0));
+    assert_eq!((rect.x, rect.y, rect.w, rect.h), (10.0, 80.0, 10.0, 20.0));
+    assert_eq!(rect.border_radius, 2.0);
+
+    let c = coords.apply(Circle {
+        x: 1.0,
+        y: 1.0,
+        radius: 4.0,
+        style: Style::default(),
+        comment: None,
+    });
+    assert_eq!((c.x, c.y, c.radius), (12.0, 98.0, 8.0));
+
+    let poly = coords.apply(polygon(&[[0.0, 0.0], [1.0, 2.0]]));
+    assert_eq!(poly.points, vec![[10.0, 100.0], [12.0, 96.0]]);
+
+    let line = coords.apply(line_segment(0.0, 1.0, 2.0, 3.0).width(3.0));
+    assert_eq!(
+        (line.x1, line.y1, line.x2, line.y2),
+        (10.0, 98.0, 14.0, 94.0)
+    );
+    assert_eq!(line.width, 3.0);
+
+    let p = coords.apply(path().move_to(1.0, 1.0).line_to(2.0, 2.0).close());
+    assert_eq!(
+        p.to_string(),
+        path()
+            .move_to(12.0, 98.0)
+            .line_to(14.0, 96.0)
+            .close()
+            .to_string()
+    );
+
+    let t = coords.apply(text(1.0, 1.0, "a").size(12.0));
+    assert_eq!((t.x, t.y, t.size), (12.0, 98.0, 12.0));
+
+    // Without flipping, only the origin and scale apply.
+    let coords = CoordinateSystem::new(1.0, 1.0, 10.0);
+    assert_eq!(coords.point(1.0, 2.0), [11.0, 21.0]);
+}
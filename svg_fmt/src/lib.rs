@@ -1,5 +1,11 @@
+mod coordinates;
 mod layout;
+mod simplify;
 mod svg;
+mod writer;
 
+pub use coordinates::*;
 pub use layout::*;
+pub use simplify::*;
 pub use svg::*;
+pub use writer::*;
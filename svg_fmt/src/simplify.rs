@@ -0,0 +1,104 @@
+use crate::svg::Polygon;
+
+/// Removes the points of a polyline that are within `tolerance` of the
+/// simplified line, using the Douglas-Peucker algorithm.
+///
+/// The first and last points are always kept. This is useful to dump long
+/// traces, which would otherwise produce files too large to be displayed.
+///
+/// ```
+/// use svg_fmt::simplify;
+///
+/// let points = [[0.0, 0.0], [1.0, 0.1], [2.0, -0.1], [3.0, 5.0], [4.0, 6.0]];
+/// assert_eq!(
+///     simplify(&points, 0.5),
+///     vec![[0.0, 0.0], [2.0, -0.1], [3.0, 5.0], [4.0, 6.0]],
+/// );
+/// ```
+pub fn simplify(points: &[[f32; 2]], tolerance: f32) -> Vec<[f32; 2]> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Ranges left to simplify, using a stack rather than recursion to support
+    // very long polylines.
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let mut max_distance = 0.0;
+        let mut farthest = first;
+        for i in first + 1..last {
+            let d = distance_to_segment(points[i], points[first], points[last]);
+            if d > max_distance {
+                max_distance = d;
+                farthest = i;
+            }
+        }
+
+        if max_distance > tolerance {
+            keep[farthest] = true;
+            stack.push((first, farthest));
+            stack.push((farthest, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(p, _)| *p)
+        .collect()
+}
+
+impl Polygon {
+    /// Removes the points that are within `tolerance` of the simplified
+    /// outline, see `simplify`.
+    pub fn simplify(mut self, tolerance: f32) -> Self {
+        self.points = simplify(&self.points, tolerance);
+        self
+    }
+}
+
+fn distance_to_segment(p: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    // Large coordinates lose too much precision in single precision.
+    let [px, py, ax, ay, bx, by] = [p[0], p[1], a[0], a[1], b[0], b[1]].map(f64::from);
+    let (abx, aby) = (bx - ax, by - ay);
+    let (apx, apy) = (px - ax, py - ay);
+    let len2 = abx * abx + aby * aby;
+    let t = if len2 > 0.0 {
+        ((apx * abx + apy * aby) / len2).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (dx, dy) = (apx - abx * t, apy - aby * t);
+
+    (dx * dx + dy * dy).sqrt() as f32
+}
+
+#[test]
+fn simplify_polyline() {
+    assert!(simplify(&[], 1.0).is_empty());
+    assert_eq!(simplify(&[[0.0, 0.0], [1.0, 1.0]], 1.0).len(), 2);
+
+    // A straight line only keeps its end points.
+    let line: Vec<[f32; 2]> = (0..100_000).map(|i| [i as f32, i as f32 * 0.5]).collect();
+    assert_eq!(simplify(&line, 0.01), vec![[0.0, 0.0], [99999.0, 49999.5]]);
+
+    // A zero tolerance only removes points exactly on the line.
+    let zigzag: Vec<[f32; 2]> = (0..10).map(|i| [i as f32, (i % 2) as f32]).collect();
+    assert_eq!(simplify(&zigzag, 0.0), zigzag);
+    assert_eq!(simplify(&zigzag, 1.0).len(), 2);
+
+    // Closed shapes start and end with the same point.
+    let square = [
+        [0.0, 0.0],
+        [10.0, 0.0],
+        [10.0, 10.0],
+        [0.0, 10.0],
+        [0.0, 0.0],
+    ];
+    assert_eq!(simplify(&square, 1.0), square);
+}
@@ -40,18 +40,13 @@ pub fn blue() -> Color {
 }
 
 /// `fill:{self}`
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Default, PartialEq)]
 pub enum Fill {
     Color(Color),
+    #[default]
     None,
 }
 
-impl Default for Fill {
-    fn default() -> Self {
-        Fill::None
-    }
-}
-
 /// `stroke:{self}`
 #[derive(Copy, Clone, PartialEq)]
 pub enum Stroke {
@@ -100,7 +95,7 @@ impl From<Fill> for Style {
         Style {
             fill,
             stroke: Stroke::None,
-            .. Default::default()
+            ..Default::default()
         }
     }
 }
@@ -110,7 +105,7 @@ impl From<Stroke> for Style {
         Style {
             fill: Fill::None,
             stroke,
-            .. Default::default()
+            ..Default::default()
         }
     }
 }
@@ -133,15 +128,15 @@ impl fmt::Display for Stroke {
     }
 }
 
-impl Into<Fill> for Color {
-    fn into(self) -> Fill {
-        Fill::Color(self)
+impl From<Color> for Fill {
+    fn from(color: Color) -> Fill {
+        Fill::Color(color)
     }
 }
 
-impl Into<Stroke> for Color {
-    fn into(self) -> Stroke {
-        Stroke::Color(self, 1.0)
+impl From<Color> for Stroke {
+    fn from(color: Color) -> Stroke {
+        Stroke::Color(color, 1.0)
     }
 }
 
@@ -329,7 +324,7 @@ pub struct Polygon {
 impl fmt::Display for Polygon {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, r#"<path d="#)?;
-        if self.points.len() > 0 {
+        if !self.points.is_empty() {
             write!(f, "M {} {} ", self.points[0][0], self.points[0][1])?;
             for &p in &self.points[1..] {
                 write!(f, "L {} {} ", p[0], p[1])?;
@@ -0,0 +1,104 @@
+use crate::svg::{indent, BeginSvg, EndSvg, Indentation};
+use std::fmt;
+use std::io::{self, BufWriter, Write};
+
+/// Writes an SVG document element by element to an `io::Write`.
+///
+/// The output is buffered and elements are formatted directly into it, which is
+/// much cheaper than formatting each of them into a string when dumping a large
+/// number of shapes. The document is closed by `finish`, or when the writer is
+/// dropped.
+///
+/// # Example
+///
+/// ```
+/// use svg_fmt::*;
+///
+/// let mut output = Vec::new();
+/// let mut writer = SvgWriter::new(&mut output, 800.0, 600.0).unwrap();
+/// for i in 0..100 {
+///     writer.push(rectangle(i as f32 * 8.0, 0.0, 6.0, 6.0).fill(red())).unwrap();
+/// }
+/// writer.finish().unwrap();
+/// ```
+pub struct SvgWriter<W: Write> {
+    /// `None` once the document is finished.
+    output: Option<BufWriter<W>>,
+    indentation: Indentation,
+}
+
+impl<W: Write> SvgWriter<W> {
+    /// Starts a document of the provided size.
+    pub fn new(output: W, w: f32, h: f32) -> io::Result<Self> {
+        let mut output = BufWriter::new(output);
+        writeln!(output, "{}", BeginSvg { w, h })?;
+
+        Ok(SvgWriter {
+            output: Some(output),
+            indentation: indent(1),
+        })
+    }
+
+    /// Writes an element on its own line, for example a `Rectangle` or a `Text`.
+    pub fn push(&mut self, element: impl fmt::Display) -> io::Result<()> {
+        let output = self.output.as_mut().unwrap();
+        writeln!(output, "{}{}", self.indentation, element)
+    }
+
+    /// Indents the elements pushed afterwards one more level.
+    pub fn indent(&mut self) {
+        self.indentation.push();
+    }
+
+    /// Reverts the last call to `indent`.
+    pub fn unindent(&mut self) {
+        if self.indentation.n > 1 {
+            self.indentation.pop();
+        }
+    }
+
+    /// Ends the document and returns the underlying output.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut output = self.output.take().unwrap();
+        writeln!(output, "{}", EndSvg)?;
+        output.into_inner().map_err(|e| e.into_error())
+    }
+}
+
+impl<W: Write> Drop for SvgWriter<W> {
+    fn drop(&mut self) {
+        if let Some(mut output) = self.output.take() {
+            let _ = writeln!(output, "{}", EndSvg);
+        }
+    }
+}
+
+#[test]
+fn svg_writer() {
+    use crate::svg::{comment, rectangle, text};
+
+    let mut output = Vec::new();
+    let mut writer = SvgWriter::new(&mut output, 100.0, 50.0).unwrap();
+    writer.push(rectangle(0.0, 0.0, 10.0, 10.0)).unwrap();
+    writer.indent();
+    writer.push(text(5.0, 5.0, "a")).unwrap();
+    writer.unindent();
+    writer.unindent();
+    writer.push(comment("b")).unwrap();
+    writer.finish().unwrap();
+
+    let expected = format!(
+        "{}\n    {}\n        {}\n    {}\n{}\n",
+        BeginSvg { w: 100.0, h: 50.0 },
+        rectangle(0.0, 0.0, 10.0, 10.0),
+        text(5.0, 5.0, "a"),
+        comment("b"),
+        EndSvg,
+    );
+    assert_eq!(String::from_utf8(output).unwrap(), expected);
+
+    // Dropping the writer also ends the document.
+    let mut output = Vec::new();
+    SvgWriter::new(&mut output, 10.0, 10.0).unwrap();
+    assert!(String::from_utf8(output).unwrap().ends_with("</svg>\n"));
+}